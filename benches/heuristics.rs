@@ -0,0 +1,95 @@
+//! Batched heuristics against their scalar versions, `cargo bench --bench heuristics`.
+//! Every evaluator runs over the same frontier a few times and the best time per state
+//! is reported.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use algoritmos_rust::cannibals::{
+    batch_heuristics, BoatSide, Cannibals, Missionaries, PackedWorldState, PuzzleSize, SideState,
+    WorldState,
+};
+use algoritmos_rust::n_puzzle::{Board, ManhattanBatch};
+use algoritmos_rust::rng::Rng;
+
+const RUNS: u32 = 20;
+
+/// Best time per item of `evaluate` over `RUNS` runs.
+fn measure<T>(items: usize, mut evaluate: impl FnMut() -> T) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(evaluate());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+        / items as u32
+}
+
+fn report(name: &str, scalar: Duration, batch: Duration) {
+    println!(
+        "{name:<24} scalar {:>8.1?}  batch {:>8.1?}  speedup {:.2}x",
+        scalar,
+        batch,
+        scalar.as_secs_f64() / batch.as_secs_f64()
+    );
+}
+
+fn n_puzzle(size: usize) {
+    let mut rng = Rng::new(7);
+    let boards = (0..10_000)
+        .map(|_| Board::random_solvable(size, &mut rng).unwrap())
+        .collect::<Vec<Board>>();
+    let batch = ManhattanBatch::new(size);
+
+    let scalar = measure(boards.len(), || {
+        boards
+            .iter()
+            .map(Board::manhattan_distance)
+            .collect::<Vec<usize>>()
+    });
+    let batched = measure(boards.len(), || batch.evaluate(black_box(&boards)));
+    report(&format!("n_puzzle {size}x{size}"), scalar, batched);
+}
+
+fn cannibals(size: PuzzleSize) {
+    let states = (0..=size.people)
+        .flat_map(|cannibals| (0..=size.people).map(move |missionaries| (cannibals, missionaries)))
+        .flat_map(|(cannibals, missionaries)| {
+            [BoatSide::LeftSide, BoatSide::RightSide].map(|boat_side| {
+                let left = SideState::new(Cannibals(cannibals), Missionaries(missionaries));
+                let right = SideState::new(
+                    Cannibals(size.people - cannibals),
+                    Missionaries(size.people - missionaries),
+                );
+                WorldState::new_sized(size, left, right, boat_side, vec![], 0).unwrap()
+            })
+        })
+        .collect::<Vec<WorldState>>();
+    let packed = states
+        .iter()
+        .map(|state| PackedWorldState::try_from(state).unwrap())
+        .collect::<Vec<PackedWorldState>>();
+
+    // The scalar side gets the states it works on unpacked, so only the evaluators are timed.
+    let scalar = measure(states.len(), || {
+        black_box(&states)
+            .iter()
+            .map(WorldState::get_heuristic)
+            .collect::<Vec<f32>>()
+    });
+    let batched = measure(packed.len(), || batch_heuristics(black_box(&packed)));
+    report(
+        &format!("cannibals {}/{}", size.people, size.boat_capacity),
+        scalar,
+        batched,
+    );
+}
+
+fn main() {
+    n_puzzle(3);
+    n_puzzle(4);
+    cannibals(PuzzleSize::CLASSIC);
+    cannibals(PuzzleSize::new(100, 10).unwrap());
+}
@@ -0,0 +1,18 @@
+//! Solves the cannibals puzzle with the algorithm, size and from the state given on the
+//! command line, `cargo run --bin ai-algorithms -- solve [--algorithm <name>]
+//! [--people <n>] [--boat <n>] [--initial "<state>"] [--output text|json] [--stats]
+//! [--verbose]`, or compares every algorithm on the same puzzle with
+//! `cargo run --bin ai-algorithms -- compare [--people <n>] [--boat <n>]
+//! [--initial "<state>"]`. `--no-color` disables the colors of both, `--help` lists the
+//! options.
+use std::error::Error;
+
+use algoritmos_rust::cli::Cli;
+use algoritmos_rust::Locale;
+use clap::Parser;
+
+pub fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+    println!("{}", cli.run(Locale::from_env())?);
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! Solves the cannibals puzzle with beam search,
+//! `cargo run --bin beam_search -- [--width <n>] [--verbose]`, then shows which beam
+//! widths still find a solution on larger puzzles and how long it is.
+use std::error::Error;
+
+use algoritmos_rust::search::{beam_search, beam_search_traced, bfs};
+use algoritmos_rust::terminal::Theme;
+use algoritmos_rust::{solver_report, DeadEnds, FixedCannibalsProblem, FixedWorldState, Locale};
+
+pub fn main() -> Result<(), Box<dyn Error>> {
+    let args = std::env::args().collect::<Vec<String>>();
+    let width = match args.iter().position(|arg| arg == "--width") {
+        Some(position) => args
+            .get(position + 1)
+            .ok_or("--width needs a value")?
+            .parse::<usize>()
+            .map_err(|error| format!("--width: {}", error))?,
+        None => 2,
+    };
+
+    let mut dead_ends = DeadEnds::default();
+    let solution = beam_search_traced(
+        &FixedCannibalsProblem::<3, 2>,
+        FixedWorldState::heuristic,
+        width,
+        &mut dead_ends,
+    );
+    let verbose = args.iter().any(|arg| arg == "--verbose" || arg == "-v");
+    let theme = Theme::from_env();
+    println!(
+        "{}",
+        solver_report(
+            solution.as_ref(),
+            &dead_ends,
+            &theme,
+            Locale::from_env(),
+            verbose
+        )?
+    );
+
+    println!("{}", theme.paint(theme.heading, "Steps by beam width:"));
+    let mut rows = steps_by_width::<4, 3>();
+    rows.extend(steps_by_width::<5, 3>());
+    let rows = rows
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.clone()))
+        .collect::<Vec<_>>();
+    println!("{}", theme.stats_table(&rows));
+    Ok(())
+}
+
+/// One row per beam width for the puzzle with `N` of each and a boat for `BOAT`, the
+/// last one being the optimal length.
+fn steps_by_width<const N: u8, const BOAT: u8>() -> Vec<(String, String)> {
+    let problem = FixedCannibalsProblem::<N, BOAT>;
+    let steps = |solution: Option<algoritmos_rust::search::Solution<_, _>>| {
+        solution.map_or("no solution".to_string(), |solution| {
+            format!("{} ({} expanded)", solution.len(), solution.stats.expanded)
+        })
+    };
+    let mut rows = [1, 2, 4]
+        .into_iter()
+        .map(|width| {
+            (
+                format!("width {} {}/{}", width, N, BOAT),
+                steps(beam_search(&problem, FixedWorldState::heuristic, width)),
+            )
+        })
+        .collect::<Vec<_>>();
+    rows.push((format!("bfs {}/{}", N, BOAT), steps(bfs(&problem))));
+    rows
+}
@@ -0,0 +1,388 @@
+//! Desktop visualizer, run with `cargo run --features gui --bin gui`.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+
+use algoritmos_rust::crossing::{Bank, CrossingProblem, CrossingState, JealousHusbands};
+use algoritmos_rust::hanoi::HanoiProblem;
+use algoritmos_rust::n_puzzle::{Board, NPuzzleProblem};
+use algoritmos_rust::search::{Cost, SearchProblem};
+use eframe::egui;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Domain {
+    NPuzzle,
+    Hanoi,
+    JealousHusbands,
+}
+
+impl Domain {
+    const ALL: [Domain; 3] = [Domain::NPuzzle, Domain::Hanoi, Domain::JealousHusbands];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Domain::NPuzzle => "8-puzzle",
+            Domain::Hanoi => "Towers of Hanoi (5 discs, 3 pegs)",
+            Domain::JealousHusbands => "Jealous husbands (3 couples)",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Algorithm {
+    BreadthFirst,
+    Greedy,
+    AStar,
+}
+
+impl Algorithm {
+    const ALL: [Algorithm; 3] = [Algorithm::BreadthFirst, Algorithm::Greedy, Algorithm::AStar];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Algorithm::BreadthFirst => "Breadth-first search",
+            Algorithm::Greedy => "Greedy best-first search",
+            Algorithm::AStar => "A*",
+        }
+    }
+}
+
+/// A search that can be advanced one expansion at a time.
+trait Explorer {
+    /// Expands one node, returns `false` once the search is over.
+    fn step(&mut self) -> bool;
+    fn expanded(&self) -> usize;
+    /// Frontier size after every expansion.
+    fn frontier_history(&self) -> &[usize];
+    /// Rendering of every state of the solution, once found.
+    fn solution(&self) -> Option<&[String]>;
+    fn is_done(&self) -> bool;
+}
+
+type Heuristic<S> = Box<dyn Fn(&S) -> f64>;
+type Render<S> = Box<dyn Fn(&S) -> String>;
+
+struct BestFirstExplorer<P: SearchProblem> {
+    problem: P,
+    algorithm: Algorithm,
+    heuristic: Heuristic<P::State>,
+    render: Render<P::State>,
+    /// Every generated state, its parent and its path cost.
+    nodes: Vec<(P::State, Option<usize>, P::Cost)>,
+    best_path_cost: HashMap<P::State, P::Cost>,
+    /// `(priority, insertion)`, priorities are never negative so their bits keep the order.
+    frontier: BinaryHeap<Reverse<(u64, usize)>>,
+    history: Vec<usize>,
+    solution: Option<Vec<String>>,
+    done: bool,
+}
+
+impl<P: SearchProblem> BestFirstExplorer<P> {
+    fn new(
+        problem: P,
+        algorithm: Algorithm,
+        heuristic: Heuristic<P::State>,
+        render: Render<P::State>,
+    ) -> Self {
+        let mut explorer = Self {
+            problem,
+            algorithm,
+            heuristic,
+            render,
+            nodes: vec![],
+            best_path_cost: HashMap::new(),
+            frontier: BinaryHeap::new(),
+            history: vec![],
+            solution: None,
+            done: false,
+        };
+        let initial_state = explorer.problem.initial_state();
+        explorer.push(initial_state, None, P::Cost::zero());
+        explorer
+    }
+
+    fn push(&mut self, state: P::State, parent: Option<usize>, path_cost: P::Cost) {
+        let priority = match self.algorithm {
+            Algorithm::BreadthFirst => 0.0,
+            Algorithm::Greedy => (self.heuristic)(&state),
+            Algorithm::AStar => path_cost.to_f64() + (self.heuristic)(&state),
+        };
+        self.best_path_cost.insert(state.clone(), path_cost);
+        self.frontier
+            .push(Reverse((priority.to_bits(), self.nodes.len())));
+        self.nodes.push((state, parent, path_cost));
+    }
+}
+
+impl<P: SearchProblem> Explorer for BestFirstExplorer<P> {
+    fn step(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        let Some(Reverse((_, current))) = self.frontier.pop() else {
+            self.done = true;
+            return false;
+        };
+        let (state, _, path_cost) = self.nodes[current].clone();
+        if self.best_path_cost[&state] < path_cost {
+            return true;
+        }
+        if self.problem.is_goal(&state) {
+            let mut path = vec![current];
+            while let Some(parent) = self.nodes[*path.last().unwrap()].1 {
+                path.push(parent);
+            }
+            path.reverse();
+            self.solution = Some(
+                path.iter()
+                    .map(|i| (self.render)(&self.nodes[*i].0))
+                    .collect(),
+            );
+            self.done = true;
+            return false;
+        }
+        let successors = self.problem.successors(&state).collect::<Vec<_>>();
+        for successor in successors {
+            if self.problem.is_dead_end(&successor.state) {
+                continue;
+            }
+            let successor_path_cost = path_cost.add(successor.cost);
+            let improves = match self.best_path_cost.get(&successor.state) {
+                None => true,
+                Some(best) => self.algorithm == Algorithm::AStar && successor_path_cost < *best,
+            };
+            if improves {
+                self.push(successor.state, Some(current), successor_path_cost);
+            }
+        }
+        self.history.push(self.frontier.len());
+        true
+    }
+
+    fn expanded(&self) -> usize {
+        self.history.len()
+    }
+
+    fn frontier_history(&self) -> &[usize] {
+        &self.history
+    }
+
+    fn solution(&self) -> Option<&[String]> {
+        self.solution.as_deref()
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+fn explorer(domain: Domain, algorithm: Algorithm) -> Box<dyn Explorer> {
+    match domain {
+        Domain::NPuzzle => {
+            let board = Board::try_from("8 6 7 2 5 4 3 0 1").expect("valid board");
+            Box::new(BestFirstExplorer::new(
+                NPuzzleProblem::new(board),
+                algorithm,
+                Box::new(|board: &Board| board.manhattan_distance() as f64),
+                Box::new(|board: &Board| board.to_string()),
+            ))
+        }
+        Domain::Hanoi => {
+            let problem = HanoiProblem::new(5, 3).expect("3 pegs");
+            let heuristic_problem = problem.clone();
+            Box::new(BestFirstExplorer::new(
+                problem,
+                algorithm,
+                Box::new(move |state| heuristic_problem.heuristic(state)),
+                Box::new(|state| state.to_string()),
+            ))
+        }
+        Domain::JealousHusbands => {
+            let problem = CrossingProblem::new(JealousHusbands::new(3, 2).expect("6 people"))
+                .expect("6 passengers");
+            let render_problem = problem.clone();
+            Box::new(BestFirstExplorer::new(
+                problem,
+                algorithm,
+                Box::new(|state: &CrossingState| {
+                    f64::from(6 - state.on_destination.count_ones()) / 2.0
+                }),
+                Box::new(move |state: &CrossingState| {
+                    let names = |people: Vec<_>| join(people.iter());
+                    format!(
+                        "start: {}\ndestination: {}\nboat: {:?}",
+                        names(render_problem.group(state, Bank::Start)),
+                        names(render_problem.group(state, Bank::Destination)),
+                        state.boat
+                    )
+                }),
+            ))
+        }
+    }
+}
+
+fn join<T: Display>(items: impl Iterator<Item = T>) -> String {
+    items
+        .map(|item| item.to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+struct Visualizer {
+    domain: Domain,
+    algorithm: Algorithm,
+    explorer: Box<dyn Explorer>,
+    running: bool,
+    expansions_per_frame: usize,
+    solution_step: usize,
+}
+
+impl Visualizer {
+    fn new() -> Self {
+        Self {
+            domain: Domain::NPuzzle,
+            algorithm: Algorithm::AStar,
+            explorer: explorer(Domain::NPuzzle, Algorithm::AStar),
+            running: false,
+            expansions_per_frame: 10,
+            solution_step: 0,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.explorer = explorer(self.domain, self.algorithm);
+        self.running = false;
+        self.solution_step = 0;
+    }
+
+    fn controls(&mut self, ui: &mut egui::Ui) {
+        let (domain, algorithm) = (self.domain, self.algorithm);
+        egui::ComboBox::from_label("Domain")
+            .selected_text(self.domain.name())
+            .show_ui(ui, |ui| {
+                for domain in Domain::ALL {
+                    ui.selectable_value(&mut self.domain, domain, domain.name());
+                }
+            });
+        egui::ComboBox::from_label("Algorithm")
+            .selected_text(self.algorithm.name())
+            .show_ui(ui, |ui| {
+                for algorithm in Algorithm::ALL {
+                    ui.selectable_value(&mut self.algorithm, algorithm, algorithm.name());
+                }
+            });
+        if (domain, algorithm) != (self.domain, self.algorithm) {
+            self.reset();
+        }
+
+        ui.separator();
+        ui.add(
+            egui::Slider::new(&mut self.expansions_per_frame, 1..=1000)
+                .text("expansions per frame"),
+        );
+        ui.horizontal(|ui| {
+            let label = if self.running { "Pause" } else { "Run" };
+            if ui
+                .add_enabled(!self.explorer.is_done(), egui::Button::new(label))
+                .clicked()
+            {
+                self.running = !self.running;
+            }
+            if ui
+                .add_enabled(!self.explorer.is_done(), egui::Button::new("Step"))
+                .clicked()
+            {
+                self.explorer.step();
+            }
+            if ui.button("Reset").clicked() {
+                self.reset();
+            }
+        });
+        ui.label(format!("expanded nodes: {}", self.explorer.expanded()));
+        ui.label(format!(
+            "frontier: {}",
+            self.explorer
+                .frontier_history()
+                .last()
+                .copied()
+                .unwrap_or(1)
+        ));
+        if self.explorer.is_done() && self.explorer.solution().is_none() {
+            ui.label("no solution was found!");
+        }
+    }
+
+    fn frontier_plot(&self, ui: &mut egui::Ui) {
+        let history = self.explorer.frontier_history();
+        let size = egui::vec2(ui.available_width(), 200.0);
+        let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
+        let rect = response.rect;
+        painter.rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+        let max = history.iter().copied().max().unwrap_or(1).max(1) as f32;
+        let points = history
+            .iter()
+            .enumerate()
+            .map(|(i, size)| {
+                let x = rect.left() + rect.width() * i as f32 / history.len().max(2) as f32;
+                let y = rect.bottom() - rect.height() * *size as f32 / max;
+                egui::pos2(x, y)
+            })
+            .collect::<Vec<egui::Pos2>>();
+        painter.add(egui::Shape::line(
+            points,
+            egui::Stroke::new(1.5, egui::Color32::LIGHT_BLUE),
+        ));
+        painter.text(
+            rect.left_top() + egui::vec2(4.0, 4.0),
+            egui::Align2::LEFT_TOP,
+            format!("frontier size (max {})", max),
+            egui::FontId::monospace(12.0),
+            ui.visuals().text_color(),
+        );
+    }
+
+    fn solution_viewer(&mut self, ui: &mut egui::Ui) {
+        let Some(solution) = self.explorer.solution() else {
+            return;
+        };
+        let last = solution.len() - 1;
+        ui.horizontal(|ui| {
+            if ui.button("<").clicked() {
+                self.solution_step = self.solution_step.saturating_sub(1);
+            }
+            ui.label(format!("state {}/{}", self.solution_step, last));
+            if ui.button(">").clicked() {
+                self.solution_step = (self.solution_step + 1).min(last);
+            }
+        });
+        ui.monospace(&solution[self.solution_step.min(last)]);
+    }
+}
+
+impl eframe::App for Visualizer {
+    fn ui(&mut self, ui: &mut egui::Ui, _frame: &mut eframe::Frame) {
+        if self.running {
+            for _ in 0..self.expansions_per_frame {
+                if !self.explorer.step() {
+                    self.running = false;
+                    break;
+                }
+            }
+            ui.ctx().request_repaint();
+        }
+        egui::Panel::left("controls").show(ui, |ui| self.controls(ui));
+        egui::CentralPanel::default().show(ui, |ui| {
+            self.frontier_plot(ui);
+            ui.separator();
+            self.solution_viewer(ui);
+        });
+    }
+}
+
+fn main() -> eframe::Result {
+    eframe::run_native(
+        "Search visualizer",
+        eframe::NativeOptions::default(),
+        Box::new(|_| Ok(Box::new(Visualizer::new()))),
+    )
+}
@@ -0,0 +1,36 @@
+//! Solves the cannibals puzzle with iterative deepening A*,
+//! `cargo run --bin ida_star -- [--verbose]`.
+use std::error::Error;
+
+use algoritmos_rust::search::ida_star_traced;
+use algoritmos_rust::terminal::Theme;
+use algoritmos_rust::{solver_report, DeadEnds, FixedCannibalsProblem, FixedWorldState, Locale};
+
+pub fn main() -> Result<(), Box<dyn Error>> {
+    let mut dead_ends = DeadEnds::default();
+    let outcome = ida_star_traced(
+        &FixedCannibalsProblem::<3, 2>,
+        FixedWorldState::heuristic,
+        &mut dead_ends,
+    );
+    let verbose = std::env::args().any(|arg| arg == "--verbose" || arg == "-v");
+    let theme = Theme::from_env();
+    println!(
+        "{}",
+        solver_report(
+            outcome.solution.as_ref(),
+            &dead_ends,
+            &theme,
+            Locale::from_env(),
+            verbose
+        )?
+    );
+    println!(
+        "{}",
+        theme.stats_table(&[
+            ("iterations", outcome.stats.iterations.to_string()),
+            ("re-expansions", outcome.stats.reexpansions.to_string()),
+        ])
+    );
+    Ok(())
+}
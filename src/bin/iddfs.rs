@@ -0,0 +1,78 @@
+//! Solves the cannibals puzzle with iterative deepening,
+//! `cargo run --bin iddfs -- [--increment <n>] [--max-depth <n>] [--verbose]`, then
+//! compares the frontier peaks of breadth-first, depth-first and iterative deepening
+//! searches on larger puzzles.
+use std::error::Error;
+
+use algoritmos_rust::search::{bfs, dfs, iddfs, iddfs_traced};
+use algoritmos_rust::terminal::Theme;
+use algoritmos_rust::{solver_report, DeadEnds, FixedCannibalsProblem, Locale};
+
+pub fn main() -> Result<(), Box<dyn Error>> {
+    let args = std::env::args().collect::<Vec<String>>();
+    let option = |name: &str, default: usize| match args.iter().position(|arg| arg == name) {
+        Some(position) => args
+            .get(position + 1)
+            .ok_or(format!("{} needs a value", name))?
+            .parse::<usize>()
+            .map_err(|error| format!("{}: {}", name, error)),
+        None => Ok(default),
+    };
+    let (increment, max_depth) = (option("--increment", 1)?, option("--max-depth", 100)?);
+
+    let mut dead_ends = DeadEnds::default();
+    let solution = iddfs_traced(
+        &FixedCannibalsProblem::<3, 2>,
+        increment,
+        max_depth,
+        &mut dead_ends,
+    );
+    let verbose = args.iter().any(|arg| arg == "--verbose" || arg == "-v");
+    let theme = Theme::from_env();
+    println!(
+        "{}",
+        solver_report(
+            solution.as_ref(),
+            &dead_ends,
+            &theme,
+            Locale::from_env(),
+            verbose
+        )?
+    );
+
+    println!("{}", theme.paint(theme.heading, "Frontier peaks:"));
+    let mut rows = frontier_peaks::<4, 3>(increment, max_depth);
+    rows.extend(frontier_peaks::<5, 3>(increment, max_depth));
+    rows.extend(frontier_peaks::<8, 4>(increment, max_depth));
+    let rows = rows
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.clone()))
+        .collect::<Vec<_>>();
+    println!("{}", theme.stats_table(&rows));
+    Ok(())
+}
+
+/// One row per algorithm for the puzzle with `N` of each and a boat for `BOAT`.
+fn frontier_peaks<const N: u8, const BOAT: u8>(
+    increment: usize,
+    max_depth: usize,
+) -> Vec<(String, String)> {
+    let problem = FixedCannibalsProblem::<N, BOAT>;
+    let peak = |solution: Option<algoritmos_rust::search::Solution<_, _>>| {
+        solution.map_or("no solution".to_string(), |solution| {
+            format!(
+                "{} ({} steps)",
+                solution.stats.frontier_peak,
+                solution.len()
+            )
+        })
+    };
+    vec![
+        (format!("bfs {}/{}", N, BOAT), peak(bfs(&problem))),
+        (format!("dfs {}/{}", N, BOAT), peak(dfs(&problem))),
+        (
+            format!("iddfs {}/{}", N, BOAT),
+            peak(iddfs(&problem, increment, max_depth)),
+        ),
+    ]
+}
@@ -0,0 +1,28 @@
+//! Solves the cannibals puzzle with recursive best-first search,
+//! `cargo run --bin rbfs -- [--verbose]`.
+use std::error::Error;
+
+use algoritmos_rust::search::rbfs_traced;
+use algoritmos_rust::terminal::Theme;
+use algoritmos_rust::{solver_report, DeadEnds, FixedCannibalsProblem, FixedWorldState, Locale};
+
+pub fn main() -> Result<(), Box<dyn Error>> {
+    let mut dead_ends = DeadEnds::default();
+    let solution = rbfs_traced(
+        &FixedCannibalsProblem::<3, 2>,
+        FixedWorldState::heuristic,
+        &mut dead_ends,
+    );
+    let verbose = std::env::args().any(|arg| arg == "--verbose" || arg == "-v");
+    println!(
+        "{}",
+        solver_report(
+            solution.as_ref(),
+            &dead_ends,
+            &Theme::from_env(),
+            Locale::from_env(),
+            verbose
+        )?
+    );
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! Runs a solver described by a TOML file, with
+//! `cargo run --features config --bin run -- --config run.toml`, or plays a campaign
+//! of increasingly difficult levels with
+//! `cargo run --features config --bin run -- campaign [--progress campaign.json]
+//! [--seed <n>] [--watch <algorithm>]`.
+use std::error::Error;
+use std::io::BufRead;
+use std::path::Path;
+
+use algoritmos_rust::campaign::Progress;
+use algoritmos_rust::config::RunConfig;
+
+pub fn main() -> Result<(), Box<dyn Error>> {
+    let args = std::env::args().collect::<Vec<String>>();
+    let option = |name: &str| {
+        args.iter()
+            .position(|arg| arg == name)
+            .map(|position| {
+                args.get(position + 1)
+                    .ok_or(format!("{} needs a value", name))
+            })
+            .transpose()
+    };
+    if args.get(1).map(String::as_str) == Some("campaign") {
+        let progress = option("--progress")?.map_or("campaign.json", String::as_str);
+        let seed = option("--seed")?.map_or(Ok(0), |seed| seed.parse())?;
+        return campaign(
+            Path::new(progress),
+            seed,
+            option("--watch")?.map(String::as_str),
+        );
+    }
+    let path = match option("--config")? {
+        Some(path) => path,
+        None => return Err("usage: run --config <run.toml> | run campaign".into()),
+    };
+    let config = RunConfig::try_from(std::fs::read_to_string(path)?.as_str())?;
+    let report = config.run()?;
+    config.write_report(&report, &mut std::io::stdout())?;
+    Ok(())
+}
+
+/// Plays (or lets `watch` solve) one level after the other, saving the progress after
+/// every level, until the player quits.
+fn campaign(path: &Path, seed: u64, watch: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let mut progress = Progress::load_or_new(path, seed)?;
+    let mut lines = std::io::stdin().lock().lines();
+    loop {
+        let level = progress.campaign().level(progress.level);
+        println!(
+            "level {} ({}), score {}",
+            level.number,
+            level.problem.domain(),
+            progress.score
+        );
+        let result = match watch {
+            Some(algorithm) => {
+                let report = level.watch(algorithm)?;
+                println!("{}", report);
+                level.result(report.length.unwrap_or(0), Some(algorithm))
+            }
+            None => {
+                let mut play = level.play();
+                while !play.is_solved() {
+                    println!("{}", play);
+                    let Some(line) = lines.next().transpose()? else {
+                        return Ok(());
+                    };
+                    if line.trim() == "quit" {
+                        return Ok(());
+                    }
+                    if let Err(error) = play.apply(&line) {
+                        println!("{}", error);
+                    }
+                }
+                level.result(play.moves(), None)
+            }
+        };
+        println!(
+            "solved in {} moves ({} at best): {} points",
+            result.moves, result.optimal, result.points
+        );
+        progress.record(result);
+        progress.save(path)?;
+        if watch.is_some() {
+            println!("press enter for the next level, quit to stop");
+            match lines.next().transpose()? {
+                Some(line) if line.trim() != "quit" => {}
+                _ => return Ok(()),
+            }
+        }
+    }
+}
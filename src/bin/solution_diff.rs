@@ -0,0 +1,27 @@
+use std::error::Error;
+
+use algoritmos_rust::n_puzzle::{Board, NPuzzleProblem};
+use algoritmos_rust::search::{a_star, diff_solutions, greedy_best_first};
+
+/// Compares the greedy best-first and A* solutions of an N-puzzle board given as the
+/// first argument (tiles row by row, `0` is the blank).
+pub fn main() -> Result<(), Box<dyn Error>> {
+    let tiles = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "8 6 7 2 5 4 3 0 1".to_string());
+    let problem = NPuzzleProblem::new(Board::try_from(tiles.as_str())?);
+    let manhattan = |board: &Board| board.manhattan_distance() as f64;
+
+    match (
+        greedy_best_first(&problem, manhattan),
+        a_star(&problem, manhattan),
+    ) {
+        (Some(greedy), Some(optimal)) => {
+            println!("left: greedy best-first, right: A*");
+            println!("{}", diff_solutions(&greedy, &optimal));
+        }
+        _ => println!("no solution was found!"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+//! Solves the cannibals puzzle with weighted A*,
+//! `cargo run --bin weighted_a_star -- [--weight <w>] [--verbose]`, then compares the
+//! nodes expanded and the solution lengths of several weights on larger puzzles.
+use std::error::Error;
+
+use algoritmos_rust::search::{weighted_a_star, weighted_a_star_traced};
+use algoritmos_rust::terminal::Theme;
+use algoritmos_rust::{solver_report, DeadEnds, FixedCannibalsProblem, FixedWorldState, Locale};
+
+pub fn main() -> Result<(), Box<dyn Error>> {
+    let args = std::env::args().collect::<Vec<String>>();
+    let weight = match args.iter().position(|arg| arg == "--weight") {
+        Some(position) => args
+            .get(position + 1)
+            .ok_or("--weight needs a value")?
+            .parse::<f64>()
+            .map_err(|error| format!("--weight: {}", error))?,
+        None => 2.0,
+    };
+
+    let mut dead_ends = DeadEnds::default();
+    let solution = weighted_a_star_traced(
+        &FixedCannibalsProblem::<3, 2>,
+        FixedWorldState::heuristic,
+        weight,
+        &mut dead_ends,
+    );
+    let verbose = args.iter().any(|arg| arg == "--verbose" || arg == "-v");
+    let theme = Theme::from_env();
+    println!(
+        "{}",
+        solver_report(
+            solution.as_ref(),
+            &dead_ends,
+            &theme,
+            Locale::from_env(),
+            verbose
+        )?
+    );
+
+    println!("{}", theme.paint(theme.heading, "Steps by weight:"));
+    let mut rows = steps_by_weight::<5, 3>();
+    rows.extend(steps_by_weight::<8, 4>());
+    let rows = rows
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.clone()))
+        .collect::<Vec<_>>();
+    println!("{}", theme.stats_table(&rows));
+    Ok(())
+}
+
+/// One row per weight for the puzzle with `N` of each and a boat for `BOAT`.
+fn steps_by_weight<const N: u8, const BOAT: u8>() -> Vec<(String, String)> {
+    let problem = FixedCannibalsProblem::<N, BOAT>;
+    [0.0, 1.0, 2.0, 5.0]
+        .into_iter()
+        .map(|weight| {
+            let steps = weighted_a_star(&problem, FixedWorldState::heuristic, weight)
+                .map_or("no solution".to_string(), |solution| {
+                    format!("{} ({} expanded)", solution.len(), solution.stats.expanded)
+                });
+            (format!("weight {} {}/{}", weight, N, BOAT), steps)
+        })
+        .collect()
+}
@@ -0,0 +1,199 @@
+use super::{first_fit_decreasing, BinPackingInstance, Packing};
+use crate::branch_and_bound::{BranchAndBound, BranchAndBoundProblem, Exploration, Sense};
+
+/// [`BranchAndBoundOutcome`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchAndBoundOutcome {
+    pub packing: Packing,
+    /// Number of nodes of the search tree that were explored.
+    pub nodes_explored: usize,
+    /// Whether the search finished before reaching the node limit (the packing is optimal).
+    pub proven_optimal: bool,
+}
+
+/// [`branch_and_bound`]
+/// ## Exact bin packing solver.
+/// Items are placed from biggest to smallest, every node tries the next item in each
+/// open bin where it fits (bins with the same load are equivalent, only one of them is
+/// tried) and in a new bin.
+/// The first-fit-decreasing packing is the initial incumbent, and a node is pruned when
+/// the bins already open plus the room needed for the remaining items can't beat it.
+/// The search stops as soon as the incumbent reaches [`BinPackingInstance::lower_bound`],
+/// or after `node_limit` nodes.
+pub fn branch_and_bound(instance: &BinPackingInstance, node_limit: usize) -> BranchAndBoundOutcome {
+    let sizes = instance.sizes();
+    let mut order = (0..sizes.len()).collect::<Vec<usize>>();
+    order.sort_by(|a, b| sizes[*b].cmp(&sizes[*a]));
+    let remaining_total = order
+        .iter()
+        .rev()
+        .scan(0u64, |total, item| {
+            *total += u64::from(sizes[*item]);
+            Some(*total)
+        })
+        .collect::<Vec<u64>>()
+        .into_iter()
+        .rev()
+        .collect();
+
+    let problem = PackingTree {
+        instance,
+        order,
+        remaining_total,
+    };
+    let outcome = BranchAndBound::new(Exploration::DepthFirst)
+        .node_limit(node_limit)
+        .solve(&problem);
+
+    BranchAndBoundOutcome {
+        packing: outcome
+            .best
+            .map(|best| best.solution)
+            .unwrap_or_else(|| first_fit_decreasing(instance)),
+        nodes_explored: outcome.stats.nodes_explored,
+        proven_optimal: outcome.proven_optimal,
+    }
+}
+
+struct PackingTree<'a> {
+    instance: &'a BinPackingInstance,
+    order: Vec<usize>,
+    /// Sum of the sizes of `order[k..]`.
+    remaining_total: Vec<u64>,
+}
+
+/// Node of the search tree, the first `position` items of `order` are already packed.
+#[derive(Clone)]
+struct Node {
+    position: usize,
+    bins: Vec<Vec<usize>>,
+    loads: Vec<u32>,
+}
+
+impl BranchAndBoundProblem for PackingTree<'_> {
+    type Node = Node;
+    type Solution = Packing;
+
+    const SENSE: Sense = Sense::Minimize;
+
+    fn root(&self) -> Node {
+        Node {
+            position: 0,
+            bins: vec![],
+            loads: vec![],
+        }
+    }
+
+    fn branch(&self, node: &Node) -> Vec<Node> {
+        let Some(item) = self.order.get(node.position).copied() else {
+            return vec![];
+        };
+        let size = self.instance.sizes()[item];
+        let mut children = vec![];
+        let mut tried_loads = vec![];
+        for (bin, load) in node.loads.iter().enumerate() {
+            if load + size > self.instance.capacity() || tried_loads.contains(load) {
+                continue;
+            }
+            tried_loads.push(*load);
+
+            let mut child = node.clone();
+            child.position += 1;
+            child.bins[bin].push(item);
+            child.loads[bin] += size;
+            children.push(child);
+        }
+
+        let mut child = node.clone();
+        child.position += 1;
+        child.bins.push(vec![item]);
+        child.loads.push(size);
+        children.push(child);
+
+        // the engine explores the last child first, keep first-fit order.
+        children.reverse();
+        children
+    }
+
+    /// Bins already open plus the bins needed for the remaining items after filling the
+    /// free space of the open ones.
+    fn bound(&self, node: &Node) -> f64 {
+        let capacity = u64::from(self.instance.capacity());
+        let free_space = node
+            .loads
+            .iter()
+            .map(|load| capacity - u64::from(*load))
+            .sum::<u64>();
+        let remaining = self
+            .remaining_total
+            .get(node.position)
+            .copied()
+            .unwrap_or(0);
+        let extra_bins = remaining.saturating_sub(free_space).div_ceil(capacity) as usize;
+        (node.bins.len() + extra_bins) as f64
+    }
+
+    fn solution(&self, node: &Node) -> Option<(Packing, f64)> {
+        (node.position == self.order.len()).then(|| {
+            let packing = Packing {
+                bins: node.bins.clone(),
+            };
+            (packing, node.bins.len() as f64)
+        })
+    }
+
+    fn initial_incumbent(&self) -> Option<(Packing, f64)> {
+        let packing = first_fit_decreasing(self.instance);
+        let bins = packing.bin_count() as f64;
+        Some((packing, bins))
+    }
+
+    fn optimum_bound(&self) -> Option<f64> {
+        Some(self.instance.lower_bound() as f64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rng::Rng;
+
+    #[test]
+    fn bin_packing_branch_and_bound_improves_first_fit_decreasing() {
+        // FFD packs [5, 4] [4, 3, 2] [2], the optimum is [5, 3, 2] [4, 4, 2].
+        let instance = BinPackingInstance::new(vec![5, 4, 4, 3, 2, 2], 10).unwrap();
+
+        let ffd = first_fit_decreasing(&instance);
+        let outcome = branch_and_bound(&instance, 100_000);
+
+        assert_eq!(ffd.bin_count(), 3);
+        assert_eq!(outcome.packing.bin_count(), 2);
+        assert!(outcome.proven_optimal);
+        assert!(instance.is_valid_packing(&outcome.packing.bins));
+    }
+
+    #[test]
+    fn bin_packing_branch_and_bound_never_goes_below_lower_bound() {
+        let mut rng = Rng::new(9);
+        for _ in 0..10 {
+            let instance = BinPackingInstance::random(12, 100, 20, 70, &mut rng).unwrap();
+
+            let outcome = branch_and_bound(&instance, 1_000_000);
+
+            assert!(outcome.proven_optimal);
+            assert!(instance.is_valid_packing(&outcome.packing.bins));
+            assert!(outcome.packing.bin_count() >= instance.lower_bound());
+            assert!(outcome.packing.bin_count() <= first_fit_decreasing(&instance).bin_count());
+        }
+    }
+
+    #[test]
+    fn bin_packing_branch_and_bound_reports_node_limit() {
+        let instance = BinPackingInstance::new(vec![5, 4, 4, 3, 2, 2], 10).unwrap();
+
+        let outcome = branch_and_bound(&instance, 1);
+
+        assert!(!outcome.proven_optimal);
+        assert_eq!(outcome.packing.bin_count(), 3);
+    }
+}
@@ -0,0 +1,62 @@
+use super::{BinPackingInstance, Packing};
+
+/// [`first_fit`]
+/// Places every item, in the given order, into the first bin with enough room left,
+/// opening a new bin when none fits.
+pub fn first_fit(instance: &BinPackingInstance) -> Packing {
+    let order = (0..instance.sizes().len()).collect::<Vec<usize>>();
+    first_fit_in_order(instance, &order)
+}
+
+/// [`first_fit_decreasing`]
+/// ## First-fit after sorting items from biggest to smallest.
+/// Never uses more than `11/9 * OPT + 6/9` bins, a good and fast baseline.
+pub fn first_fit_decreasing(instance: &BinPackingInstance) -> Packing {
+    let sizes = instance.sizes();
+    let mut order = (0..sizes.len()).collect::<Vec<usize>>();
+    order.sort_by(|a, b| sizes[*b].cmp(&sizes[*a]));
+    first_fit_in_order(instance, &order)
+}
+
+fn first_fit_in_order(instance: &BinPackingInstance, order: &[usize]) -> Packing {
+    let sizes = instance.sizes();
+    let mut bins: Vec<Vec<usize>> = vec![];
+    let mut loads: Vec<u32> = vec![];
+
+    for item in order {
+        let size = sizes[*item];
+        match loads
+            .iter()
+            .position(|load| load + size <= instance.capacity())
+        {
+            Some(bin) => {
+                bins[bin].push(*item);
+                loads[bin] += size;
+            }
+            None => {
+                bins.push(vec![*item]);
+                loads.push(size);
+            }
+        }
+    }
+
+    Packing { bins }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bin_packing_first_fit_decreasing_beats_first_fit_on_bad_order() {
+        let instance = BinPackingInstance::new(vec![2, 5, 4, 7, 1, 3, 8], 10).unwrap();
+
+        let first_fit = first_fit(&instance);
+        let first_fit_decreasing = first_fit_decreasing(&instance);
+
+        assert!(instance.is_valid_packing(&first_fit.bins));
+        assert!(instance.is_valid_packing(&first_fit_decreasing.bins));
+        assert_eq!(first_fit.bin_count(), 4);
+        assert_eq!(first_fit_decreasing.bin_count(), 3);
+    }
+}
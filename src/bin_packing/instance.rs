@@ -0,0 +1,203 @@
+use super::BinPackingError;
+use crate::rng::Rng;
+
+/// [`BinPackingInstance`]
+/// ## Bin packing instance.
+/// Pack every item into the fewest bins possible, the sum of the sizes of the items in a
+/// bin can't exceed `capacity`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinPackingInstance {
+    sizes: Vec<u32>,
+    capacity: u32,
+}
+
+impl BinPackingInstance {
+    pub fn new(sizes: Vec<u32>, capacity: u32) -> Result<Self, BinPackingError> {
+        if capacity == 0 {
+            return Err(BinPackingError::InvalidCapacity);
+        }
+        if let Some(index) = sizes.iter().position(|size| *size == 0 || *size > capacity) {
+            return Err(BinPackingError::InvalidItemSize(index, sizes[index]));
+        }
+        Ok(Self { sizes, capacity })
+    }
+
+    /// [`random`]
+    /// Generates `items` items with sizes uniformly distributed in `min_size..=max_size`,
+    /// an error when the range is empty.
+    pub fn random(
+        items: usize,
+        capacity: u32,
+        min_size: u32,
+        max_size: u32,
+        rng: &mut Rng,
+    ) -> Result<Self, BinPackingError> {
+        if min_size > max_size {
+            return Err(BinPackingError::InvalidSizeRange(min_size, max_size));
+        }
+        let sizes = (0..items)
+            .map(|_| min_size + rng.gen_range(0..(max_size - min_size + 1) as usize) as u32)
+            .collect();
+        Self::new(sizes, capacity)
+    }
+
+    pub fn sizes(&self) -> &[u32] {
+        &self.sizes
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// [`lower_bound`]
+    /// ## Lower bound on the number of bins.
+    /// The largest of:
+    /// * `L1`: total size divided by the capacity, rounded up;
+    /// * `L2` (Martello & Toth): for every threshold `k <= capacity / 2`, items bigger than
+    ///   `capacity - k` need a bin of their own, items in `(capacity / 2, capacity - k]` can't
+    ///   share a bin with each other, and the space left in their bins might not be enough
+    ///   for all the items in `[k, capacity / 2]`.
+    pub fn lower_bound(&self) -> usize {
+        let capacity = u64::from(self.capacity);
+        let total = self.sizes.iter().map(|s| u64::from(*s)).sum::<u64>();
+        let l1 = total.div_ceil(capacity) as usize;
+
+        let mut thresholds = self
+            .sizes
+            .iter()
+            .map(|s| u64::from(*s))
+            .filter(|s| 2 * s <= capacity)
+            .collect::<Vec<u64>>();
+        thresholds.push(0);
+        thresholds.sort();
+        thresholds.dedup();
+
+        let l2 = thresholds
+            .into_iter()
+            .map(|k| {
+                let sizes = self.sizes.iter().map(|s| u64::from(*s));
+                let big = sizes.clone().filter(|s| *s > capacity - k).count();
+                let medium = sizes
+                    .clone()
+                    .filter(|s| *s <= capacity - k && 2 * s > capacity)
+                    .collect::<Vec<u64>>();
+                let small_total = sizes.filter(|s| *s >= k && 2 * s <= capacity).sum::<u64>();
+                let medium_space = medium.len() as u64 * capacity - medium.iter().sum::<u64>();
+                let extra = small_total.saturating_sub(medium_space).div_ceil(capacity);
+                big + medium.len() + extra as usize
+            })
+            .max()
+            .unwrap_or(0);
+
+        l1.max(l2)
+    }
+
+    /// Checks that `bins` contains every item exactly once and no bin overflows.
+    pub fn is_valid_packing(&self, bins: &[Vec<usize>]) -> bool {
+        let mut packed = vec![false; self.sizes.len()];
+        for bin in bins {
+            let load = bin
+                .iter()
+                .map(|i| self.sizes.get(*i).copied().unwrap_or(0))
+                .sum::<u32>();
+            if load > self.capacity {
+                return false;
+            }
+            for item in bin {
+                match packed.get_mut(*item) {
+                    Some(packed) if !*packed => *packed = true,
+                    _ => return false,
+                }
+            }
+        }
+        packed.into_iter().all(|packed| packed)
+    }
+}
+
+/// [TryFrom<&str>]
+/// Accepts the format: first line `n_items capacity`, then the item sizes separated by
+/// spaces or new lines.
+impl TryFrom<&str> for BinPackingInstance {
+    type Error = BinPackingError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let numbers = value
+            .split_whitespace()
+            .map(|n| n.parse::<u32>())
+            .collect::<Result<Vec<u32>, _>>()
+            .map_err(|_| BinPackingError::ParseFromStringError("Invalid number".into()))?;
+        match &numbers[..] {
+            [n_items, capacity, sizes @ ..] if sizes.len() == *n_items as usize => {
+                BinPackingInstance::new(sizes.to_vec(), *capacity)
+            }
+            _ => Err(BinPackingError::ParseFromStringError(
+                "Expected number of items, capacity and every item size".into(),
+            )),
+        }
+    }
+}
+
+/// [`Packing`]
+/// Items (by index) placed in each bin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Packing {
+    pub bins: Vec<Vec<usize>>,
+}
+
+impl Packing {
+    pub fn bin_count(&self) -> usize {
+        self.bins.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bin_packing_new_returns_error_when_item_does_not_fit() {
+        assert_eq!(
+            BinPackingInstance::new(vec![3, 11], 10),
+            Err(BinPackingError::InvalidItemSize(1, 11))
+        );
+        assert_eq!(
+            BinPackingInstance::new(vec![], 0),
+            Err(BinPackingError::InvalidCapacity)
+        );
+        assert!(BinPackingInstance::try_from("2 10 3").is_err());
+        assert!(BinPackingInstance::try_from("2 10\n3 4").is_ok());
+    }
+
+    #[test]
+    fn bin_packing_lower_bound_returns_expected_values() {
+        let l1_tight = BinPackingInstance::new(vec![5, 5, 5, 5], 10).unwrap();
+        // three items bigger than half the capacity can't share bins, L1 would say 2.
+        let l2_tight = BinPackingInstance::new(vec![6, 6, 6], 10).unwrap();
+
+        assert_eq!(l1_tight.lower_bound(), 2);
+        assert_eq!(l2_tight.lower_bound(), 3);
+    }
+
+    #[test]
+    fn bin_packing_random_is_reproducible() {
+        let a = BinPackingInstance::random(20, 100, 10, 60, &mut Rng::new(5)).unwrap();
+        let b = BinPackingInstance::random(20, 100, 10, 60, &mut Rng::new(5)).unwrap();
+
+        assert_eq!(a, b);
+        assert!(a.sizes().iter().all(|s| (10..=60).contains(s)));
+        assert_eq!(
+            BinPackingInstance::random(20, 100, 60, 10, &mut Rng::new(5)),
+            Err(BinPackingError::InvalidSizeRange(60, 10))
+        );
+    }
+
+    #[test]
+    fn bin_packing_is_valid_packing_detects_invalid_packings() {
+        let instance = BinPackingInstance::new(vec![5, 6, 4], 10).unwrap();
+
+        assert!(instance.is_valid_packing(&[vec![1, 2], vec![0]]));
+        assert!(!instance.is_valid_packing(&[vec![0, 1], vec![2]]));
+        assert!(!instance.is_valid_packing(&[vec![1, 2]]));
+        assert!(!instance.is_valid_packing(&[vec![1, 2], vec![0, 0]]));
+    }
+}
@@ -0,0 +1,22 @@
+pub mod branch_and_bound;
+pub mod heuristics;
+pub mod instance;
+
+pub use branch_and_bound::*;
+pub use heuristics::*;
+pub use instance::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum BinPackingError {
+    #[error("Item size must be between 1 and the bin capacity")]
+    InvalidItemSize(usize, u32),
+    #[error("The bin capacity must be positive")]
+    InvalidCapacity,
+    #[error("Invalid item size range {0}..={1}")]
+    InvalidSizeRange(u32, u32),
+    #[error("Error when trying to parse from BinPackingInstance string")]
+    ParseFromStringError(String),
+}
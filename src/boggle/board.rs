@@ -0,0 +1,233 @@
+use std::fmt::Display;
+
+use super::{BoggleError, Trie};
+
+/// [`FoundWord`]
+/// A word found on the board and the cells `(row, column)` used to spell it, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoundWord {
+    pub word: String,
+    pub path: Vec<(usize, usize)>,
+}
+
+/// [`BoggleBoard`]
+/// ## Grid of letters.
+/// A word is spelled by a path of cells where each cell is adjacent (horizontally,
+/// vertically or diagonally) to the previous one, and no cell is used twice.
+/// As in the physical game, a `q` cell reads as `qu`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoggleBoard {
+    rows: usize,
+    columns: usize,
+    letters: Vec<char>,
+}
+
+impl BoggleBoard {
+    pub fn new(rows: Vec<Vec<char>>) -> Result<Self, BoggleError> {
+        let columns = rows.first().map(|row| row.len()).unwrap_or(0);
+        if columns == 0 || rows.iter().any(|row| row.len() != columns) {
+            return Err(BoggleError::InvalidShape);
+        }
+        let letters = rows
+            .iter()
+            .flatten()
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<Vec<char>>();
+        if !letters.iter().all(|c| c.is_ascii_lowercase()) {
+            return Err(BoggleError::ParseFromStringError(
+                "Cells must be ascii letters".into(),
+            ));
+        }
+        Ok(Self {
+            rows: rows.len(),
+            columns,
+            letters,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn letter(&self, row: usize, column: usize) -> char {
+        self.letters[row * self.columns + column]
+    }
+
+    /// [`solve`]
+    /// ## Every dictionary word of at least `min_length` letters findable on the board.
+    /// A DFS starts from every cell and walks the trie alongside the grid, a branch is
+    /// abandoned as soon as no word starts with the letters gathered so far.
+    /// Words are returned sorted, each with the first path found for it.
+    pub fn solve(&self, dictionary: &Trie, min_length: usize) -> Vec<FoundWord> {
+        let mut found: Vec<FoundWord> = vec![];
+        let mut visited = vec![false; self.letters.len()];
+        let mut path = vec![];
+        let mut word = String::new();
+        for cell in 0..self.letters.len() {
+            self.explore(
+                dictionary,
+                min_length,
+                cell,
+                0,
+                &mut visited,
+                &mut path,
+                &mut word,
+                &mut found,
+            );
+        }
+        found.sort_by(|a, b| a.word.cmp(&b.word));
+        found.dedup_by(|a, b| a.word == b.word);
+        found
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn explore(
+        &self,
+        dictionary: &Trie,
+        min_length: usize,
+        cell: usize,
+        trie_node: usize,
+        visited: &mut Vec<bool>,
+        path: &mut Vec<(usize, usize)>,
+        word: &mut String,
+        found: &mut Vec<FoundWord>,
+    ) {
+        let letters = match self.letters[cell] {
+            'q' => "qu".to_string(),
+            letter => letter.to_string(),
+        };
+        let Some(trie_node) = dictionary.step(trie_node, &letters) else {
+            return;
+        };
+
+        visited[cell] = true;
+        path.push((cell / self.columns, cell % self.columns));
+        word.push_str(&letters);
+
+        if dictionary.is_word_node(trie_node) && word.len() >= min_length {
+            found.push(FoundWord {
+                word: word.clone(),
+                path: path.clone(),
+            });
+        }
+        for neighbor in self.adjacent(cell) {
+            if !visited[neighbor] {
+                self.explore(
+                    dictionary, min_length, neighbor, trie_node, visited, path, word, found,
+                );
+            }
+        }
+
+        word.truncate(word.len() - letters.len());
+        path.pop();
+        visited[cell] = false;
+    }
+
+    fn adjacent(&self, cell: usize) -> impl Iterator<Item = usize> + '_ {
+        let (row, column) = (
+            (cell / self.columns) as isize,
+            (cell % self.columns) as isize,
+        );
+        (-1..=1)
+            .flat_map(|dr| (-1..=1).map(move |dc| (dr, dc)))
+            .filter(|delta| *delta != (0, 0))
+            .map(move |(dr, dc)| (row + dr, column + dc))
+            .filter(|(r, c)| {
+                (0..self.rows as isize).contains(r) && (0..self.columns as isize).contains(c)
+            })
+            .map(|(r, c)| r as usize * self.columns + c as usize)
+    }
+}
+
+/// [TryFrom<&str>]
+/// One line per row, spaces inside a row are ignored, e.g. `"c a t\nx a r"`.
+impl TryFrom<&str> for BoggleBoard {
+    type Error = BoggleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let rows = value
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .filter(|c| !c.is_whitespace())
+                    .collect::<Vec<char>>()
+            })
+            .filter(|row| !row.is_empty())
+            .collect();
+        BoggleBoard::new(rows)
+    }
+}
+
+impl Display for BoggleBoard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.letters.chunks(self.columns) {
+            let row = row
+                .iter()
+                .map(|c| match c {
+                    'q' => "Qu".to_string(),
+                    c => c.to_ascii_uppercase().to_string(),
+                })
+                .collect::<Vec<String>>();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn boggle_try_from_rejects_ragged_boards() {
+        assert_eq!(
+            BoggleBoard::try_from("ab\nc"),
+            Err(BoggleError::InvalidShape)
+        );
+        assert!(BoggleBoard::try_from("a1\ncd").is_err());
+        assert_eq!(BoggleBoard::try_from("a b\nc d").unwrap().letter(1, 0), 'c');
+    }
+
+    #[test]
+    fn boggle_solve_finds_words_with_valid_paths() {
+        let board = BoggleBoard::try_from("cat\nxar\nqie").unwrap();
+        let dictionary = Trie::from_words([
+            "cat", "car", "tar", "rat", "act", "cart", "quiz", "tact", "at",
+        ]);
+
+        let found = board.solve(&dictionary, 3);
+        let words = found.iter().map(|f| f.word.as_str()).collect::<Vec<&str>>();
+
+        // "act" needs `c` next to `t`, "tact" needs the only `t` twice, "quiz" has no `z`
+        // and "at" is too short.
+        assert_eq!(words, vec!["car", "cart", "cat", "rat", "tar"]);
+        for found_word in &found {
+            let spelled = found_word
+                .path
+                .iter()
+                .map(|(r, c)| board.letter(*r, *c))
+                .collect::<String>();
+            assert_eq!(spelled, found_word.word);
+            for pair in found_word.path.windows(2) {
+                assert!(pair[0].0.abs_diff(pair[1].0) <= 1 && pair[0].1.abs_diff(pair[1].1) <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn boggle_q_cell_reads_as_qu() {
+        let board = BoggleBoard::try_from("qi\nta").unwrap();
+        let dictionary = Trie::from_words(["quit", "quat", "qit"]);
+
+        let found = board.solve(&dictionary, 3);
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].word, "quat");
+        assert_eq!(found[0].path, vec![(0, 0), (1, 1), (1, 0)]);
+        assert_eq!(found[1].word, "quit");
+    }
+}
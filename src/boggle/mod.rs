@@ -0,0 +1,18 @@
+pub mod board;
+pub mod trie;
+
+pub use board::*;
+pub use trie::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum BoggleError {
+    #[error("Every row of the board must have the same number of letters")]
+    InvalidShape,
+    #[error("Error when trying to parse from BoggleBoard string")]
+    ParseFromStringError(String),
+    #[error("Error while reading the word list")]
+    Io(String),
+}
@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+use std::io::BufRead;
+
+use super::BoggleError;
+
+/// [`Trie`]
+/// ## Prefix tree of lowercase words.
+/// Besides membership, tells whether any word starts with a given prefix, which lets a
+/// search stop as soon as the letters gathered so far can't become a word.
+#[derive(Debug, Clone)]
+pub struct Trie {
+    /// Node `0` is the root (empty prefix).
+    nodes: Vec<TrieNode>,
+    words: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: BTreeMap<char, usize>,
+    is_word: bool,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self {
+            nodes: vec![TrieNode::default()],
+            words: 0,
+        }
+    }
+}
+
+impl Trie {
+    pub fn from_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut trie = Self::default();
+        words
+            .into_iter()
+            .for_each(|word| trie.insert(word.as_ref()));
+        trie
+    }
+
+    /// [`from_reader`]
+    /// Loads a word list with one word per line.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, BoggleError> {
+        let mut trie = Self::default();
+        for line in reader.lines() {
+            let line = line.map_err(|e| BoggleError::Io(e.to_string()))?;
+            trie.insert(&line);
+        }
+        Ok(trie)
+    }
+
+    /// Words are normalized to lowercase, words with anything other than ascii letters are
+    /// ignored.
+    pub fn insert(&mut self, word: &str) {
+        let word = word.trim().to_lowercase();
+        if word.is_empty() || !word.chars().all(|c| c.is_ascii_lowercase()) {
+            return;
+        }
+        let mut node = 0;
+        for letter in word.chars() {
+            node = match self.nodes[node].children.get(&letter) {
+                Some(child) => *child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(letter, child);
+                    child
+                }
+            };
+        }
+        if !self.nodes[node].is_word {
+            self.nodes[node].is_word = true;
+            self.words += 1;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.words
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words == 0
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| self.nodes[node].is_word)
+    }
+
+    pub fn contains_prefix(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    /// Node reached from `node` after reading `letters`, used to walk the trie one step
+    /// at a time instead of searching the whole prefix again.
+    pub(crate) fn step(&self, node: usize, letters: &str) -> Option<usize> {
+        letters.chars().try_fold(node, |node, letter| {
+            self.nodes[node].children.get(&letter).copied()
+        })
+    }
+
+    pub(crate) fn is_word_node(&self, node: usize) -> bool {
+        self.nodes[node].is_word
+    }
+
+    fn find(&self, prefix: &str) -> Option<usize> {
+        self.step(0, &prefix.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn boggle_trie_distinguishes_words_and_prefixes() {
+        let trie = Trie::from_words(["tea", "ten", "Inn", "te4", ""]);
+
+        assert_eq!(trie.len(), 3);
+        assert!(trie.contains("tea"));
+        assert!(trie.contains("INN"));
+        assert!(!trie.contains("te"));
+        assert!(trie.contains_prefix("te"));
+        assert!(trie.contains_prefix(""));
+        assert!(!trie.contains_prefix("to"));
+    }
+}
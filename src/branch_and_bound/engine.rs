@@ -0,0 +1,353 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use super::{BranchAndBoundProblem, Exploration, Incumbent, Sense};
+
+/// [`BranchAndBound`]
+/// ## Generic branch and bound engine.
+/// # Example
+/// ```
+/// # use algoritmos_rust::branch_and_bound::*;
+/// /// Pick a subset of `[3, 5, 7]` whose sum is as close as possible to 11 from below.
+/// struct SubsetSum;
+///
+/// impl BranchAndBoundProblem for SubsetSum {
+///     type Node = (usize, u32); // (next number, current sum)
+///     type Solution = u32;
+///     const SENSE: Sense = Sense::Maximize;
+///
+///     fn root(&self) -> (usize, u32) { (0, 0) }
+///     fn branch(&self, &(i, sum): &(usize, u32)) -> Vec<(usize, u32)> {
+///         let numbers = [3, 5, 7];
+///         if i == numbers.len() { return vec![]; }
+///         [(i + 1, sum + numbers[i]), (i + 1, sum)].into_iter().filter(|(_, s)| *s <= 11).collect()
+///     }
+///     fn bound(&self, &(i, sum): &(usize, u32)) -> f64 {
+///         f64::from((sum + [3, 5, 7][i..].iter().sum::<u32>()).min(11))
+///     }
+///     fn solution(&self, &(_, sum): &(usize, u32)) -> Option<(u32, f64)> {
+///         Some((sum, f64::from(sum)))
+///     }
+/// }
+///
+/// let outcome = BranchAndBound::new(Exploration::BestFirst).solve(&SubsetSum);
+/// assert_eq!(outcome.best.unwrap().solution, 10);
+/// assert!(outcome.proven_optimal);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BranchAndBound {
+    exploration: Exploration,
+    node_limit: usize,
+}
+
+impl BranchAndBound {
+    pub fn new(exploration: Exploration) -> Self {
+        Self {
+            exploration,
+            node_limit: usize::MAX,
+        }
+    }
+
+    /// Stops the search after exploring `node_limit` nodes, the outcome is then not
+    /// proven optimal.
+    pub fn node_limit(mut self, node_limit: usize) -> Self {
+        self.node_limit = node_limit;
+        self
+    }
+
+    pub fn solve<P: BranchAndBoundProblem>(
+        &self,
+        problem: &P,
+    ) -> BranchAndBoundOutcome<P::Solution> {
+        let sense = P::SENSE;
+        let mut best: Option<Incumbent<P::Solution>> =
+            problem
+                .initial_incumbent()
+                .map(|(solution, objective)| Incumbent {
+                    solution,
+                    objective,
+                });
+        let optimum_reached =
+            |best: &Option<Incumbent<P::Solution>>| match (best, problem.optimum_bound()) {
+                (Some(best), Some(optimum)) => !sense.is_better(optimum, best.objective),
+                _ => false,
+            };
+        let can_improve = |bound: f64, best: &Option<Incumbent<P::Solution>>| match best {
+            Some(best) => sense.is_better(bound, best.objective),
+            None => true,
+        };
+
+        let mut stats = BranchAndBoundStats::default();
+        let mut frontier = Frontier::new(self.exploration, sense);
+        if !optimum_reached(&best) {
+            let root = problem.root();
+            frontier.push(problem.bound(&root), root);
+        }
+
+        let mut exhausted = true;
+        while let Some((bound, node)) = frontier.pop() {
+            if stats.nodes_explored >= self.node_limit {
+                exhausted = false;
+                break;
+            }
+            if !can_improve(bound, &best) {
+                stats.nodes_pruned += 1;
+                continue;
+            }
+            stats.nodes_explored += 1;
+
+            if let Some((solution, objective)) = problem.solution(&node) {
+                if can_improve(objective, &best) {
+                    best = Some(Incumbent {
+                        solution,
+                        objective,
+                    });
+                    stats.incumbent_updates += 1;
+                    if optimum_reached(&best) {
+                        break;
+                    }
+                }
+            }
+
+            for child in problem.branch(&node) {
+                let child_bound = problem.bound(&child);
+                if can_improve(child_bound, &best) {
+                    frontier.push(child_bound, child);
+                } else {
+                    stats.nodes_pruned += 1;
+                }
+            }
+            stats.max_open_nodes = stats.max_open_nodes.max(frontier.len());
+        }
+
+        BranchAndBoundOutcome {
+            best,
+            proven_optimal: exhausted,
+            stats,
+        }
+    }
+}
+
+/// [`BranchAndBoundStats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BranchAndBoundStats {
+    pub nodes_explored: usize,
+    /// Nodes discarded because their bound could not beat the incumbent.
+    pub nodes_pruned: usize,
+    pub incumbent_updates: usize,
+    pub max_open_nodes: usize,
+}
+
+/// [`BranchAndBoundOutcome`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchAndBoundOutcome<S> {
+    /// Best solution found, `None` if the problem has no feasible solution.
+    pub best: Option<Incumbent<S>>,
+    /// `false` when the node limit stopped the search before the optimum was proven.
+    pub proven_optimal: bool,
+    pub stats: BranchAndBoundStats,
+}
+
+enum Frontier<N> {
+    Stack(Vec<(f64, N)>),
+    Heap(BinaryHeap<HeapEntry<N>>, Sense, usize),
+}
+
+impl<N> Frontier<N> {
+    fn new(exploration: Exploration, sense: Sense) -> Self {
+        match exploration {
+            Exploration::DepthFirst => Frontier::Stack(vec![]),
+            Exploration::BestFirst => Frontier::Heap(BinaryHeap::new(), sense, 0),
+        }
+    }
+
+    fn push(&mut self, bound: f64, node: N) {
+        match self {
+            Frontier::Stack(stack) => stack.push((bound, node)),
+            Frontier::Heap(heap, sense, insertion) => {
+                *insertion += 1;
+                // the heap is a max-heap, negate the bound when minimizing.
+                let priority = match sense {
+                    Sense::Minimize => -bound,
+                    Sense::Maximize => bound,
+                };
+                heap.push(HeapEntry {
+                    priority,
+                    insertion: *insertion,
+                    bound,
+                    node,
+                });
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<(f64, N)> {
+        match self {
+            Frontier::Stack(stack) => stack.pop(),
+            Frontier::Heap(heap, _, _) => heap.pop().map(|entry| (entry.bound, entry.node)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Frontier::Stack(stack) => stack.len(),
+            Frontier::Heap(heap, _, _) => heap.len(),
+        }
+    }
+}
+
+/// Ties are broken in favor of the most recent node, so best-first dives like DFS among
+/// equally promising nodes.
+struct HeapEntry<N> {
+    priority: f64,
+    insertion: usize,
+    bound: f64,
+    node: N,
+}
+
+impl<N> PartialEq for HeapEntry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N> Eq for HeapEntry<N> {}
+
+impl<N> PartialOrd for HeapEntry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for HeapEntry<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .total_cmp(&other.priority)
+            .then(self.insertion.cmp(&other.insertion))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::branch_and_bound::Sense;
+
+    /// Assign each of `n` workers to a distinct task minimizing the total cost.
+    struct Assignment {
+        costs: Vec<Vec<u32>>,
+    }
+
+    impl BranchAndBoundProblem for Assignment {
+        /// tasks assigned to the first workers.
+        type Node = Vec<usize>;
+        type Solution = Vec<usize>;
+        const SENSE: Sense = Sense::Minimize;
+
+        fn root(&self) -> Vec<usize> {
+            vec![]
+        }
+
+        fn branch(&self, node: &Vec<usize>) -> Vec<Vec<usize>> {
+            (0..self.costs.len())
+                .filter(|task| !node.contains(task))
+                .map(|task| {
+                    let mut child = node.clone();
+                    child.push(task);
+                    child
+                })
+                .collect()
+        }
+
+        fn bound(&self, node: &Vec<usize>) -> f64 {
+            let assigned = node
+                .iter()
+                .enumerate()
+                .map(|(worker, task)| self.costs[worker][*task])
+                .sum::<u32>();
+            let remaining = self.costs[node.len()..]
+                .iter()
+                .map(|row| {
+                    (0..row.len())
+                        .filter(|task| !node.contains(task))
+                        .map(|task| row[task])
+                        .min()
+                        .unwrap_or(0)
+                })
+                .sum::<u32>();
+            f64::from(assigned + remaining)
+        }
+
+        fn solution(&self, node: &Vec<usize>) -> Option<(Vec<usize>, f64)> {
+            (node.len() == self.costs.len()).then(|| (node.clone(), self.bound(node)))
+        }
+    }
+
+    fn assignment() -> Assignment {
+        Assignment {
+            costs: vec![
+                vec![9, 2, 7, 8],
+                vec![6, 4, 3, 7],
+                vec![5, 8, 1, 8],
+                vec![7, 6, 9, 4],
+            ],
+        }
+    }
+
+    #[test]
+    fn branch_and_bound_best_first_and_depth_first_find_same_optimum() {
+        let best_first = BranchAndBound::new(Exploration::BestFirst).solve(&assignment());
+        let depth_first = BranchAndBound::new(Exploration::DepthFirst).solve(&assignment());
+
+        let best = best_first.best.unwrap();
+        assert_eq!(best.solution, vec![1, 0, 2, 3]);
+        assert_eq!(best.objective, 13.0);
+        assert_eq!(depth_first.best.unwrap().objective, 13.0);
+        assert!(best_first.proven_optimal && depth_first.proven_optimal);
+        assert!(best_first.stats.nodes_explored <= depth_first.stats.nodes_explored);
+    }
+
+    #[test]
+    fn branch_and_bound_node_limit_stops_the_search() {
+        let outcome = BranchAndBound::new(Exploration::DepthFirst)
+            .node_limit(2)
+            .solve(&assignment());
+
+        assert!(!outcome.proven_optimal);
+        assert_eq!(outcome.stats.nodes_explored, 2);
+        assert!(outcome.best.is_none());
+    }
+
+    #[test]
+    fn branch_and_bound_initial_incumbent_prunes_everything_when_optimal() {
+        struct Seeded(Assignment);
+        impl BranchAndBoundProblem for Seeded {
+            type Node = Vec<usize>;
+            type Solution = Vec<usize>;
+            const SENSE: Sense = Sense::Minimize;
+            fn root(&self) -> Vec<usize> {
+                self.0.root()
+            }
+            fn branch(&self, node: &Vec<usize>) -> Vec<Vec<usize>> {
+                self.0.branch(node)
+            }
+            fn bound(&self, node: &Vec<usize>) -> f64 {
+                self.0.bound(node)
+            }
+            fn solution(&self, node: &Vec<usize>) -> Option<(Vec<usize>, f64)> {
+                self.0.solution(node)
+            }
+            fn initial_incumbent(&self) -> Option<(Vec<usize>, f64)> {
+                Some((vec![1, 0, 2, 3], 13.0))
+            }
+            fn optimum_bound(&self) -> Option<f64> {
+                Some(13.0)
+            }
+        }
+
+        let outcome = BranchAndBound::new(Exploration::BestFirst).solve(&Seeded(assignment()));
+
+        assert_eq!(outcome.stats.nodes_explored, 0);
+        assert_eq!(outcome.best.unwrap().objective, 13.0);
+        assert!(outcome.proven_optimal);
+    }
+}
@@ -0,0 +1,78 @@
+pub mod engine;
+
+pub use engine::*;
+
+/// [`Sense`]
+/// Whether the objective of a [`BranchAndBoundProblem`] is minimized or maximized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sense {
+    Minimize,
+    Maximize,
+}
+
+impl Sense {
+    /// Whether objective `a` is strictly better than `b`.
+    pub fn is_better(&self, a: f64, b: f64) -> bool {
+        match self {
+            Sense::Minimize => a < b,
+            Sense::Maximize => a > b,
+        }
+    }
+}
+
+/// [`BranchAndBoundProblem`]
+/// ## A problem solvable by the generic [`BranchAndBound`] engine.
+/// The problem provides the tree (a root and a branching rule), an optimistic bound for
+/// every node, and tells when a node represents a feasible solution.
+/// The engine takes care of the exploration order, the incumbent (best solution found so
+/// far) and the pruning of nodes whose bound can't beat it.
+pub trait BranchAndBoundProblem {
+    type Node;
+    type Solution: Clone;
+
+    const SENSE: Sense;
+
+    fn root(&self) -> Self::Node;
+
+    /// Branching rule: the children of `node`.
+    fn branch(&self, node: &Self::Node) -> Vec<Self::Node>;
+
+    /// Bounding function: the best objective any solution below `node` could reach
+    /// (a lower bound when minimizing, an upper bound when maximizing).
+    fn bound(&self, node: &Self::Node) -> f64;
+
+    /// The feasible solution represented by `node`, if any, and its objective.
+    fn solution(&self, node: &Self::Node) -> Option<(Self::Solution, f64)>;
+
+    /// A solution known before the search starts (e.g. from a greedy heuristic), used to
+    /// prune from the very first node.
+    fn initial_incumbent(&self) -> Option<(Self::Solution, f64)> {
+        None
+    }
+
+    /// A bound on the optimum of the whole problem, the search stops as soon as the
+    /// incumbent reaches it.
+    fn optimum_bound(&self) -> Option<f64> {
+        None
+    }
+}
+
+/// [`Incumbent`]
+/// Best solution found so far.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Incumbent<S> {
+    pub solution: S,
+    pub objective: f64,
+}
+
+/// [`Exploration`]
+/// Order in which open nodes are explored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exploration {
+    /// Always explore the open node with the best bound, usually explores fewer nodes
+    /// but keeps many open nodes in memory.
+    BestFirst,
+    /// Explore the most recently created node first, linear memory and finds
+    /// incumbents quickly.
+    DepthFirst,
+}
@@ -0,0 +1,374 @@
+//! Progressive challenges across domains, used by `run campaign`.
+use std::fmt::Display;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::config::{ConfigError, ProblemConfig, RunConfig, RunReport};
+use crate::hanoi::{frame_stewart_moves, HanoiMove, HanoiProblem, HanoiState};
+use crate::n_puzzle::{Board, NPuzzleProblem, Slide};
+use crate::rng::Rng;
+use crate::search::{a_star, SearchProblem};
+
+/// Points of a level solved in the fewest moves.
+pub const MAX_POINTS: u32 = 100;
+
+/// [`Campaign`]
+/// ## Sequence of increasingly difficult levels.
+/// Even levels are N-puzzle boards scrambled by more and more slides (moving to the
+/// 15-puzzle from level 10), odd levels are Towers of Hanoi with one more disc every
+/// time. The levels only depend on the seed, so a campaign can be resumed from its
+/// [`Progress`].
+/// # Example
+/// ```
+/// # use algoritmos_rust::campaign::*;
+/// let campaign = Campaign::new(7);
+/// assert_eq!(campaign.level(0).problem.domain(), "n_puzzle");
+/// assert_eq!(campaign.level(1).optimal_moves(), 7);
+/// assert_eq!(campaign.level(3).optimal_moves(), 15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Campaign {
+    seed: u64,
+}
+
+impl Campaign {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn level(&self, number: usize) -> Level {
+        let step = number / 2;
+        let problem = if number.is_multiple_of(2) {
+            let (size, slides) = match step {
+                0..5 => (3, 6 + 4 * step),
+                _ => (4, 10 + 2 * (step - 5)),
+            };
+            let mut rng = Rng::new(self.seed ^ number as u64);
+            ProblemConfig::NPuzzle {
+                board: scramble(size, slides, &mut rng),
+            }
+        } else {
+            ProblemConfig::Hanoi {
+                discs: 3 + step,
+                pegs: 3,
+            }
+        };
+        Level { number, problem }
+    }
+}
+
+/// A random walk of `slides` from the goal board that never undoes the previous slide.
+fn scramble(size: usize, slides: usize, rng: &mut Rng) -> Board {
+    let mut board = Board::goal(size).expect("campaign boards are 3x3 or 4x4");
+    let mut previous: Option<Slide> = None;
+    for _ in 0..slides {
+        let children = board
+            .get_child_boards()
+            .into_iter()
+            .filter(|(slide, _)| Some(slide.opposite()) != previous)
+            .collect::<Vec<_>>();
+        let (slide, child) = rng
+            .choose(&children)
+            .expect("every board has 2 slides")
+            .clone();
+        previous = Some(slide);
+        board = child;
+    }
+    board
+}
+
+/// [`Level`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Level {
+    pub number: usize,
+    pub problem: ProblemConfig,
+}
+
+impl Level {
+    /// Fewest moves that solve the level.
+    pub fn optimal_moves(&self) -> usize {
+        match &self.problem {
+            ProblemConfig::NPuzzle { board } => {
+                a_star(&NPuzzleProblem::new(board.clone()), |board| {
+                    board.manhattan_distance() as f64
+                })
+                .map_or(0, |solution| solution.len())
+            }
+            ProblemConfig::Hanoi { discs, pegs } => {
+                frame_stewart_moves(*discs, *pegs).expect("campaign towers have 3 pegs") as usize
+            }
+            ProblemConfig::Romania { .. } | ProblemConfig::Cannibals { .. } => 0,
+        }
+    }
+
+    /// The level to be solved move by move.
+    pub fn play(&self) -> Play {
+        let position = match &self.problem {
+            ProblemConfig::NPuzzle { board } => Position::NPuzzle(board.clone()),
+            ProblemConfig::Hanoi { discs, pegs } => {
+                let problem =
+                    HanoiProblem::new(*discs, *pegs).expect("campaign towers have 3 pegs");
+                Position::Hanoi(problem.initial_state(), problem.target())
+            }
+            ProblemConfig::Romania { .. } => unreachable!("campaigns have no routes"),
+            ProblemConfig::Cannibals { .. } => unreachable!("campaigns have no river crossings"),
+        };
+        Play { position, moves: 0 }
+    }
+
+    /// Lets `algorithm` solve the level, see [`RunConfig`].
+    pub fn watch(&self, algorithm: &str) -> Result<RunReport, ConfigError> {
+        let heuristic = match self.problem {
+            ProblemConfig::NPuzzle { .. } => "manhattan",
+            ProblemConfig::Hanoi { .. } => "discs_off",
+            ProblemConfig::Romania { .. } => "straight_line",
+            ProblemConfig::Cannibals { .. } => "people_right",
+        };
+        RunConfig::new(self.problem.clone())
+            .algorithm(algorithm)
+            .heuristic(heuristic)
+            .run()
+    }
+
+    /// The result of solving the level in `moves`, [`MAX_POINTS`] when none could be
+    /// saved and proportionally less otherwise. Watching an algorithm scores nothing.
+    pub fn result(&self, moves: usize, watched: Option<&str>) -> LevelResult {
+        let optimal = self.optimal_moves();
+        let points = match watched {
+            Some(_) => 0,
+            None => (MAX_POINTS as usize * optimal / moves.max(1)).min(MAX_POINTS as usize) as u32,
+        };
+        LevelResult {
+            level: self.number,
+            domain: self.problem.domain().into(),
+            moves,
+            optimal,
+            points,
+            watched: watched.map(String::from),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Position {
+    NPuzzle(Board),
+    /// The state and its target peg.
+    Hanoi(HanoiState, usize),
+}
+
+/// [`Play`]
+/// ## A level being solved by the player.
+/// Moves are typed as the direction the blank moves (`up`, `down`, `left`, `right` or
+/// their initials) for the N-puzzle, and as the pegs to move a disc from and to (`0 2`
+/// or `0 -> 2`) for the Towers of Hanoi. [`Display`] renders the current position.
+#[derive(Debug, Clone)]
+pub struct Play {
+    position: Position,
+    moves: usize,
+}
+
+impl Play {
+    pub fn apply(&mut self, input: &str) -> Result<(), CampaignError> {
+        let input = input.trim();
+        let next = match &self.position {
+            Position::NPuzzle(board) => {
+                let slide = match input.to_lowercase().as_str() {
+                    "up" | "u" => Slide::Up,
+                    "down" | "d" => Slide::Down,
+                    "left" | "l" => Slide::Left,
+                    "right" | "r" => Slide::Right,
+                    _ => return Err(CampaignError::InvalidMove(input.into())),
+                };
+                board.slide(slide).map(Position::NPuzzle)
+            }
+            Position::Hanoi(state, target) => {
+                let pegs = input
+                    .split(|c: char| !c.is_ascii_digit())
+                    .filter(|peg| !peg.is_empty())
+                    .map(str::parse)
+                    .collect::<Result<Vec<usize>, _>>()
+                    .map_err(|_| CampaignError::InvalidMove(input.into()))?;
+                let [from, to] = pegs[..] else {
+                    return Err(CampaignError::InvalidMove(input.into()));
+                };
+                state
+                    .apply(HanoiMove { from, to })
+                    .map(|state| Position::Hanoi(state, *target))
+            }
+        };
+        self.position = next.ok_or_else(|| CampaignError::IllegalMove(input.into()))?;
+        self.moves += 1;
+        Ok(())
+    }
+
+    pub fn is_solved(&self) -> bool {
+        match &self.position {
+            Position::NPuzzle(board) => board.is_goal(),
+            Position::Hanoi(state, target) => state.is_stacked_on(*target),
+        }
+    }
+
+    pub fn moves(&self) -> usize {
+        self.moves
+    }
+}
+
+impl Display for Play {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.position {
+            Position::NPuzzle(board) => write!(f, "{}", board),
+            Position::Hanoi(state, target) => {
+                write!(f, "{}move every disc to peg {}", state, target)
+            }
+        }
+    }
+}
+
+/// [`LevelResult`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LevelResult {
+    pub level: usize,
+    pub domain: String,
+    pub moves: usize,
+    pub optimal: usize,
+    pub points: u32,
+    /// The algorithm that solved the level, `None` when the player did.
+    pub watched: Option<String>,
+}
+
+/// [`Progress`]
+/// ## Score and next level of a campaign, saved as JSON between sessions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Progress {
+    pub seed: u64,
+    /// Next level to play.
+    pub level: usize,
+    pub score: u32,
+    pub results: Vec<LevelResult>,
+}
+
+impl Progress {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            level: 0,
+            score: 0,
+            results: vec![],
+        }
+    }
+
+    /// The progress saved at `path`, a new campaign with `seed` when there is no file.
+    pub fn load_or_new(path: &Path, seed: u64) -> Result<Self, CampaignError> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|e| CampaignError::Io(e.to_string())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::new(seed)),
+            Err(e) => Err(CampaignError::Io(e.to_string())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), CampaignError> {
+        let json =
+            serde_json::to_string_pretty(self).map_err(|e| CampaignError::Io(e.to_string()))?;
+        std::fs::write(path, json + "\n").map_err(|e| CampaignError::Io(e.to_string()))
+    }
+
+    pub fn campaign(&self) -> Campaign {
+        Campaign::new(self.seed)
+    }
+
+    /// Adds the points of `result` and moves on to the level after it.
+    pub fn record(&mut self, result: LevelResult) {
+        self.score += result.points;
+        self.level = result.level + 1;
+        self.results.push(result);
+    }
+}
+
+/// [`CampaignError`]
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum CampaignError {
+    #[error("Invalid move {0}")]
+    InvalidMove(String),
+    #[error("Move {0} is not allowed here")]
+    IllegalMove(String),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error("{0}")]
+    Io(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn campaign_levels_get_harder() {
+        let campaign = Campaign::new(3);
+        let optimal = |domain: &str| {
+            (0..12)
+                .map(|number| campaign.level(number))
+                .filter(|level| level.problem.domain() == domain)
+                .map(|level| level.optimal_moves())
+                .collect::<Vec<usize>>()
+        };
+
+        assert_eq!(optimal("hanoi"), [7, 15, 31, 63, 127, 255]);
+        let boards = optimal("n_puzzle");
+        assert!(boards[0] < boards[4], "{:?}", boards);
+        assert_eq!(campaign.level(4), Campaign::new(3).level(4));
+        assert!(matches!(
+            campaign.level(10).problem,
+            ProblemConfig::NPuzzle { board } if board.size() == 4
+        ));
+    }
+
+    #[test]
+    fn campaign_play_scores_the_moves() {
+        let level = Campaign::new(1).level(1);
+        let mut play = level.play();
+
+        assert_eq!(play.apply("0 1"), Ok(()));
+        assert_eq!(
+            play.apply("0 1"),
+            Err(CampaignError::IllegalMove("0 1".into()))
+        );
+        assert_eq!(play.apply("x"), Err(CampaignError::InvalidMove("x".into())));
+        for movement in ["1 -> 0", "0 2", "0 1", "2 1", "0 2", "1 0", "1 2", "0 2"] {
+            assert!(!play.is_solved());
+            play.apply(movement).unwrap();
+        }
+        assert!(play.is_solved());
+
+        let result = level.result(play.moves(), None);
+        assert_eq!((result.moves, result.points), (9, 77));
+        assert_eq!(level.result(7, None).points, MAX_POINTS);
+        assert_eq!(level.result(7, Some("bfs")).points, 0);
+        assert_eq!(level.watch("bfs").unwrap().length, Some(7));
+    }
+
+    #[test]
+    fn campaign_progress_is_saved_between_sessions() {
+        let path = std::env::temp_dir().join("algoritmos_rust_campaign_progress.json");
+        let _ = std::fs::remove_file(&path);
+        let mut progress = Progress::load_or_new(&path, 5).unwrap();
+        let level = progress.campaign().level(progress.level);
+
+        progress.record(level.result(level.optimal_moves(), None));
+        progress.save(&path).unwrap();
+        let loaded = Progress::load_or_new(&path, 0).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, progress);
+        assert_eq!(
+            (loaded.seed, loaded.level, loaded.score),
+            (5, 1, MAX_POINTS)
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use super::{BoatSide, SideState, WorldState};
+
+/// [`render_ascii`]
+/// ## Bank-boat-bank picture of a state.
+/// Cannibals are `C`, missionaries `M` and the boat `\__/` floats next to its bank.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// let state = WorldState::try_from("1 2 2 1 left").unwrap();
+/// assert_eq!(render_ascii(&state), r"C   MM  |\__/~~~~~~| CC  M");
+/// ```
+pub fn render_ascii(state: &WorldState) -> String {
+    let river = match state.boat_side {
+        BoatSide::LeftSide => r"\__/~~~~~~",
+        BoatSide::RightSide => r"~~~~~~\__/",
+    };
+    format!(
+        "{} |{}| {}",
+        bank(&state.left_state),
+        river,
+        bank(&state.right_state)
+    )
+    .trim_end()
+    .to_string()
+}
+
+fn bank(side: &SideState) -> String {
+    format!(
+        "{:<3} {:<3}",
+        "C".repeat(usize::from(side.cannibals.0)),
+        "M".repeat(usize::from(side.missionaries.0))
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ascii_renders_both_banks_and_the_boat() {
+        let start = WorldState::try_from("0 0 3 3 right").unwrap();
+        let goal = WorldState::try_from("3 3 0 0 left").unwrap();
+
+        assert_eq!(render_ascii(&start), r"        |~~~~~~\__/| CCC MMM");
+        assert_eq!(render_ascii(&goal), r"CCC MMM |\__/~~~~~~|");
+    }
+}
@@ -0,0 +1,80 @@
+use super::{BoatSide, PackedWorldState};
+
+/// States evaluated together, one per lane.
+pub const LANES: usize = 16;
+
+/// [`batch_heuristics`]
+/// ## Heuristic of many packed states at once.
+/// The people left on the right bank and the side of the boat are decoded [`LANES`]
+/// states at a time, then the estimate is computed with one value per lane so the loop
+/// is independent across lanes and gets vectorized on stable Rust (`std::simd` is still
+/// nightly only). Meant for frontier chunks, it returns the same values as
+/// [`WorldState::get_heuristic`](super::WorldState::get_heuristic) on the unpacked
+/// states, whatever the size of their puzzles.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// let states = ["1 1 2 2 right", "1 1 2 2 left", "3 3 0 0 left"]
+///     .map(|state| PackedWorldState::try_from(&WorldState::try_from(state).unwrap()).unwrap());
+/// assert_eq!(batch_heuristics(&states), [1.25, 2.0, 0.0]);
+/// ```
+pub fn batch_heuristics(states: &[PackedWorldState]) -> Vec<f32> {
+    let mut heuristics = Vec::with_capacity(states.len());
+    for chunk in states.chunks(LANES) {
+        let lanes = evaluate_lanes(chunk);
+        heuristics.extend_from_slice(&lanes[..chunk.len()]);
+    }
+    heuristics
+}
+
+/// The heuristics of up to [`LANES`] states, the unused lanes are 0.
+fn evaluate_lanes(states: &[PackedWorldState]) -> [f32; LANES] {
+    let mut right = [0u16; LANES];
+    let mut boat_right = [false; LANES];
+    for (lane, state) in states.iter().enumerate() {
+        let (people, left) = (u16::from(state.size().people), state.left());
+        right[lane] = 2 * people - u16::from(left.cannibals.0) - u16::from(left.missionaries.0);
+        boat_right[lane] = state.boat_side() == BoatSide::RightSide;
+    }
+    let mut heuristics = [0f32; LANES];
+    for ((heuristic, right), boat_right) in heuristics.iter_mut().zip(right).zip(boat_right) {
+        // everyone across is a solution, without the boat bonus.
+        let bonus = if boat_right && right > 0 { 1.5 } else { 0.0 };
+        *heuristic = (f32::from(right) - bonus) / 2.0;
+    }
+    heuristics
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::{PuzzleSize, WorldState};
+
+    #[test]
+    fn batch_heuristics_match_the_scalar_heuristic() {
+        let states = [PuzzleSize::CLASSIC, PuzzleSize::new(20, 4).unwrap()]
+            .into_iter()
+            .flat_map(|size| {
+                let mut reached = vec![size.initial_state()];
+                for _ in 0..3 {
+                    let next = reached.iter().flat_map(WorldState::get_child_states);
+                    reached = next.flatten().collect();
+                }
+                reached
+            })
+            .collect::<Vec<WorldState>>();
+        let packed = states
+            .iter()
+            .map(|state| PackedWorldState::try_from(state).unwrap())
+            .collect::<Vec<PackedWorldState>>();
+
+        let batch = batch_heuristics(&packed);
+
+        assert!(states.len() > LANES * 3);
+        assert_eq!(batch.len(), states.len());
+        for (state, heuristic) in states.iter().zip(batch) {
+            assert_eq!(heuristic, state.get_heuristic(), "{state}");
+        }
+        assert!(batch_heuristics(&[]).is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+use super::{validate, BoatSide, Move, WorldState};
+use crate::genetic::{FitnessFn, RandomGene};
+use crate::rng::Rng;
+
+/// `(cannibals, missionaries)` on the boat, the genes of [`MoveSequenceEncoding`].
+pub type BoatLoad = (u8, u8);
+
+/// [`BoatLoadMutation`]
+pub type BoatLoadMutation = RandomGene<fn(&mut Rng) -> BoatLoad>;
+
+/// Every load the boat can carry.
+pub const BOAT_LOADS: [BoatLoad; 5] = [(1, 0), (2, 0), (0, 1), (0, 2), (1, 1)];
+
+/// [`MoveSequenceEncoding`]
+/// ## Cannibals solutions as chromosomes.
+/// Encoding to evolve with a [`GeneticAlgorithm`](crate::genetic::GeneticAlgorithm).
+/// A chromosome is `length` boat loads, every load crossing towards the side the boat
+/// isn't on. [`decode`](Self::decode) plays them from `initial_state`, skipping the ones
+/// that are illegal where they happen and stopping at the goal. The fitness is the number
+/// of people brought to the left bank, plus up to 1 for reaching the goal in few moves.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::genetic::*;
+/// # use algoritmos_rust::rng::Rng;
+/// let initial_state = WorldState::try_from("0 0 3 3 right").unwrap();
+/// let encoding = MoveSequenceEncoding::new(initial_state, 30);
+/// let mutation = encoding.mutation(0.05);
+/// let mut algorithm = GeneticAlgorithm::new(Tournament::new(3), OnePoint, mutation);
+/// algorithm.population_size = 200;
+/// algorithm.target_fitness = Some(MoveSequenceEncoding::SOLVED);
+/// let random = |rng: &mut Rng| encoding.random_chromosome(rng);
+/// let outcome = algorithm.run(&encoding, random, &mut Rng::new(1));
+/// let (state, moves) = encoding.decode(&outcome.best);
+/// assert!(state.is_solution());
+/// assert!(moves.len() >= 11);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MoveSequenceEncoding {
+    pub initial_state: WorldState,
+    pub length: usize,
+}
+
+impl MoveSequenceEncoding {
+    /// Chromosomes reaching the goal are fitter, the others at most 5.
+    pub const SOLVED: f64 = 6.0;
+
+    pub fn new(initial_state: WorldState, length: usize) -> Self {
+        Self {
+            initial_state,
+            length,
+        }
+    }
+
+    pub fn random_chromosome(&self, rng: &mut Rng) -> Vec<BoatLoad> {
+        (0..self.length).map(|_| random_load(rng)).collect()
+    }
+
+    /// Replaces every load with probability `rate`.
+    pub fn mutation(&self, rate: f64) -> BoatLoadMutation {
+        RandomGene::new(rate, random_load)
+    }
+
+    /// The state reached by the legal loads of `chromosome` and the moves they made.
+    pub fn decode(&self, chromosome: &[BoatLoad]) -> (WorldState, Vec<Move>) {
+        let mut state = self.initial_state.clone();
+        let mut moves = vec![];
+        for (cannibals, missionaries) in chromosome {
+            if state.is_solution() {
+                break;
+            }
+            let direction = match state.boat_side {
+                BoatSide::LeftSide => BoatSide::RightSide,
+                BoatSide::RightSide => BoatSide::LeftSide,
+            };
+            let mov = Move::new(*cannibals, *missionaries, direction);
+            if let Ok(next) = validate(&state, &[mov]) {
+                state = next;
+                moves.push(mov);
+            }
+        }
+        (state, moves)
+    }
+}
+
+impl FitnessFn<Vec<BoatLoad>> for MoveSequenceEncoding {
+    fn fitness(&self, chromosome: &Vec<BoatLoad>) -> f64 {
+        let (state, moves) = self.decode(chromosome);
+        let left = f64::from(state.left_state.cannibals.0 + state.left_state.missionaries.0);
+        if state.is_solution() {
+            left + 1.0 - moves.len() as f64 / (self.length + 1) as f64
+        } else {
+            left
+        }
+    }
+}
+
+fn random_load(rng: &mut Rng) -> BoatLoad {
+    *rng.choose(&BOAT_LOADS).expect("there are boat loads")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::search::bfs;
+    use crate::FixedCannibalsProblem;
+
+    #[test]
+    fn move_sequence_encoding_rewards_short_solutions() {
+        let encoding = MoveSequenceEncoding::new("0 0 3 3 right".try_into().unwrap(), 15);
+        let optimal = bfs(&FixedCannibalsProblem::<3, 2>).unwrap();
+        let mut chromosome = optimal
+            .actions
+            .iter()
+            .map(|mov| (mov.cannibals, mov.missionaries))
+            .collect::<Vec<_>>();
+        chromosome.resize(15, (2, 0));
+        // an illegal first load is skipped.
+        let mut longer = vec![(0, 2)];
+        longer.extend_from_slice(&chromosome[..14]);
+
+        let (state, moves) = encoding.decode(&chromosome);
+
+        assert!(state.is_solution());
+        assert_eq!(moves, optimal.actions);
+        assert_eq!(encoding.decode(&longer).1, optimal.actions);
+        assert!(encoding.fitness(&chromosome) > MoveSequenceEncoding::SOLVED);
+        let unsolved = vec![(1, 1); 15];
+        assert!(encoding.fitness(&unsolved) < MoveSequenceEncoding::SOLVED);
+    }
+}
@@ -0,0 +1,302 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{BoatSide, Cannibals, GoalCondition, Missionaries, Move, SideState, WorldStateError};
+use crate::search::{BoxedSuccessors, SearchProblem, Successor};
+
+/// [`FixedWorldState`]
+/// ## World state with the puzzle size fixed at compile time.
+/// `N` cannibals and `N` missionaries, the boat carries from 1 to `BOAT` people. Only the
+/// left bank and the boat side are stored, so the state is `Copy`, hashable and never
+/// allocates. Sizes that make no sense (`N == 0` or `BOAT == 0`) are rejected at compile
+/// time. [`WorldState`](super::WorldState) remains the variant configured at runtime.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// type Classic = FixedWorldState<3, 2>;
+/// let state = Classic::initial();
+/// assert_eq!(state.to_string(), "0 0 3 3 right");
+/// assert_eq!(state.moves().count(), 5);
+/// ```
+/// ```compile_fail
+/// # use algoritmos_rust::cannibals::*;
+/// let boatless = FixedWorldState::<3, 0>::initial();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedWorldState<const N: u8, const BOAT: u8> {
+    left_cannibals: Cannibals,
+    left_missionaries: Missionaries,
+    boat_side: BoatSide,
+}
+
+impl<const N: u8, const BOAT: u8> FixedWorldState<N, BOAT> {
+    const VALID_SIZE: () = assert!(N > 0 && BOAT > 0, "N and BOAT must be positive");
+
+    /// Everyone and the boat on the right bank.
+    pub fn initial() -> Self {
+        let () = Self::VALID_SIZE;
+        Self {
+            left_cannibals: Cannibals(0),
+            left_missionaries: Missionaries(0),
+            boat_side: BoatSide::RightSide,
+        }
+    }
+
+    pub fn new(left: SideState, boat_side: BoatSide) -> Result<Self, WorldStateError> {
+        let () = Self::VALID_SIZE;
+        match (left.cannibals, left.missionaries) {
+            (Cannibals(can), _) if can > N => {
+                Err(WorldStateError::ImpossibleNumberOfCannibals(can))
+            }
+            (_, Missionaries(mis)) if mis > N => {
+                Err(WorldStateError::ImpossibleNumberOfMissionaries(mis))
+            }
+            (left_cannibals, left_missionaries) => Ok(Self {
+                left_cannibals,
+                left_missionaries,
+                boat_side,
+            }),
+        }
+    }
+
+    pub fn left(&self) -> SideState {
+        SideState::new(self.left_cannibals, self.left_missionaries)
+    }
+
+    pub fn right(&self) -> SideState {
+        SideState::new(
+            Cannibals(N - self.left_cannibals.0),
+            Missionaries(N - self.left_missionaries.0),
+        )
+    }
+
+    pub fn boat_side(&self) -> BoatSide {
+        self.boat_side
+    }
+
+    pub fn is_solution(&self) -> bool {
+        self.satisfies(&GoalCondition::EveryoneAcross)
+    }
+
+    /// Whether this state meets `goal` without anyone being eaten.
+    pub fn satisfies(&self, goal: &GoalCondition) -> bool {
+        goal.is_met(&self.left(), &self.right(), self.boat_side) && !self.is_game_over()
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.left().cannibal_can_eat_missionary() || self.right().cannibal_can_eat_missionary()
+    }
+
+    /// Same estimate as [`WorldState::get_heuristic`](super::WorldState::get_heuristic):
+    /// half the people still on the right, minus a bonus when the boat is there.
+    pub fn heuristic(&self) -> f64 {
+        let right = self.right();
+        let boat_bonus = match self.boat_side {
+            BoatSide::RightSide if !self.is_solution() => 1.5,
+            _ => 0.0,
+        };
+        (f64::from(right.cannibals.0 + right.missionaries.0) - boat_bonus) / 2.0
+    }
+
+    /// [`moves`]
+    /// Every boat load (1 to `BOAT` people) available on the boat side.
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        let (from, direction) = match self.boat_side {
+            BoatSide::LeftSide => (self.left(), BoatSide::RightSide),
+            BoatSide::RightSide => (self.right(), BoatSide::LeftSide),
+        };
+        (0..=from.cannibals.0.min(BOAT)).flat_map(move |cannibals| {
+            (0..=from.missionaries.0.min(BOAT - cannibals))
+                .filter(move |missionaries| cannibals + missionaries > 0)
+                .map(move |missionaries| Move::new(cannibals, missionaries, direction))
+        })
+    }
+
+    /// [`apply`]
+    /// The state after `mov`, `None` when the move is not one of [`moves`](Self::moves).
+    pub fn apply(&self, mov: Move) -> Option<Self> {
+        if mov.direction == self.boat_side || !(1..=BOAT).contains(&mov.passengers()) {
+            return None;
+        }
+        let group = SideState::new(Cannibals(mov.cannibals), Missionaries(mov.missionaries));
+        let left = match mov.direction {
+            BoatSide::LeftSide => self.left().checked_add(group)?,
+            BoatSide::RightSide => self.left().checked_sub(group)?,
+        };
+        Self::new(left, mov.direction).ok()
+    }
+}
+
+/// Same format as [`WorldState`](super::WorldState) strings, e.g. `"1 1 2 2 right"`.
+impl<const N: u8, const BOAT: u8> Display for FixedWorldState<N, BOAT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let boat_side: String = self.boat_side.into();
+        let (left, right) = (self.left(), self.right());
+        write!(
+            f,
+            "{} {} {} {} {}",
+            left.cannibals, left.missionaries, right.cannibals, right.missionaries, boat_side
+        )
+    }
+}
+
+/// The same position in a [`WorldState`](super::WorldState) of size `N` and `BOAT`,
+/// without moves.
+impl<const N: u8, const BOAT: u8> From<FixedWorldState<N, BOAT>> for super::WorldState {
+    fn from(value: FixedWorldState<N, BOAT>) -> Self {
+        let size = super::PuzzleSize {
+            people: N,
+            boat_capacity: BOAT,
+        };
+        super::WorldState::new_sized(
+            size,
+            value.left(),
+            value.right(),
+            value.boat_side,
+            vec![],
+            0,
+        )
+        .expect("fixed states are valid")
+    }
+}
+
+/// [`FixedCannibalsProblem`]
+/// Search problem over [`FixedWorldState`], every crossing costs 1 and states that leave
+/// missionaries outnumbered are dead ends.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixedCannibalsProblem<const N: u8, const BOAT: u8>;
+
+impl<const N: u8, const BOAT: u8> SearchProblem for FixedCannibalsProblem<N, BOAT> {
+    type State = FixedWorldState<N, BOAT>;
+    type Action = Move;
+    type Cost = u32;
+    type Successors<'a> = BoxedSuccessors<'a, FixedWorldState<N, BOAT>, Move>;
+
+    fn initial_state(&self) -> FixedWorldState<N, BOAT> {
+        FixedWorldState::initial()
+    }
+
+    fn is_goal(&self, state: &FixedWorldState<N, BOAT>) -> bool {
+        state.is_solution()
+    }
+
+    fn successors<'a>(&'a self, state: &'a FixedWorldState<N, BOAT>) -> Self::Successors<'a> {
+        Box::new(
+            state
+                .moves()
+                .filter_map(|mov| state.apply(mov).map(|next| (mov, next)))
+                .map(|(mov, next)| Successor::new(mov, next, 1)),
+        )
+    }
+
+    fn is_dead_end(&self, state: &FixedWorldState<N, BOAT>) -> bool {
+        state.is_game_over()
+    }
+}
+
+/// [`FixedCannibalsGoalProblem`]
+/// ## [`FixedCannibalsProblem`] from any position to any goal.
+/// Same moves and dead ends, but the search starts at `start` and stops at the first state
+/// that [satisfies](FixedWorldState::satisfies) `goal`.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::search::bfs;
+/// let goal = GoalCondition::Custom(|left, _, boat_side| {
+///     boat_side == BoatSide::RightSide && left.missionaries == Missionaries(2)
+/// });
+/// let problem = FixedCannibalsGoalProblem::<3, 2>::new(goal);
+/// let solution = bfs(&problem).unwrap();
+/// assert_eq!(solution.goal().to_string(), "2 2 1 1 right");
+/// assert_eq!(solution.len(), 10);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FixedCannibalsGoalProblem<const N: u8, const BOAT: u8> {
+    pub start: FixedWorldState<N, BOAT>,
+    pub goal: GoalCondition,
+}
+
+impl<const N: u8, const BOAT: u8> FixedCannibalsGoalProblem<N, BOAT> {
+    /// Searches for `goal` from the initial state.
+    pub fn new(goal: GoalCondition) -> Self {
+        Self {
+            start: FixedWorldState::initial(),
+            goal,
+        }
+    }
+
+    pub fn from_state(mut self, start: FixedWorldState<N, BOAT>) -> Self {
+        self.start = start;
+        self
+    }
+}
+
+impl<const N: u8, const BOAT: u8> Default for FixedCannibalsGoalProblem<N, BOAT> {
+    fn default() -> Self {
+        Self::new(GoalCondition::default())
+    }
+}
+
+impl<const N: u8, const BOAT: u8> SearchProblem for FixedCannibalsGoalProblem<N, BOAT> {
+    type State = FixedWorldState<N, BOAT>;
+    type Action = Move;
+    type Cost = u32;
+    type Successors<'a> = <FixedCannibalsProblem<N, BOAT> as SearchProblem>::Successors<'a>;
+
+    fn initial_state(&self) -> FixedWorldState<N, BOAT> {
+        self.start
+    }
+
+    fn is_goal(&self, state: &FixedWorldState<N, BOAT>) -> bool {
+        state.satisfies(&self.goal)
+    }
+
+    fn successors<'a>(&'a self, state: &'a FixedWorldState<N, BOAT>) -> Self::Successors<'a> {
+        FixedCannibalsProblem.successors(state)
+    }
+
+    fn is_dead_end(&self, state: &FixedWorldState<N, BOAT>) -> bool {
+        FixedCannibalsProblem.is_dead_end(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::{validate, WorldState};
+    use crate::search::{bfs, solve_or_prove, Verdict};
+
+    #[test]
+    fn fixed_classic_puzzle_matches_world_state() {
+        let solution = bfs(&FixedCannibalsProblem::<3, 2>).unwrap();
+
+        assert_eq!(solution.len(), 11);
+        assert_eq!(std::mem::size_of::<FixedWorldState<3, 2>>(), 3);
+        let initial = WorldState::try_from(solution.states[0].to_string().as_str()).unwrap();
+        assert!(validate(&initial, &solution.actions).unwrap().is_solution());
+        for state in &solution.states {
+            let world_state = WorldState::try_from(state.to_string().as_str()).unwrap();
+            assert_eq!(state.heuristic(), f64::from(world_state.get_heuristic()));
+        }
+    }
+
+    #[test]
+    fn fixed_sizes_change_solvability() {
+        assert!(matches!(
+            solve_or_prove(&FixedCannibalsProblem::<4, 2>, 1000),
+            Verdict::Unsolvable(_)
+        ));
+        let solution = bfs(&FixedCannibalsProblem::<5, 3>).unwrap();
+        assert_eq!(solution.len(), 11);
+        assert_eq!(
+            FixedWorldState::<3, 2>::new(
+                SideState::new(Cannibals(4), Missionaries(0)),
+                BoatSide::LeftSide
+            ),
+            Err(WorldStateError::ImpossibleNumberOfCannibals(4))
+        );
+    }
+}
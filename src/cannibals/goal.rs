@@ -0,0 +1,78 @@
+use super::{BoatSide, Cannibals, Missionaries, SideState};
+
+/// [`GoalCondition`]
+/// ## What a cannibals search is looking for.
+/// The puzzle asks for everyone on the left bank, other goals ask for a given state or
+/// anything a function accepts. A goal is never met when someone gets eaten, see
+/// [`WorldState::satisfies`](super::WorldState::satisfies) and
+/// [`FixedWorldState::satisfies`](super::FixedWorldState::satisfies).
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// // boat on the right with 2 missionaries across.
+/// let goal = GoalCondition::Custom(|left, _, boat_side| {
+///     boat_side == BoatSide::RightSide && left.missionaries == Missionaries(2)
+/// });
+/// let state = WorldState::try_from("2 2 1 1 right").unwrap();
+/// assert!(state.satisfies(&goal));
+/// assert!(!state.satisfies(&GoalCondition::EveryoneAcross));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub enum GoalCondition {
+    /// Everyone on the left bank, the goal of the puzzle.
+    #[default]
+    EveryoneAcross,
+    /// Exactly `left` on the left bank with the boat on `boat_side`.
+    State {
+        left: SideState,
+        boat_side: BoatSide,
+    },
+    /// Met when the function accepts the left bank, the right bank and the boat side.
+    Custom(fn(&SideState, &SideState, BoatSide) -> bool),
+}
+
+impl GoalCondition {
+    /// Whether the banks and the boat side meet the goal, whoever is eaten.
+    pub fn is_met(&self, left: &SideState, right: &SideState, boat_side: BoatSide) -> bool {
+        match self {
+            GoalCondition::EveryoneAcross => {
+                right.cannibals == Cannibals(0) && right.missionaries == Missionaries(0)
+            }
+            GoalCondition::State {
+                left: goal_left,
+                boat_side: goal_boat_side,
+            } => left == goal_left && boat_side == *goal_boat_side,
+            GoalCondition::Custom(is_goal) => is_goal(left, right, boat_side),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::{FixedCannibalsGoalProblem, FixedWorldState, WorldState};
+    use crate::search::bfs;
+
+    #[test]
+    fn goal_condition_state_stops_the_search_there() {
+        let left = SideState::new(Cannibals(1), Missionaries(1));
+        let goal = GoalCondition::State {
+            left,
+            boat_side: BoatSide::LeftSide,
+        };
+
+        let solution = bfs(&FixedCannibalsGoalProblem::<3, 2>::new(goal)).unwrap();
+
+        assert_eq!(solution.goal().to_string(), "1 1 2 2 left");
+        assert_eq!(solution.len(), 1);
+        let back = FixedCannibalsGoalProblem::new(GoalCondition::State {
+            left: SideState::new(Cannibals(0), Missionaries(0)),
+            boat_side: BoatSide::RightSide,
+        })
+        .from_state(FixedWorldState::<3, 2>::new(left, BoatSide::LeftSide).unwrap());
+        assert_eq!(bfs(&back).unwrap().len(), 1);
+        // someone is eaten, whatever the goal says.
+        let eaten = WorldState::try_from("2 1 1 2 left").unwrap();
+        assert!(!eaten.satisfies(&GoalCondition::Custom(|_, _, _| true)));
+    }
+}
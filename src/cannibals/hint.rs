@@ -0,0 +1,120 @@
+use super::{CannibalsProblem, Move, WorldState};
+use crate::search::a_star;
+
+/// [`NextMove`]
+/// The first move of a shortest way to the goal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NextMove {
+    pub mov: Move,
+    /// Crossings from the current state to the goal, `mov` included.
+    pub remaining_cost: u32,
+}
+
+impl WorldState {
+    /// [`best_next_move`]
+    /// ## Hint for trainers and interactive modes.
+    /// Runs A* from this state, whatever the size of its puzzle, and keeps the first move
+    /// of the optimal path with its cost. `None` when the state is already solved, is a
+    /// game over, or no solution can be reached from it.
+    /// # Example
+    /// ```
+    /// # use algoritmos_rust::cannibals::*;
+    /// let state = WorldState::try_from("0 0 3 3 right").unwrap();
+    /// let next = state.best_next_move().unwrap();
+    /// assert_eq!(next.remaining_cost, 11);
+    /// let state = state.apply_move(next.mov).unwrap();
+    /// assert_eq!(state.best_next_move().unwrap().remaining_cost, 10);
+    /// ```
+    pub fn best_next_move(&self) -> Option<NextMove> {
+        if self.is_solution() || self.is_game_over() {
+            return None;
+        }
+        let problem = CannibalsProblem::new(self.clone());
+        let solution = a_star(&problem, |state| problem.heuristic(state))?;
+        Some(NextMove {
+            mov: *solution.actions.first()?,
+            remaining_cost: solution.cost,
+        })
+    }
+}
+
+/// [`suggest_move`]
+/// ## Hint for the player.
+/// The move of [`best_next_move`](WorldState::best_next_move), for puzzles of any size.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// let state = WorldState::try_from("0 0 3 3 right").unwrap();
+/// let hint = suggest_move(&state).unwrap();
+/// let next = validate(&state, &[hint]).unwrap();
+/// assert!(next.get_heuristic() < state.get_heuristic());
+/// ```
+pub fn suggest_move(state: &WorldState) -> Option<Move> {
+    state.best_next_move().map(|next| next.mov)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::{validate, PuzzleSize};
+
+    #[test]
+    fn hint_following_the_hints_solves_the_puzzle() {
+        let mut state = WorldState::try_from("0 0 3 3 right").unwrap();
+        let mut moves = 0;
+
+        while let Some(hint) = suggest_move(&state) {
+            state = validate(&state, &[hint]).unwrap();
+            moves += 1;
+        }
+
+        assert!(state.is_solution());
+        assert_eq!(moves, 11);
+        assert_eq!(
+            suggest_move(&WorldState::try_from("2 1 1 2 left").unwrap()),
+            None,
+            "game over"
+        );
+        let larger = PuzzleSize::new(5, 3).unwrap().initial_state();
+        let next = larger.best_next_move().unwrap();
+        assert_eq!(suggest_move(&larger), Some(next.mov));
+        assert_eq!(
+            larger
+                .apply_move(next.mov)
+                .unwrap()
+                .best_next_move()
+                .unwrap()
+                .remaining_cost,
+            next.remaining_cost - 1
+        );
+        assert_eq!(
+            PuzzleSize::new(4, 2)
+                .unwrap()
+                .initial_state()
+                .best_next_move(),
+            None
+        );
+    }
+
+    #[test]
+    fn hint_suggests_moves_for_puzzles_of_any_size() {
+        for (people, boat) in [(4, 3), (5, 3)] {
+            let mut state = PuzzleSize::new(people, boat).unwrap().initial_state();
+            let optimal = state.best_next_move().unwrap().remaining_cost;
+            let mut moves = 0;
+
+            while let Some(hint) = suggest_move(&state) {
+                state = validate(&state, &[hint]).unwrap();
+                moves += 1;
+            }
+
+            assert!(state.is_solution(), "{people} {boat}");
+            assert_eq!(moves, optimal);
+        }
+        assert_eq!(
+            suggest_move(&PuzzleSize::new(4, 2).unwrap().initial_state()),
+            None,
+            "no solution"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use super::{BoatSide, Move};
+
+/// [`Locale`]
+/// Language of the messages shown to the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    PtBr,
+}
+
+impl Locale {
+    /// [`from_env`]
+    /// [`Locale::PtBr`] when `LC_ALL`, `LC_MESSAGES` or `LANG` (the first one set) starts
+    /// with `pt`, [`Locale::En`] otherwise.
+    pub fn from_env() -> Self {
+        let language = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+        Self::from_tag(&language)
+    }
+
+    /// Locale of a language tag such as `pt-BR`, `pt_BR.UTF-8` or `en_US`.
+    pub fn from_tag(tag: &str) -> Self {
+        if tag.to_lowercase().starts_with("pt") {
+            Locale::PtBr
+        } else {
+            Locale::En
+        }
+    }
+}
+
+/// `singular` or `plural` according to `count`, prefixed by `count`.
+fn counted(count: u8, singular: &str, plural: &str) -> String {
+    match count {
+        1 => format!("1 {}", singular),
+        _ => format!("{} {}", count, plural),
+    }
+}
+
+/// Noun phrase for a group of people, e.g. "2 cannibals and 1 missionary", "nobody" when
+/// the group is empty.
+pub(super) fn people(cannibals: u8, missionaries: u8, locale: Locale) -> String {
+    let (cannibal_words, missionary_words, and, nobody) = match locale {
+        Locale::En => (
+            ("cannibal", "cannibals"),
+            ("missionary", "missionaries"),
+            "and",
+            "nobody",
+        ),
+        Locale::PtBr => (
+            ("canibal", "canibais"),
+            ("missionário", "missionários"),
+            "e",
+            "ninguém",
+        ),
+    };
+    let cannibal_phrase = counted(cannibals, cannibal_words.0, cannibal_words.1);
+    let missionary_phrase = counted(missionaries, missionary_words.0, missionary_words.1);
+    match (cannibals, missionaries) {
+        (0, 0) => nobody.to_string(),
+        (_, 0) => cannibal_phrase,
+        (0, _) => missionary_phrase,
+        _ => format!("{} {} {}", cannibal_phrase, and, missionary_phrase),
+    }
+}
+
+impl Move {
+    /// [`describe`]
+    /// ## Localized description of the move.
+    /// Pluralizes the nouns and leaves out the kind of people that don't cross.
+    /// The [`Display`](std::fmt::Display) format is kept for the step by step and parsing.
+    /// # Example
+    /// ```
+    /// # use algoritmos_rust::cannibals::*;
+    /// let mov = Move::new(2, 1, BoatSide::RightSide);
+    /// assert_eq!(mov.describe(Locale::En), "send 2 cannibals and 1 missionary to the right bank");
+    /// assert_eq!(mov.describe(Locale::PtBr), "levar 2 canibais e 1 missionário para a margem direita");
+    /// ```
+    pub fn describe(&self, locale: Locale) -> String {
+        let (send, side) = match (locale, self.direction) {
+            (Locale::En, BoatSide::LeftSide) => ("send", "to the left bank"),
+            (Locale::En, BoatSide::RightSide) => ("send", "to the right bank"),
+            (Locale::PtBr, BoatSide::LeftSide) => ("levar", "para a margem esquerda"),
+            (Locale::PtBr, BoatSide::RightSide) => ("levar", "para a margem direita"),
+        };
+        format!(
+            "{} {} {}",
+            send,
+            people(self.cannibals, self.missionaries, locale),
+            side
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn locale_describe_pluralizes_and_omits_empty_groups() {
+        let one_cannibal = Move::new(1, 0, BoatSide::LeftSide);
+        let two_missionaries = Move::new(0, 2, BoatSide::RightSide);
+
+        assert_eq!(
+            one_cannibal.describe(Locale::En),
+            "send 1 cannibal to the left bank"
+        );
+        assert_eq!(
+            two_missionaries.describe(Locale::PtBr),
+            "levar 2 missionários para a margem direita"
+        );
+        assert_eq!(Locale::from_tag("pt_BR.UTF-8"), Locale::PtBr);
+        assert_eq!(Locale::from_tag("en_US"), Locale::En);
+        assert_eq!(Locale::from_tag(""), Locale::En);
+    }
+}
@@ -1,5 +1,37 @@
+pub mod ascii;
+pub mod batch;
+pub mod evolution;
+pub mod fixed;
+pub mod goal;
+pub mod hint;
+pub mod locale;
+pub mod moves;
+pub mod narration;
+pub mod neighborhood;
+pub mod packed;
+pub mod parser;
+pub mod problem;
+pub mod report;
 pub mod side_state;
+pub mod svg;
+pub mod validator;
 pub mod world_state;
 
+pub use ascii::*;
+pub use batch::*;
+pub use evolution::*;
+pub use fixed::*;
+pub use goal::*;
+pub use hint::*;
+pub use locale::*;
+pub use moves::*;
+pub use narration::*;
+pub use neighborhood::*;
+pub use packed::*;
+pub use parser::*;
+pub use problem::*;
+pub use report::*;
 pub use side_state::*;
+pub use svg::*;
+pub use validator::*;
 pub use world_state::*;
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{BoatSide, WorldStateError};
+
+/// [`Move`]
+/// Sends `cannibals` and `missionaries` with the boat to the `direction` side.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Move {
+    pub cannibals: u8,
+    pub missionaries: u8,
+    pub direction: BoatSide,
+}
+
+impl Move {
+    pub fn new(cannibals: u8, missionaries: u8, direction: BoatSide) -> Self {
+        Self {
+            cannibals,
+            missionaries,
+            direction,
+        }
+    }
+
+    /// Number of people on the boat.
+    pub fn passengers(&self) -> u8 {
+        self.cannibals.saturating_add(self.missionaries)
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let side = match self.direction {
+            BoatSide::LeftSide => "left",
+            BoatSide::RightSide => "right",
+        };
+        write!(
+            f,
+            "send {} cannibals and {} missionaries to the {} side",
+            self.cannibals, self.missionaries, side
+        )
+    }
+}
+
+/// [TryFrom<&str>]
+/// Accepts the [`Display`] format:
+/// `"send u8 cannibals and u8 missionaries to the left | right side"`.
+impl TryFrom<&str> for Move {
+    type Error = WorldStateError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["send", cannibals, "cannibals", "and", missionaries, "missionaries", "to", "the", side, "side"] => {
+                Ok(Move::new(
+                    cannibals.parse()?,
+                    missionaries.parse()?,
+                    BoatSide::try_from(side)?,
+                ))
+            }
+            _ => Err(WorldStateError::ParseFromStringError(format!(
+                "Invalid move {}",
+                value
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn move_display_and_try_from_round_trip() {
+        let mov = Move::new(2, 1, BoatSide::RightSide);
+
+        let text = mov.to_string();
+
+        assert_eq!(
+            text,
+            "send 2 cannibals and 1 missionaries to the right side"
+        );
+        assert_eq!(Move::try_from(text.as_str()), Ok(mov));
+        assert!(Move::try_from("send 2 cannibals").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use super::locale::people;
+use super::{replay, BoatSide, Locale, Move, ValidationError, WorldState};
+
+/// [`NarrationTemplate`]
+/// ## Sentences used by [`narrate`].
+/// Every method has an English default, override the ones to customize. A move towards
+/// the left bank (the goal) is a crossing, a move back to the right bank is a return.
+pub trait NarrationTemplate {
+    /// Sentence for the `step`-th move (starting at 0) that goes to the left bank.
+    fn crossing(&self, step: usize, mov: &Move, after: &WorldState) -> String {
+        format!(
+            "{} {} {} to the left bank. {}",
+            connective(step),
+            people(mov.cannibals, mov.missionaries, Locale::En),
+            verb(mov, "crosses", "cross"),
+            self.tally(after)
+        )
+    }
+
+    /// Sentence for the `step`-th move that comes back to the right bank.
+    fn returning(&self, step: usize, mov: &Move, after: &WorldState) -> String {
+        format!(
+            "{} {} {} to the right bank. {}",
+            connective(step),
+            people(mov.cannibals, mov.missionaries, Locale::En),
+            verb(mov, "returns", "return"),
+            self.tally(after)
+        )
+    }
+
+    /// Running tally of both banks.
+    fn tally(&self, state: &WorldState) -> String {
+        format!(
+            "The left bank now has {} and the right bank {}.",
+            people(
+                state.left_state.cannibals.0,
+                state.left_state.missionaries.0,
+                Locale::En
+            ),
+            people(
+                state.right_state.cannibals.0,
+                state.right_state.missionaries.0,
+                Locale::En
+            ),
+        )
+    }
+
+    /// Closing sentence, `crossings` is the number of moves.
+    fn conclusion(&self, final_state: &WorldState, crossings: usize) -> String {
+        if final_state.is_solution() {
+            format!(
+                "After {} crossings everybody is safely on the left bank.",
+                crossings
+            )
+        } else {
+            format!(
+                "After {} crossings the puzzle is not solved yet.",
+                crossings
+            )
+        }
+    }
+}
+
+fn connective(step: usize) -> &'static str {
+    if step == 0 {
+        "First,"
+    } else {
+        "Then"
+    }
+}
+
+fn verb(mov: &Move, singular: &'static str, plural: &'static str) -> &'static str {
+    if mov.passengers() == 1 {
+        singular
+    } else {
+        plural
+    }
+}
+
+/// [`DefaultNarration`]
+/// [`NarrationTemplate`] with every default sentence.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultNarration;
+
+impl NarrationTemplate for DefaultNarration {}
+
+/// [`narrate`]
+/// ## Paragraph explaining a solution.
+/// Replays `moves` from `initial_state` (see [`replay`]) and joins one sentence per move
+/// and the conclusion, as written by `template`.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// let initial = WorldState::try_from("0 0 3 3 right").unwrap();
+/// let moves = [Move::new(2, 0, BoatSide::LeftSide)];
+/// let text = narrate(&initial, &moves, &DefaultNarration).unwrap();
+/// assert!(text.starts_with("First, 2 cannibals cross to the left bank."));
+/// ```
+pub fn narrate(
+    initial_state: &WorldState,
+    moves: &[Move],
+    template: &dyn NarrationTemplate,
+) -> Result<String, ValidationError> {
+    let states = replay(initial_state, moves)?;
+    let mut sentences = moves
+        .iter()
+        .zip(&states[1..])
+        .enumerate()
+        .map(|(step, (mov, after))| match mov.direction {
+            BoatSide::LeftSide => template.crossing(step, mov, after),
+            BoatSide::RightSide => template.returning(step, mov, after),
+        })
+        .collect::<Vec<String>>();
+    sentences.push(template.conclusion(&states[states.len() - 1], moves.len()));
+    Ok(sentences.join(" "))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn moves() -> [Move; 2] {
+        [
+            Move::new(1, 1, BoatSide::LeftSide),
+            Move::new(0, 1, BoatSide::RightSide),
+        ]
+    }
+
+    #[test]
+    fn narration_tells_who_crosses_and_returns() {
+        let initial = WorldState::try_from("0 0 3 3 right").unwrap();
+
+        let text = narrate(&initial, &moves(), &DefaultNarration).unwrap();
+
+        assert_eq!(
+            text,
+            "First, 1 cannibal and 1 missionary cross to the left bank. \
+             The left bank now has 1 cannibal and 1 missionary and the right bank 2 cannibals and 2 missionaries. \
+             Then 1 missionary returns to the right bank. \
+             The left bank now has 1 cannibal and the right bank 2 cannibals and 3 missionaries. \
+             After 2 crossings the puzzle is not solved yet."
+        );
+    }
+
+    #[test]
+    fn narration_template_can_be_customized() {
+        struct Terse;
+        impl NarrationTemplate for Terse {
+            fn tally(&self, state: &WorldState) -> String {
+                format!("[{}]", String::from(state))
+            }
+            fn conclusion(&self, _: &WorldState, crossings: usize) -> String {
+                format!("{} moves.", crossings)
+            }
+        }
+        let initial = WorldState::try_from("0 0 3 3 right").unwrap();
+
+        let text = narrate(&initial, &moves(), &Terse).unwrap();
+
+        assert!(text.contains("to the left bank. [1 1 2 2 left]"));
+        assert!(text.ends_with("[1 0 2 3 right] 2 moves."));
+        assert!(narrate(&initial, &moves()[1..], &Terse).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use super::{BoatSide, Cannibals, Missionaries, SideState, WorldState};
+use crate::local_search::LocalSearchProblem;
+use crate::rng::Rng;
+
+/// [`CannibalsLocalSearch`]
+/// Exposes the classic puzzle as a [`LocalSearchProblem`] minimizing
+/// [`WorldState::get_heuristic`], the neighbors of a state being its children that
+/// aren't a game over. The heuristic is flat over many crossings (every trip back to the
+/// left undoes part of the progress), so hill climbing gets stuck quickly, and its lowest
+/// value is one crossing away from the goal rather than at the goal.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::local_search::*;
+/// let restarts = SteepestAscent::default().with_restarts(20, 0).run(&CannibalsLocalSearch);
+/// assert_eq!(restarts.best_state.to_string(), "2 3 1 0 right");
+/// assert!(restarts.best_cost < CannibalsLocalSearch.cost(&"3 3 0 0 left".try_into().unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CannibalsLocalSearch;
+
+impl LocalSearchProblem for CannibalsLocalSearch {
+    type State = WorldState;
+
+    /// Any position that isn't a game over, reached by no moves.
+    fn random_state(&self, rng: &mut Rng) -> WorldState {
+        loop {
+            let (cannibals, missionaries) = (rng.gen_range(0..4) as u8, rng.gen_range(0..4) as u8);
+            let boat_side = *rng
+                .choose(&[BoatSide::LeftSide, BoatSide::RightSide])
+                .expect("two sides");
+            let state = WorldState::new(
+                SideState::new(Cannibals(cannibals), Missionaries(missionaries)),
+                SideState::new(Cannibals(3 - cannibals), Missionaries(3 - missionaries)),
+                boat_side,
+                vec![],
+                0,
+            )
+            .expect("the totals are always 3");
+            if !state.is_game_over() {
+                return state;
+            }
+        }
+    }
+
+    fn neighbors(&self, state: &WorldState) -> Vec<WorldState> {
+        state
+            .get_child_states()
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|child| !child.is_game_over())
+            .collect()
+    }
+
+    fn cost(&self, state: &WorldState) -> f64 {
+        f64::from(state.get_heuristic())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::local_search::{FirstChoice, LocalSearch, SteepestAscent};
+
+    #[test]
+    fn cannibals_hill_climbing_gets_stuck_from_the_initial_state() {
+        let start = WorldState::try_from("0 0 3 3 right").unwrap();
+        let mut rng = Rng::new(0);
+
+        let steepest =
+            SteepestAscent::default().search(&CannibalsLocalSearch, start.clone(), &mut rng);
+        let first_choice = FirstChoice.search(&CannibalsLocalSearch, start, &mut rng);
+
+        assert!(!steepest.best_state.is_solution());
+        assert!(steepest.best_cost > 0.0);
+        assert!(!first_choice.best_state.is_solution());
+        for _ in 0..20 {
+            let state = CannibalsLocalSearch.random_state(&mut rng);
+            assert!(!state.is_game_over());
+            assert!(CannibalsLocalSearch
+                .neighbors(&state)
+                .iter()
+                .all(|neighbor| !neighbor.is_game_over()));
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use super::{
+    BoatSide, Cannibals, Missionaries, PuzzleSize, SideState, WorldState, WorldStateError,
+};
+
+const CAPACITY_SHIFT: u32 = u8::BITS;
+const BOAT_SIDE_BIT: u32 = 2 * u8::BITS;
+const COUNTS_SHIFT: u32 = BOAT_SIDE_BIT + 1;
+
+/// Bits taken by a count of at most `people`.
+fn count_bits(people: u8) -> u32 {
+    u8::BITS - people.leading_zeros()
+}
+
+/// [`PackedWorldState`]
+/// ## A [`WorldState`] in 32 bits.
+/// 8 bits for the number of people of each kind, 8 for the boat capacity, 1 for the boat
+/// side, then the left cannibals and missionaries with as many bits as the number of
+/// people needs (2 each for the classic puzzle), the right bank being the rest of them.
+/// Puzzles of up to 127 people of each kind pack without loss, apart from the moves that
+/// led to the state. A fixed 2 bits per count would fit in 16 bits but only pack puzzles
+/// of up to 3 people, so the state takes 32 bits for the larger puzzles to pack too.
+/// Searches over [`PackedCannibalsProblem`](super::PackedCannibalsProblem) keep these
+/// 4 bytes per state in their visited sets and frontiers.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// let state = WorldState::try_from("1 1 2 2 left").unwrap();
+/// let packed = PackedWorldState::try_from(&state).unwrap();
+/// assert_eq!(WorldState::from(packed), state);
+/// let larger = PuzzleSize::new(5, 3).unwrap().initial_state();
+/// assert_eq!(PackedWorldState::try_from(&larger).unwrap().size(), larger.size());
+/// let crowded = PuzzleSize::new(128, 2).unwrap().initial_state();
+/// assert!(PackedWorldState::try_from(&crowded).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PackedWorldState(u32);
+
+impl PackedWorldState {
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn size(&self) -> PuzzleSize {
+        PuzzleSize {
+            people: self.0 as u8,
+            boat_capacity: (self.0 >> CAPACITY_SHIFT) as u8,
+        }
+    }
+
+    /// The people on the left bank.
+    pub fn left(&self) -> SideState {
+        let width = count_bits(self.size().people);
+        let mask = (1 << width) - 1;
+        let counts = self.0 >> COUNTS_SHIFT;
+        SideState::new(
+            Cannibals((counts & mask) as u8),
+            Missionaries((counts >> width & mask) as u8),
+        )
+    }
+
+    pub fn boat_side(&self) -> BoatSide {
+        match self.0 & (1 << BOAT_SIDE_BIT) {
+            0 => BoatSide::RightSide,
+            _ => BoatSide::LeftSide,
+        }
+    }
+}
+
+impl TryFrom<&WorldState> for PackedWorldState {
+    type Error = WorldStateError;
+
+    /// Fails for puzzles of more than 127 people of each kind.
+    fn try_from(value: &WorldState) -> Result<Self, Self::Error> {
+        let size = value.size();
+        let width = count_bits(size.people);
+        if COUNTS_SHIFT + 2 * width > u32::BITS {
+            return Err(WorldStateError::TooLargeToPack(
+                size.people,
+                size.boat_capacity,
+            ));
+        }
+        let counts = u32::from(value.left_state.cannibals.0)
+            | u32::from(value.left_state.missionaries.0) << width;
+        let boat_side = match value.boat_side {
+            BoatSide::RightSide => 0,
+            BoatSide::LeftSide => 1 << BOAT_SIDE_BIT,
+        };
+        Ok(Self(
+            u32::from(size.people)
+                | u32::from(size.boat_capacity) << CAPACITY_SHIFT
+                | boat_side
+                | counts << COUNTS_SHIFT,
+        ))
+    }
+}
+
+/// The unpacked state has no moves, like a parsed one.
+impl From<PackedWorldState> for WorldState {
+    fn from(value: PackedWorldState) -> Self {
+        let size = value.size();
+        let left = value.left();
+        let right = SideState::new(
+            Cannibals(size.people - left.cannibals.0),
+            Missionaries(size.people - left.missionaries.0),
+        );
+        WorldState::new_sized(size, left, right, value.boat_side(), vec![], 0)
+            .expect("only valid states are packed")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::Move;
+    use crate::datasets::cannibals_variants;
+
+    #[test]
+    fn packed_world_state_round_trips() {
+        let variants = cannibals_variants();
+        let small = PuzzleSize::new(2, 1).unwrap().initial_state();
+        let larger = PuzzleSize::new(127, 255).unwrap().initial_state();
+        let crossed = larger
+            .apply(Move::new(100, 27, BoatSide::LeftSide))
+            .unwrap();
+
+        for state in variants
+            .iter()
+            .map(|variant| &variant.instance)
+            .chain([&small, &larger, &crossed])
+        {
+            let packed = PackedWorldState::try_from(state).unwrap();
+            let unpacked = WorldState::from(packed);
+
+            assert_eq!(&unpacked, state);
+            assert_eq!(unpacked.size(), state.size());
+            assert!(unpacked.get_moves().is_empty());
+        }
+        assert_eq!(std::mem::size_of::<PackedWorldState>(), 4);
+        assert_eq!(
+            PackedWorldState::try_from(&PuzzleSize::new(128, 2).unwrap().initial_state()),
+            Err(WorldStateError::TooLargeToPack(128, 2))
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use super::{
+    BoatSide, Cannibals, Missionaries, PuzzleSize, SideState, WorldState, WorldStateError,
+};
+
+/// [`ParseMode`]
+/// How forgiving [`WorldState::parse`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Exactly the five fields, boat side in lower case.
+    #[default]
+    Strict,
+    /// Ignores anything after the five fields and accepts the boat side in any case.
+    Lenient,
+}
+
+const FIELDS: [&str; 5] = [
+    "number of cannibals on the left",
+    "number of missionaries on the left",
+    "number of cannibals on the right",
+    "number of missionaries on the right",
+    "boat side",
+];
+
+impl WorldState {
+    /// [`parse`]
+    /// Parses the `TryFrom<&str>` format, errors tell which field failed, the offending
+    /// token and its position (byte offset) in `value`.
+    /// # Example
+    /// ```
+    /// # use algoritmos_rust::cannibals::*;
+    /// assert_eq!(
+    ///     WorldState::parse("1 x 2 2 right", ParseMode::Strict),
+    ///     Err(WorldStateError::InvalidField {
+    ///         field: "number of missionaries on the left",
+    ///         token: "x".into(),
+    ///         position: 2,
+    ///     })
+    /// );
+    /// assert!(WorldState::parse("1 1 2 2 Right # comment", ParseMode::Lenient).is_ok());
+    /// ```
+    pub fn parse(value: &str, mode: ParseMode) -> Result<WorldState, WorldStateError> {
+        WorldState::parse_sized(value, mode, PuzzleSize::CLASSIC)
+    }
+
+    /// [`parse_sized`]
+    /// [`parse`](Self::parse) for a state of a puzzle of `size`.
+    /// # Example
+    /// ```
+    /// # use algoritmos_rust::cannibals::*;
+    /// let size = PuzzleSize::new(5, 3).unwrap();
+    /// let state = WorldState::parse_sized("2 2 3 3 left", ParseMode::Strict, size).unwrap();
+    /// assert_eq!(state.size(), size);
+    /// assert!(WorldState::parse("2 2 3 3 left", ParseMode::Strict).is_err());
+    /// ```
+    pub fn parse_sized(
+        value: &str,
+        mode: ParseMode,
+        size: PuzzleSize,
+    ) -> Result<WorldState, WorldStateError> {
+        let mut tokens = tokens(value);
+        let mut field = |index: usize| {
+            tokens.next().ok_or(WorldStateError::MissingField {
+                field: FIELDS[index],
+                position: value.len(),
+            })
+        };
+        let invalid =
+            |index: usize, (position, token): (usize, &str)| WorldStateError::InvalidField {
+                field: FIELDS[index],
+                token: token.to_string(),
+                position,
+            };
+
+        let mut counts = [0u8; 4];
+        for (index, count) in counts.iter_mut().enumerate() {
+            let token = field(index)?;
+            *count = token.1.parse().map_err(|_| invalid(index, token))?;
+        }
+        let token = field(4)?;
+        let boat_side = match mode {
+            ParseMode::Strict => BoatSide::try_from(token.1),
+            ParseMode::Lenient => BoatSide::try_from(token.1.to_lowercase().as_str()),
+        }
+        .map_err(|_| invalid(4, token))?;
+
+        if let (ParseMode::Strict, Some((position, token))) = (mode, tokens.next()) {
+            return Err(WorldStateError::TrailingTokens {
+                token: token.to_string(),
+                position,
+            });
+        }
+
+        WorldState::new_sized(
+            size,
+            SideState::new(Cannibals(counts[0]), Missionaries(counts[1])),
+            SideState::new(Cannibals(counts[2]), Missionaries(counts[3])),
+            boat_side,
+            vec![],
+            0,
+        )
+    }
+}
+
+/// Whitespace separated tokens with their byte offset.
+fn tokens(value: &str) -> impl Iterator<Item = (usize, &str)> {
+    value
+        .split_whitespace()
+        .map(move |token| (token.as_ptr() as usize - value.as_ptr() as usize, token))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parser_strict_reports_offending_field() {
+        assert_eq!(
+            WorldState::parse("1 1 2", ParseMode::Strict),
+            Err(WorldStateError::MissingField {
+                field: "number of missionaries on the right",
+                position: 5,
+            })
+        );
+        assert_eq!(
+            WorldState::parse("1 1 2 2 up", ParseMode::Strict),
+            Err(WorldStateError::InvalidField {
+                field: "boat side",
+                token: "up".into(),
+                position: 8,
+            })
+        );
+        assert_eq!(
+            WorldState::parse("1 1 2 2 right  left", ParseMode::Strict),
+            Err(WorldStateError::TrailingTokens {
+                token: "left".into(),
+                position: 15,
+            })
+        );
+        assert_eq!(
+            WorldState::parse("1 1 3 2 right", ParseMode::Strict),
+            Err(WorldStateError::ImpossibleNumberOfCannibals(4))
+        );
+    }
+
+    #[test]
+    fn parser_lenient_ignores_trailing_tokens_and_case() {
+        let strict = WorldState::parse("  1 1\t2 2 right ", ParseMode::Strict).unwrap();
+
+        let lenient = WorldState::parse("1 1 2 2 RIGHT extra", ParseMode::Lenient).unwrap();
+
+        assert_eq!(strict, lenient);
+        assert!(WorldState::parse("1 1 2 2 RIGHT", ParseMode::Strict).is_err());
+        assert!(WorldState::parse("1 1 2 -2 right", ParseMode::Lenient).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use super::{Move, PackedWorldState, PuzzleSize, WorldState, WorldStateError};
+use crate::search::{BoxedSuccessors, HeuristicRegistry, SearchProblem, Successor};
+
+/// [`CannibalsProblem`]
+/// ## The puzzle as a [`SearchProblem`], whatever its size.
+/// Same rules as [`FixedCannibalsProblem`](super::FixedCannibalsProblem) for a
+/// [`PuzzleSize`] chosen at runtime: the search starts at `start` and ends with everyone on
+/// the left bank, every crossing costs 1 and game overs are dead ends. The states keep the
+/// moves that led to them, [`PackedCannibalsProblem`] is the same search over 4-byte
+/// states.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::search::*;
+/// let problem = CannibalsProblem::sized(PuzzleSize::new(5, 3).unwrap());
+/// let solution = a_star(&problem, |state| problem.heuristic(state)).unwrap();
+/// assert_eq!(solution.len(), 11);
+/// assert_eq!(solution.goal().to_string(), "5 5 0 0 left");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CannibalsProblem {
+    pub start: WorldState,
+}
+
+impl CannibalsProblem {
+    pub fn new(start: WorldState) -> Self {
+        Self { start }
+    }
+
+    /// The puzzle of `size` from its initial state.
+    pub fn sized(size: PuzzleSize) -> Self {
+        Self::new(size.initial_state())
+    }
+
+    /// Admissible heuristic: the people on the right bank over the boat capacity, every
+    /// one of them still has to cross to the left.
+    pub fn heuristic(&self, state: &WorldState) -> f64 {
+        let right = state.right_state.cannibals.0 + state.right_state.missionaries.0;
+        f64::from(right) / f64::from(state.size().boat_capacity)
+    }
+
+    /// [`heuristics`]
+    /// `"people_right"`, the admissible [`heuristic`](Self::heuristic).
+    pub fn heuristics() -> HeuristicRegistry<Self> {
+        let mut registry = HeuristicRegistry::new();
+        registry.register("people_right", |problem: &CannibalsProblem| {
+            let problem = problem.clone();
+            Box::new(move |state| problem.heuristic(state))
+        });
+        registry
+    }
+}
+
+impl SearchProblem for CannibalsProblem {
+    type State = WorldState;
+    type Action = Move;
+    type Cost = u32;
+    type Successors<'a> = BoxedSuccessors<'a, WorldState, Move>;
+
+    fn initial_state(&self) -> WorldState {
+        self.start.clone()
+    }
+
+    fn is_goal(&self, state: &WorldState) -> bool {
+        state.is_solution()
+    }
+
+    fn successors<'a>(&'a self, state: &'a WorldState) -> Self::Successors<'a> {
+        Box::new(state.actions().filter_map(move |mov| {
+            state
+                .apply(mov)
+                .ok()
+                .map(|next| Successor::new(mov, next, 1))
+        }))
+    }
+
+    fn is_dead_end(&self, state: &WorldState) -> bool {
+        state.is_game_over()
+    }
+}
+
+/// [`PackedCannibalsProblem`]
+/// ## [`CannibalsProblem`] over packed states.
+/// The states are [`PackedWorldState`]s, so the visited sets and the frontiers of the
+/// searches hold 4 bytes per state instead of a [`WorldState`] and its moves. States are
+/// unpacked only while they are expanded, the solution is the same as the one of
+/// [`CannibalsProblem`] with the states packed.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::search::*;
+/// let start = PuzzleSize::new(5, 3).unwrap().initial_state();
+/// let problem = PackedCannibalsProblem::new(&start).unwrap();
+/// let solution = a_star(&problem, |state| problem.heuristic(state)).unwrap();
+/// assert_eq!(solution.len(), 11);
+/// assert_eq!(WorldState::from(*solution.goal()).to_string(), "5 5 0 0 left");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PackedCannibalsProblem {
+    pub start: PackedWorldState,
+}
+
+impl PackedCannibalsProblem {
+    /// Fails for puzzles too large to pack.
+    pub fn new(start: &WorldState) -> Result<Self, WorldStateError> {
+        Ok(Self {
+            start: PackedWorldState::try_from(start)?,
+        })
+    }
+
+    /// Same heuristic as [`CannibalsProblem::heuristic`].
+    pub fn heuristic(&self, state: &PackedWorldState) -> f64 {
+        let (size, left) = (state.size(), state.left());
+        let right = 2 * u16::from(size.people)
+            - u16::from(left.cannibals.0)
+            - u16::from(left.missionaries.0);
+        f64::from(right) / f64::from(size.boat_capacity)
+    }
+}
+
+impl SearchProblem for PackedCannibalsProblem {
+    type State = PackedWorldState;
+    type Action = Move;
+    type Cost = u32;
+    type Successors<'a> = BoxedSuccessors<'a, PackedWorldState, Move>;
+
+    fn initial_state(&self) -> PackedWorldState {
+        self.start
+    }
+
+    fn is_goal(&self, state: &PackedWorldState) -> bool {
+        WorldState::from(*state).is_solution()
+    }
+
+    fn successors<'a>(&'a self, state: &'a PackedWorldState) -> Self::Successors<'a> {
+        let state = WorldState::from(*state);
+        let moves = state.actions().collect::<Vec<Move>>();
+        Box::new(moves.into_iter().filter_map(move |mov| {
+            let next = state.apply(mov).ok()?;
+            let next = PackedWorldState::try_from(&next).expect("states of a packed size pack");
+            Some(Successor::new(mov, next, 1))
+        }))
+    }
+
+    fn is_dead_end(&self, state: &PackedWorldState) -> bool {
+        WorldState::from(*state).is_game_over()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::FixedCannibalsProblem;
+    use crate::search::{a_star, bfs};
+
+    #[test]
+    fn cannibals_problem_matches_the_fixed_sizes() {
+        let classic = CannibalsProblem::sized(PuzzleSize::CLASSIC);
+        let larger = CannibalsProblem::sized(PuzzleSize::new(5, 3).unwrap());
+
+        let solution = bfs(&classic).unwrap();
+
+        assert_eq!(
+            solution.len(),
+            bfs(&FixedCannibalsProblem::<3, 2>).unwrap().len()
+        );
+        assert!(solution.states.iter().all(|state| !state.is_game_over()));
+        assert_eq!(
+            a_star(&larger, |state| larger.heuristic(state))
+                .unwrap()
+                .len(),
+            bfs(&FixedCannibalsProblem::<5, 3>).unwrap().len()
+        );
+        assert!(bfs(&CannibalsProblem::sized(PuzzleSize::new(4, 2).unwrap())).is_none());
+    }
+
+    #[test]
+    fn packed_cannibals_problem_matches_the_unpacked_one() {
+        for size in [PuzzleSize::CLASSIC, PuzzleSize::new(5, 3).unwrap()] {
+            let problem = CannibalsProblem::sized(size);
+            let packed = PackedCannibalsProblem::new(&problem.start).unwrap();
+
+            let solution = bfs(&problem).unwrap();
+            let packed_solution = bfs(&packed).unwrap();
+
+            assert_eq!(packed_solution.actions, solution.actions);
+            assert_eq!(packed_solution.stats, solution.stats);
+            for (packed_state, state) in packed_solution.states.iter().zip(&solution.states) {
+                assert_eq!(&WorldState::from(*packed_state), state);
+                assert_eq!(packed.heuristic(packed_state), problem.heuristic(state));
+            }
+        }
+        assert!(bfs(
+            &PackedCannibalsProblem::new(&PuzzleSize::new(4, 2).unwrap().initial_state()).unwrap()
+        )
+        .is_none());
+        assert!(
+            PackedCannibalsProblem::new(&PuzzleSize::new(128, 2).unwrap().initial_state()).is_err()
+        );
+    }
+}
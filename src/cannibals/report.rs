@@ -0,0 +1,113 @@
+use super::{render_ascii, FixedWorldState, Locale, Move, WorldState, WorldStateError};
+use crate::search::{PruneReason, SearchEvent, SearchSink, Solution};
+use crate::terminal::Theme;
+
+type Classic = FixedWorldState<3, 2>;
+
+/// [`DeadEnds`]
+/// ## A [`SearchSink`] keeping the game overs pruned by a search.
+/// Of the classic puzzle by default, `DeadEnds<WorldState>` for puzzles sized at runtime.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::search::*;
+/// let mut dead_ends = DeadEnds::default();
+/// bfs_traced(&FixedCannibalsProblem::<3, 2>, &mut dead_ends).unwrap();
+/// assert!(dead_ends.0.iter().all(FixedWorldState::is_game_over));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeadEnds<S = Classic>(pub Vec<S>);
+
+impl<S> Default for DeadEnds<S> {
+    fn default() -> Self {
+        Self(vec![])
+    }
+}
+
+impl<S: Clone, C> SearchSink<S, C> for DeadEnds<S> {
+    fn event(&mut self, event: SearchEvent<'_, S, C>) {
+        if let SearchEvent::Prune {
+            state,
+            reason: PruneReason::DeadEnd,
+            ..
+        } = event
+        {
+            self.0.push(state.clone())
+        }
+    }
+}
+
+/// [`solver_report`]
+/// ## What the cannibals binaries print.
+/// The statistics and the moves of `solution`, followed when `verbose` by a picture of
+/// every state of the solution and of every dead end. The states are those of
+/// [`FixedWorldState`]s or of [`WorldState`]s of any size.
+pub fn solver_report<S: Clone + Into<WorldState>>(
+    solution: Option<&Solution<S, Move>>,
+    dead_ends: &DeadEnds<S>,
+    theme: &Theme,
+    locale: Locale,
+    verbose: bool,
+) -> Result<String, WorldStateError> {
+    let Some(solution) = solution else {
+        return Ok(theme.paint(theme.error, "no solution was found!"));
+    };
+    let picture = |state: &S| render_ascii(&state.clone().into());
+    let mut lines = vec![
+        theme.paint(theme.heading, "Follow the steps:"),
+        theme.stats_table(&[
+            ("generated states", solution.stats.generated.to_string()),
+            ("visited states", solution.stats.expanded.to_string()),
+            ("duplicate states", solution.stats.duplicates.to_string()),
+            ("pruned states", dead_ends.0.len().to_string()),
+            ("number of steps", solution.len().to_string()),
+        ]),
+    ];
+    for mov in &solution.actions {
+        lines.push(theme.paint(theme.path, &mov.describe(locale)));
+    }
+    if verbose {
+        for state in &solution.states {
+            lines.push(picture(state));
+        }
+        lines.push(theme.paint(theme.heading, "Pruned states:"));
+        for dead_end in &dead_ends.0 {
+            lines.push(theme.paint(theme.pruned, &picture(dead_end)));
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::FixedCannibalsProblem;
+    use crate::search::a_star_traced;
+
+    #[test]
+    fn report_lists_moves_and_dead_ends() {
+        let mut dead_ends = DeadEnds::default();
+        let solution = a_star_traced(
+            &FixedCannibalsProblem::<3, 2>,
+            Classic::heuristic,
+            &mut dead_ends,
+        );
+        let theme = Theme::plain();
+
+        let short = solver_report(solution.as_ref(), &dead_ends, &theme, Locale::En, false);
+        let verbose = solver_report(solution.as_ref(), &dead_ends, &theme, Locale::En, true);
+
+        let short = short.unwrap();
+        assert!(short.starts_with("Follow the steps:"));
+        assert_eq!(short.lines().count(), 6 + 11);
+        assert!(!dead_ends.0.is_empty());
+        assert_eq!(
+            verbose.unwrap().lines().count(),
+            short.lines().count() + 12 + 1 + dead_ends.0.len()
+        );
+        assert_eq!(
+            solver_report(None, &dead_ends, &theme, Locale::En, true).unwrap(),
+            "no solution was found!"
+        );
+    }
+}
@@ -1,15 +1,55 @@
 use std::fmt::Display;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+macro_rules! person_count {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        pub struct $name(pub u8);
+
+        impl $name {
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                self.0.checked_add(other.0).map(Self)
+            }
+
+            /// `None` when `other` is greater than `self`.
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                self.0.checked_sub(other.0).map(Self)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+person_count!(
+    /// [`Cannibals`]
+    /// Number of cannibals in a group.
+    Cannibals
+);
+person_count!(
+    /// [`Missionaries`]
+    /// Number of missionaries in a group.
+    Missionaries
+);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SideState {
-    pub cannibals: u8,
-    pub missionaries: u8,
+    pub cannibals: Cannibals,
+    pub missionaries: Missionaries,
 }
 
 impl SideState {
-    pub fn new(cannibals: u8, missionaries: u8) -> Self {
+    pub fn new(cannibals: Cannibals, missionaries: Missionaries) -> Self {
         Self {
             cannibals,
             missionaries,
@@ -17,37 +57,78 @@ impl SideState {
     }
 
     pub fn cannibal_can_eat_missionary(&self) -> bool {
-        self.cannibals > self.missionaries && self.missionaries > 0
+        self.cannibals.0 > self.missionaries.0 && self.missionaries.0 > 0
+    }
+
+    /// [`checked_add`]
+    /// This side with `group` added, `None` on overflow.
+    pub fn checked_add(&self, group: SideState) -> Option<SideState> {
+        Some(SideState::new(
+            self.cannibals.checked_add(group.cannibals)?,
+            self.missionaries.checked_add(group.missionaries)?,
+        ))
+    }
+
+    /// [`checked_sub`]
+    /// This side with `group` removed, `None` when there are not enough people.
+    pub fn checked_sub(&self, group: SideState) -> Option<SideState> {
+        Some(SideState::new(
+            self.cannibals.checked_sub(group.cannibals)?,
+            self.missionaries.checked_sub(group.missionaries)?,
+        ))
     }
 
     /// [`get_all_send_combinations`]
     /// ## Gets all the possible send combinations given the actual number of cannibals and missionaries.
-    /// Returns a tuple containing `(number_of_cannibals, number_of_missionaries)` that can be sent.
-    pub fn get_all_send_combinations(&self) -> Vec<(u8, u8)> {
-        match (self.cannibals, self.missionaries) {
-            (c, m) if c >= 2 && m >= 2 => vec![(2, 0), (0, 2), (1, 1), (1, 0), (0, 1)],
-            (c, m) if c >= 2 && m == 1 => vec![(2, 0), (0, 1), (1, 1), (1, 0)],
-            (c, m) if c >= 2 && m == 0 => vec![(2, 0), (1, 0)],
-            (c, m) if c == 1 && m == 1 => vec![(1, 0), (0, 1), (1, 1)],
-            (c, m) if c == 1 && m == 0 => vec![(1, 0)],
-            (c, m) if c == 0 && m == 1 => vec![(0, 1)],
-            (c, m) if c == 0 && m >= 2 => vec![(0, 2), (0, 1)],
-            (c, m) if c == 1 && m >= 2 => vec![(0, 2), (0, 1), (1, 1), (1, 0)],
-            _ => vec![(0, 0)],
-        }
+    /// Returns a tuple containing `(number_of_cannibals, number_of_missionaries)` that can be sent
+    /// with the classic boat of 2, see [`send_combinations`](Self::send_combinations).
+    pub fn get_all_send_combinations(&self) -> Vec<(Cannibals, Missionaries)> {
+        self.send_combinations(2)
     }
-}
 
-impl PartialEq for SideState {
-    fn eq(&self, other: &Self) -> bool {
-        self.cannibals == other.cannibals && self.missionaries == other.missionaries
+    /// [`send_combinations`]
+    /// Every `(number_of_cannibals, number_of_missionaries)` from this side that fits in a
+    /// boat of `boat_capacity`, fullest boats first and, among them, most cannibals first.
+    /// Only the empty crossing `(0, 0)` when nobody is on this side.
+    /// # Example
+    /// ```
+    /// # use algoritmos_rust::cannibals::*;
+    /// let side = SideState::new(Cannibals(1), Missionaries(3));
+    /// let loads = side
+    ///     .send_combinations(3)
+    ///     .into_iter()
+    ///     .map(|(cannibals, missionaries)| (cannibals.0, missionaries.0))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(loads, [(1, 2), (0, 3), (1, 1), (0, 2), (1, 0), (0, 1)]);
+    /// ```
+    pub fn send_combinations(&self, boat_capacity: u8) -> Vec<(Cannibals, Missionaries)> {
+        if self.cannibals.0 == 0 && self.missionaries.0 == 0 {
+            return vec![(Cannibals(0), Missionaries(0))];
+        }
+        (1..=boat_capacity)
+            .rev()
+            .flat_map(|passengers| {
+                (0..=passengers)
+                    .rev()
+                    .map(move |cannibals| (cannibals, passengers - cannibals))
+            })
+            .filter(|(cannibals, missionaries)| {
+                *cannibals <= self.cannibals.0 && *missionaries <= self.missionaries.0
+            })
+            .map(|(c, m)| (Cannibals(c), Missionaries(m)))
+            .collect()
     }
 }
 
+/// Labels before the counts, e.g. `"cannibals: 1, missionaries: 2"`, so that no count
+/// needs a plural.
 impl Display for SideState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let json = serde_json::to_string(self).unwrap_or_default();
-        write!(f, "{}", json)
+        write!(
+            f,
+            "cannibals: {}, missionaries: {}",
+            self.cannibals, self.missionaries
+        )
     }
 }
 
@@ -57,8 +138,12 @@ mod test {
 
     #[test]
     fn side_state_get_all_send_combinations_generates_expected_combinations() {
-        let side_state = SideState::new(3, 3);
-        let expected_combinations: Vec<(u8, u8)> = vec![(2, 0), (0, 2), (1, 1), (1, 0), (0, 1)];
+        let side_state = SideState::new(Cannibals(3), Missionaries(3));
+        let expected_combinations: Vec<(Cannibals, Missionaries)> =
+            [(2, 0), (0, 2), (1, 1), (1, 0), (0, 1)]
+                .into_iter()
+                .map(|(c, m)| (Cannibals(c), Missionaries(m)))
+                .collect();
 
         let combinations = side_state.get_all_send_combinations();
 
@@ -81,4 +166,19 @@ mod test {
 
         assert!(combination_matches, "Combinations count did not matched")
     }
+
+    #[test]
+    fn side_state_checked_arithmetic_does_not_underflow() {
+        let side_state = SideState::new(Cannibals(1), Missionaries(2));
+        let boat = SideState::new(Cannibals(2), Missionaries(0));
+
+        assert_eq!(side_state.checked_sub(boat), None);
+        assert_eq!(
+            side_state.checked_add(boat),
+            Some(SideState::new(Cannibals(3), Missionaries(2)))
+        );
+        assert_eq!(Cannibals(0).checked_sub(Cannibals(1)), None);
+        assert_eq!(side_state.to_string(), "cannibals: 1, missionaries: 2");
+        assert_eq!(boat.to_string(), "cannibals: 2, missionaries: 0");
+    }
 }
@@ -0,0 +1,144 @@
+use std::fmt::Write;
+
+use super::{BoatSide, SideState, WorldState};
+
+const WIDTH: u32 = 420;
+const HEIGHT: u32 = 160;
+const RIVER: (u32, u32) = (150, 270);
+
+/// [`render_svg_frame`]
+/// ## SVG picture of a state.
+/// Both banks with their cannibals (red `C`) and missionaries (blue `M`), the river in
+/// between and the boat next to the bank it is on.
+pub fn render_svg_frame(state: &WorldState) -> String {
+    svg_document(&frame(state))
+}
+
+/// [`render_animated_svg`]
+/// ## Animated SVG of a solution.
+/// Shows every state of `states` (e.g. from [`replay`](super::replay)) for
+/// `seconds_per_step` seconds, looping forever.
+pub fn render_animated_svg(states: &[WorldState], seconds_per_step: f64) -> String {
+    if states.is_empty() {
+        return svg_document("");
+    }
+    let frames = states.len();
+    let duration = seconds_per_step * frames as f64;
+    let key_times = (0..=frames)
+        .map(|i| format!("{:.4}", i as f64 / frames as f64))
+        .collect::<Vec<String>>()
+        .join(";");
+    let mut body = String::new();
+    for (index, state) in states.iter().enumerate() {
+        let values = (0..=frames)
+            .map(|i| if i == index { "1" } else { "0" })
+            .collect::<Vec<&str>>()
+            .join(";");
+        let _ = write!(
+            body,
+            r#"<g opacity="{}"><animate attributeName="opacity" calcMode="discrete" dur="{}s" repeatCount="indefinite" keyTimes="{}" values="{}"/>"#,
+            if index == 0 { 1 } else { 0 },
+            duration,
+            key_times,
+            values
+        );
+        let _ = write!(
+            body,
+            r#"{}<text x="{}" y="{}" font-size="12" text-anchor="middle">step {}/{}</text></g>"#,
+            frame(state),
+            WIDTH / 2,
+            HEIGHT - 8,
+            index,
+            states.len() - 1
+        );
+    }
+    svg_document(&body)
+}
+
+fn svg_document(body: &str) -> String {
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">{2}</svg>"#,
+        WIDTH, HEIGHT, body
+    )
+}
+
+fn frame(state: &WorldState) -> String {
+    let mut svg = format!(
+        r##"<rect x="0" y="0" width="{}" height="{}" fill="#d9c27e"/><rect x="{}" y="0" width="{}" height="{}" fill="#5b8fd6"/>"##,
+        WIDTH,
+        HEIGHT,
+        RIVER.0,
+        RIVER.1 - RIVER.0,
+        HEIGHT
+    );
+    svg.push_str(&bank(&state.left_state, 20));
+    svg.push_str(&bank(&state.right_state, RIVER.1 + 20));
+    let boat_x = match state.boat_side {
+        BoatSide::LeftSide => RIVER.0 + 5,
+        BoatSide::RightSide => RIVER.1 - 55,
+    };
+    let _ = write!(
+        svg,
+        r##"<rect class="boat" x="{}" y="70" width="50" height="20" rx="6" fill="#7a4a21"/>"##,
+        boat_x
+    );
+    svg
+}
+
+/// People of a bank, cannibals on the first row and missionaries on the second.
+fn bank(side: &SideState, x: u32) -> String {
+    let mut svg = String::new();
+    let rows = [
+        (side.cannibals.0, "C", "#c0392b", 40),
+        (side.missionaries.0, "M", "#2c3e90", 90),
+    ];
+    for (count, letter, color, y) in rows {
+        for i in 0..u32::from(count) {
+            let cx = x + 15 + i * 36;
+            let _ = write!(
+                svg,
+                r#"<circle cx="{0}" cy="{1}" r="14" fill="{2}"/><text x="{0}" y="{3}" font-size="14" fill="white" text-anchor="middle">{4}</text>"#,
+                cx,
+                y,
+                color,
+                y + 5,
+                letter
+            );
+        }
+    }
+    svg
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::{replay, Move};
+
+    #[test]
+    fn svg_frame_draws_every_person_and_the_boat() {
+        let state = WorldState::try_from("1 2 2 1 left").unwrap();
+
+        let svg = render_svg_frame(&state);
+
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>"));
+        assert_eq!(svg.matches("<circle").count(), 6);
+        assert_eq!(svg.matches(">C</text>").count(), 3);
+        assert!(svg.contains(r#"class="boat" x="155""#));
+    }
+
+    #[test]
+    fn svg_animation_has_one_frame_per_state() {
+        let initial = WorldState::try_from("0 0 3 3 right").unwrap();
+        let moves = [
+            Move::new(2, 0, BoatSide::LeftSide),
+            Move::new(1, 0, BoatSide::RightSide),
+        ];
+        let states = replay(&initial, &moves).unwrap();
+
+        let svg = render_animated_svg(&states, 1.5);
+
+        assert_eq!(svg.matches("<animate ").count(), 3);
+        assert!(svg.contains(r#"dur="4.5s""#));
+        assert!(svg.contains("step 2/2"));
+    }
+}
@@ -0,0 +1,137 @@
+use thiserror::Error;
+
+use super::{Move, WorldState, WorldStateError};
+
+/// [`validate`]
+/// ## Replays a sequence of moves.
+/// Starting from `initial_state`, applies every move with [`WorldState::apply_move`],
+/// checking that the boat is on the opposite side of the move direction, carries 1 or 2
+/// people that are actually on its side, and that nobody gets eaten afterwards.
+/// Returns the final state, which might not be a solution, or the first illegal step.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// let initial = WorldState::try_from("0 0 3 3 right").unwrap();
+/// let moves = [
+///     Move::new(1, 1, BoatSide::LeftSide),
+///     Move::new(0, 1, BoatSide::RightSide),
+/// ];
+/// let state = validate(&initial, &moves).unwrap();
+/// assert_eq!(String::from(state), "1 0 2 3 right");
+/// ```
+pub fn validate(initial_state: &WorldState, moves: &[Move]) -> Result<WorldState, ValidationError> {
+    moves
+        .iter()
+        .enumerate()
+        .try_fold(initial_state.clone(), |state, (step, mov)| {
+            apply_step(&state, step, mov)
+        })
+}
+
+/// [`replay`]
+/// Like [`validate`], but returns every state visited, starting with `initial_state`.
+pub fn replay(
+    initial_state: &WorldState,
+    moves: &[Move],
+) -> Result<Vec<WorldState>, ValidationError> {
+    let mut states = vec![initial_state.clone()];
+    for (step, mov) in moves.iter().enumerate() {
+        let next = apply_step(&states[step], step, mov)?;
+        states.push(next);
+    }
+    Ok(states)
+}
+
+fn apply_step(state: &WorldState, step: usize, mov: &Move) -> Result<WorldState, ValidationError> {
+    state.apply_move(*mov).map_err(|error| match error {
+        WorldStateError::WrongDirection => ValidationError::WrongDirection(step, *mov),
+        WorldStateError::InvalidBoatLoad(_) => ValidationError::InvalidBoatLoad(step, *mov),
+        WorldStateError::NotEnoughPeople(..) => ValidationError::NotEnoughPeople(step, *mov),
+        WorldStateError::Unsafe => ValidationError::Unsafe(step, *mov),
+        error => ValidationError::InvalidState(step, error),
+    })
+}
+
+/// [`ValidationError`]
+/// Every variant carries the index of the offending move.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum ValidationError {
+    #[error("Move {0} ({1}) goes to the side the boat is already on")]
+    WrongDirection(usize, Move),
+    #[error("Move {0} ({1}) must carry 1 or 2 people")]
+    InvalidBoatLoad(usize, Move),
+    #[error("Move {0} ({1}) sends more people than there are on the boat side")]
+    NotEnoughPeople(usize, Move),
+    #[error("Move {0} ({1}) leaves missionaries outnumbered by cannibals")]
+    Unsafe(usize, Move),
+    #[error("Move {0} leads to an invalid state: {1}")]
+    InvalidState(usize, WorldStateError),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::BoatSide;
+
+    fn classic_solution() -> Vec<Move> {
+        use BoatSide::{LeftSide as L, RightSide as R};
+        vec![
+            Move::new(2, 0, L),
+            Move::new(1, 0, R),
+            Move::new(2, 0, L),
+            Move::new(1, 0, R),
+            Move::new(0, 2, L),
+            Move::new(1, 1, R),
+            Move::new(0, 2, L),
+            Move::new(1, 0, R),
+            Move::new(2, 0, L),
+            Move::new(1, 0, R),
+            Move::new(2, 0, L),
+        ]
+    }
+
+    #[test]
+    fn validator_accepts_classic_solution() {
+        let initial = WorldState::try_from("0 0 3 3 right").unwrap();
+
+        let state = validate(&initial, &classic_solution()).unwrap();
+
+        assert!(state.is_solution());
+        assert_eq!(state.get_branch_cost(), 1.1);
+        assert_eq!(state.get_moves(), classic_solution());
+        assert_eq!(replay(&initial, &classic_solution()).unwrap()[11], state);
+        assert_eq!(validate(&initial, &[]), Ok(initial));
+    }
+
+    #[test]
+    fn validator_reports_first_illegal_move() {
+        let initial = WorldState::try_from("0 0 3 3 right").unwrap();
+        let mut moves = classic_solution();
+        moves[4] = Move::new(1, 1, BoatSide::LeftSide); // leaves 1 missionary with 2 cannibals
+
+        assert_eq!(
+            validate(&initial, &moves),
+            Err(ValidationError::Unsafe(4, moves[4]))
+        );
+        assert_eq!(
+            validate(&initial, &[Move::new(1, 0, BoatSide::RightSide)]),
+            Err(ValidationError::WrongDirection(
+                0,
+                Move::new(1, 0, BoatSide::RightSide)
+            ))
+        );
+        assert!(matches!(
+            validate(&initial, &[Move::new(0, 0, BoatSide::LeftSide)]),
+            Err(ValidationError::InvalidBoatLoad(0, _))
+        ));
+        let moves = [
+            Move::new(2, 0, BoatSide::LeftSide),
+            Move::new(0, 1, BoatSide::RightSide),
+        ];
+        assert_eq!(
+            validate(&initial, &moves),
+            Err(ValidationError::NotEnoughPeople(1, moves[1]))
+        );
+    }
+}
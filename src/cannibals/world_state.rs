@@ -1,12 +1,14 @@
-use super::SideState;
-use std::{fmt::Display, num::ParseIntError, rc::Rc};
+use super::{Cannibals, GoalCondition, Missionaries, Move, ParseMode, SideState, ValidationError};
+use std::{fmt::Display, hash::Hash, num::ParseIntError};
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 pub type WorldStateResult = Result<WorldState, WorldStateError>;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BoatSide {
     RightSide,
     LeftSide,
@@ -26,102 +28,261 @@ impl TryFrom<&str> for BoatSide {
     }
 }
 
-impl Into<String> for BoatSide {
-    fn into(self) -> String {
-        match self {
-            Self::RightSide => "right".to_string(),
-            Self::LeftSide => "left".to_string(),
+impl From<BoatSide> for String {
+    fn from(value: BoatSide) -> Self {
+        match value {
+            BoatSide::RightSide => "right".to_string(),
+            BoatSide::LeftSide => "left".to_string(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+/// [`PuzzleSize`]
+/// ## Size of a cannibals and missionaries puzzle.
+/// `people` cannibals and `people` missionaries, the boat carries from 1 to
+/// `boat_capacity` of them. The classic puzzle is 3 and 2, [`FixedWorldState`] is the
+/// same puzzle with the size fixed at compile time.
+///
+/// [`FixedWorldState`]: super::FixedWorldState
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// let size = PuzzleSize::new(5, 3).unwrap();
+/// let state = size.initial_state();
+/// assert_eq!(state.to_string(), "0 0 5 5 right");
+/// assert_eq!(state.actions().count(), 9);
+/// assert!(PuzzleSize::new(5, 0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PuzzleSize {
+    pub people: u8,
+    pub boat_capacity: u8,
+}
+
+impl PuzzleSize {
+    pub const CLASSIC: PuzzleSize = PuzzleSize {
+        people: 3,
+        boat_capacity: 2,
+    };
+
+    /// Rejects puzzles without people or without room in the boat.
+    pub fn new(people: u8, boat_capacity: u8) -> Result<Self, WorldStateError> {
+        if people == 0 || boat_capacity == 0 {
+            return Err(WorldStateError::InvalidPuzzleSize(people, boat_capacity));
+        }
+        Ok(Self {
+            people,
+            boat_capacity,
+        })
+    }
+
+    /// Everyone and the boat on the right bank.
+    pub fn initial_state(&self) -> WorldState {
+        WorldState::new_sized(
+            *self,
+            SideState::new(Cannibals(0), Missionaries(0)),
+            SideState::new(Cannibals(self.people), Missionaries(self.people)),
+            BoatSide::RightSide,
+            vec![],
+            0,
+        )
+        .expect("everyone is on the right bank")
+    }
+}
+
+impl Default for PuzzleSize {
+    fn default() -> Self {
+        Self::CLASSIC
+    }
+}
+
+/// [`WorldState`]
+/// ## A position of the puzzle and the moves that led to it.
+/// Deserializing a state replays its moves from the position they start at, so the
+/// history is rebuilt exactly as [`apply_move`](Self::apply_move) would have and a history
+/// breaking the rules of the game is rejected. `size` defaults to the classic puzzle,
+/// `moves` to none and `branch_cost` to the number of moves.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// let state = WorldState::try_from("0 0 3 3 right")
+///     .and_then(|state| state.apply_move(Move::new(1, 1, BoatSide::LeftSide)))
+///     .unwrap();
+/// let json = serde_json::to_string(&state).unwrap();
+/// let back: WorldState = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back.get_moves(), state.get_moves());
+/// // the boat can't be on the right after crossing to the left.
+/// let moved_boat = json.replace(r#""boat_side":"LeftSide""#, r#""boat_side":"RightSide""#);
+/// assert!(serde_json::from_str::<WorldState>(&moved_boat).is_err());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "WorldStateFields"))]
 pub struct WorldState {
     pub left_state: SideState,
     pub right_state: SideState,
     pub boat_side: BoatSide,
-    backtrack: String,
+    size: PuzzleSize,
+    /// The moves that lead from the root state to this state.
+    moves: Vec<Move>,
     branch_cost: u8,
 }
 
 /// World state:
 impl WorldState {
+    /// A state of the classic puzzle, see [`new_sized`](Self::new_sized).
     pub fn new(
         left_state: SideState,
         right_state: SideState,
         boat_side: BoatSide,
-        backtrack: String,
+        moves: Vec<Move>,
+        branch_cost: u8,
+    ) -> Result<Self, WorldStateError> {
+        Self::new_sized(
+            PuzzleSize::CLASSIC,
+            left_state,
+            right_state,
+            boat_side,
+            moves,
+            branch_cost,
+        )
+    }
+
+    /// [`new_sized`]
+    /// A state of a puzzle of `size`, the people on both banks must add up to
+    /// `size.people` cannibals and `size.people` missionaries.
+    pub fn new_sized(
+        size: PuzzleSize,
+        left_state: SideState,
+        right_state: SideState,
+        boat_side: BoatSide,
+        moves: Vec<Move>,
         branch_cost: u8,
     ) -> Result<Self, WorldStateError> {
-        let total_cannibals = left_state.cannibals + right_state.cannibals;
-        let total_missionaries = left_state.missionaries + right_state.missionaries;
+        // totals above `u8::MAX` can't match any size.
+        let total_cannibals = left_state
+            .cannibals
+            .0
+            .checked_add(right_state.cannibals.0)
+            .ok_or(WorldStateError::ImpossibleNumberOfCannibals(
+                right_state.cannibals.0,
+            ))?;
+        let total_missionaries = left_state
+            .missionaries
+            .0
+            .checked_add(right_state.missionaries.0)
+            .ok_or(WorldStateError::ImpossibleNumberOfMissionaries(
+                right_state.missionaries.0,
+            ))?;
 
         match (total_cannibals, total_missionaries) {
-            (can, _) if can != 3 => Err(WorldStateError::ImpossibleNumberOfCannibals(can)),
-            (_, mis) if mis != 3 => Err(WorldStateError::ImpossibleNumberOfMissionaries(mis)),
+            (can, _) if can != size.people => {
+                Err(WorldStateError::ImpossibleNumberOfCannibals(can))
+            }
+            (_, mis) if mis != size.people => {
+                Err(WorldStateError::ImpossibleNumberOfMissionaries(mis))
+            }
             (_, _) => Ok(Self {
                 left_state,
                 right_state,
                 boat_side,
-                backtrack: backtrack,
-                branch_cost: branch_cost,
+                size,
+                moves,
+                branch_cost,
             }),
         }
     }
 
+    /// [`key`]
+    /// The identity of this state, without the moves that led to it.
+    pub fn key(&self) -> StateKey {
+        StateKey {
+            left: self.left_state,
+            boat_side: self.boat_side,
+            size: self.size,
+        }
+    }
+
+    /// The size of the puzzle this state belongs to.
+    pub fn size(&self) -> PuzzleSize {
+        self.size
+    }
+
+    /// [`actions`]
+    /// Every move the boat can make from its current side, without building the states.
+    pub fn actions(&self) -> impl Iterator<Item = Move> {
+        let (from, direction) = match self.boat_side {
+            BoatSide::LeftSide => (&self.left_state, BoatSide::RightSide),
+            BoatSide::RightSide => (&self.right_state, BoatSide::LeftSide),
+        };
+        from.send_combinations(self.size.boat_capacity)
+            .into_iter()
+            .map(move |(cann, missi)| Move::new(cann.0, missi.0, direction))
+    }
+
+    /// [`apply`]
+    /// The state reached by sending the people of `mov` to `mov.direction`, recording the
+    /// move after the moves of this state. Only checks that the people are there, see
+    /// [`validate`](super::validate) for the full rules.
+    pub fn apply(&self, mov: Move) -> WorldStateResult {
+        let group = SideState::new(Cannibals(mov.cannibals), Missionaries(mov.missionaries));
+        let not_enough_people =
+            || WorldStateError::NotEnoughPeople(mov.cannibals, mov.missionaries);
+        let (left_state, right_state) = match mov.direction {
+            BoatSide::RightSide => (
+                self.left_state.checked_sub(group),
+                self.right_state.checked_add(group),
+            ),
+            BoatSide::LeftSide => (
+                self.left_state.checked_add(group),
+                self.right_state.checked_sub(group),
+            ),
+        };
+        WorldState::new_sized(
+            self.size,
+            left_state.ok_or_else(not_enough_people)?,
+            right_state.ok_or_else(not_enough_people)?,
+            mov.direction,
+            self.moves_then(mov),
+            self.branch_cost.saturating_add(1),
+        )
+    }
+
+    /// [`apply_move`]
+    /// [`apply`](Self::apply) with every rule of the game checked: the boat crosses to the
+    /// other side carrying from 1 to `boat_capacity` people that are on its side, and
+    /// nobody is eaten afterwards.
+    /// # Example
+    /// ```
+    /// # use algoritmos_rust::cannibals::*;
+    /// let state = WorldState::try_from("0 0 3 3 right").unwrap();
+    /// let next = state.apply_move(Move::new(1, 1, BoatSide::LeftSide)).unwrap();
+    /// assert_eq!(next.to_string(), "1 1 2 2 left");
+    /// assert_eq!(
+    ///     state.apply_move(Move::new(0, 2, BoatSide::LeftSide)),
+    ///     Err(WorldStateError::Unsafe)
+    /// );
+    /// ```
+    pub fn apply_move(&self, mov: Move) -> WorldStateResult {
+        if mov.direction == self.boat_side {
+            return Err(WorldStateError::WrongDirection);
+        }
+        if !(1..=self.size.boat_capacity).contains(&mov.passengers()) {
+            return Err(WorldStateError::InvalidBoatLoad(mov.passengers()));
+        }
+        let next = self.apply(mov)?;
+        if next.is_game_over() {
+            return Err(WorldStateError::Unsafe);
+        }
+        Ok(next)
+    }
+
     /// [`get_son_states`]
-    /// gets all possible son states
+    /// gets all possible son states, [`apply`](Self::apply) on every one of the
+    /// [`actions`](Self::actions).
     pub fn get_child_states(&self) -> Vec<WorldStateResult> {
-        match self.boat_side {
-            BoatSide::LeftSide => self
-                .left_state
-                .get_all_send_combinations()
-                .into_iter()
-                .map(|(cann, missi)| {
-                    let mov = format!(
-                        "send {} cannibals and {} missionaries to the right side",
-                        cann, missi
-                    );
-                    return WorldState::new(
-                        SideState::new(
-                            self.left_state.cannibals - cann,
-                            self.left_state.missionaries - missi,
-                        ),
-                        SideState::new(
-                            self.right_state.cannibals + cann,
-                            self.right_state.missionaries + missi,
-                        ),
-                        BoatSide::RightSide,
-                        format!("{}|{}", self.backtrack, mov),
-                        self.branch_cost + 1,
-                    );
-                })
-                .collect(),
-            BoatSide::RightSide => self
-                .right_state
-                .get_all_send_combinations()
-                .into_iter()
-                .map(|(cann, missi)| {
-                    let mov = format!(
-                        "send {} cannibals and {} missionaries to the left side",
-                        cann, missi
-                    );
-                    return WorldState::new(
-                        SideState::new(
-                            self.left_state.cannibals + cann,
-                            self.left_state.missionaries + missi,
-                        ),
-                        SideState::new(
-                            self.right_state.cannibals - cann,
-                            self.right_state.missionaries - missi,
-                        ),
-                        BoatSide::LeftSide,
-                        format!("{}|{}", self.backtrack, mov),
-                        self.branch_cost + 1,
-                    );
-                })
-                .collect(),
-        }
+        self.actions().map(|mov| self.apply(mov)).collect()
     }
 
     /// [`heuristic`]
@@ -152,7 +313,7 @@ impl WorldState {
         } else {
             0.0
         };
-        (f32::from(self.right_state.cannibals) + f32::from(self.right_state.missionaries)
+        (f32::from(self.right_state.cannibals.0) + f32::from(self.right_state.missionaries.0)
             - boat_right_side_bonus)
             / 2.0
     }
@@ -169,25 +330,39 @@ impl WorldState {
 
     /// [`get_step_by_step`]
     ///
-    /// Returns the step by step of how to reach to this state.
+    /// Returns the step by step of how to reach to this state, moves separated by `|`.
     /// Used to get the final answer.
     pub fn get_step_by_step(&self) -> String {
-        return self.backtrack.to_owned();
+        self.get_step_by_step_vec().join("|")
     }
 
     pub fn get_step_by_step_vec(&self) -> Vec<String> {
-        let step_by_step_string = self.get_step_by_step();
-        let step_by_step_vec = step_by_step_string.split("|").collect::<Vec<&str>>();
+        self.moves.iter().map(Move::to_string).collect()
+    }
 
-        let step_by_step = step_by_step_vec
-            .into_iter()
-            .map(|step_str| step_str.to_string())
-            .collect::<Vec<String>>();
-        step_by_step
+    /// [`get_moves`]
+    /// The moves that lead from the root state to this state.
+    pub fn get_moves(&self) -> &[Move] {
+        &self.moves
     }
 
+    /// The moves of this state followed by `mov`.
+    pub(super) fn moves_then(&self, mov: Move) -> Vec<Move> {
+        let mut moves = Vec::with_capacity(self.moves.len() + 1);
+        moves.extend_from_slice(&self.moves);
+        moves.push(mov);
+        moves
+    }
+
+    /// Everyone on the left bank, see [`satisfies`](Self::satisfies) for other goals.
     pub fn is_solution(&self) -> bool {
-        self.left_state.missionaries == 3 && self.left_state.cannibals == 3 && !self.is_game_over()
+        self.satisfies(&GoalCondition::EveryoneAcross)
+    }
+
+    /// [`satisfies`]
+    /// Whether this state meets `goal` without anyone being eaten.
+    pub fn satisfies(&self, goal: &GoalCondition) -> bool {
+        goal.is_met(&self.left_state, &self.right_state, self.boat_side) && !self.is_game_over()
     }
 
     pub fn is_game_over(&self) -> bool {
@@ -206,58 +381,38 @@ impl WorldState {
 /// means:
 /// * left: 1 cannibal and 1 missionary
 /// * right: 2 cannibals and 2 missionaries and the boat
+///
 /// `"1 0 2 3 left"`
 /// means:
 /// * left: 1 cannibal and 0 missionary and the boat
 /// * right: 2 cannibals and 3 missionaries
+///
+/// Parsing is [`ParseMode::Strict`], see [`WorldState::parse`] for the lenient mode.
 impl TryFrom<&str> for WorldState {
     type Error = WorldStateError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let v = value.split(" ").collect::<Vec<&str>>();
-        if v.len() < 5 {
-            return Err(WorldStateError::ParseFromStringError(
-                "Non sufficient number of args".into(),
-            ));
-        }
-        let (l_c, l_m, r_c, r_m, b) = (
-            v[0].trim(),
-            v[1].trim(),
-            v[2].trim(),
-            v[3].trim(),
-            v[4].trim(),
-        );
-        let world_state = WorldState::new(
-            SideState::new(l_c.parse()?, l_m.parse()?),
-            SideState::new(r_c.parse()?, r_m.parse()?),
-            b.try_into()?,
-            "root state".into(),
-            0,
-        )?;
-
-        Ok(world_state)
+        WorldState::parse(value, ParseMode::Strict)
     }
 }
 
-impl Into<String> for WorldState {
-    fn into(self) -> String {
-        let self_ref = &self;
-        self_ref.into()
+impl From<WorldState> for String {
+    fn from(value: WorldState) -> Self {
+        (&value).into()
     }
 }
 
-impl Into<String> for &WorldState {
-    fn into(self) -> String {
-        let boat_string: String = self.boat_side.into();
+impl From<&WorldState> for String {
+    fn from(value: &WorldState) -> Self {
+        let boat_string: String = value.boat_side.into();
         format!(
             "{} {} {} {} {}",
-            self.left_state.cannibals,
-            self.left_state.missionaries,
-            self.right_state.cannibals,
-            self.right_state.missionaries,
+            value.left_state.cannibals,
+            value.left_state.missionaries,
+            value.right_state.cannibals,
+            value.right_state.missionaries,
             boat_string
         )
-        .to_string()
     }
 }
 
@@ -266,6 +421,16 @@ impl PartialEq for WorldState {
         self.left_state == other.left_state
             && self.right_state == other.right_state
             && self.boat_side == other.boat_side
+            && self.size == other.size
+    }
+}
+
+impl Eq for WorldState {}
+
+/// Consistent with `PartialEq`: hashes the [`key`](WorldState::key) of the state.
+impl Hash for WorldState {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
     }
 }
 
@@ -283,26 +448,128 @@ impl PartialOrd for WorldState {
     }
 }
 
+/// Same format as [`TryFrom<&str>`], e.g. `"1 1 2 2 right"`.
 impl Display for WorldState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let json = serde_json::to_string(self).unwrap_or_default();
-        write!(f, "{}", json)
+        write!(f, "{}", String::from(self))
+    }
+}
+
+/// The fields of a serialized [`WorldState`], checked when converted.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct WorldStateFields {
+    left_state: SideState,
+    right_state: SideState,
+    boat_side: BoatSide,
+    #[serde(default)]
+    size: PuzzleSize,
+    #[serde(default)]
+    moves: Vec<Move>,
+    branch_cost: Option<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<WorldStateFields> for WorldState {
+    type Error = WorldStateError;
+
+    /// Undoes the moves to find where they start, then replays them with every rule.
+    fn try_from(value: WorldStateFields) -> Result<Self, Self::Error> {
+        let state = WorldState::new_sized(
+            value.size,
+            value.left_state,
+            value.right_state,
+            value.boat_side,
+            vec![],
+            0,
+        )?;
+        let undo = |state: WorldState, (step, mov): (usize, &Move)| {
+            let back = match mov.direction {
+                BoatSide::LeftSide => BoatSide::RightSide,
+                BoatSide::RightSide => BoatSide::LeftSide,
+            };
+            if state.boat_side != mov.direction {
+                return Err(ValidationError::InvalidState(
+                    step,
+                    WorldStateError::WrongDirection,
+                ));
+            }
+            state
+                .apply(Move::new(mov.cannibals, mov.missionaries, back))
+                .map_err(|error| ValidationError::InvalidState(step, error))
+        };
+        let history = |error| WorldStateError::InvalidHistory(Box::new(error));
+        let start = value
+            .moves
+            .iter()
+            .enumerate()
+            .rev()
+            .try_fold(state, undo)
+            .map_err(history)?;
+        let start = WorldState {
+            moves: vec![],
+            ..start
+        };
+        let replayed = super::validate(&start, &value.moves).map_err(history)?;
+        Ok(WorldState {
+            branch_cost: value
+                .branch_cost
+                .unwrap_or(value.moves.len().try_into().unwrap_or(u8::MAX)),
+            ..replayed
+        })
+    }
+}
+
+/// [`StateKey`]
+/// ## Compact identity of a [`WorldState`].
+/// Equal states have equal keys whatever moves led to them. The key is `Copy` and never
+/// allocates, visited sets store keys instead of the states or their strings.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use std::collections::HashSet;
+/// let state = WorldState::try_from("0 0 3 3 right").unwrap();
+/// let there_and_back = state
+///     .apply(Move::new(1, 1, BoatSide::LeftSide))
+///     .and_then(|next| next.apply(Move::new(1, 1, BoatSide::RightSide)))
+///     .unwrap();
+/// let visited = HashSet::from([state.key()]);
+/// assert!(visited.contains(&there_and_back.key()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StateKey {
+    left: SideState,
+    boat_side: BoatSide,
+    size: PuzzleSize,
+}
+
+impl From<&WorldState> for StateKey {
+    fn from(value: &WorldState) -> Self {
+        value.key()
     }
 }
 
+/// [`Shared`]
+/// Pointer shared by the [`WorldStateHeapWrapper`]s, an `Rc` unless the `sync` feature
+/// makes it an `Arc` so the wrappers can cross threads.
+#[cfg(not(feature = "sync"))]
+pub type Shared<T> = std::rc::Rc<T>;
+#[cfg(feature = "sync")]
+pub type Shared<T> = std::sync::Arc<T>;
+
 /// [`WorldStateHeapWrapper`]
 /// This struct only purpose is to allow to organize [`WorldState`] struct
 /// into `min-heap`, this is needed in order organize "next nodes to visit"
 /// using heuristic for `greedy` algorithms.
 #[derive(Debug)]
 pub struct WorldStateHeapWrapper {
-    world_state: Rc<WorldState>,
+    world_state: Shared<WorldState>,
     cost_function: WorldStateWrapperCostFunctionType,
 }
 
 impl WorldStateHeapWrapper {
     pub fn new(
-        world_state: Rc<WorldState>,
+        world_state: Shared<WorldState>,
         cost_function: WorldStateWrapperCostFunctionType,
     ) -> Self {
         Self {
@@ -310,8 +577,8 @@ impl WorldStateHeapWrapper {
             cost_function,
         }
     }
-    pub fn get_world_state(&self) -> Rc<WorldState> {
-        Rc::clone(&self.world_state)
+    pub fn get_world_state(&self) -> Shared<WorldState> {
+        Shared::clone(&self.world_state)
     }
     fn get_cost(&self) -> f32 {
         match self.cost_function {
@@ -319,6 +586,10 @@ impl WorldStateHeapWrapper {
             WorldStateWrapperCostFunctionType::HeuristicPlusBranchCost => {
                 self.world_state.get_heuristic() + self.world_state.get_branch_cost()
             }
+            WorldStateWrapperCostFunctionType::WeightedHeuristicPlusBranchCost(weight) => {
+                weight * self.world_state.get_heuristic() + self.world_state.get_branch_cost()
+            }
+            WorldStateWrapperCostFunctionType::OnlyBranchCost => self.world_state.get_branch_cost(),
         }
     }
 }
@@ -329,37 +600,39 @@ impl PartialEq for WorldStateHeapWrapper {
     }
 }
 
-impl Eq for WorldStateHeapWrapper {
-    fn assert_receiver_is_total_eq(&self) {}
-}
+impl Eq for WorldStateHeapWrapper {}
 
 impl PartialOrd for WorldStateHeapWrapper {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WorldStateHeapWrapper {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         let heuristics = (self.get_cost(), other.get_cost());
 
         match heuristics {
             (my_heuristic, other_heuristic) if my_heuristic > other_heuristic => {
-                Some(std::cmp::Ordering::Greater)
+                std::cmp::Ordering::Greater
             }
             (my_heuristic, other_heuristic) if my_heuristic == other_heuristic => {
-                Some(std::cmp::Ordering::Equal)
+                std::cmp::Ordering::Equal
             }
-            _ => Some(std::cmp::Ordering::Less),
+            _ => std::cmp::Ordering::Less,
         }
     }
 }
 
-impl Ord for WorldStateHeapWrapper {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other)
-            .expect("PartialOrd implementation for WorldStateHeapWrapper should not return None!")
-    }
-}
-
 #[derive(Debug)]
 pub enum WorldStateWrapperCostFunctionType {
     OnlyHeuristic,
     HeuristicPlusBranchCost,
+    /// Weighted A* with the given weight, see
+    /// [`weighted_a_star`](crate::search::weighted_a_star).
+    WeightedHeuristicPlusBranchCost(f32),
+    /// Uniform cost search, see [`uniform_cost_search`](crate::search::uniform_cost_search).
+    OnlyBranchCost,
 }
 
 #[non_exhaustive]
@@ -369,8 +642,35 @@ pub enum WorldStateError {
     ImpossibleNumberOfMissionaries(u8),
     #[error("Impossible number of cannibals")]
     ImpossibleNumberOfCannibals(u8),
+    #[error("Not enough people on the bank to send {0} cannibals and {1} missionaries")]
+    NotEnoughPeople(u8, u8),
+    #[error("The boat can't cross to the side it is already on")]
+    WrongDirection,
+    #[error("The boat can't carry {0} people")]
+    InvalidBoatLoad(u8),
+    #[error("Impossible puzzle of {0} cannibals and missionaries with a boat of {1}")]
+    InvalidPuzzleSize(u8, u8),
+    #[error("Puzzles of {0} cannibals and missionaries with a boat of {1} can't be packed")]
+    TooLargeToPack(u8, u8),
+    #[error("Missionaries outnumbered by cannibals")]
+    Unsafe,
+    #[error("The moves can't lead to this state: {0}")]
+    InvalidHistory(Box<ValidationError>),
     #[error("Error when trying to parse from WorldState string")]
     ParseFromStringError(String),
+    #[error("Missing {field} at position {position}")]
+    MissingField {
+        field: &'static str,
+        position: usize,
+    },
+    #[error("Invalid {field} `{token}` at position {position}")]
+    InvalidField {
+        field: &'static str,
+        token: String,
+        position: usize,
+    },
+    #[error("Unexpected trailing `{token}` at position {position}")]
+    TrailingTokens { token: String, position: usize },
 }
 
 impl From<ParseIntError> for WorldStateError {
@@ -380,24 +680,25 @@ impl From<ParseIntError> for WorldStateError {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::to_string_in_format_args)]
 mod world_state_test {
     use super::*;
 
     #[test]
     fn world_state_new_returns_error_when_state_is_invalid() {
         let wrong_n_of_missionaries = WorldState::new(
-            SideState::new(0, 0),
-            SideState::new(3, 2),
+            SideState::new(Cannibals(0), Missionaries(0)),
+            SideState::new(Cannibals(3), Missionaries(2)),
             BoatSide::LeftSide,
-            "root state".to_string(),
+            vec![],
             0,
         )
         .unwrap_err();
         let wrong_n_of_cannibals = WorldState::new(
-            SideState::new(2, 0),
-            SideState::new(3, 1),
+            SideState::new(Cannibals(2), Missionaries(0)),
+            SideState::new(Cannibals(3), Missionaries(1)),
             BoatSide::RightSide,
-            "root state".to_string(),
+            vec![],
             0,
         )
         .unwrap_err();
@@ -412,22 +713,41 @@ mod world_state_test {
         );
     }
 
+    #[test]
+    fn world_state_new_sized_rejects_totals_above_u8() {
+        let largest = PuzzleSize {
+            people: 255,
+            boat_capacity: 2,
+        };
+        assert_eq!(
+            WorldState::new_sized(
+                largest,
+                SideState::new(Cannibals(200), Missionaries(55)),
+                SideState::new(Cannibals(200), Missionaries(200)),
+                BoatSide::RightSide,
+                vec![],
+                0,
+            ),
+            Err(WorldStateError::ImpossibleNumberOfCannibals(200))
+        );
+    }
+
     #[test]
     fn world_new_state_creates_expected_state() {
         let world_state = WorldState::new(
-            SideState::new(3, 0),
-            SideState::new(0, 3),
+            SideState::new(Cannibals(3), Missionaries(0)),
+            SideState::new(Cannibals(0), Missionaries(3)),
             BoatSide::LeftSide,
-            "root state".to_string(),
+            vec![],
             0,
         )
         .unwrap();
 
-        assert_eq!(world_state.left_state.cannibals, 3);
-        assert_eq!(world_state.left_state.missionaries, 0);
+        assert_eq!(world_state.left_state.cannibals, Cannibals(3));
+        assert_eq!(world_state.left_state.missionaries, Missionaries(0));
 
-        assert_eq!(world_state.right_state.cannibals, 0);
-        assert_eq!(world_state.right_state.missionaries, 3);
+        assert_eq!(world_state.right_state.cannibals, Cannibals(0));
+        assert_eq!(world_state.right_state.missionaries, Missionaries(3));
 
         assert_eq!(world_state.boat_side, BoatSide::LeftSide);
     }
@@ -435,18 +755,18 @@ mod world_state_test {
     #[test]
     fn world_is_solution_returns_expected_response() {
         let solution_world_state = WorldState::new(
-            SideState::new(3, 3),
-            SideState::new(0, 0),
+            SideState::new(Cannibals(3), Missionaries(3)),
+            SideState::new(Cannibals(0), Missionaries(0)),
             BoatSide::LeftSide,
-            "root state".to_string(),
+            vec![],
             0,
         )
         .unwrap();
         let non_solution_world_state = WorldState::new(
-            SideState::new(1, 2),
-            SideState::new(2, 1),
+            SideState::new(Cannibals(1), Missionaries(2)),
+            SideState::new(Cannibals(2), Missionaries(1)),
             BoatSide::LeftSide,
-            "root state".to_string(),
+            vec![],
             0,
         )
         .unwrap();
@@ -459,47 +779,47 @@ mod world_state_test {
     fn world_is_game_over_returns_expected_response() {
         let world_game_over_states = vec![
             WorldState::new(
-                SideState::new(1, 2),
-                SideState::new(2, 1),
+                SideState::new(Cannibals(1), Missionaries(2)),
+                SideState::new(Cannibals(2), Missionaries(1)),
                 BoatSide::LeftSide,
-                "root state".to_string(),
+                vec![],
                 0,
             ),
             WorldState::new(
-                SideState::new(0, 1),
-                SideState::new(3, 2),
+                SideState::new(Cannibals(0), Missionaries(1)),
+                SideState::new(Cannibals(3), Missionaries(2)),
                 BoatSide::LeftSide,
-                "root state".to_string(),
+                vec![],
                 0,
             ),
             WorldState::new(
-                SideState::new(2, 1),
-                SideState::new(1, 2),
+                SideState::new(Cannibals(2), Missionaries(1)),
+                SideState::new(Cannibals(1), Missionaries(2)),
                 BoatSide::LeftSide,
-                "root state".to_string(),
+                vec![],
                 0,
             ),
         ];
         let world_non_game_over_states = vec![
             WorldState::new(
-                SideState::new(0, 0),
-                SideState::new(3, 3),
+                SideState::new(Cannibals(0), Missionaries(0)),
+                SideState::new(Cannibals(3), Missionaries(3)),
                 BoatSide::RightSide,
-                "root state".to_string(),
+                vec![],
                 0,
             ),
             WorldState::new(
-                SideState::new(2, 2),
-                SideState::new(1, 1),
+                SideState::new(Cannibals(2), Missionaries(2)),
+                SideState::new(Cannibals(1), Missionaries(1)),
                 BoatSide::LeftSide,
-                "root state".to_string(),
+                vec![],
                 0,
             ),
             WorldState::new(
-                SideState::new(0, 3),
-                SideState::new(3, 0),
+                SideState::new(Cannibals(0), Missionaries(3)),
+                SideState::new(Cannibals(3), Missionaries(0)),
                 BoatSide::LeftSide,
-                "root state".to_string(),
+                vec![],
                 0,
             ),
         ];
@@ -526,10 +846,10 @@ mod world_state_test {
             let world_state_result: Result<WorldState, WorldStateError> =
                 world_state_str.try_into();
             if let Ok(ws) = world_state_result {
-                assert_eq!(ws.left_state.cannibals, l_c);
-                assert_eq!(ws.left_state.missionaries, l_m);
-                assert_eq!(ws.right_state.cannibals, r_c);
-                assert_eq!(ws.right_state.missionaries, r_m);
+                assert_eq!(ws.left_state.cannibals, Cannibals(l_c));
+                assert_eq!(ws.left_state.missionaries, Missionaries(l_m));
+                assert_eq!(ws.right_state.cannibals, Cannibals(r_c));
+                assert_eq!(ws.right_state.missionaries, Missionaries(r_m));
                 assert_eq!(ws.boat_side, b_s);
             } else {
                 panic!("world state should not have failed")
@@ -537,6 +857,144 @@ mod world_state_test {
         }
     }
 
+    #[test]
+    fn world_actions_are_applied_lazily() {
+        let w_s: WorldState = "1 1 2 2 left".try_into().unwrap();
+
+        let actions = w_s.actions().collect::<Vec<Move>>();
+
+        assert_eq!(actions.len(), 3);
+        assert!(actions
+            .iter()
+            .all(|mov| mov.direction == BoatSide::RightSide));
+        let child = w_s.apply(Move::new(1, 1, BoatSide::RightSide)).unwrap();
+        assert_eq!(String::from(&child), "0 0 3 3 right");
+        assert_eq!(
+            child.get_moves(),
+            vec![Move::new(1, 1, BoatSide::RightSide)]
+        );
+        assert_eq!(
+            w_s.apply(Move::new(2, 0, BoatSide::RightSide)).unwrap_err(),
+            WorldStateError::NotEnoughPeople(2, 0)
+        );
+    }
+
+    #[test]
+    fn world_apply_move_checks_every_rule() {
+        let w_s: WorldState = "1 1 2 2 left".try_into().unwrap();
+
+        let next = w_s
+            .apply_move(Move::new(1, 1, BoatSide::RightSide))
+            .unwrap();
+
+        assert_eq!(
+            next,
+            w_s.apply(Move::new(1, 1, BoatSide::RightSide)).unwrap()
+        );
+        let errors = [
+            (
+                Move::new(1, 0, BoatSide::LeftSide),
+                WorldStateError::WrongDirection,
+            ),
+            (
+                Move::new(0, 0, BoatSide::RightSide),
+                WorldStateError::InvalidBoatLoad(0),
+            ),
+            (
+                Move::new(3, 0, BoatSide::RightSide),
+                WorldStateError::InvalidBoatLoad(3),
+            ),
+            (
+                Move::new(2, 0, BoatSide::RightSide),
+                WorldStateError::NotEnoughPeople(2, 0),
+            ),
+            (
+                Move::new(1, 0, BoatSide::RightSide),
+                WorldStateError::Unsafe,
+            ),
+        ];
+        for (mov, error) in errors {
+            assert_eq!(w_s.apply_move(mov), Err(error), "{}", mov);
+        }
+    }
+
+    #[test]
+    fn world_sized_puzzle_follows_its_size() {
+        use crate::search::bfs;
+        use crate::FixedCannibalsProblem;
+        use std::collections::HashSet;
+
+        let size = PuzzleSize::new(5, 3).unwrap();
+        let optimal = bfs(&FixedCannibalsProblem::<5, 3>).unwrap();
+
+        let solved = optimal
+            .actions
+            .iter()
+            .try_fold(size.initial_state(), |state, mov| state.apply_move(*mov))
+            .unwrap();
+
+        assert!(solved.is_solution());
+        assert_eq!(solved.get_moves(), optimal.actions);
+        assert_eq!(
+            size.initial_state()
+                .apply_move(Move::new(2, 2, BoatSide::LeftSide)),
+            Err(WorldStateError::InvalidBoatLoad(4))
+        );
+        assert_eq!(
+            WorldState::try_from("0 0 5 5 right"),
+            Err(WorldStateError::ImpossibleNumberOfCannibals(5))
+        );
+        // 4 and 4 can't cross with a boat of 2.
+        let initial = PuzzleSize::new(4, 2).unwrap().initial_state();
+        let mut discovered = HashSet::from([initial.key()]);
+        let mut frontier = vec![initial];
+        while let Some(state) = frontier.pop() {
+            assert!(!state.is_solution(), "{}", state);
+            for next in state.actions().filter_map(|mov| state.apply_move(mov).ok()) {
+                if discovered.insert(next.key()) {
+                    frontier.push(next);
+                }
+            }
+        }
+        assert!(discovered.len() > 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn world_deserialize_replays_the_moves() {
+        use crate::search::bfs;
+        use crate::FixedCannibalsProblem;
+
+        let optimal = bfs(&FixedCannibalsProblem::<3, 2>).unwrap();
+        let solved =
+            crate::cannibals::validate(&PuzzleSize::CLASSIC.initial_state(), &optimal.actions)
+                .unwrap();
+
+        let json = serde_json::to_value(&solved).unwrap();
+        let back: WorldState = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(back, solved);
+        assert_eq!(back.get_moves(), optimal.actions);
+        assert_eq!(back.get_branch_cost(), solved.get_branch_cost());
+        let mut bare = json.clone();
+        bare.as_object_mut()
+            .unwrap()
+            .retain(|field, _| field != "size" && field != "branch_cost");
+        assert_eq!(
+            serde_json::from_value::<WorldState>(bare)
+                .unwrap()
+                .get_branch_cost(),
+            1.1
+        );
+        // a crossing that breaks the rest of the history.
+        let mut impossible = json;
+        impossible["moves"][9]["cannibals"] = 2.into();
+        assert!(serde_json::from_value::<WorldState>(impossible)
+            .unwrap_err()
+            .to_string()
+            .contains("can't lead to this state"));
+    }
+
     #[test]
     fn world_get_son_states_returns_expected_states() {
         let solution_world_state: WorldStateResult = "0 0 3 3 right".try_into();
@@ -597,6 +1055,22 @@ mod world_state_heap_wrapper_test {
     use std::cmp::Reverse;
     use std::collections::BinaryHeap;
 
+    #[cfg(feature = "sync")]
+    #[test]
+    fn world_state_heap_wrapper_crosses_threads() {
+        let state: WorldState = "1 1 2 2 left".try_into().unwrap();
+        let wrapper = WorldStateHeapWrapper::new(
+            Shared::new(state.clone()),
+            WorldStateWrapperCostFunctionType::OnlyHeuristic,
+        );
+
+        let moved = std::thread::spawn(move || wrapper.get_world_state())
+            .join()
+            .unwrap();
+
+        assert_eq!(*moved, state);
+    }
+
     #[test]
     fn test_world_state_heap_wrapper_maintains_expected_order() {
         let world_state_2: WorldStateResult = "1 1 2 2 right".try_into();
@@ -624,27 +1098,27 @@ mod world_state_heap_wrapper_test {
         let mut heap: BinaryHeap<Reverse<WorldStateHeapWrapper>> = BinaryHeap::new();
 
         heap.push(Reverse(WorldStateHeapWrapper::new(
-            Rc::new(world_state_1),
+            Shared::new(world_state_1),
             WorldStateWrapperCostFunctionType::OnlyHeuristic,
         )));
         heap.push(Reverse(WorldStateHeapWrapper::new(
-            Rc::new(world_state_2),
+            Shared::new(world_state_2),
             WorldStateWrapperCostFunctionType::OnlyHeuristic,
         )));
         heap.push(Reverse(WorldStateHeapWrapper::new(
-            Rc::new(world_state_5),
+            Shared::new(world_state_5),
             WorldStateWrapperCostFunctionType::OnlyHeuristic,
         )));
         heap.push(Reverse(WorldStateHeapWrapper::new(
-            Rc::new(world_state_3),
+            Shared::new(world_state_3),
             WorldStateWrapperCostFunctionType::OnlyHeuristic,
         )));
         heap.push(Reverse(WorldStateHeapWrapper::new(
-            Rc::new(world_state_4),
+            Shared::new(world_state_4),
             WorldStateWrapperCostFunctionType::OnlyHeuristic,
         )));
         heap.push(Reverse(WorldStateHeapWrapper::new(
-            Rc::new(world_state_6),
+            Shared::new(world_state_6),
             WorldStateWrapperCostFunctionType::OnlyHeuristic,
         )));
 
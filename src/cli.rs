@@ -0,0 +1,480 @@
+//! The command line of the `ai-algorithms` binary.
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use thiserror::Error;
+
+use crate::cannibals::{
+    solver_report, CannibalsProblem, DeadEnds, Locale, Move, ParseMode, PuzzleSize, WorldState,
+    WorldStateError,
+};
+use crate::search::{
+    a_star_traced, bfs_traced, check_optimality, dfs_traced, greedy_best_first_traced,
+    iddfs_traced, AlgorithmRegistry, SearchSink, Solution,
+};
+use crate::terminal::Theme;
+
+/// [`CliError`]
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum CliError {
+    #[error("Invalid puzzle size: {0}")]
+    InvalidSize(WorldStateError),
+    #[error("Invalid initial state: {0}")]
+    InvalidState(#[from] WorldStateError),
+    #[error("Can't write the solution: {0}")]
+    Output(String),
+}
+
+/// [`Algorithm`]
+/// The solvers of the `solve` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Algorithm {
+    Bfs,
+    Dfs,
+    #[value(name = "astar")]
+    AStar,
+    Greedy,
+    /// Iterative deepening depth-first search, only practical for small puzzles (up to 5
+    /// people or so): it keeps no visited set and re-explores every path of each depth.
+    Ids,
+}
+
+impl Algorithm {
+    pub const ALL: [Algorithm; 5] = [
+        Algorithm::Bfs,
+        Algorithm::Dfs,
+        Algorithm::AStar,
+        Algorithm::Greedy,
+        Algorithm::Ids,
+    ];
+
+    /// The name given to `--algorithm`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Bfs => "bfs",
+            Algorithm::Dfs => "dfs",
+            Algorithm::AStar => "astar",
+            Algorithm::Greedy => "greedy",
+            Algorithm::Ids => "ids",
+        }
+    }
+
+    /// Solves `problem`, the informed algorithms with
+    /// [`CannibalsProblem::heuristic`]. Iterative deepening stops at the number of states
+    /// of the puzzle, no solution is longer, but its time grows exponentially with the
+    /// length of the solution, so it takes too long on larger puzzles.
+    pub fn solve(
+        &self,
+        problem: &CannibalsProblem,
+        sink: &mut dyn SearchSink<WorldState>,
+    ) -> Option<Solution<WorldState, Move>> {
+        let heuristic = |state: &WorldState| problem.heuristic(state);
+        match self {
+            Algorithm::Bfs => bfs_traced(problem, sink),
+            Algorithm::Dfs => dfs_traced(problem, sink),
+            Algorithm::AStar => a_star_traced(problem, heuristic, sink),
+            Algorithm::Greedy => greedy_best_first_traced(problem, heuristic, sink),
+            Algorithm::Ids => {
+                let people = usize::from(problem.start.size().people);
+                iddfs_traced(problem, 1, 2 * (people + 1).pow(2), sink)
+            }
+        }
+    }
+}
+
+/// [`Output`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Output {
+    /// The report of the cannibals binaries.
+    Text,
+    /// [`Solution::to_json`], `null` without a solution.
+    Json,
+}
+
+/// [`Puzzle`]
+/// ## The puzzle to solve and where to start.
+/// The classic puzzle (3 cannibals, 3 missionaries and a boat of 2) from everyone on the
+/// right bank by default.
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct Puzzle {
+    /// Number of cannibals, and of missionaries.
+    #[arg(short, long, default_value_t = PuzzleSize::CLASSIC.people)]
+    pub people: u8,
+    /// Most people the boat carries.
+    #[arg(short, long, default_value_t = PuzzleSize::CLASSIC.boat_capacity)]
+    pub boat: u8,
+    /// Initial state, e.g. "0 0 3 3 right".
+    #[arg(short, long)]
+    pub initial: Option<String>,
+}
+
+impl Default for Puzzle {
+    fn default() -> Self {
+        Self {
+            people: PuzzleSize::CLASSIC.people,
+            boat: PuzzleSize::CLASSIC.boat_capacity,
+            initial: None,
+        }
+    }
+}
+
+impl Puzzle {
+    /// The search problem from the initial state of a puzzle of this size, an initial
+    /// state where missionaries are already outnumbered is rejected.
+    pub fn problem(&self) -> Result<CannibalsProblem, CliError> {
+        let size = PuzzleSize::new(self.people, self.boat).map_err(CliError::InvalidSize)?;
+        let start = match &self.initial {
+            Some(state) => WorldState::parse_sized(state, ParseMode::Strict, size)?,
+            None => size.initial_state(),
+        };
+        if start.is_game_over() {
+            return Err(CliError::InvalidState(WorldStateError::Unsafe));
+        }
+        Ok(CannibalsProblem::new(start))
+    }
+}
+
+/// [`Solve`]
+/// ## Options of the `solve` command.
+/// Defaults to A* on the classic puzzle, printing text without the extra statistics.
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct Solve {
+    #[arg(short, long, value_enum, default_value_t = Algorithm::AStar)]
+    pub algorithm: Algorithm,
+    #[command(flatten)]
+    pub puzzle: Puzzle,
+    #[arg(short, long, value_enum, default_value_t = Output::Text)]
+    pub output: Output,
+    /// Adds the frontier peak, the deepest node and the time to the report, and the
+    /// statistics to the JSON.
+    #[arg(long)]
+    pub stats: bool,
+    /// Pictures every state of the solution and every dead end.
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+impl Default for Solve {
+    fn default() -> Self {
+        Self {
+            algorithm: Algorithm::AStar,
+            puzzle: Puzzle::default(),
+            output: Output::Text,
+            stats: false,
+            verbose: false,
+        }
+    }
+}
+
+impl Solve {
+    /// Solves the puzzle from its initial state and renders the solution.
+    pub fn run(&self, theme: &Theme, locale: Locale) -> Result<String, CliError> {
+        let problem = self.puzzle.problem()?;
+        let mut dead_ends = DeadEnds::default();
+        let solution = self.algorithm.solve(&problem, &mut dead_ends);
+        match self.output {
+            Output::Text => self.text(solution.as_ref(), &dead_ends, theme, locale),
+            Output::Json => self.json(solution.as_ref()),
+        }
+    }
+
+    fn text(
+        &self,
+        solution: Option<&Solution<WorldState, Move>>,
+        dead_ends: &DeadEnds<WorldState>,
+        theme: &Theme,
+        locale: Locale,
+    ) -> Result<String, CliError> {
+        let mut report = solver_report(solution, dead_ends, theme, locale, self.verbose)
+            .map_err(|error| CliError::Output(error.to_string()))?;
+        if let Some(stats) = solution.map(|solution| solution.stats) {
+            if self.stats {
+                report.push('\n');
+                report.push_str(&theme.stats_table(&[
+                    ("frontier peak", stats.frontier_peak.to_string()),
+                    ("max depth", stats.max_depth.to_string()),
+                    ("time", format!("{:?}", stats.elapsed)),
+                ]));
+            }
+        }
+        Ok(report)
+    }
+
+    fn json(&self, solution: Option<&Solution<WorldState, Move>>) -> Result<String, CliError> {
+        let Some(solution) = solution else {
+            return Ok("null".to_string());
+        };
+        let mut json =
+            serde_json::to_value(solution).map_err(|error| CliError::Output(error.to_string()))?;
+        if !self.stats {
+            if let Some(object) = json.as_object_mut() {
+                object.remove("stats");
+            }
+        }
+        Ok(json.to_string())
+    }
+}
+
+/// [`algorithms`]
+/// ## The algorithms of the `compare` command.
+/// The [`AlgorithmRegistry`] of the search module, with the [`Algorithm`]s it lacks (`dfs`
+/// and `ids`) registered under their `--algorithm` names.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cli::*;
+/// let names = algorithms().names().map(String::from).collect::<Vec<String>>();
+/// assert!(names.contains(&"a_star".to_string()));
+/// assert!(names.contains(&"ids".to_string()));
+/// ```
+pub fn algorithms() -> AlgorithmRegistry<CannibalsProblem> {
+    let mut algorithms = AlgorithmRegistry::new();
+    for algorithm in [Algorithm::Dfs, Algorithm::Ids] {
+        algorithms.register(
+            algorithm.name(),
+            move |problem: &CannibalsProblem, _: &dyn Fn(&WorldState) -> f64| {
+                algorithm.solve(problem, &mut ())
+            },
+        );
+    }
+    algorithms
+}
+
+/// [`Compare`]
+/// ## Options of the `compare` command.
+/// Runs every algorithm of [`algorithms`] on the puzzle, with
+/// [`CannibalsProblem::heuristic`] for the informed ones, and tabulates the length of
+/// their solutions, whether [`check_optimality`] finds it optimal, the nodes they
+/// expanded and their time.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cli::*;
+/// # use algoritmos_rust::terminal::Theme;
+/// let table = Compare::default().run(&Theme::plain()).unwrap();
+/// let columns = |line: &str| line.split_whitespace().map(String::from).collect::<Vec<_>>();
+/// let mut lines = table.lines();
+/// assert_eq!(
+///     columns(lines.next().unwrap()),
+///     ["algorithm", "steps", "optimal", "expanded", "time"]
+/// );
+/// assert_eq!(columns(lines.next().unwrap())[..3], ["a_star", "11", "yes"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Args)]
+pub struct Compare {
+    #[command(flatten)]
+    pub puzzle: Puzzle,
+}
+
+impl Compare {
+    pub fn run(&self, theme: &Theme) -> Result<String, CliError> {
+        let problem = self.puzzle.problem()?;
+        let algorithms = algorithms();
+        let heuristic = |state: &WorldState| problem.heuristic(state);
+        let rows = algorithms
+            .names()
+            .map(|name| {
+                let solver = algorithms.get(name).expect("the name was just listed");
+                let mut solution = None;
+                let report = check_optimality(name, &problem, |problem| {
+                    solution = solver.solve(problem, &heuristic);
+                    solution.clone()
+                });
+                match solution {
+                    Some(solution) => vec![
+                        name.to_string(),
+                        solution.len().to_string(),
+                        match report.is_optimal() {
+                            true => "yes".to_string(),
+                            false => "no".to_string(),
+                        },
+                        solution.stats.expanded.to_string(),
+                        format!("{:?}", solution.stats.elapsed),
+                    ],
+                    None => vec![name.to_string(), "no solution".to_string()],
+                }
+            })
+            .collect::<Vec<_>>();
+        Ok(theme.table(
+            &["algorithm", "steps", "optimal", "expanded", "time"],
+            &rows,
+        ))
+    }
+}
+
+/// [`Command`]
+/// The subcommands of the `ai-algorithms` binary.
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub enum Command {
+    /// Solves the puzzle with one algorithm.
+    Solve(Solve),
+    /// Tabulates every algorithm on the same puzzle.
+    Compare(Compare),
+}
+
+impl Command {
+    pub fn run(&self, theme: &Theme, locale: Locale) -> Result<String, CliError> {
+        match self {
+            Command::Solve(solve) => solve.run(theme, locale),
+            Command::Compare(compare) => compare.run(theme),
+        }
+    }
+}
+
+/// [`Cli`]
+/// ## A command line of the `ai-algorithms` binary.
+/// `--no-color` is accepted before or after the subcommand.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cli::*;
+/// # use clap::Parser;
+/// let args = ["ai-algorithms", "solve", "-a", "bfs", "-p", "5", "-b", "3", "--no-color"];
+/// let cli = Cli::try_parse_from(args).unwrap();
+/// let Command::Solve(solve) = &cli.command else {
+///     unreachable!()
+/// };
+/// assert_eq!(solve.algorithm, Algorithm::Bfs);
+/// assert_eq!(solve.puzzle.problem().unwrap().start.to_string(), "0 0 5 5 right");
+/// assert!(!cli.theme().enabled);
+/// assert!(Cli::try_parse_from(["ai-algorithms", "solve", "-a", "bogo"]).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Parser)]
+#[command(
+    name = "ai-algorithms",
+    about = "Solves the cannibals and missionaries puzzle"
+)]
+pub struct Cli {
+    /// Prints without colors.
+    #[arg(long, global = true)]
+    pub no_color: bool,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+impl Cli {
+    /// The theme of the output, see [`Theme::new`].
+    pub fn theme(&self) -> Theme {
+        Theme::new(self.no_color)
+    }
+
+    pub fn run(&self, locale: Locale) -> Result<String, CliError> {
+        self.command.run(&self.theme(), locale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from(["ai-algorithms"].iter().chain(args))
+    }
+
+    #[test]
+    fn cli_solves_from_the_given_state() {
+        let run = |args: &[&str]| {
+            let cli = parse(args).unwrap();
+            cli.command.run(&Theme::plain(), Locale::En)
+        };
+
+        let text = run(&["solve", "--algorithm", "ids", "--initial", "2 2 1 1 right"]).unwrap();
+        let json = run(&["solve", "-a", "bfs", "-o", "json", "--stats"]).unwrap();
+
+        assert!(text.starts_with("Follow the steps:"));
+        assert!(text.contains("number of steps   1"));
+        let solution = Solution::<WorldState, Move>::from_json(&json).unwrap();
+        assert_eq!(solution.len(), 11);
+        assert!(solution.stats.expanded > 0);
+        assert!(!run(&["solve", "-o", "json"]).unwrap().contains("stats"));
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["solve", "--initial"]).is_err());
+        assert!(parse(&["solve", "--fast"]).is_err());
+        assert_eq!(
+            run(&["solve", "--initial", "0 0 4 4 right"]),
+            Err(CliError::InvalidState(
+                WorldStateError::ImpossibleNumberOfCannibals(4)
+            ))
+        );
+        assert_eq!(
+            run(&["solve", "-i", "1 2 2 1 left"]),
+            Err(CliError::InvalidState(WorldStateError::Unsafe))
+        );
+        assert_eq!(
+            run(&["compare", "-i", "1 2 2 1 left"]),
+            Err(CliError::InvalidState(WorldStateError::Unsafe))
+        );
+        assert_eq!(
+            run(&["solve", "--boat", "0"]),
+            Err(CliError::InvalidSize(WorldStateError::InvalidPuzzleSize(
+                3, 0
+            )))
+        );
+    }
+
+    #[test]
+    fn cli_solves_puzzles_of_any_size() {
+        let run = |args: &[&str]| {
+            let args = [args, &["-o", "json", "--stats"]].concat();
+            let json = parse(&args)
+                .unwrap()
+                .command
+                .run(&Theme::plain(), Locale::En)
+                .unwrap();
+            Solution::<WorldState, Move>::from_json(&json).ok()
+        };
+
+        let larger = run(&["solve", "-a", "bfs", "-p", "5", "-b", "3"]).unwrap();
+        let from = run(&["solve", "-p", "5", "-b", "3", "-i", "2 2 3 3 left"]).unwrap();
+
+        assert_eq!(larger.states[0].to_string(), "0 0 5 5 right");
+        assert!(larger.goal().is_solution());
+        assert_eq!(larger.goal().size(), PuzzleSize::new(5, 3).unwrap());
+        assert_eq!(from.states[0].to_string(), "2 2 3 3 left");
+        assert!(from.goal().is_solution());
+        assert_eq!(run(&["solve", "-p", "4", "-b", "2"]), None);
+    }
+
+    #[test]
+    fn cli_no_color_is_a_global_option() {
+        for args in [
+            ["--no-color", "solve"],
+            ["solve", "--no-color"],
+            ["compare", "--no-color"],
+        ] {
+            let cli = parse(&args).unwrap();
+
+            assert!(cli.no_color);
+            assert!(!cli.theme().enabled);
+        }
+        assert!(!parse(&["solve"]).unwrap().no_color);
+    }
+
+    #[test]
+    fn cli_compare_runs_every_algorithm() {
+        let cli = parse(&["compare", "--initial", "1 1 2 2 left"]).unwrap();
+
+        let table = cli.command.run(&Theme::plain(), Locale::En).unwrap();
+
+        let rows = table.lines().skip(1).collect::<Vec<_>>();
+        let names = algorithms().names().map(String::from).collect::<Vec<_>>();
+        assert_eq!(rows.len(), names.len());
+        for (row, name) in rows.iter().zip(&names) {
+            assert!(row.starts_with(name.as_str()), "{}", row);
+        }
+        let problem = CannibalsProblem::new(WorldState::try_from("1 1 2 2 left").unwrap());
+        let shortest = Algorithm::Bfs.solve(&problem, &mut ()).unwrap().len();
+        let columns = |row: &str| row.split_whitespace().map(String::from).collect::<Vec<_>>();
+        let row = |name: &str| columns(rows[names.iter().position(|n| n == name).unwrap()]);
+        assert_eq!(row("bfs")[1..3], [shortest.to_string(), "yes".into()]);
+        assert_eq!(row("uniform_cost")[2], "yes");
+        assert_eq!(row("a_star")[2], "yes", "A* is optimal");
+        assert_eq!(row("ida_star")[2], "yes", "IDA* is optimal");
+        let walk = row("random_walk");
+        let optimal = if walk[1] == shortest.to_string() {
+            "yes"
+        } else {
+            "no"
+        };
+        assert_eq!(
+            walk[2], optimal,
+            "random walks are only checked, not assumed optimal"
+        );
+    }
+}
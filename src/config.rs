@@ -0,0 +1,804 @@
+//! Solver runs described by a TOML file, used by the `run` binary.
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::fmt::{Debug, Display};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use toml_edit::{DocumentMut, Item};
+
+use crate::cannibals::{CannibalsProblem, ParseMode, PuzzleSize, WorldState, WorldStateError};
+use crate::hanoi::{HanoiError, HanoiProblem};
+use crate::n_puzzle::{Board, NPuzzleProblem};
+use crate::rng::Rng;
+use crate::romania::{City, RouteProblem};
+use crate::search::{
+    random_walk, AlgorithmRegistry, HeuristicRegistry, RegistryError, SearchProblem,
+};
+
+/// Largest seed of a [`RunConfig`], the largest integer of TOML.
+pub const MAX_SEED: u64 = i64::MAX as u64;
+
+/// [`ProblemConfig`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProblemConfig {
+    NPuzzle {
+        board: Board,
+    },
+    Hanoi {
+        discs: usize,
+        pegs: usize,
+    },
+    Romania {
+        from: City,
+        to: City,
+    },
+    /// From `initial`, or from everyone on the right bank when `None`.
+    Cannibals {
+        size: PuzzleSize,
+        initial: Option<WorldState>,
+    },
+}
+
+impl ProblemConfig {
+    pub fn domain(&self) -> &'static str {
+        match self {
+            ProblemConfig::NPuzzle { .. } => "n_puzzle",
+            ProblemConfig::Hanoi { .. } => "hanoi",
+            ProblemConfig::Romania { .. } => "romania",
+            ProblemConfig::Cannibals { .. } => "cannibals",
+        }
+    }
+}
+
+/// [`OutputFormat`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+/// [`RunConfig`]
+/// ## A solver run: problem, algorithm, heuristic, limits and output.
+/// Algorithms and heuristics are looked up by name in the [`AlgorithmRegistry`] and in
+/// the heuristics of the domain, plus `"random_walk"` driven by `seed`. Defaults to A*
+/// with the `"zero"` heuristic, without limits, seed 0 and printing text to stdout.
+/// [`Display`] renders the configuration back as TOML.
+/// # Example
+/// ```
+/// # use algoritmos_rust::config::*;
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// let config = RunConfig::new(ProblemConfig::Hanoi { discs: 3, pegs: 3 })
+///     .algorithm("bfs")
+///     .max_expansions(1000);
+/// let report = config.run().unwrap();
+/// assert_eq!(report.length, Some(7));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunConfig {
+    pub problem: ProblemConfig,
+    pub algorithm: String,
+    pub heuristic: String,
+    pub max_expansions: Option<usize>,
+    pub seed: u64,
+    pub format: OutputFormat,
+    /// Where the report is written, stdout when `None`.
+    pub output: Option<PathBuf>,
+    /// Where the [`RunManifest`] is written, none is written when `None`.
+    pub manifest: Option<PathBuf>,
+}
+
+impl RunConfig {
+    pub fn new(problem: ProblemConfig) -> Self {
+        Self {
+            problem,
+            algorithm: "a_star".into(),
+            heuristic: "zero".into(),
+            max_expansions: None,
+            seed: 0,
+            format: OutputFormat::Text,
+            output: None,
+            manifest: None,
+        }
+    }
+
+    pub fn algorithm(mut self, algorithm: &str) -> Self {
+        self.algorithm = algorithm.into();
+        self
+    }
+
+    pub fn heuristic(mut self, heuristic: &str) -> Self {
+        self.heuristic = heuristic.into();
+        self
+    }
+
+    /// Stops expanding states after `max_expansions`, the run then fails to solve.
+    pub fn max_expansions(mut self, max_expansions: usize) -> Self {
+        self.max_expansions = Some(max_expansions);
+        self
+    }
+
+    /// Rejects seeds above [`MAX_SEED`], TOML integers are signed so the configuration
+    /// couldn't be read back from its [`Display`] or from a [`RunManifest`].
+    pub fn seed(mut self, seed: u64) -> Result<Self, ConfigError> {
+        if seed > MAX_SEED {
+            return Err(ConfigError::invalid("seed", &seed.to_string()));
+        }
+        self.seed = seed;
+        Ok(self)
+    }
+
+    pub fn format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn output(mut self, output: PathBuf) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    pub fn manifest(mut self, manifest: PathBuf) -> Self {
+        self.manifest = Some(manifest);
+        self
+    }
+
+    pub fn run(&self) -> Result<RunReport, ConfigError> {
+        match &self.problem {
+            ProblemConfig::NPuzzle { board } => self.solve(
+                NPuzzleProblem::new(board.clone()),
+                NPuzzleProblem::heuristics(),
+            ),
+            ProblemConfig::Hanoi { discs, pegs } => self.solve(
+                HanoiProblem::new(*discs, *pegs)?,
+                HanoiProblem::heuristics(),
+            ),
+            ProblemConfig::Romania { from, to } => {
+                self.solve(RouteProblem::new(*from, *to), RouteProblem::heuristics())
+            }
+            ProblemConfig::Cannibals { size, initial } => {
+                let start = initial.clone().unwrap_or_else(|| size.initial_state());
+                if start.is_game_over() {
+                    return Err(ConfigError::Cannibals(WorldStateError::Unsafe));
+                }
+                self.solve(CannibalsProblem::new(start), CannibalsProblem::heuristics())
+            }
+        }
+    }
+
+    /// Renders `report` in the configured format and writes it to the configured output,
+    /// or to `stdout` when there is none, along with the manifest of the run if one was
+    /// asked for.
+    pub fn write_report(
+        &self,
+        report: &RunReport,
+        stdout: &mut impl Write,
+    ) -> Result<(), ConfigError> {
+        if let Some(path) = &self.manifest {
+            let manifest = serde_json::to_string_pretty(&RunManifest::new(self, report))
+                .map_err(|e| ConfigError::Serialize(e.to_string()))?;
+            std::fs::write(path, manifest + "\n").map_err(|e| ConfigError::Io(e.to_string()))?;
+        }
+        let rendered = match self.format {
+            OutputFormat::Text => report.to_string(),
+            OutputFormat::Json => serde_json::to_string_pretty(report)
+                .map_err(|e| ConfigError::Serialize(e.to_string()))?,
+        };
+        match &self.output {
+            Some(path) => std::fs::write(path, rendered + "\n"),
+            None => writeln!(stdout, "{}", rendered),
+        }
+        .map_err(|e| ConfigError::Io(e.to_string()))
+    }
+
+    fn solve<P>(
+        &self,
+        problem: P,
+        heuristics: HeuristicRegistry<P>,
+    ) -> Result<RunReport, ConfigError>
+    where
+        P: SearchProblem<Cost = u32>,
+        P::Action: Debug,
+    {
+        let heuristic = heuristics.build(&self.heuristic, &problem)?;
+        let limited = Limited {
+            problem,
+            max_expansions: self.max_expansions.unwrap_or(usize::MAX),
+            expanded: Cell::new(0),
+        };
+        let mut algorithms = AlgorithmRegistry::new();
+        let (seed, step_limit) = (self.seed, self.max_expansions.unwrap_or(1_000_000));
+        algorithms.register(
+            "random_walk",
+            move |problem: &Limited<P>, _: &dyn Fn(&P::State) -> f64| {
+                random_walk(problem, step_limit, &mut Rng::new(seed))
+            },
+        );
+        let solver = algorithms.get(&self.algorithm)?;
+
+        let start = Instant::now();
+        let solution = solver.solve(&limited, &*heuristic);
+        let elapsed = start.elapsed();
+        Ok(RunReport {
+            domain: self.problem.domain().into(),
+            algorithm: self.algorithm.clone(),
+            heuristic: self.heuristic.clone(),
+            expanded: limited.expanded.get(),
+            limit_reached: limited.expanded.get() > limited.max_expansions,
+            cost: solution.as_ref().map(|solution| solution.cost),
+            length: solution.as_ref().map(|solution| solution.len()),
+            actions: solution
+                .iter()
+                .flat_map(|solution| solution.actions.iter())
+                .map(|action| format!("{:?}", action))
+                .collect(),
+            elapsed,
+        })
+    }
+}
+
+/// [TryFrom<&str>]
+/// Accepts a TOML document like:
+/// ```toml
+/// algorithm = "a_star"        # optional, any name of the AlgorithmRegistry
+/// heuristic = "manhattan"     # optional, any heuristic of the domain
+/// seed = 42                   # optional, for randomized algorithms
+///
+/// [problem]
+/// domain = "n_puzzle"         # with board = "<tiles>"
+/// board = "8 6 7 2 5 4 3 0 1"
+/// # domain = "hanoi"          # with discs = <n> and pegs = <n>
+/// # domain = "romania"        # with from = "<city>" and to = "<city>"
+/// # domain = "cannibals"      # with people = <n>, boat = <n> and an optional
+/// #                           # initial = "<state>", e.g. "1 1 2 2 left"
+///
+/// [limits]                    # optional
+/// max_expansions = 100000
+///
+/// [output]                    # optional
+/// format = "json"             # or "text"
+/// path = "report.json"        # stdout when missing
+/// manifest = "run.json"       # optional, see RunManifest
+/// ```
+impl TryFrom<&str> for RunConfig {
+    type Error = ConfigError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let document = value
+            .parse::<DocumentMut>()
+            .map_err(|e| ConfigError::ParseFromStringError(e.to_string()))?;
+        let problem = document
+            .get("problem")
+            .ok_or(ConfigError::MissingField("problem"))?;
+        let problem = match string(problem, "problem.domain", "domain")? {
+            Some("n_puzzle") => ProblemConfig::NPuzzle {
+                board: required(string(problem, "problem.board", "board")?, "problem.board")
+                    .and_then(|board| {
+                        Board::try_from(board)
+                            .map_err(|_| ConfigError::invalid("problem.board", board))
+                    })?,
+            },
+            Some("hanoi") => ProblemConfig::Hanoi {
+                discs: required(integer(problem, "problem.discs", "discs")?, "problem.discs")?,
+                pegs: required(integer(problem, "problem.pegs", "pegs")?, "problem.pegs")?,
+            },
+            Some("romania") => {
+                let city = |key: &str, field: &'static str| {
+                    required(string(problem, field, key)?, field).and_then(|name| {
+                        City::try_from(name).map_err(|_| ConfigError::invalid(field, name))
+                    })
+                };
+                ProblemConfig::Romania {
+                    from: city("from", "problem.from")?,
+                    to: city("to", "problem.to")?,
+                }
+            }
+            Some("cannibals") => {
+                let size = PuzzleSize::new(
+                    required(
+                        integer(problem, "problem.people", "people")?,
+                        "problem.people",
+                    )?,
+                    required(integer(problem, "problem.boat", "boat")?, "problem.boat")?,
+                )?;
+                let initial = string(problem, "problem.initial", "initial")?
+                    .map(|state| {
+                        WorldState::parse_sized(state, ParseMode::Strict, size)
+                            .map_err(|_| ConfigError::invalid("problem.initial", state))
+                    })
+                    .transpose()?;
+                ProblemConfig::Cannibals { size, initial }
+            }
+            Some(domain) => return Err(ConfigError::invalid("problem.domain", domain)),
+            None => return Err(ConfigError::MissingField("problem.domain")),
+        };
+
+        let mut config = RunConfig::new(problem);
+        let root = document.as_item();
+        if let Some(algorithm) = string(root, "algorithm", "algorithm")? {
+            config = config.algorithm(algorithm);
+        }
+        if let Some(heuristic) = string(root, "heuristic", "heuristic")? {
+            config = config.heuristic(heuristic);
+        }
+        if let Some(seed) = integer(root, "seed", "seed")? {
+            config = config.seed(seed)?;
+        }
+        if let Some(limits) = document.get("limits") {
+            if let Some(max_expansions) =
+                integer(limits, "limits.max_expansions", "max_expansions")?
+            {
+                config = config.max_expansions(max_expansions);
+            }
+        }
+        if let Some(output) = document.get("output") {
+            match string(output, "output.format", "format")? {
+                Some("text") | None => {}
+                Some("json") => config = config.format(OutputFormat::Json),
+                Some(format) => return Err(ConfigError::invalid("output.format", format)),
+            }
+            if let Some(path) = string(output, "output.path", "path")? {
+                config = config.output(path.into());
+            }
+            if let Some(path) = string(output, "output.manifest", "manifest")? {
+                config = config.manifest(path.into());
+            }
+        }
+        Ok(config)
+    }
+}
+
+impl Display for RunConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let quoted = |path: &Option<PathBuf>| {
+            path.as_ref()
+                .map(|path| format!("{:?}", path.display().to_string()))
+        };
+        writeln!(f, "algorithm = {:?}", self.algorithm)?;
+        writeln!(f, "heuristic = {:?}", self.heuristic)?;
+        writeln!(f, "seed = {}", self.seed)?;
+        writeln!(f, "\n[problem]\ndomain = {:?}", self.problem.domain())?;
+        match &self.problem {
+            ProblemConfig::NPuzzle { board } => writeln!(f, "board = {:?}", String::from(board))?,
+            ProblemConfig::Hanoi { discs, pegs } => {
+                writeln!(f, "discs = {}\npegs = {}", discs, pegs)?
+            }
+            ProblemConfig::Romania { from, to } => writeln!(
+                f,
+                "from = {:?}\nto = {:?}",
+                from.to_string(),
+                to.to_string()
+            )?,
+            ProblemConfig::Cannibals { size, initial } => {
+                writeln!(f, "people = {}\nboat = {}", size.people, size.boat_capacity)?;
+                if let Some(initial) = initial {
+                    writeln!(f, "initial = {:?}", initial.to_string())?;
+                }
+            }
+        }
+        if let Some(max_expansions) = self.max_expansions {
+            writeln!(f, "\n[limits]\nmax_expansions = {}", max_expansions)?;
+        }
+        let format = match self.format {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+        };
+        write!(f, "\n[output]\nformat = {:?}", format)?;
+        if let Some(path) = quoted(&self.output) {
+            write!(f, "\npath = {}", path)?;
+        }
+        if let Some(path) = quoted(&self.manifest) {
+            write!(f, "\nmanifest = {}", path)?;
+        }
+        Ok(())
+    }
+}
+
+fn string<'a>(
+    table: &'a Item,
+    field: &'static str,
+    key: &str,
+) -> Result<Option<&'a str>, ConfigError> {
+    match table.get(key) {
+        None => Ok(None),
+        Some(item) => item
+            .as_str()
+            .map(Some)
+            .ok_or_else(|| ConfigError::invalid(field, &describe(item))),
+    }
+}
+
+/// Rejects integers out of the range of `T`, like negative counts.
+fn integer<T: TryFrom<i64>>(
+    table: &Item,
+    field: &'static str,
+    key: &str,
+) -> Result<Option<T>, ConfigError> {
+    match table.get(key) {
+        None => Ok(None),
+        Some(item) => item
+            .as_integer()
+            .and_then(|value| T::try_from(value).ok())
+            .map(Some)
+            .ok_or_else(|| ConfigError::invalid(field, &describe(item))),
+    }
+}
+
+/// Short rendering of a value of the wrong type for error messages.
+fn describe(item: &Item) -> String {
+    match (item.as_integer(), item.as_str()) {
+        (Some(integer), _) => integer.to_string(),
+        (_, Some(string)) => string.to_string(),
+        _ => item.type_name().to_string(),
+    }
+}
+
+fn required<T>(value: Option<T>, field: &'static str) -> Result<T, ConfigError> {
+    value.ok_or(ConfigError::MissingField(field))
+}
+
+/// Stops a search after `max_expansions` by giving no more successors.
+struct Limited<P> {
+    problem: P,
+    max_expansions: usize,
+    expanded: Cell<usize>,
+}
+
+impl<P: SearchProblem> SearchProblem for Limited<P> {
+    type State = P::State;
+    type Action = P::Action;
+    type Cost = P::Cost;
+    type Successors<'a>
+        = std::iter::Take<P::Successors<'a>>
+    where
+        Self: 'a;
+
+    fn initial_state(&self) -> P::State {
+        self.problem.initial_state()
+    }
+
+    fn is_goal(&self, state: &P::State) -> bool {
+        self.problem.is_goal(state)
+    }
+
+    fn successors<'a>(&'a self, state: &'a P::State) -> Self::Successors<'a> {
+        let expanded = self.expanded.get() + 1;
+        self.expanded.set(expanded);
+        let limit = match expanded > self.max_expansions {
+            true => 0,
+            false => usize::MAX,
+        };
+        self.problem.successors(state).take(limit)
+    }
+
+    fn canonicalize<'a>(&self, state: &'a P::State) -> Cow<'a, P::State> {
+        self.problem.canonicalize(state)
+    }
+
+    fn is_dead_end(&self, state: &P::State) -> bool {
+        self.problem.is_dead_end(state)
+    }
+
+    fn dominates(&self, a: &P::State, b: &P::State) -> bool {
+        self.problem.dominates(a, b)
+    }
+}
+
+/// [`RunReport`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunReport {
+    pub domain: String,
+    pub algorithm: String,
+    pub heuristic: String,
+    pub expanded: usize,
+    /// The run hit `max_expansions`, so not finding a solution proves nothing.
+    pub limit_reached: bool,
+    pub cost: Option<u32>,
+    pub length: Option<usize>,
+    pub actions: Vec<String>,
+    pub elapsed: Duration,
+}
+
+impl Display for RunReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} with {} ({} heuristic): {} expanded in {:?}",
+            self.domain, self.algorithm, self.heuristic, self.expanded, self.elapsed
+        )?;
+        match (self.cost, self.limit_reached) {
+            (Some(cost), _) => {
+                write!(f, "solved with cost {}: {}", cost, self.actions.join(", "))
+            }
+            (None, true) => write!(f, "expansion limit reached"),
+            (None, false) => write!(f, "no solution was found"),
+        }
+    }
+}
+
+/// [`RunManifest`]
+/// ## Everything needed to reproduce a run.
+/// Written as JSON next to the report when [`RunConfig::manifest`] is set: the crate
+/// version, the whole configuration (problem parameters, algorithm, heuristic, limits
+/// and seed) as TOML, a hash of the result and the timings. Runs are deterministic, so
+/// running [`config`](Self::config) again on any machine must give the same hash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunManifest {
+    pub crate_version: String,
+    pub config: String,
+    pub seed: u64,
+    /// FNV-1a hash of the cost and actions of the solution, in hexadecimal.
+    pub result_hash: String,
+    pub expanded: usize,
+    pub elapsed: Duration,
+}
+
+impl RunManifest {
+    pub fn new(config: &RunConfig, report: &RunReport) -> Self {
+        Self {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            config: config.to_string(),
+            seed: config.seed,
+            result_hash: report.result_hash(),
+            expanded: report.expanded,
+            elapsed: report.elapsed,
+        }
+    }
+
+    /// The configuration of the run, ready to be run again.
+    pub fn config(&self) -> Result<RunConfig, ConfigError> {
+        RunConfig::try_from(self.config.as_str())
+    }
+
+    /// Whether `report` found the same result as the run of this manifest.
+    pub fn reproduces(&self, report: &RunReport) -> bool {
+        report.result_hash() == self.result_hash && report.expanded == self.expanded
+    }
+}
+
+impl RunReport {
+    /// See [`RunManifest::result_hash`].
+    pub fn result_hash(&self) -> String {
+        let result = format!("{:?}|{}", self.cost, self.actions.join(","));
+        let hash = result.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        });
+        format!("{:016x}", hash)
+    }
+}
+
+/// [`ConfigError`]
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum ConfigError {
+    #[error("Invalid TOML: {0}")]
+    ParseFromStringError(String),
+    #[error("Missing field {0}")]
+    MissingField(&'static str),
+    #[error("Invalid value {value} for {field}")]
+    InvalidField { field: &'static str, value: String },
+    #[error(transparent)]
+    Registry(#[from] RegistryError),
+    #[error(transparent)]
+    Hanoi(#[from] HanoiError),
+    #[error(transparent)]
+    Cannibals(#[from] WorldStateError),
+    #[error("{0}")]
+    Io(String),
+    #[error("Can't serialize the report: {0}")]
+    Serialize(String),
+}
+
+impl ConfigError {
+    fn invalid(field: &'static str, value: &str) -> Self {
+        ConfigError::InvalidField {
+            field,
+            value: value.trim().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn run_config_try_from_reads_every_section() {
+        let config = RunConfig::try_from(
+            r#"
+            algorithm = "greedy"
+            heuristic = "manhattan"
+
+            [problem]
+            domain = "n_puzzle"
+            board = "1 2 3 4 5 6 0 7 8"
+
+            [limits]
+            max_expansions = 500
+
+            [output]
+            format = "json"
+            path = "report.json"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            RunConfig::new(ProblemConfig::NPuzzle {
+                board: Board::try_from("1 2 3 4 5 6 0 7 8").unwrap()
+            })
+            .algorithm("greedy")
+            .heuristic("manhattan")
+            .max_expansions(500)
+            .format(OutputFormat::Json)
+            .output("report.json".into())
+        );
+        let report = config.run().unwrap();
+        assert_eq!(report.cost, Some(2));
+        assert_eq!(report.actions.len(), 2);
+    }
+
+    #[test]
+    fn run_config_reports_invalid_configurations() {
+        let romania = |extra: &str| {
+            RunConfig::try_from(
+                format!(
+                    "{}\n[problem]\ndomain = \"romania\"\nfrom = \"Arad\"\nto = \"Bucharest\"",
+                    extra
+                )
+                .as_str(),
+            )
+        };
+
+        assert_eq!(
+            romania("")
+                .unwrap()
+                .heuristic("straight_line")
+                .run()
+                .unwrap()
+                .cost,
+            Some(418)
+        );
+        assert!(matches!(
+            romania("algorithm = \"dfs\"").unwrap().run(),
+            Err(ConfigError::Registry(RegistryError::UnknownAlgorithm(..)))
+        ));
+        assert_eq!(
+            RunConfig::try_from("[problem]\ndomain = \"hanoi\"\ndiscs = 3"),
+            Err(ConfigError::MissingField("problem.pegs"))
+        );
+        assert_eq!(
+            RunConfig::try_from("[problem]\ndomain = \"hanoi\"\ndiscs = -1\npegs = 3"),
+            Err(ConfigError::InvalidField {
+                field: "problem.discs",
+                value: "-1".into()
+            })
+        );
+        assert_eq!(
+            RunConfig::new(ProblemConfig::Hanoi { discs: 3, pegs: 2 }).run(),
+            Err(ConfigError::Hanoi(HanoiError::NotEnoughPegs(2)))
+        );
+        assert!(matches!(
+            RunConfig::try_from("[problem"),
+            Err(ConfigError::ParseFromStringError(_))
+        ));
+    }
+
+    #[test]
+    fn run_manifest_reproduces_the_run() {
+        let config = RunConfig::new(ProblemConfig::Hanoi { discs: 3, pegs: 4 })
+            .algorithm("random_walk")
+            .seed(7)
+            .unwrap()
+            .max_expansions(10_000)
+            .manifest("run.json".into());
+        let report = config.run().unwrap();
+        let manifest = RunManifest::new(&config, &report);
+
+        let json = serde_json::to_string(&manifest).unwrap();
+        let manifest = serde_json::from_str::<RunManifest>(&json).unwrap();
+        assert_eq!(manifest.config().unwrap(), config);
+        assert!(manifest.reproduces(&manifest.config().unwrap().run().unwrap()));
+        assert!(!manifest.reproduces(&config.clone().seed(8).unwrap().run().unwrap()));
+        assert_eq!(manifest.crate_version, env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn run_config_rejects_seeds_toml_cannot_hold() {
+        let config = RunConfig::new(ProblemConfig::Hanoi { discs: 3, pegs: 3 });
+
+        let largest = config.clone().seed(MAX_SEED).unwrap();
+        assert_eq!(
+            RunConfig::try_from(largest.to_string().as_str()),
+            Ok(largest)
+        );
+        assert_eq!(
+            config.seed(MAX_SEED + 1),
+            Err(ConfigError::InvalidField {
+                field: "seed",
+                value: (MAX_SEED + 1).to_string()
+            })
+        );
+        assert_eq!(
+            RunConfig::try_from("seed = -1\n[problem]\ndomain = \"hanoi\"\ndiscs = 3\npegs = 3"),
+            Err(ConfigError::InvalidField {
+                field: "seed",
+                value: "-1".into()
+            })
+        );
+    }
+
+    #[test]
+    fn run_config_solves_cannibals_of_any_size() {
+        let config = RunConfig::try_from(
+            "heuristic = \"people_right\"\n[problem]\ndomain = \"cannibals\"\npeople = 5\nboat = 3",
+        )
+        .unwrap();
+        assert_eq!(config.run().unwrap().length, Some(11));
+        assert_eq!(RunConfig::try_from(config.to_string().as_str()), Ok(config));
+
+        let from = |initial: &str| {
+            RunConfig::try_from(
+                format!(
+                    "[problem]\ndomain = \"cannibals\"\npeople = 3\nboat = 2\ninitial = {:?}",
+                    initial
+                )
+                .as_str(),
+            )
+        };
+        let config = from("1 1 2 2 left").unwrap();
+        assert_eq!(
+            RunConfig::try_from(config.to_string().as_str()),
+            Ok(config.clone())
+        );
+        assert_eq!(config.run().unwrap().length, Some(10));
+        assert_eq!(
+            from("1 2 2 1 left").unwrap().run(),
+            Err(ConfigError::Cannibals(WorldStateError::Unsafe))
+        );
+        assert_eq!(
+            from("4 4 0 0 left"),
+            Err(ConfigError::invalid("problem.initial", "4 4 0 0 left"))
+        );
+        assert_eq!(
+            RunConfig::try_from("[problem]\ndomain = \"cannibals\"\npeople = 3\nboat = 0"),
+            Err(ConfigError::Cannibals(WorldStateError::InvalidPuzzleSize(
+                3, 0
+            )))
+        );
+    }
+
+    #[test]
+    fn run_config_stops_at_the_expansion_limit() {
+        let report = RunConfig::new(ProblemConfig::Hanoi { discs: 6, pegs: 3 })
+            .algorithm("bfs")
+            .max_expansions(10)
+            .run()
+            .unwrap();
+
+        assert!(report.limit_reached);
+        assert_eq!(report.cost, None);
+        assert!(report.to_string().ends_with("expansion limit reached"));
+    }
+
+    #[test]
+    fn run_config_writes_the_report_to_the_given_writer() {
+        let config = RunConfig::new(ProblemConfig::Hanoi { discs: 3, pegs: 3 })
+            .algorithm("bfs")
+            .format(OutputFormat::Json);
+        let report = config.run().unwrap();
+        let mut written = vec![];
+
+        config.write_report(&report, &mut written).unwrap();
+
+        let written = String::from_utf8(written).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&written).unwrap()["length"],
+            7
+        );
+    }
+}
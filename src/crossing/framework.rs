@@ -0,0 +1,261 @@
+use std::fmt::{Debug, Display};
+
+use super::CrossingError;
+use crate::search::{BoxedSuccessors, SearchProblem, Successor};
+
+/// Passengers a [`CrossingState`] has a bit for.
+pub const MAX_PASSENGERS: usize = 64;
+
+/// [`Bank`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bank {
+    Start,
+    Destination,
+}
+
+impl Bank {
+    pub fn opposite(&self) -> Bank {
+        match self {
+            Bank::Start => Bank::Destination,
+            Bank::Destination => Bank::Start,
+        }
+    }
+}
+
+/// [`CrossingRules`]
+/// ## Rules of a river crossing puzzle.
+/// A group of passengers must cross the river with a boat that carries at most
+/// [`boat_capacity`](CrossingRules::boat_capacity) of them, and no group left together
+/// (on a bank or in the boat) may be unsafe.
+/// The cannibals and missionaries problem, the jealous husbands or the wolf, goat and
+/// cabbage puzzle only differ in their passengers and in their safety rule.
+pub trait CrossingRules {
+    type Passenger: Clone + PartialEq + Debug;
+
+    /// Every passenger, at most [`MAX_PASSENGERS`].
+    fn passengers(&self) -> Vec<Self::Passenger>;
+
+    fn boat_capacity(&self) -> usize;
+
+    /// Whether `group` can be left together.
+    fn is_safe(&self, group: &[Self::Passenger]) -> bool;
+
+    /// Whether `crew` can take the boat across, by default any non empty crew.
+    fn can_row(&self, crew: &[Self::Passenger]) -> bool {
+        !crew.is_empty()
+    }
+}
+
+/// [`CrossingState`]
+/// Which bank every passenger (bit `i` for the `i`-th passenger) and the boat are on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CrossingState {
+    /// Bit `i` is set when passenger `i` is on the destination bank.
+    pub on_destination: u64,
+    pub boat: Bank,
+}
+
+impl CrossingState {
+    pub fn bank_of(&self, passenger: usize) -> Bank {
+        if self.on_destination & (1 << passenger) != 0 {
+            Bank::Destination
+        } else {
+            Bank::Start
+        }
+    }
+}
+
+/// [`Crossing`]
+/// `crew` takes the boat to the bank `to`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crossing<P> {
+    pub crew: Vec<P>,
+    pub to: Bank,
+}
+
+impl<P: Debug> Display for Crossing<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "send {:?} to the {:?} bank", self.crew, self.to)
+    }
+}
+
+/// [`CrossingProblem`]
+/// Exposes any [`CrossingRules`] as a [`SearchProblem`]: everybody starts on the start
+/// bank with the boat, and must reach the destination bank. Every crossing costs 1.
+#[derive(Debug, Clone)]
+pub struct CrossingProblem<R> {
+    pub rules: R,
+}
+
+impl<R: CrossingRules> CrossingProblem<R> {
+    /// An error when `rules` have more than [`MAX_PASSENGERS`] passengers.
+    pub fn new(rules: R) -> Result<Self, CrossingError> {
+        match rules.passengers().len() {
+            n if n > MAX_PASSENGERS => Err(CrossingError::TooManyPassengers(n)),
+            _ => Ok(Self { rules }),
+        }
+    }
+
+    /// Passengers on `bank` in `state`.
+    pub fn group(&self, state: &CrossingState, bank: Bank) -> Vec<R::Passenger> {
+        self.rules
+            .passengers()
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| state.bank_of(*i) == bank)
+            .map(|(_, passenger)| passenger)
+            .collect()
+    }
+
+    /// Whether both banks of `state` are safe.
+    pub fn is_safe(&self, state: &CrossingState) -> bool {
+        self.rules.is_safe(&self.group(state, Bank::Start))
+            && self.rules.is_safe(&self.group(state, Bank::Destination))
+    }
+
+    /// [`apply`]
+    /// Applies `crossing` to `state`, `None` when it breaks the rules: the crew is not on
+    /// the boat's bank, is too big, can't row, or leaves an unsafe group behind, in the
+    /// boat or on arrival.
+    pub fn apply(
+        &self,
+        state: &CrossingState,
+        crossing: &Crossing<R::Passenger>,
+    ) -> Option<CrossingState> {
+        let passengers = self.rules.passengers();
+        if crossing.to != state.boat.opposite()
+            || crossing.crew.len() > self.rules.boat_capacity()
+            || !self.rules.can_row(&crossing.crew)
+            || !self.rules.is_safe(&crossing.crew)
+        {
+            return None;
+        }
+        let mut next = *state;
+        for member in &crossing.crew {
+            let index = passengers.iter().position(|p| p == member)?;
+            if state.bank_of(index) != state.boat {
+                return None;
+            }
+            next.on_destination ^= 1 << index;
+        }
+        next.boat = crossing.to;
+        self.is_safe(&next).then_some(next)
+    }
+}
+
+impl<R: CrossingRules> SearchProblem for CrossingProblem<R> {
+    type State = CrossingState;
+    type Action = Crossing<R::Passenger>;
+    type Cost = u32;
+    type Successors<'a>
+        = BoxedSuccessors<'a, CrossingState, Crossing<R::Passenger>>
+    where
+        R: 'a;
+
+    fn initial_state(&self) -> CrossingState {
+        CrossingState {
+            on_destination: 0,
+            boat: Bank::Start,
+        }
+    }
+
+    fn is_goal(&self, state: &CrossingState) -> bool {
+        state.on_destination == every_bit(self.rules.passengers().len())
+    }
+
+    fn successors<'a>(&'a self, state: &'a CrossingState) -> Self::Successors<'a> {
+        let passengers = self.rules.passengers();
+        let on_boat_bank = (0..passengers.len())
+            .filter(|i| state.bank_of(*i) == state.boat)
+            .collect::<Vec<usize>>();
+        // every subset of the passengers next to the boat, up to the boat capacity.
+        let subsets = (1..=every_bit(on_boat_bank.len()))
+            .filter(|subset| subset.count_ones() as usize <= self.rules.boat_capacity());
+        Box::new(subsets.filter_map(move |subset| {
+            let crew = on_boat_bank
+                .iter()
+                .enumerate()
+                .filter(|(bit, _)| subset & (1 << bit) != 0)
+                .map(|(_, i)| passengers[*i].clone())
+                .collect();
+            let crossing = Crossing {
+                crew,
+                to: state.boat.opposite(),
+            };
+            let next = self.apply(state, &crossing)?;
+            Some(Successor::new(crossing, next, 1))
+        }))
+    }
+}
+
+/// The lowest `count` bits set, `count` is at most 64.
+fn every_bit(count: usize) -> u64 {
+    match count {
+        0 => 0,
+        n => u64::MAX >> (64 - n),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::search::a_star;
+
+    /// Wolf, goat and cabbage: the farmer rows, the goat can't stay alone with the wolf
+    /// or the cabbage.
+    struct Farmer;
+
+    impl CrossingRules for Farmer {
+        type Passenger = &'static str;
+
+        fn passengers(&self) -> Vec<&'static str> {
+            vec!["farmer", "wolf", "goat", "cabbage"]
+        }
+
+        fn boat_capacity(&self) -> usize {
+            2
+        }
+
+        fn is_safe(&self, group: &[&'static str]) -> bool {
+            let has = |name| group.contains(&name);
+            has("farmer") || !(has("goat") && (has("wolf") || has("cabbage")))
+        }
+
+        fn can_row(&self, crew: &[&'static str]) -> bool {
+            crew.contains(&"farmer")
+        }
+    }
+
+    #[test]
+    fn crossing_solves_wolf_goat_cabbage() {
+        let problem = CrossingProblem::new(Farmer).unwrap();
+
+        let solution = a_star(&problem, |_| 0.0).unwrap();
+
+        assert_eq!(solution.cost, 7);
+        assert_eq!(solution.actions[0].crew, vec!["farmer", "goat"]);
+        assert!(solution.states.iter().all(|state| problem.is_safe(state)));
+    }
+
+    #[test]
+    fn crossing_apply_rejects_illegal_crossings() {
+        let problem = CrossingProblem::new(Farmer).unwrap();
+        let start = problem.initial_state();
+        let cross = |crew: Vec<&'static str>| Crossing {
+            crew,
+            to: Bank::Destination,
+        };
+
+        assert_eq!(problem.apply(&start, &cross(vec!["wolf"])), None);
+        assert_eq!(problem.apply(&start, &cross(vec!["farmer", "wolf"])), None);
+        assert_eq!(
+            problem.apply(&start, &cross(vec!["farmer", "wolf", "goat"])),
+            None
+        );
+        let after = problem
+            .apply(&start, &cross(vec!["farmer", "goat"]))
+            .unwrap();
+        assert_eq!(after.bank_of(2), Bank::Destination);
+        assert_eq!(after.boat, Bank::Destination);
+    }
+}
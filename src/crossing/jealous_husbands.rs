@@ -0,0 +1,126 @@
+use std::fmt::Display;
+
+use super::{CrossingError, CrossingRules, MAX_PASSENGERS};
+
+/// [`Person`]
+/// Members of the `n` couples, identified by their couple number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Person {
+    Husband(usize),
+    Wife(usize),
+}
+
+impl Display for Person {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Person::Husband(couple) => write!(f, "H{}", couple),
+            Person::Wife(couple) => write!(f, "W{}", couple),
+        }
+    }
+}
+
+/// [`JealousHusbands`]
+/// ## Jealous husbands river crossing.
+/// `couples` couples cross with a boat for `boat_capacity` people. No wife may be with
+/// another man, on a bank or in the boat, unless her husband is also there.
+/// With a boat for 2 it is solvable for up to 3 couples (in 11 crossings), with a boat
+/// for 3 for up to 5 couples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JealousHusbands {
+    pub couples: usize,
+    pub boat_capacity: usize,
+}
+
+impl JealousHusbands {
+    /// An error when the couples are more than [`MAX_PASSENGERS`] people.
+    pub fn new(couples: usize, boat_capacity: usize) -> Result<Self, CrossingError> {
+        if couples > MAX_PASSENGERS / 2 {
+            return Err(CrossingError::TooManyPassengers(couples.saturating_mul(2)));
+        }
+        Ok(Self {
+            couples,
+            boat_capacity,
+        })
+    }
+}
+
+impl CrossingRules for JealousHusbands {
+    type Passenger = Person;
+
+    fn passengers(&self) -> Vec<Person> {
+        (0..self.couples)
+            .flat_map(|couple| [Person::Husband(couple), Person::Wife(couple)])
+            .collect()
+    }
+
+    fn boat_capacity(&self) -> usize {
+        self.boat_capacity
+    }
+
+    fn is_safe(&self, group: &[Person]) -> bool {
+        let has_husband = |couple: usize| group.contains(&Person::Husband(couple));
+        let any_husband = group.iter().any(|p| matches!(p, Person::Husband(_)));
+        group.iter().all(|person| match person {
+            Person::Wife(couple) => has_husband(*couple) || !any_husband,
+            Person::Husband(_) => true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::crossing::CrossingProblem;
+    use crate::search::a_star;
+
+    #[test]
+    fn jealous_husbands_safety_rule() {
+        let rules = JealousHusbands::new(2, 2).unwrap();
+
+        assert!(rules.is_safe(&[Person::Wife(0), Person::Wife(1)]));
+        assert!(rules.is_safe(&[Person::Husband(0), Person::Wife(0), Person::Husband(1)]));
+        assert!(!rules.is_safe(&[Person::Wife(0), Person::Husband(1)]));
+        assert!(!rules.is_safe(&[Person::Husband(0), Person::Wife(0), Person::Wife(1),]));
+        assert!(JealousHusbands::new(32, 2).is_ok());
+        assert_eq!(
+            JealousHusbands::new(33, 2),
+            Err(CrossingError::TooManyPassengers(66))
+        );
+        let crowded = JealousHusbands {
+            couples: 40,
+            boat_capacity: 2,
+        };
+        assert!(matches!(
+            CrossingProblem::new(crowded),
+            Err(CrossingError::TooManyPassengers(80))
+        ));
+    }
+
+    #[test]
+    fn jealous_husbands_three_couples_need_eleven_crossings() {
+        let problem = CrossingProblem::new(JealousHusbands::new(3, 2).unwrap()).unwrap();
+
+        let solution = a_star(&problem, |_| 0.0).unwrap();
+
+        assert_eq!(solution.cost, 11);
+        assert!(solution.states.iter().all(|state| problem.is_safe(state)));
+        assert!(solution
+            .actions
+            .iter()
+            .all(|crossing| problem.rules.is_safe(&crossing.crew)));
+    }
+
+    #[test]
+    fn jealous_husbands_four_couples_need_a_bigger_boat() {
+        assert!(a_star(
+            &CrossingProblem::new(JealousHusbands::new(4, 2).unwrap()).unwrap(),
+            |_| 0.0
+        )
+        .is_none());
+        assert!(a_star(
+            &CrossingProblem::new(JealousHusbands::new(4, 3).unwrap()).unwrap(),
+            |_| 0.0
+        )
+        .is_some());
+    }
+}
@@ -0,0 +1,14 @@
+pub mod framework;
+pub mod jealous_husbands;
+
+pub use framework::*;
+pub use jealous_husbands::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum CrossingError {
+    #[error("A crossing puzzle has at most {MAX_PASSENGERS} passengers, not {0}")]
+    TooManyPassengers(usize),
+}
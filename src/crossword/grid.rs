@@ -0,0 +1,219 @@
+use std::fmt::Display;
+
+use super::CrosswordError;
+
+/// [`Square`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Square {
+    Block,
+    Empty,
+    Letter(char),
+}
+
+/// [`Direction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Across,
+    Down,
+}
+
+/// [`Slot`]
+/// A maximal run of at least 2 non block squares, where a word goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Slot {
+    pub direction: Direction,
+    pub row: usize,
+    pub column: usize,
+    pub length: usize,
+}
+
+impl Slot {
+    /// Position of the `i`-th letter of the slot.
+    pub fn square(&self, i: usize) -> (usize, usize) {
+        match self.direction {
+            Direction::Across => (self.row, self.column + i),
+            Direction::Down => (self.row + i, self.column),
+        }
+    }
+}
+
+/// [`CrosswordGrid`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrosswordGrid {
+    rows: usize,
+    columns: usize,
+    squares: Vec<Square>,
+}
+
+impl CrosswordGrid {
+    pub fn new(rows: Vec<Vec<Square>>) -> Result<Self, CrosswordError> {
+        let columns = rows.first().map(|row| row.len()).unwrap_or(0);
+        if columns == 0 || rows.iter().any(|row| row.len() != columns) {
+            return Err(CrosswordError::InvalidShape);
+        }
+        Ok(Self {
+            rows: rows.len(),
+            columns,
+            squares: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Square {
+        self.squares[row * self.columns + column]
+    }
+
+    pub fn set(&mut self, row: usize, column: usize, square: Square) {
+        self.squares[row * self.columns + column] = square;
+    }
+
+    pub fn is_complete(&self) -> bool {
+        !self.squares.contains(&Square::Empty)
+    }
+
+    /// Every slot, across slots first, in reading order.
+    pub fn slots(&self) -> Vec<Slot> {
+        let mut slots = vec![];
+        for direction in [Direction::Across, Direction::Down] {
+            let (outer, inner) = match direction {
+                Direction::Across => (self.rows, self.columns),
+                Direction::Down => (self.columns, self.rows),
+            };
+            for line in 0..outer {
+                let mut start = 0;
+                while start < inner {
+                    let at = |i: usize| match direction {
+                        Direction::Across => self.get(line, i),
+                        Direction::Down => self.get(i, line),
+                    };
+                    let length = (start..inner)
+                        .take_while(|i| at(*i) != Square::Block)
+                        .count();
+                    if length >= 2 {
+                        let (row, column) = match direction {
+                            Direction::Across => (line, start),
+                            Direction::Down => (start, line),
+                        };
+                        slots.push(Slot {
+                            direction,
+                            row,
+                            column,
+                            length,
+                        });
+                    }
+                    start += length + 1;
+                }
+            }
+        }
+        slots.sort_by_key(|slot| (slot.direction == Direction::Down, slot.row, slot.column));
+        slots
+    }
+}
+
+/// [TryFrom<&str>]
+/// One line per row, `#` is a block, `.` an empty square and letters are pre-filled.
+impl TryFrom<&str> for CrosswordGrid {
+    type Error = CrosswordError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let rows = value
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.chars()
+                    .map(|c| match c {
+                        '#' => Ok(Square::Block),
+                        '.' => Ok(Square::Empty),
+                        c if c.is_ascii_alphabetic() => Ok(Square::Letter(c.to_ascii_lowercase())),
+                        _ => Err(CrosswordError::ParseFromStringError(format!(
+                            "Invalid square {}",
+                            c
+                        ))),
+                    })
+                    .collect::<Result<Vec<Square>, _>>()
+            })
+            .collect::<Result<Vec<Vec<Square>>, _>>()?;
+        CrosswordGrid::new(rows)
+    }
+}
+
+impl Display for CrosswordGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.squares.chunks(self.columns) {
+            let row = row
+                .iter()
+                .map(|square| match square {
+                    Square::Block => '#',
+                    Square::Empty => '.',
+                    Square::Letter(c) => *c,
+                })
+                .collect::<String>();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn crossword_slots_finds_across_and_down_runs() {
+        let grid = CrosswordGrid::try_from("..#\n...\n#..").unwrap();
+
+        let slots = grid.slots();
+
+        assert_eq!(
+            slots,
+            vec![
+                Slot {
+                    direction: Direction::Across,
+                    row: 0,
+                    column: 0,
+                    length: 2
+                },
+                Slot {
+                    direction: Direction::Across,
+                    row: 1,
+                    column: 0,
+                    length: 3
+                },
+                Slot {
+                    direction: Direction::Across,
+                    row: 2,
+                    column: 1,
+                    length: 2
+                },
+                Slot {
+                    direction: Direction::Down,
+                    row: 0,
+                    column: 0,
+                    length: 2
+                },
+                Slot {
+                    direction: Direction::Down,
+                    row: 0,
+                    column: 1,
+                    length: 3
+                },
+                Slot {
+                    direction: Direction::Down,
+                    row: 1,
+                    column: 2,
+                    length: 2
+                },
+            ]
+        );
+        assert_eq!(slots[4].square(2), (2, 1));
+        assert!(CrosswordGrid::try_from("..\n.").is_err());
+    }
+}
@@ -0,0 +1,16 @@
+pub mod grid;
+pub mod solver;
+
+pub use grid::*;
+pub use solver::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum CrosswordError {
+    #[error("Every row of the grid must have the same number of cells")]
+    InvalidShape,
+    #[error("Error when trying to parse from CrosswordGrid string")]
+    ParseFromStringError(String),
+}
@@ -0,0 +1,142 @@
+use super::{CrosswordGrid, Slot, Square};
+use crate::csp::{self, Csp, CspStats};
+
+/// [`CrosswordFill`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrosswordFill {
+    pub grid: CrosswordGrid,
+    /// Word placed in each slot, in the order of [`CrosswordGrid::slots`].
+    pub words: Vec<(Slot, String)>,
+    pub stats: CspStats,
+}
+
+/// [`build_csp`]
+/// ## Crossword filling as a CSP.
+/// One variable per slot, whose domain is every word of the list with the slot's length
+/// that agrees with the letters already in the grid. Crossing slots must have the same
+/// letter on the shared square, and a word is used at most once.
+pub fn build_csp<I, S>(grid: &CrosswordGrid, words: I) -> (Vec<Slot>, Csp<String>)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut words = words
+        .into_iter()
+        .map(|word| word.as_ref().trim().to_lowercase())
+        .filter(|word| !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase()))
+        .collect::<Vec<String>>();
+    words.sort();
+    words.dedup();
+
+    let slots = grid.slots();
+    let domains = slots
+        .iter()
+        .map(|slot| {
+            words
+                .iter()
+                .filter(|word| word.len() == slot.length)
+                .filter(|word| {
+                    word.chars().enumerate().all(|(i, letter)| {
+                        let (row, column) = slot.square(i);
+                        match grid.get(row, column) {
+                            Square::Letter(fixed) => fixed == letter,
+                            _ => true,
+                        }
+                    })
+                })
+                .cloned()
+                .collect()
+        })
+        .collect();
+
+    let mut csp = Csp::new(domains);
+    for (a, slot_a) in slots.iter().enumerate() {
+        for (b, slot_b) in slots.iter().enumerate().skip(a + 1) {
+            let crossing = (0..slot_a.length).find_map(|i| {
+                (0..slot_b.length)
+                    .find(|j| slot_a.square(i) == slot_b.square(*j))
+                    .map(|j| (i, j))
+            });
+            if let Some((i, j)) = crossing {
+                csp.add_binary(a, b, move |x: &String, y: &String| {
+                    x.as_bytes()[i] == y.as_bytes()[j] && x != y
+                });
+            } else if slot_a.length == slot_b.length {
+                csp.add_binary(a, b, |x, y| x != y);
+            }
+        }
+    }
+    (slots, csp)
+}
+
+/// [`fill`]
+/// Fills the grid with words of the list, solving the CSP of [`build_csp`] with
+/// backtracking and AC-3. Returns `None` when the grid can't be filled.
+pub fn fill<I, S>(grid: &CrosswordGrid, words: I) -> Option<CrosswordFill>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let (slots, csp) = build_csp(grid, words);
+    let solution = csp::solve(&csp)?;
+
+    let mut filled = grid.clone();
+    for (slot, word) in slots.iter().zip(&solution.values) {
+        for (i, letter) in word.chars().enumerate() {
+            let (row, column) = slot.square(i);
+            filled.set(row, column, Square::Letter(letter));
+        }
+    }
+    Some(CrosswordFill {
+        grid: filled,
+        words: slots.into_iter().zip(solution.values).collect(),
+        stats: solution.stats,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const WORDS: [&str; 10] = [
+        "cat", "car", "ace", "are", "ten", "ran", "tea", "era", "eta", "rat",
+    ];
+
+    #[test]
+    fn crossword_fill_builds_a_word_square() {
+        let grid = CrosswordGrid::try_from("...\n...\n...").unwrap();
+
+        let filled = fill(&grid, WORDS).unwrap();
+
+        assert!(filled.grid.is_complete());
+        let rows = filled.grid.to_string();
+        for (slot, word) in &filled.words {
+            assert!(WORDS.contains(&word.as_str()));
+            let spelled = (0..slot.length)
+                .map(|i| {
+                    let (r, c) = slot.square(i);
+                    rows.lines().nth(r).unwrap().as_bytes()[c] as char
+                })
+                .collect::<String>();
+            assert_eq!(&spelled, word);
+        }
+        let mut used = filled.words.iter().map(|(_, w)| w).collect::<Vec<_>>();
+        used.sort();
+        used.dedup();
+        assert_eq!(used.len(), 6);
+    }
+
+    #[test]
+    fn crossword_fill_respects_prefilled_letters_and_blocks() {
+        let grid = CrosswordGrid::try_from("c..\n.#.\n..n").unwrap();
+
+        let filled = fill(&grid, WORDS).unwrap();
+
+        assert!(filled.grid.is_complete());
+        assert_eq!(filled.grid.get(0, 0), Square::Letter('c'));
+        assert_eq!(filled.grid.get(1, 1), Square::Block);
+        assert_eq!(filled.grid.get(2, 2), Square::Letter('n'));
+        assert_eq!(filled.words.len(), 4);
+        assert_eq!(fill(&grid, ["cat", "car"]), None);
+    }
+}
@@ -0,0 +1,115 @@
+pub mod solver;
+
+pub use solver::*;
+
+/// [`Check`]
+/// Tells whether the values of the variables of a constraint's scope (in scope order,
+/// `None` when the variable is still undecided) may still satisfy the constraint.
+/// It must only return `false` when the decided values alone already violate it.
+pub type Check<V> = Box<dyn Fn(&[Option<&V>]) -> bool>;
+
+/// [`Constraint`]
+pub struct Constraint<V> {
+    pub scope: Vec<usize>,
+    check: Check<V>,
+}
+
+impl<V> Constraint<V> {
+    pub fn allows(&self, values: &[Option<&V>]) -> bool {
+        (self.check)(values)
+    }
+}
+
+/// [`Csp`]
+/// ## Constraint satisfaction problem.
+/// Variables are `0..n`, each with a finite domain of values, constraints restrict the
+/// values a group of variables (the scope) can take together.
+/// Binary constraints are enforced by arc consistency, bigger ones by checking partial
+/// assignments (see [`solve`]).
+pub struct Csp<V> {
+    domains: Vec<Vec<V>>,
+    constraints: Vec<Constraint<V>>,
+    /// Constraints in which each variable appears.
+    constraints_of: Vec<Vec<usize>>,
+}
+
+impl<V: 'static> Csp<V> {
+    pub fn new(domains: Vec<Vec<V>>) -> Self {
+        let constraints_of = vec![vec![]; domains.len()];
+        Self {
+            domains,
+            constraints: vec![],
+            constraints_of,
+        }
+    }
+
+    pub fn variables(&self) -> usize {
+        self.domains.len()
+    }
+
+    pub fn domain(&self, variable: usize) -> &[V] {
+        &self.domains[variable]
+    }
+
+    pub fn constraints(&self) -> &[Constraint<V>] {
+        &self.constraints
+    }
+
+    pub fn constraints_of(&self, variable: usize) -> &[usize] {
+        &self.constraints_of[variable]
+    }
+
+    pub fn add_constraint<F>(&mut self, scope: Vec<usize>, check: F)
+    where
+        F: Fn(&[Option<&V>]) -> bool + 'static,
+    {
+        let index = self.constraints.len();
+        for variable in &scope {
+            if !self.constraints_of[*variable].contains(&index) {
+                self.constraints_of[*variable].push(index);
+            }
+        }
+        self.constraints.push(Constraint {
+            scope,
+            check: Box::new(check),
+        });
+    }
+
+    /// Constraint between two variables, `relation(a, b)` is only called once both are
+    /// decided.
+    pub fn add_binary<F>(&mut self, a: usize, b: usize, relation: F)
+    where
+        F: Fn(&V, &V) -> bool + 'static,
+    {
+        self.add_constraint(vec![a, b], move |values| match values {
+            [Some(x), Some(y)] => relation(x, y),
+            _ => true,
+        });
+    }
+
+    /// Pairwise `!=` between every two variables of `scope`, which arc consistency handles
+    /// better than a single constraint over the whole scope.
+    pub fn add_all_different(&mut self, scope: &[usize])
+    where
+        V: PartialEq,
+    {
+        for (i, a) in scope.iter().enumerate() {
+            for b in &scope[i + 1..] {
+                self.add_binary(*a, *b, |x, y| x != y);
+            }
+        }
+    }
+
+    /// Whether a complete assignment satisfies every constraint.
+    pub fn is_solution(&self, values: &[V]) -> bool {
+        values.len() == self.domains.len()
+            && self.constraints.iter().all(|constraint| {
+                let scoped = constraint
+                    .scope
+                    .iter()
+                    .map(|variable| Some(&values[*variable]))
+                    .collect::<Vec<Option<&V>>>();
+                constraint.allows(&scoped)
+            })
+    }
+}
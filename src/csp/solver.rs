@@ -0,0 +1,266 @@
+use std::collections::VecDeque;
+
+use super::Csp;
+
+/// Current domains, as indices into the domains of the [`Csp`].
+type Domains = Vec<Vec<usize>>;
+
+/// [`CspStats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CspStats {
+    /// Values tried for a variable during backtracking.
+    pub assignments: usize,
+    /// Values that led to a dead end.
+    pub backtracks: usize,
+    /// Values removed from domains by propagation.
+    pub pruned_values: usize,
+}
+
+/// [`CspSolution`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CspSolution<V> {
+    /// Value of every variable.
+    pub values: Vec<V>,
+    pub stats: CspStats,
+}
+
+/// [`ac3`]
+/// ## Arc consistency.
+/// Removes every value that has no support: for binary constraints a value is kept only
+/// if some value of the other variable agrees with it; bigger constraints (only checked
+/// against variables reduced to a single value) remove values the decided variables
+/// already rule out.
+/// Returns the reduced domains, or `None` if a domain became empty (no solution).
+pub fn ac3<V: Clone + 'static>(csp: &Csp<V>) -> Option<Vec<Vec<V>>> {
+    let mut domains = initial_domains(csp);
+    let mut stats = CspStats::default();
+    let all = (0..csp.variables()).collect::<Vec<usize>>();
+    if !propagate(csp, &mut domains, &all, &mut stats) {
+        return None;
+    }
+    Some(
+        domains
+            .into_iter()
+            .enumerate()
+            .map(|(variable, domain)| {
+                domain
+                    .into_iter()
+                    .map(|value| csp.domain(variable)[value].clone())
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+/// [`solve`]
+/// ## Backtracking search maintaining arc consistency.
+/// Runs [`ac3`] first, then repeatedly picks the undecided variable with the fewest
+/// values left (minimum remaining values), tries each of its values and propagates the
+/// choice with AC-3 again, undoing it when some domain becomes empty.
+/// Returns the first solution found, or `None` when there is none.
+pub fn solve<V: Clone + 'static>(csp: &Csp<V>) -> Option<CspSolution<V>> {
+    let mut stats = CspStats::default();
+    let mut domains = initial_domains(csp);
+    let all = (0..csp.variables()).collect::<Vec<usize>>();
+    if !propagate(csp, &mut domains, &all, &mut stats) {
+        return None;
+    }
+    let domains = backtrack(csp, domains, &mut stats)?;
+    let values = domains
+        .iter()
+        .enumerate()
+        .map(|(variable, domain)| csp.domain(variable)[domain[0]].clone())
+        .collect();
+    Some(CspSolution { values, stats })
+}
+
+fn initial_domains<V: 'static>(csp: &Csp<V>) -> Domains {
+    (0..csp.variables())
+        .map(|variable| (0..csp.domain(variable).len()).collect())
+        .collect()
+}
+
+fn backtrack<V: 'static>(csp: &Csp<V>, domains: Domains, stats: &mut CspStats) -> Option<Domains> {
+    let undecided = (0..domains.len())
+        .filter(|variable| domains[*variable].len() > 1)
+        .min_by_key(|variable| domains[*variable].len());
+    let Some(variable) = undecided else {
+        // constraints over more than 2 variables are only checked partially during the
+        // propagation, check the complete assignment.
+        return all_satisfied(csp, &domains).then_some(domains);
+    };
+
+    for value in domains[variable].clone() {
+        stats.assignments += 1;
+        let mut next = domains.clone();
+        next[variable] = vec![value];
+        if propagate(csp, &mut next, &[variable], stats) {
+            if let Some(solution) = backtrack(csp, next, stats) {
+                return Some(solution);
+            }
+        }
+        stats.backtracks += 1;
+    }
+    None
+}
+
+/// AC-3 starting from the arcs of the constraints of `changed`, until no domain changes.
+/// Returns `false` if a domain became empty.
+fn propagate<V: 'static>(
+    csp: &Csp<V>,
+    domains: &mut Domains,
+    changed: &[usize],
+    stats: &mut CspStats,
+) -> bool {
+    if domains.iter().any(|domain| domain.is_empty()) {
+        return false;
+    }
+    let mut queue = VecDeque::new();
+    let mut queued = vec![false; csp.constraints().len()];
+    for variable in changed {
+        for constraint in csp.constraints_of(*variable) {
+            if !queued[*constraint] {
+                queued[*constraint] = true;
+                queue.push_back(*constraint);
+            }
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        queued[index] = false;
+        let constraint = &csp.constraints()[index];
+        for (position, variable) in constraint.scope.iter().enumerate() {
+            let before = domains[*variable].len();
+            let kept = domains[*variable]
+                .iter()
+                .copied()
+                .filter(|value| has_support(csp, domains, index, position, *value))
+                .collect::<Vec<usize>>();
+            if kept.len() == before {
+                continue;
+            }
+            stats.pruned_values += before - kept.len();
+            if kept.is_empty() {
+                return false;
+            }
+            domains[*variable] = kept;
+            for other in csp.constraints_of(*variable) {
+                if *other != index && !queued[*other] {
+                    queued[*other] = true;
+                    queue.push_back(*other);
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Whether `value` of the variable at `position` of the constraint `index` has a support.
+fn has_support<V: 'static>(
+    csp: &Csp<V>,
+    domains: &Domains,
+    index: usize,
+    position: usize,
+    value: usize,
+) -> bool {
+    let constraint = &csp.constraints()[index];
+    let value_of = |position: usize, value: usize| &csp.domain(constraint.scope[position])[value];
+    if let [_, _] = constraint.scope[..] {
+        let other = 1 - position;
+        return domains[constraint.scope[other]].iter().any(|other_value| {
+            let mut values = [None, None];
+            values[position] = Some(value_of(position, value));
+            values[other] = Some(value_of(other, *other_value));
+            constraint.allows(&values)
+        });
+    }
+    let values = constraint
+        .scope
+        .iter()
+        .enumerate()
+        .map(|(p, variable)| match &domains[*variable][..] {
+            _ if p == position => Some(value_of(p, value)),
+            [single] => Some(value_of(p, *single)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    constraint.allows(&values)
+}
+
+fn all_satisfied<V: 'static>(csp: &Csp<V>, domains: &Domains) -> bool {
+    csp.constraints().iter().all(|constraint| {
+        let values = constraint
+            .scope
+            .iter()
+            .map(|variable| Some(&csp.domain(*variable)[domains[*variable][0]]))
+            .collect::<Vec<_>>();
+        constraint.allows(&values)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Australia map coloring: WA, NT, SA, Q, NSW, V, T.
+    fn australia(colors: usize) -> Csp<usize> {
+        let mut csp = Csp::new(vec![(0..colors).collect(); 7]);
+        for (a, b) in [
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (2, 4),
+            (2, 5),
+            (3, 4),
+            (4, 5),
+        ] {
+            csp.add_binary(a, b, |x, y| x != y);
+        }
+        csp
+    }
+
+    #[test]
+    fn csp_solve_colors_australia() {
+        let csp = australia(3);
+
+        let solution = solve(&csp).unwrap();
+
+        assert!(csp.is_solution(&solution.values));
+        assert_eq!(solve(&australia(2)), None);
+    }
+
+    #[test]
+    fn csp_ac3_prunes_unsupported_values() {
+        // x < y < z over 0..3 has the single solution 0, 1, 2.
+        let mut csp = Csp::new(vec![vec![0, 1, 2]; 3]);
+        csp.add_binary(0, 1, |x, y| x < y);
+        csp.add_binary(1, 2, |x, y| x < y);
+
+        assert_eq!(ac3(&csp), Some(vec![vec![0], vec![1], vec![2]]));
+
+        csp.add_binary(2, 0, |z, x| z < x);
+        assert_eq!(ac3(&csp), None);
+    }
+
+    #[test]
+    fn csp_solve_checks_constraints_over_many_variables() {
+        // three different digits 1..=4 adding up to 9, ac3 alone can't decide it.
+        let mut csp = Csp::new(vec![vec![1, 2, 3, 4]; 3]);
+        csp.add_all_different(&[0, 1, 2]);
+        csp.add_constraint(vec![0, 1, 2], |values| {
+            let sum = values.iter().flatten().copied().sum::<u32>();
+            match values.iter().all(|v| v.is_some()) {
+                true => sum == 9,
+                false => sum < 9,
+            }
+        });
+
+        let solution = solve(&csp).unwrap();
+
+        assert!(csp.is_solution(&solution.values));
+        assert_eq!(solution.values.iter().sum::<u32>(), 9);
+        assert!(solution.stats.assignments > 0);
+    }
+}
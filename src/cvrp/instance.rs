@@ -0,0 +1,208 @@
+use super::CvrpError;
+
+/// [`Customer`]
+/// A point on the plane that must receive `demand` units of goods.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Customer {
+    pub x: f64,
+    pub y: f64,
+    pub demand: u32,
+}
+
+impl Customer {
+    pub fn new(x: f64, y: f64, demand: u32) -> Self {
+        Self { x, y, demand }
+    }
+}
+
+/// [`CvrpInstance`]
+/// ## Capacitated vehicle routing instance.
+/// Vehicles leave the depot, visit some customers and come back, the total demand of the
+/// customers of a route can't exceed `capacity`. Every customer is visited exactly once
+/// and the total euclidean distance travelled is minimized.
+///
+/// Routes are lists of customer indices, the depot is implicit at both ends.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CvrpInstance {
+    depot: (f64, f64),
+    customers: Vec<Customer>,
+    capacity: u32,
+    /// `distances[a][b]`, index `0` is the depot and customer `i` is index `i + 1`.
+    distances: Vec<Vec<f64>>,
+}
+
+impl CvrpInstance {
+    pub fn new(
+        depot: (f64, f64),
+        customers: Vec<Customer>,
+        capacity: u32,
+    ) -> Result<Self, CvrpError> {
+        if let Some(index) = customers
+            .iter()
+            .position(|c| c.demand == 0 || c.demand > capacity)
+        {
+            return Err(CvrpError::InvalidDemand(index, customers[index].demand));
+        }
+        let points = std::iter::once(depot)
+            .chain(customers.iter().map(|c| (c.x, c.y)))
+            .collect::<Vec<(f64, f64)>>();
+        let distances = points
+            .iter()
+            .map(|a| {
+                points
+                    .iter()
+                    .map(|b| (a.0 - b.0).hypot(a.1 - b.1))
+                    .collect()
+            })
+            .collect();
+        Ok(Self {
+            depot,
+            customers,
+            capacity,
+            distances,
+        })
+    }
+
+    pub fn depot(&self) -> (f64, f64) {
+        self.depot
+    }
+
+    pub fn customers(&self) -> &[Customer] {
+        &self.customers
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    pub fn distance(&self, a: usize, b: usize) -> f64 {
+        self.distances[a + 1][b + 1]
+    }
+
+    pub fn depot_distance(&self, customer: usize) -> f64 {
+        self.distances[0][customer + 1]
+    }
+
+    pub fn route_load(&self, route: &[usize]) -> u32 {
+        route.iter().map(|c| self.customers[*c].demand).sum()
+    }
+
+    /// Length of depot -> `route` -> depot.
+    pub fn route_cost(&self, route: &[usize]) -> f64 {
+        match (route.first(), route.last()) {
+            (Some(first), Some(last)) => {
+                self.depot_distance(*first)
+                    + route
+                        .windows(2)
+                        .map(|pair| self.distance(pair[0], pair[1]))
+                        .sum::<f64>()
+                    + self.depot_distance(*last)
+            }
+            _ => 0.0,
+        }
+    }
+
+    pub fn total_cost(&self, routes: &[Vec<usize>]) -> f64 {
+        routes.iter().map(|route| self.route_cost(route)).sum()
+    }
+
+    /// Checks that every customer is visited exactly once and no route overloads its vehicle.
+    pub fn is_valid_solution(&self, routes: &[Vec<usize>]) -> bool {
+        let mut visited = vec![false; self.customers.len()];
+        for route in routes {
+            for customer in route {
+                match visited.get_mut(*customer) {
+                    Some(visited) if !*visited => *visited = true,
+                    _ => return false,
+                }
+            }
+            if self.route_load(route) > self.capacity {
+                return false;
+            }
+        }
+        visited.into_iter().all(|visited| visited)
+    }
+}
+
+/// [TryFrom<&str>]
+/// Accepts the format:
+/// first line `n_customers capacity`, second line `depot_x depot_y`, then one line per
+/// customer with `x y demand`.
+impl TryFrom<&str> for CvrpInstance {
+    type Error = CvrpError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parse_error = |message: &str| CvrpError::ParseFromStringError(message.into());
+        let mut lines = value
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|n| n.parse::<f64>())
+                    .collect::<Result<Vec<f64>, _>>()
+                    .map_err(|_| parse_error("Invalid number"))
+            });
+
+        let (n_customers, capacity) = match lines.next().transpose()?.as_deref() {
+            Some([n_customers, capacity]) => (*n_customers as usize, *capacity as u32),
+            _ => {
+                return Err(parse_error(
+                    "Header must contain number of customers and capacity",
+                ))
+            }
+        };
+        let depot = match lines.next().transpose()?.as_deref() {
+            Some([x, y]) => (*x, *y),
+            _ => return Err(parse_error("Depot must contain x and y")),
+        };
+        let customers = lines
+            .take(n_customers)
+            .map(|line| match line?[..] {
+                [x, y, demand] => Ok(Customer::new(x, y, demand as u32)),
+                _ => Err(parse_error("Customer must contain x, y and demand")),
+            })
+            .collect::<Result<Vec<Customer>, CvrpError>>()?;
+        if customers.len() != n_customers {
+            return Err(parse_error("Missing customers"));
+        }
+        CvrpInstance::new(depot, customers, capacity)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LINE_INSTANCE: &str = "4 2
+        0 0
+        1 0 1
+        2 0 1
+        -1 0 1
+        -2 0 1";
+
+    #[test]
+    fn cvrp_try_from_parses_instance() {
+        let instance = CvrpInstance::try_from(LINE_INSTANCE).unwrap();
+
+        assert_eq!(instance.customers().len(), 4);
+        assert_eq!(instance.capacity(), 2);
+        assert_eq!(instance.distance(1, 3), 4.0);
+        assert!(CvrpInstance::try_from("2 2\n0 0\n1 0 1").is_err());
+        assert_eq!(
+            CvrpInstance::try_from("1 2\n0 0\n1 0 3"),
+            Err(CvrpError::InvalidDemand(0, 3))
+        );
+    }
+
+    #[test]
+    fn cvrp_route_cost_and_validity() {
+        let instance = CvrpInstance::try_from(LINE_INSTANCE).unwrap();
+
+        assert_eq!(instance.route_cost(&[0, 1]), 4.0);
+        assert_eq!(instance.total_cost(&[vec![0, 1], vec![3, 2]]), 8.0);
+        assert!(instance.is_valid_solution(&[vec![0, 1], vec![3, 2]]));
+        assert!(!instance.is_valid_solution(&[vec![0, 1, 2], vec![3]]));
+        assert!(!instance.is_valid_solution(&[vec![0, 1], vec![2]]));
+    }
+}
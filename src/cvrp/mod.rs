@@ -0,0 +1,18 @@
+pub mod instance;
+pub mod neighborhood;
+pub mod savings;
+
+pub use instance::*;
+pub use neighborhood::*;
+pub use savings::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum CvrpError {
+    #[error("Customer demand must be greater than zero and fit in a vehicle")]
+    InvalidDemand(usize, u32),
+    #[error("Error when trying to parse from CvrpInstance string")]
+    ParseFromStringError(String),
+}
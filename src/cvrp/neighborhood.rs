@@ -0,0 +1,235 @@
+use super::CvrpInstance;
+use crate::local_search::LocalSearchProblem;
+use crate::rng::Rng;
+
+/// [`Operator`]
+/// Improvement moves between routes, only moves that keep every route within the
+/// vehicle capacity are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// Move a customer to another position, in the same or in another route.
+    Relocate,
+    /// Exchange two customers of different routes.
+    Swap,
+    /// Cut two routes and exchange their tails.
+    TwoOptStar,
+}
+
+impl Operator {
+    pub const ALL: [Operator; 3] = [Operator::Relocate, Operator::Swap, Operator::TwoOptStar];
+}
+
+/// [`CvrpLocalSearch`]
+/// Exposes a [`CvrpInstance`] as a [`LocalSearchProblem`] minimizing the total distance,
+/// the neighborhood is the union of the moves of every operator in `operators`.
+#[derive(Debug, Clone)]
+pub struct CvrpLocalSearch<'a> {
+    pub instance: &'a CvrpInstance,
+    pub operators: Vec<Operator>,
+}
+
+impl<'a> CvrpLocalSearch<'a> {
+    pub fn new(instance: &'a CvrpInstance, operators: &[Operator]) -> Self {
+        Self {
+            instance,
+            operators: operators.to_vec(),
+        }
+    }
+
+    /// [`improve`]
+    /// Applies the best improving move until none is left (steepest descent).
+    pub fn improve(&self, mut routes: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+        let mut cost = self.cost(&routes);
+        loop {
+            let best = self
+                .neighbors(&routes)
+                .into_iter()
+                .map(|neighbor| (self.cost(&neighbor), neighbor))
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+            match best {
+                Some((neighbor_cost, neighbor)) if neighbor_cost < cost - 1e-9 => {
+                    cost = neighbor_cost;
+                    routes = neighbor;
+                }
+                _ => return routes,
+            }
+        }
+    }
+
+    fn fits(&self, route: &[usize]) -> bool {
+        self.instance.route_load(route) <= self.instance.capacity()
+    }
+
+    fn relocate(&self, routes: &[Vec<usize>], neighbors: &mut Vec<Vec<Vec<usize>>>) {
+        for from in 0..routes.len() {
+            for position in 0..routes[from].len() {
+                let customer = routes[from][position];
+                let mut removed = routes.to_vec();
+                removed[from].remove(position);
+                for to in 0..routes.len() {
+                    for insert_at in 0..=removed[to].len() {
+                        if to == from && (insert_at == position || removed[to].is_empty()) {
+                            continue;
+                        }
+                        let mut neighbor = removed.clone();
+                        neighbor[to].insert(insert_at, customer);
+                        if self.fits(&neighbor[to]) {
+                            neighbors.push(neighbor);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn swap(&self, routes: &[Vec<usize>], neighbors: &mut Vec<Vec<Vec<usize>>>) {
+        for a in 0..routes.len() {
+            for b in a + 1..routes.len() {
+                for i in 0..routes[a].len() {
+                    for j in 0..routes[b].len() {
+                        let mut neighbor = routes.to_vec();
+                        neighbor[a][i] = routes[b][j];
+                        neighbor[b][j] = routes[a][i];
+                        if self.fits(&neighbor[a]) && self.fits(&neighbor[b]) {
+                            neighbors.push(neighbor);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn two_opt_star(&self, routes: &[Vec<usize>], neighbors: &mut Vec<Vec<Vec<usize>>>) {
+        for a in 0..routes.len() {
+            for b in a + 1..routes.len() {
+                for i in 0..=routes[a].len() {
+                    for j in 0..=routes[b].len() {
+                        // exchanging whole routes or empty tails changes nothing.
+                        let whole = i == 0 && j == 0;
+                        let empty = i == routes[a].len() && j == routes[b].len();
+                        if whole || empty {
+                            continue;
+                        }
+                        let mut neighbor = routes.to_vec();
+                        neighbor[a] = [&routes[a][..i], &routes[b][j..]].concat();
+                        neighbor[b] = [&routes[b][..j], &routes[a][i..]].concat();
+                        if self.fits(&neighbor[a]) && self.fits(&neighbor[b]) {
+                            neighbors.push(neighbor);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl LocalSearchProblem for CvrpLocalSearch<'_> {
+    type State = Vec<Vec<usize>>;
+
+    /// Shuffles the customers and fills one vehicle after the other.
+    fn random_state(&self, rng: &mut Rng) -> Vec<Vec<usize>> {
+        let mut customers = (0..self.instance.customers().len()).collect::<Vec<usize>>();
+        rng.shuffle(&mut customers);
+        let mut routes: Vec<Vec<usize>> = vec![];
+        let mut load = 0;
+        for customer in customers {
+            let demand = self.instance.customers()[customer].demand;
+            match routes.last_mut() {
+                Some(route) if load + demand <= self.instance.capacity() => {
+                    route.push(customer);
+                    load += demand;
+                }
+                _ => {
+                    routes.push(vec![customer]);
+                    load = demand;
+                }
+            }
+        }
+        routes
+    }
+
+    /// Routes left empty by a move are removed.
+    fn neighbors(&self, state: &Vec<Vec<usize>>) -> Vec<Vec<Vec<usize>>> {
+        let mut neighbors = vec![];
+        for operator in &self.operators {
+            match operator {
+                Operator::Relocate => self.relocate(state, &mut neighbors),
+                Operator::Swap => self.swap(state, &mut neighbors),
+                Operator::TwoOptStar => self.two_opt_star(state, &mut neighbors),
+            }
+        }
+        for neighbor in neighbors.iter_mut() {
+            neighbor.retain(|route| !route.is_empty());
+        }
+        neighbors.retain(|neighbor| neighbor != state);
+        neighbors
+    }
+
+    fn cost(&self, state: &Vec<Vec<usize>>) -> f64 {
+        self.instance.total_cost(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cvrp::{savings, Customer};
+
+    fn random_instance(rng: &mut Rng) -> CvrpInstance {
+        let customers = (0..10)
+            .map(|_| {
+                Customer::new(
+                    rng.gen_f64() * 100.0,
+                    rng.gen_f64() * 100.0,
+                    rng.gen_range(1..5) as u32,
+                )
+            })
+            .collect();
+        CvrpInstance::new((50.0, 50.0), customers, 8).unwrap()
+    }
+
+    #[test]
+    fn cvrp_neighbors_are_valid_solutions() {
+        let instance = random_instance(&mut Rng::new(1));
+
+        for operator in Operator::ALL {
+            let problem = CvrpLocalSearch::new(&instance, &[operator]);
+            let start = problem.random_state(&mut Rng::new(2));
+            let neighbors = problem.neighbors(&start);
+
+            assert!(instance.is_valid_solution(&start));
+            assert!(!neighbors.is_empty());
+            for neighbor in neighbors {
+                assert!(instance.is_valid_solution(&neighbor));
+            }
+        }
+    }
+
+    #[test]
+    fn cvrp_two_opt_star_exchanges_route_tails() {
+        // both routes cross the depot, exchanging tails leaves one route per side.
+        let instance = CvrpInstance::try_from("4 2\n0 0\n1 0 1\n2 0 1\n-1 0 1\n-2 0 1").unwrap();
+        let problem = CvrpLocalSearch::new(&instance, &[Operator::TwoOptStar]);
+
+        let crossing = vec![vec![0, 3], vec![2, 1]];
+        let improved = problem.improve(crossing.clone());
+
+        assert_eq!(instance.total_cost(&crossing), 12.0);
+        assert_eq!(instance.total_cost(&improved), 8.0);
+    }
+
+    #[test]
+    fn cvrp_improve_never_worsens_savings() {
+        let mut rng = Rng::new(4);
+        for _ in 0..5 {
+            let instance = random_instance(&mut rng);
+            let problem = CvrpLocalSearch::new(&instance, &Operator::ALL);
+
+            let constructed = savings(&instance);
+            let improved = problem.improve(constructed.clone());
+
+            assert!(instance.is_valid_solution(&improved));
+            assert!(instance.total_cost(&improved) <= instance.total_cost(&constructed));
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use super::CvrpInstance;
+
+/// [`savings`]
+/// ## Clarke & Wright savings construction.
+/// Starts with one route per customer, then merges routes in decreasing order of the
+/// saving `d(depot, i) + d(depot, j) - d(i, j)` obtained by linking customer `i` to `j`
+/// directly. Two routes are merged only when `i` and `j` are at their ends and the merged
+/// route fits in a vehicle.
+pub fn savings(instance: &CvrpInstance) -> Vec<Vec<usize>> {
+    let n = instance.customers().len();
+    let mut pairs = (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let saving =
+                instance.depot_distance(i) + instance.depot_distance(j) - instance.distance(i, j);
+            (saving, i, j)
+        })
+        .filter(|(saving, _, _)| *saving > 0.0)
+        .collect::<Vec<(f64, usize, usize)>>();
+    pairs.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut routes = (0..n)
+        .map(|c| Some(vec![c]))
+        .collect::<Vec<Option<Vec<usize>>>>();
+    let mut route_of = (0..n).collect::<Vec<usize>>();
+    let mut loads = instance
+        .customers()
+        .iter()
+        .map(|c| c.demand)
+        .collect::<Vec<u32>>();
+
+    for (_, i, j) in pairs {
+        let (a, b) = (route_of[i], route_of[j]);
+        if a == b || loads[a] + loads[b] > instance.capacity() {
+            continue;
+        }
+        let (Some(mut route_a), Some(mut route_b)) = (routes[a].take(), routes[b].take()) else {
+            unreachable!("customers always point to an existing route");
+        };
+        // put `i` at the end of `route_a` and `j` at the start of `route_b`.
+        if route_a.first() == Some(&i) && route_a.len() > 1 {
+            route_a.reverse();
+        }
+        if route_b.last() == Some(&j) && route_b.len() > 1 {
+            route_b.reverse();
+        }
+        if route_a.last() != Some(&i) || route_b.first() != Some(&j) {
+            // one of them is an interior customer.
+            routes[a] = Some(route_a);
+            routes[b] = Some(route_b);
+            continue;
+        }
+
+        for customer in &route_b {
+            route_of[*customer] = a;
+        }
+        route_a.extend(route_b);
+        routes[a] = Some(route_a);
+        loads[a] += loads[b];
+    }
+
+    routes.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cvrp_savings_merges_customers_on_the_same_side() {
+        let instance = CvrpInstance::try_from("4 2\n0 0\n1 0 1\n2 0 1\n-1 0 1\n-2 0 1").unwrap();
+
+        let routes = savings(&instance);
+
+        assert!(instance.is_valid_solution(&routes));
+        assert_eq!(routes.len(), 2);
+        assert_eq!(instance.total_cost(&routes), 8.0);
+    }
+
+    #[test]
+    fn cvrp_savings_respects_capacity() {
+        let instance = CvrpInstance::try_from("3 5\n0 0\n1 1 3\n1 2 3\n2 1 3").unwrap();
+
+        let routes = savings(&instance);
+
+        assert!(instance.is_valid_solution(&routes));
+        assert_eq!(routes.len(), 3);
+    }
+}
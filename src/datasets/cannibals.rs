@@ -0,0 +1,52 @@
+use super::{parse_instances, Instance};
+use crate::WorldState;
+
+const CANNIBALS: &str = include_str!("cannibals.txt");
+
+/// [`cannibals_variants`]
+/// Every safe state of the 3 cannibals, 3 missionaries puzzle other than the goal, to
+/// be used as starting points. Named after the state, e.g. `"1 1 2 2 left"`.
+pub fn cannibals_variants() -> Vec<Instance<WorldState>> {
+    parse_instances(
+        CANNIBALS,
+        |text| WorldState::try_from(text),
+        |_| String::new(),
+    )
+    .expect("bundled cannibals dataset is valid")
+    .into_iter()
+    .map(|variant| Instance {
+        name: variant.instance.to_string(),
+        ..variant
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::PackedCannibalsProblem;
+    use crate::search::bfs;
+
+    /// Fewest crossings from `state` to the goal, breadth-first over packed states.
+    fn fewest_crossings(state: &WorldState) -> Option<u32> {
+        let problem = PackedCannibalsProblem::new(state).expect("classic states pack");
+        bfs(&problem).map(|solution| solution.cost)
+    }
+
+    #[test]
+    fn cannibals_variants_match_their_optimal_cost() {
+        let variants = cannibals_variants();
+
+        assert_eq!(variants.len(), 18);
+        assert_eq!(variants[0].name, "0 0 3 3 right");
+        assert_eq!(variants[17].name, variants[17].instance.to_string());
+        for variant in variants {
+            assert_eq!(
+                fewest_crossings(&variant.instance),
+                variant.optimal_cost,
+                "{}",
+                variant.name
+            );
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use super::{parse_instances, Instance};
+use crate::n_puzzle::Board;
+
+const EIGHT_PUZZLE: &str = include_str!("eight_puzzle.txt");
+
+/// [`eight_puzzle_100`]
+/// 100 solvable 8-puzzle boards drawn uniformly at random with a fixed seed, with their
+/// optimal number of moves (from 9 to 28), named `"8-puzzle #1"` to `"8-puzzle #100"`.
+pub fn eight_puzzle_100() -> Vec<Instance<Board>> {
+    parse_instances(
+        EIGHT_PUZZLE,
+        |text| Board::try_from(text),
+        |i| format!("8-puzzle #{}", i + 1),
+    )
+    .expect("bundled 8-puzzle dataset is valid")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::NPuzzleProblem;
+    use crate::search::a_star;
+
+    #[test]
+    fn eight_puzzle_100_optimal_costs_match_a_star() {
+        let instances = eight_puzzle_100();
+
+        assert_eq!(instances.len(), 100);
+        assert!(instances.iter().all(|i| i.instance.is_solvable()));
+        for instance in instances.iter().step_by(10) {
+            let problem = NPuzzleProblem::new(instance.instance.clone());
+            let solution = a_star(&problem, |board| board.manhattan_distance() as f64).unwrap();
+            assert_eq!(
+                Some(solution.cost),
+                instance.optimal_cost,
+                "{}",
+                instance.name
+            );
+        }
+    }
+}
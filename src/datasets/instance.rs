@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use super::DatasetError;
+
+/// [`Instance`]
+/// A benchmark instance together with the cost of its optimal solutions, `None` when it
+/// has no solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instance<T> {
+    pub name: String,
+    pub instance: T,
+    pub optimal_cost: Option<u32>,
+}
+
+/// [`parse_instances`]
+/// ## Loads a dataset in the bundled format.
+/// One instance per line as `instance | optimal cost`, the cost being `-` for unsolvable
+/// instances. Blank lines and lines starting with `#` are skipped. `parse` reads the
+/// instance and `name` names it from its position among the instances.
+/// # Example
+/// ```
+/// # use algoritmos_rust::datasets::*;
+/// # use algoritmos_rust::n_puzzle::Board;
+/// let instances = parse_instances(
+///     "# two boards\n1 2 3 4 5 6 7 0 8 | 1\n1 2 3 4 5 6 8 7 0 | -",
+///     |text| Board::try_from(text),
+///     |i| format!("board {}", i),
+/// )
+/// .unwrap();
+/// assert_eq!(instances[0].optimal_cost, Some(1));
+/// assert_eq!(instances[1].optimal_cost, None);
+/// ```
+pub fn parse_instances<T, E, F, N>(
+    text: &str,
+    parse: F,
+    name: N,
+) -> Result<Vec<Instance<T>>, DatasetError>
+where
+    E: Display,
+    F: Fn(&str) -> Result<T, E>,
+    N: Fn(usize) -> String,
+{
+    text.lines()
+        .enumerate()
+        .map(|(number, line)| (number + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .enumerate()
+        .map(|(i, (number, line))| {
+            let error = |message: String| DatasetError::ParseFromStringError(number, message);
+            let (instance, cost) = line
+                .split_once('|')
+                .ok_or_else(|| error("Missing optimal cost".into()))?;
+            let optimal_cost = match cost.trim() {
+                "-" => None,
+                cost => Some(
+                    cost.parse::<u32>()
+                        .map_err(|_| error(format!("Invalid cost {}", cost)))?,
+                ),
+            };
+            Ok(Instance {
+                name: name(i),
+                instance: parse(instance.trim()).map_err(|e| error(e.to_string()))?,
+                optimal_cost,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_instances_reports_the_faulty_line() {
+        let parse = |text: &str| text.parse::<u8>();
+
+        assert_eq!(
+            parse_instances("# header\n\n1 | 2\n2 3", parse, |i| i.to_string()),
+            Err(DatasetError::ParseFromStringError(
+                4,
+                "Missing optimal cost".into()
+            ))
+        );
+        assert!(parse_instances("x | 2", parse, |i| i.to_string()).is_err());
+        assert!(parse_instances("1 | two", parse, |i| i.to_string()).is_err());
+    }
+}
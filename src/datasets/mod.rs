@@ -0,0 +1,16 @@
+pub mod cannibals;
+pub mod eight_puzzle;
+pub mod instance;
+
+pub use cannibals::*;
+pub use eight_puzzle::*;
+pub use instance::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum DatasetError {
+    #[error("Line {0}: {1}")]
+    ParseFromStringError(usize, String),
+}
@@ -0,0 +1,18 @@
+pub mod puzzle;
+pub mod solver;
+
+pub use puzzle::*;
+pub use solver::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum FutoshikiError {
+    #[error("Puzzle size must be between 2 and 9")]
+    InvalidSize(usize),
+    #[error("Given value does not fit in the puzzle")]
+    InvalidGiven(usize, usize),
+    #[error("Error when trying to parse from Futoshiki string")]
+    ParseFromStringError(String),
+}
@@ -0,0 +1,163 @@
+use super::FutoshikiError;
+
+/// [`Inequality`]
+/// `(a, b)` means the value at cell `a` is smaller than the value at cell `b`.
+pub type Inequality = ((usize, usize), (usize, usize));
+
+/// [`Futoshiki`]
+/// ## Futoshiki (inequality Latin square) puzzle.
+/// Fill the `size x size` grid with `1..=size` so that no value repeats in a row or
+/// column, and every inequality between neighboring cells holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Futoshiki {
+    size: usize,
+    givens: Vec<Option<u8>>,
+    less_than: Vec<Inequality>,
+}
+
+impl Futoshiki {
+    pub fn new(
+        size: usize,
+        givens: Vec<Option<u8>>,
+        less_than: Vec<Inequality>,
+    ) -> Result<Self, FutoshikiError> {
+        if !(2..=9).contains(&size) || givens.len() != size * size {
+            return Err(FutoshikiError::InvalidSize(size));
+        }
+        if let Some(index) = givens
+            .iter()
+            .position(|given| given.is_some_and(|v| v == 0 || usize::from(v) > size))
+        {
+            return Err(FutoshikiError::InvalidGiven(index / size, index % size));
+        }
+        Ok(Self {
+            size,
+            givens,
+            less_than,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn given(&self, row: usize, column: usize) -> Option<u8> {
+        self.givens[row * self.size + column]
+    }
+
+    pub fn less_than(&self) -> &[Inequality] {
+        &self.less_than
+    }
+}
+
+/// [TryFrom<&str>]
+/// Rows of cells (`.` or a digit) separated by a space, or by `<` / `>` between
+/// horizontal neighbors. Between two rows of cells there may be a line with `^` (the upper
+/// cell is smaller) or `v` (the upper cell is bigger) right under the cells, e.g.:
+/// ```text
+/// .<. .
+/// ^   v
+/// . .>1
+/// . . .
+/// ```
+impl TryFrom<&str> for Futoshiki {
+    type Error = FutoshikiError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parse_error = |message: &str| FutoshikiError::ParseFromStringError(message.into());
+        let lines = value
+            .lines()
+            .map(|line| line.trim_end())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<&str>>();
+        let is_cell_line = |line: &str| line.starts_with(|c: char| c == '.' || c.is_ascii_digit());
+
+        let mut givens = vec![];
+        let mut less_than = vec![];
+        let mut size = 0;
+        let mut row = 0;
+        for line in lines {
+            let chars = line.chars().collect::<Vec<char>>();
+            if is_cell_line(line) {
+                let cells = chars.iter().step_by(2).collect::<Vec<&char>>();
+                if row == 0 {
+                    size = cells.len();
+                } else if cells.len() != size {
+                    return Err(parse_error("Every row must have the same number of cells"));
+                }
+                for (column, cell) in cells.iter().enumerate() {
+                    givens.push(match cell {
+                        '.' => None,
+                        c => Some(c.to_digit(10).ok_or(parse_error("Invalid cell"))? as u8),
+                    });
+                    match chars.get(2 * column + 1) {
+                        Some('<') => less_than.push(((row, column), (row, column + 1))),
+                        Some('>') => less_than.push(((row, column + 1), (row, column))),
+                        Some(' ') | None => {}
+                        Some(_) => return Err(parse_error("Invalid horizontal relation")),
+                    }
+                }
+                row += 1;
+            } else {
+                if row == 0 {
+                    return Err(parse_error("Puzzle must start with a row of cells"));
+                }
+                for (position, relation) in chars.iter().enumerate() {
+                    let column = position / 2;
+                    match relation {
+                        '^' => less_than.push(((row - 1, column), (row, column))),
+                        'v' => less_than.push(((row, column), (row - 1, column))),
+                        ' ' => {}
+                        _ => return Err(parse_error("Invalid vertical relation")),
+                    }
+                }
+            }
+        }
+        if row != size {
+            return Err(FutoshikiError::InvalidSize(size));
+        }
+        if less_than
+            .iter()
+            .any(|(a, b)| a.0 >= size || a.1 >= size || b.0 >= size || b.1 >= size)
+        {
+            return Err(parse_error("Relation outside the grid"));
+        }
+        Futoshiki::new(size, givens, less_than)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn futoshiki_try_from_reads_inequalities() {
+        let puzzle = Futoshiki::try_from(".<. .\n^   v\n. .>1\n. . .").unwrap();
+
+        assert_eq!(puzzle.size(), 3);
+        assert_eq!(puzzle.given(1, 2), Some(1));
+        assert_eq!(
+            puzzle.less_than(),
+            &[
+                ((0, 0), (0, 1)),
+                ((0, 0), (1, 0)),
+                ((1, 2), (0, 2)),
+                ((1, 2), (1, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn futoshiki_try_from_rejects_invalid_puzzles() {
+        assert!(Futoshiki::try_from(". .\n. . .").is_err());
+        assert_eq!(
+            Futoshiki::try_from(". 3\n. ."),
+            Err(FutoshikiError::InvalidGiven(0, 1))
+        );
+        assert_eq!(
+            Futoshiki::try_from(". .\n. .\n. ."),
+            Err(FutoshikiError::InvalidSize(2))
+        );
+        assert!(Futoshiki::try_from(".?.\n. .").is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt::Display;
+
+use super::Futoshiki;
+use crate::csp::{self, Csp, CspStats};
+
+/// [`FutoshikiSolution`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FutoshikiSolution {
+    pub grid: Vec<Vec<u8>>,
+    pub stats: CspStats,
+}
+
+impl Display for FutoshikiSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in &self.grid {
+            let row = row
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<String>>();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// [`build_csp`]
+/// ## Futoshiki as a CSP.
+/// One variable per cell (row by row), given cells have a single value. Every row and
+/// column is all-different and every inequality is a binary `<` constraint.
+pub fn build_csp(puzzle: &Futoshiki) -> Csp<u8> {
+    let size = puzzle.size();
+    let domains = (0..size * size)
+        .map(|index| match puzzle.given(index / size, index % size) {
+            Some(value) => vec![value],
+            None => (1..=size as u8).collect(),
+        })
+        .collect();
+    let mut csp = Csp::new(domains);
+    for line in 0..size {
+        let row = (0..size).map(|c| line * size + c).collect::<Vec<usize>>();
+        let column = (0..size).map(|r| r * size + line).collect::<Vec<usize>>();
+        csp.add_all_different(&row);
+        csp.add_all_different(&column);
+    }
+    for (a, b) in puzzle.less_than() {
+        csp.add_binary(a.0 * size + a.1, b.0 * size + b.1, |x, y| x < y);
+    }
+    csp
+}
+
+/// [`solve`]
+/// Solves the puzzle with the CSP of [`build_csp`]. Returns `None` if it has no solution.
+pub fn solve(puzzle: &Futoshiki) -> Option<FutoshikiSolution> {
+    let solution = csp::solve(&build_csp(puzzle))?;
+    Some(FutoshikiSolution {
+        grid: solution
+            .values
+            .chunks(puzzle.size())
+            .map(|row| row.to_vec())
+            .collect(),
+        stats: solution.stats,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn is_valid(puzzle: &Futoshiki, grid: &[Vec<u8>]) -> bool {
+        let size = puzzle.size();
+        let latin = (0..size).all(|i| {
+            let mut row = grid[i].clone();
+            let mut column = grid.iter().map(|row| row[i]).collect::<Vec<u8>>();
+            row.sort();
+            column.sort();
+            let expected = (1..=size as u8).collect::<Vec<u8>>();
+            row == expected && column == expected
+        });
+        let givens = (0..size * size).all(|i| {
+            puzzle
+                .given(i / size, i % size)
+                .is_none_or(|g| g == grid[i / size][i % size])
+        });
+        let inequalities = puzzle
+            .less_than()
+            .iter()
+            .all(|(a, b)| grid[a.0][a.1] < grid[b.0][b.1]);
+        latin && givens && inequalities
+    }
+
+    #[test]
+    fn futoshiki_solve_respects_inequalities() {
+        // the chain forces the first row to 1 2 3 4.
+        let puzzle = Futoshiki::try_from(".<.<.<.\n. . . .\n. . . .\n. . . .").unwrap();
+
+        let solution = solve(&puzzle).unwrap();
+
+        assert_eq!(solution.grid[0], vec![1, 2, 3, 4]);
+        assert!(is_valid(&puzzle, &solution.grid));
+    }
+
+    #[test]
+    fn futoshiki_solve_five_by_five_puzzle() {
+        let puzzle = Futoshiki::try_from(
+            ". . .>. .\n\
+             ^       v\n\
+             . .<4 . .\n\
+             . . . . 2\n\
+             v   ^    \n\
+             . . .<. .\n\
+             . 3 . .>.",
+        )
+        .unwrap();
+
+        let solution = solve(&puzzle).unwrap();
+
+        assert!(is_valid(&puzzle, &solution.grid));
+        assert_eq!(solution.to_string().lines().count(), 5);
+    }
+
+    #[test]
+    fn futoshiki_solve_returns_none_for_contradictions() {
+        let puzzle = Futoshiki::try_from("2<.\n. .").unwrap();
+
+        assert_eq!(solve(&puzzle), None);
+    }
+}
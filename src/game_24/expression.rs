@@ -0,0 +1,274 @@
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// [`Rational`]
+/// Exact fraction `numerator / denominator`, always reduced with a positive denominator,
+/// so intermediate results like `8 / 3` don't lose precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    /// `None` when `denominator` is zero or the reduced fraction doesn't fit in `i64`.
+    pub fn new(numerator: i64, denominator: i64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator.checked_abs()?, denominator.checked_abs()?).max(1);
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+        if denominator < 0 {
+            Some(Self {
+                numerator: numerator.checked_neg()?,
+                denominator: -denominator,
+            })
+        } else {
+            Some(Self {
+                numerator,
+                denominator,
+            })
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    /// `None` on overflow.
+    pub fn checked_add(self, other: Rational) -> Option<Rational> {
+        Rational::new(
+            self.numerator
+                .checked_mul(other.denominator)?
+                .checked_add(other.numerator.checked_mul(self.denominator)?)?,
+            self.denominator.checked_mul(other.denominator)?,
+        )
+    }
+
+    /// `None` on overflow.
+    pub fn checked_sub(self, other: Rational) -> Option<Rational> {
+        self.checked_add(Rational {
+            numerator: other.numerator.checked_neg()?,
+            denominator: other.denominator,
+        })
+    }
+
+    /// `None` on overflow.
+    pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+        Rational::new(
+            self.numerator.checked_mul(other.numerator)?,
+            self.denominator.checked_mul(other.denominator)?,
+        )
+    }
+
+    /// `None` when dividing by zero or on overflow.
+    pub fn checked_div(self, other: Rational) -> Option<Rational> {
+        Rational::new(
+            self.numerator.checked_mul(other.denominator)?,
+            self.denominator.checked_mul(other.numerator)?,
+        )
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+/// The operators panic on overflow, use the `checked_*` methods for untrusted values.
+impl Add for Rational {
+    type Output = Rational;
+    fn add(self, other: Rational) -> Rational {
+        self.checked_add(other).expect("rational overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+    fn sub(self, other: Rational) -> Rational {
+        self.checked_sub(other).expect("rational overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+    fn mul(self, other: Rational) -> Rational {
+        self.checked_mul(other).expect("rational overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+    /// Panics when dividing by zero, check [`Rational::is_zero`] first.
+    fn div(self, other: Rational) -> Rational {
+        self.checked_div(other)
+            .expect("division by zero or rational overflow")
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            d => write!(f, "{}/{}", self.numerator, d),
+        }
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// [`Operator`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator {
+    pub const ALL: [Operator; 4] = [Operator::Add, Operator::Sub, Operator::Mul, Operator::Div];
+
+    pub fn symbol(&self) -> char {
+        match self {
+            Operator::Add => '+',
+            Operator::Sub => '-',
+            Operator::Mul => '*',
+            Operator::Div => '/',
+        }
+    }
+
+    pub fn is_commutative(&self) -> bool {
+        matches!(self, Operator::Add | Operator::Mul)
+    }
+
+    /// `None` when dividing by zero or when the result overflows.
+    pub fn apply(&self, a: Rational, b: Rational) -> Option<Rational> {
+        match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Sub => a.checked_sub(b),
+            Operator::Mul => a.checked_mul(b),
+            Operator::Div => a.checked_div(b),
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Operator::Add | Operator::Sub => 1,
+            Operator::Mul | Operator::Div => 2,
+        }
+    }
+}
+
+/// [`Expression`]
+/// Binary expression tree over the input numbers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Expression {
+    Number(i64),
+    Binary(Box<Expression>, Operator, Box<Expression>),
+}
+
+impl Expression {
+    pub fn binary(left: Expression, operator: Operator, right: Expression) -> Self {
+        Expression::Binary(Box::new(left), operator, Box::new(right))
+    }
+
+    /// `None` if the expression divides by zero or overflows.
+    pub fn evaluate(&self) -> Option<Rational> {
+        match self {
+            Expression::Number(n) => Some(Rational::from(*n)),
+            Expression::Binary(left, operator, right) => {
+                operator.apply(left.evaluate()?, right.evaluate()?)
+            }
+        }
+    }
+
+    /// Numbers used by the expression, left to right.
+    pub fn numbers(&self) -> Vec<i64> {
+        match self {
+            Expression::Number(n) => vec![*n],
+            Expression::Binary(left, _, right) => [left.numbers(), right.numbers()].concat(),
+        }
+    }
+
+    fn fmt_child(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        parent: Operator,
+        right: bool,
+    ) -> std::fmt::Result {
+        let needs_parens = match self {
+            Expression::Number(_) => false,
+            Expression::Binary(_, operator, _) => {
+                operator.precedence() < parent.precedence()
+                    || (right
+                        && operator.precedence() == parent.precedence()
+                        && !parent.is_commutative())
+            }
+        };
+        if needs_parens {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+/// Infix notation with only the parentheses that are needed, e.g. `(8 - 4) * 6`.
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expression::Number(n) => write!(f, "{}", n),
+            Expression::Binary(left, operator, right) => {
+                left.fmt_child(f, *operator, false)?;
+                write!(f, " {} ", operator.symbol())?;
+                right.fmt_child(f, *operator, true)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Expression::Number;
+
+    #[test]
+    fn game_24_rational_arithmetic_is_exact() {
+        let third = Rational::new(1, 3).unwrap();
+
+        assert_eq!(third + third + third, Rational::from(1));
+        assert_eq!(
+            Rational::from(8) / (Rational::from(3) - Rational::from(8) / Rational::from(3)),
+            Rational::from(24)
+        );
+        assert_eq!(Rational::new(2, -4).unwrap().to_string(), "-1/2");
+        assert_eq!(Operator::Div.apply(third, Rational::from(0)), None);
+        let big = Rational::from(i64::MAX);
+        assert_eq!(Operator::Mul.apply(big, Rational::from(2)), None);
+        assert_eq!(Operator::Sub.apply(Rational::from(i64::MIN), big), None);
+        assert_eq!(Rational::new(i64::MIN, -1), None);
+    }
+
+    #[test]
+    fn game_24_expression_prints_minimal_parentheses() {
+        let difference = Expression::binary(Number(8), Operator::Sub, Number(4));
+        let product = Expression::binary(difference.clone(), Operator::Mul, Number(6));
+        let nested = Expression::binary(Number(1), Operator::Sub, difference);
+
+        assert_eq!(product.to_string(), "(8 - 4) * 6");
+        assert_eq!(nested.to_string(), "1 - (8 - 4)");
+        assert_eq!(product.evaluate(), Some(Rational::from(24)));
+        assert_eq!(product.numbers(), vec![8, 4, 6]);
+    }
+}
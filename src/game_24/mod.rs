@@ -0,0 +1,5 @@
+pub mod expression;
+pub mod solver;
+
+pub use expression::*;
+pub use solver::*;
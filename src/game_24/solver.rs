@@ -0,0 +1,134 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Expression, Operator, Rational};
+
+/// [`solve`]
+/// ## Every distinct expression combining all `numbers` into `target`.
+/// Repeatedly picks two of the remaining values and replaces them by `a op b` (for every
+/// operator, and both orders when it matters), until a single value is left: this covers
+/// every operator assignment and every parenthesization.
+/// Arithmetic is exact, and expressions that only differ by the order of the operands of
+/// `+` or `*`, or by how a chain of them is grouped, are reported once. Results are
+/// sorted by their printed form.
+pub fn solve(numbers: &[i64], target: i64) -> Vec<Expression> {
+    let values = numbers
+        .iter()
+        .map(|n| (Rational::from(*n), Expression::Number(*n)))
+        .collect::<Vec<(Rational, Expression)>>();
+    let mut found = HashSet::new();
+    search(values, Rational::from(target), &mut found);
+
+    // regrouping a chain of `+` or `*` prints the same expression, keep only one tree.
+    let mut distinct = found
+        .into_iter()
+        .map(|expression| (expression.to_string(), expression))
+        .collect::<HashMap<String, Expression>>()
+        .into_iter()
+        .collect::<Vec<(String, Expression)>>();
+    distinct.sort_by(|a, b| a.0.cmp(&b.0));
+    distinct
+        .into_iter()
+        .map(|(_, expression)| expression)
+        .collect()
+}
+
+/// [`solve_24`]
+pub fn solve_24(numbers: &[i64]) -> Vec<Expression> {
+    solve(numbers, 24)
+}
+
+fn search(values: Vec<(Rational, Expression)>, target: Rational, found: &mut HashSet<Expression>) {
+    if let [(value, expression)] = &values[..] {
+        if *value == target {
+            found.insert(expression.clone());
+        }
+        return;
+    }
+    for i in 0..values.len() {
+        for j in 0..values.len() {
+            if i == j {
+                continue;
+            }
+            let rest = values
+                .iter()
+                .enumerate()
+                .filter(|(k, _)| *k != i && *k != j)
+                .map(|(_, value)| value.clone())
+                .collect::<Vec<(Rational, Expression)>>();
+            let ((a, left), (b, right)) = (&values[i], &values[j]);
+            for operator in Operator::ALL {
+                // `a + b` and `b + a` are the same expression, only build it once.
+                if operator.is_commutative() && left > right {
+                    continue;
+                }
+                let Some(value) = operator.apply(*a, *b) else {
+                    continue;
+                };
+                let mut next = rest.clone();
+                next.push((
+                    value,
+                    Expression::binary(left.clone(), operator, right.clone()),
+                ));
+                search(next, target, found);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn game_24_solve_finds_fraction_solutions() {
+        // the only way is 8 / (3 - 8 / 3), which needs an exact 1/3.
+        let solutions = solve_24(&[3, 3, 8, 8]);
+
+        assert_eq!(
+            solutions
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<String>>(),
+            vec!["8 / (3 - 8 / 3)"]
+        );
+    }
+
+    #[test]
+    fn game_24_solve_returns_valid_distinct_expressions() {
+        let solutions = solve_24(&[1, 2, 3, 4]);
+
+        assert!(!solutions.is_empty());
+        for expression in &solutions {
+            assert_eq!(expression.evaluate(), Some(Rational::from(24)));
+            let mut numbers = expression.numbers();
+            numbers.sort();
+            assert_eq!(numbers, vec![1, 2, 3, 4]);
+        }
+        let printed = solutions
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<HashSet<String>>();
+        assert_eq!(printed.len(), solutions.len());
+    }
+
+    #[test]
+    fn game_24_solve_handles_unsolvable_and_other_targets() {
+        assert!(solve_24(&[1, 1, 1, 1]).is_empty());
+        assert_eq!(
+            solve(&[2, 5], 10)
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<String>>(),
+            vec!["2 * 5"]
+        );
+        // their product overflows `i64`, combinations that overflow are skipped.
+        let huge = i64::MAX / 2;
+        assert_eq!(
+            solve(&[huge, huge, 2], 1)
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<String>>(),
+            vec![format!("2 - {huge} / {huge}")]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use super::{Crossover, FitnessFn, Mutation, Selection};
+use crate::rng::Rng;
+
+/// [`GeneticAlgorithm`]
+/// ## Generational genetic algorithm.
+/// Every generation keeps its `elitism` fittest members unchanged and fills the rest of
+/// the next one with children of two selected parents (a copy of the first parent when
+/// no crossover happens), each mutated. Stops after `generations` generations or as
+/// soon as a member reaches `target_fitness`.
+/// # Example
+/// ```
+/// # use algoritmos_rust::genetic::*;
+/// # use algoritmos_rust::rng::Rng;
+/// // as many ones as possible.
+/// let fitness = |bits: &Vec<u8>| bits.iter().map(|bit| f64::from(*bit)).sum::<f64>();
+/// let algorithm = GeneticAlgorithm::new(
+///     Tournament::new(3),
+///     OnePoint,
+///     RandomGene::new(0.02, |rng: &mut Rng| rng.gen_range(0..2) as u8),
+/// );
+/// let random = |rng: &mut Rng| (0..20).map(|_| rng.gen_range(0..2) as u8).collect();
+/// let outcome = algorithm.run(&fitness, random, &mut Rng::new(0));
+/// assert_eq!(outcome.best_fitness, 20.0);
+/// assert!(outcome.generations.len() <= algorithm.generations);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GeneticAlgorithm<S, X, M> {
+    pub population_size: usize,
+    pub generations: usize,
+    pub elitism: usize,
+    /// Probability that a child is a crossover of its parents.
+    pub crossover_rate: f64,
+    pub target_fitness: Option<f64>,
+    pub selection: S,
+    pub crossover: X,
+    pub mutation: M,
+}
+
+impl<S, X, M> GeneticAlgorithm<S, X, M> {
+    /// 50 members, 100 generations, elitism of 1 and a crossover rate of 0.9, without a
+    /// target fitness.
+    pub fn new(selection: S, crossover: X, mutation: M) -> Self {
+        Self {
+            population_size: 50,
+            generations: 100,
+            elitism: 1,
+            crossover_rate: 0.9,
+            target_fitness: None,
+            selection,
+            crossover,
+            mutation,
+        }
+    }
+
+    /// [`run`]
+    /// Evolves a population of `population_size` chromosomes drawn with `random` (at
+    /// least 1), `rng` being the only source of randomness.
+    pub fn run<C, F, R>(&self, fitness: &F, mut random: R, rng: &mut Rng) -> GeneticOutcome<C>
+    where
+        S: Selection,
+        X: Crossover<C>,
+        M: Mutation<C>,
+        F: FitnessFn<C>,
+        R: FnMut(&mut Rng) -> C,
+        C: Clone,
+    {
+        let size = self.population_size.max(1);
+        let mut population = (0..size).map(|_| random(rng)).collect::<Vec<_>>();
+        let mut scores = population
+            .iter()
+            .map(|chromosome| fitness.fitness(chromosome))
+            .collect::<Vec<_>>();
+        let mut evaluations = size;
+        let mut generations = vec![GenerationStats::new(&scores)];
+
+        for _ in 1..self.generations.max(1) {
+            if self
+                .target_fitness
+                .is_some_and(|target| generations.last().is_some_and(|last| last.best >= target))
+            {
+                break;
+            }
+            let mut ranking = (0..size).collect::<Vec<_>>();
+            ranking.sort_by(|a, b| scores[*b].total_cmp(&scores[*a]));
+            let mut next = ranking
+                .iter()
+                .take(self.elitism.min(size))
+                .map(|i| population[*i].clone())
+                .collect::<Vec<_>>();
+            let mut next_scores = ranking
+                .iter()
+                .take(next.len())
+                .map(|i| scores[*i])
+                .collect::<Vec<_>>();
+            while next.len() < size {
+                let a = &population[self.selection.select(&scores, rng)];
+                let mut child = if rng.gen_bool(self.crossover_rate) {
+                    let b = &population[self.selection.select(&scores, rng)];
+                    self.crossover.crossover(a, b, rng)
+                } else {
+                    a.clone()
+                };
+                self.mutation.mutate(&mut child, rng);
+                next_scores.push(fitness.fitness(&child));
+                next.push(child);
+                evaluations += 1;
+            }
+            (population, scores) = (next, next_scores);
+            generations.push(GenerationStats::new(&scores));
+        }
+
+        let best = (0..size)
+            .max_by(|a, b| scores[*a].total_cmp(&scores[*b]))
+            .expect("the population is never empty");
+        GeneticOutcome {
+            best_fitness: scores[best],
+            best: population.swap_remove(best),
+            generations,
+            evaluations,
+        }
+    }
+}
+
+/// [`GenerationStats`]
+/// Fitness of the population of one generation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GenerationStats {
+    pub best: f64,
+    pub mean: f64,
+    pub worst: f64,
+}
+
+impl GenerationStats {
+    fn new(scores: &[f64]) -> Self {
+        Self {
+            best: scores.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            mean: scores.iter().sum::<f64>() / scores.len() as f64,
+            worst: scores.iter().copied().fold(f64::INFINITY, f64::min),
+        }
+    }
+}
+
+/// [`GeneticOutcome`]
+/// The fittest member of the last generation and the statistics of every generation.
+#[derive(Debug, Clone)]
+pub struct GeneticOutcome<C> {
+    pub best: C,
+    pub best_fitness: f64,
+    /// One entry per generation, the first one being the random population.
+    pub generations: Vec<GenerationStats>,
+    /// Number of times the fitness function was called.
+    pub evaluations: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::genetic::{RandomGene, Roulette, Uniform};
+
+    #[test]
+    fn genetic_algorithm_elitism_never_loses_the_best() {
+        // closest to 7 in every gene.
+        let fitness = |genes: &Vec<i32>| {
+            let distance = genes.iter().map(|gene| (gene - 7).abs()).sum::<i32>();
+            f64::from(42 - distance)
+        };
+        let mut algorithm = GeneticAlgorithm::new(
+            Roulette,
+            Uniform,
+            RandomGene::new(0.1, |rng: &mut Rng| rng.gen_range(0..10) as i32),
+        );
+        algorithm.generations = 30;
+        let random = |rng: &mut Rng| (0..6).map(|_| rng.gen_range(0..10) as i32).collect();
+
+        let outcome = algorithm.run(&fitness, random, &mut Rng::new(5));
+
+        assert_eq!(outcome.generations.len(), 30);
+        assert_eq!(outcome.evaluations, 50 + 29 * 49);
+        assert!(outcome
+            .generations
+            .windows(2)
+            .all(|pair| pair[1].best >= pair[0].best));
+        assert!(outcome
+            .generations
+            .iter()
+            .all(|g| g.worst <= g.mean && g.mean <= g.best));
+        assert_eq!(outcome.best_fitness, outcome.generations[29].best);
+        assert_eq!(fitness(&outcome.best), outcome.best_fitness);
+    }
+}
@@ -0,0 +1,65 @@
+pub mod algorithm;
+pub mod operators;
+pub mod selection;
+
+pub use algorithm::*;
+pub use operators::*;
+pub use selection::*;
+
+use crate::rng::Rng;
+
+/// [`Chromosome`]
+/// ## A candidate solution encoded as a sequence of genes.
+/// The generic operators of this module only look at the genes, so any encoding that
+/// can be rebuilt from its genes can evolve, `Vec`s of genes are chromosomes.
+pub trait Chromosome: Clone {
+    type Gene: Clone;
+
+    fn genes(&self) -> &[Self::Gene];
+
+    fn from_genes(genes: Vec<Self::Gene>) -> Self;
+}
+
+impl<G: Clone> Chromosome for Vec<G> {
+    type Gene = G;
+
+    fn genes(&self) -> &[G] {
+        self
+    }
+
+    fn from_genes(genes: Vec<G>) -> Self {
+        genes
+    }
+}
+
+/// [`FitnessFn`]
+/// Evaluates a chromosome, higher is better (unlike the costs of
+/// [`local_search`](crate::local_search)). [`Roulette`] selection needs non-negative
+/// fitness. Closures taking a chromosome are fitness functions.
+pub trait FitnessFn<C> {
+    fn fitness(&self, chromosome: &C) -> f64;
+}
+
+impl<C, F: Fn(&C) -> f64> FitnessFn<C> for F {
+    fn fitness(&self, chromosome: &C) -> f64 {
+        self(chromosome)
+    }
+}
+
+/// [`Crossover`]
+/// Combines two parents into a child.
+pub trait Crossover<C> {
+    fn crossover(&self, a: &C, b: &C, rng: &mut Rng) -> C;
+}
+
+/// [`Mutation`]
+/// Changes a chromosome in place.
+pub trait Mutation<C> {
+    fn mutate(&self, chromosome: &mut C, rng: &mut Rng);
+}
+
+/// [`Selection`]
+/// Picks a parent by its index in a population given the fitness of every member.
+pub trait Selection {
+    fn select(&self, fitness: &[f64], rng: &mut Rng) -> usize;
+}
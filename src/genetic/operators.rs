@@ -0,0 +1,97 @@
+use super::{Chromosome, Crossover, Mutation};
+use crate::rng::Rng;
+
+/// [`OnePoint`]
+/// One-point crossover: the genes of `a` up to a random cut, then those of `b`. The
+/// child is as long as `b` when the parents differ in length.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OnePoint;
+
+impl<C: Chromosome> Crossover<C> for OnePoint {
+    fn crossover(&self, a: &C, b: &C, rng: &mut Rng) -> C {
+        let (a, b) = (a.genes(), b.genes());
+        let cut = rng.gen_range(0..a.len().min(b.len()) + 1);
+        C::from_genes(a[..cut].iter().chain(&b[cut..]).cloned().collect())
+    }
+}
+
+/// [`Uniform`]
+/// Uniform crossover: every gene comes from either parent with the same probability,
+/// the child being as long as the shortest parent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Uniform;
+
+impl<C: Chromosome> Crossover<C> for Uniform {
+    fn crossover(&self, a: &C, b: &C, rng: &mut Rng) -> C {
+        let genes = a
+            .genes()
+            .iter()
+            .zip(b.genes())
+            .map(|(a, b)| if rng.gen_bool(0.5) { a } else { b }.clone())
+            .collect();
+        C::from_genes(genes)
+    }
+}
+
+/// [`RandomGene`]
+/// Replaces every gene, with probability `rate`, by one drawn with `gene`.
+#[derive(Debug, Clone, Copy)]
+pub struct RandomGene<F> {
+    pub rate: f64,
+    pub gene: F,
+}
+
+impl<F> RandomGene<F> {
+    pub fn new(rate: f64, gene: F) -> Self {
+        Self { rate, gene }
+    }
+}
+
+impl<C, F> Mutation<C> for RandomGene<F>
+where
+    C: Chromosome,
+    F: Fn(&mut Rng) -> C::Gene,
+{
+    fn mutate(&self, chromosome: &mut C, rng: &mut Rng) {
+        let genes = chromosome
+            .genes()
+            .iter()
+            .map(|gene| {
+                if rng.gen_bool(self.rate) {
+                    (self.gene)(rng)
+                } else {
+                    gene.clone()
+                }
+            })
+            .collect();
+        *chromosome = C::from_genes(genes);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn operators_mix_and_change_genes() {
+        let mut rng = Rng::new(3);
+        let (zeros, ones) = (vec![0; 8], vec![1; 8]);
+
+        for _ in 0..20 {
+            let child = OnePoint.crossover(&zeros, &ones, &mut rng);
+            assert_eq!(child.len(), 8);
+            assert!(
+                child.windows(2).all(|pair| pair[0] <= pair[1]),
+                "{:?}",
+                child
+            );
+            let child = Uniform.crossover(&zeros, &ones[..5].to_vec(), &mut rng);
+            assert_eq!(child.len(), 5);
+        }
+        let mut chromosome = zeros.clone();
+        RandomGene::new(0.0, |_: &mut Rng| 1).mutate(&mut chromosome, &mut rng);
+        assert_eq!(chromosome, zeros);
+        RandomGene::new(1.0, |_: &mut Rng| 1).mutate(&mut chromosome, &mut rng);
+        assert_eq!(chromosome, ones);
+    }
+}
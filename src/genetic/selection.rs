@@ -0,0 +1,61 @@
+use super::Selection;
+use crate::rng::Rng;
+
+/// [`Tournament`]
+/// Draws `size` members at random (with replacement) and picks the fittest, a size of 0
+/// counts as 1. The larger the tournament, the stronger the selection pressure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tournament {
+    pub size: usize,
+}
+
+impl Tournament {
+    pub fn new(size: usize) -> Self {
+        Self { size: size.max(1) }
+    }
+}
+
+impl Selection for Tournament {
+    fn select(&self, fitness: &[f64], rng: &mut Rng) -> usize {
+        (0..self.size.max(1))
+            .map(|_| rng.gen_range(0..fitness.len()))
+            .max_by(|a, b| fitness[*a].total_cmp(&fitness[*b]))
+            .expect("at least one member is drawn")
+    }
+}
+
+/// [`Roulette`]
+/// Fitness proportionate selection, negative fitness counts as 0. Picks uniformly when
+/// no member has a positive fitness.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Roulette;
+
+impl Selection for Roulette {
+    fn select(&self, fitness: &[f64], rng: &mut Rng) -> usize {
+        rng.choose_weighted(fitness)
+            .unwrap_or_else(|| rng.gen_range(0..fitness.len()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn selection_favors_the_fittest() {
+        let fitness = [1.0, 0.0, 8.0, -3.0];
+        let mut rng = Rng::new(11);
+        let mut tournament = [0; 4];
+        let mut roulette = [0; 4];
+
+        for _ in 0..1000 {
+            tournament[Tournament::new(3).select(&fitness, &mut rng)] += 1;
+            roulette[Roulette.select(&fitness, &mut rng)] += 1;
+        }
+
+        assert!(tournament[2] > tournament[0] && tournament[0] > tournament[3]);
+        assert_eq!((roulette[1], roulette[3]), (0, 0));
+        assert!(roulette[2] > 4 * roulette[0]);
+        assert!(Roulette.select(&[0.0, -1.0], &mut rng) < 2);
+    }
+}
@@ -0,0 +1,137 @@
+use super::{HanoiError, HanoiMove};
+
+/// [`frame_stewart_moves`]
+/// ## Number of moves of the Frame–Stewart strategy.
+/// With 3 pegs it is the classic `2^n - 1`. With more pegs:
+/// `FS(n, k) = min over t of 2 * FS(t, k) + FS(n - t, k - 1)`: move the `t` smallest
+/// discs aside using all pegs, the remaining `n - t` without the peg holding them, then
+/// the `t` discs back on top.
+/// Proven optimal for 4 pegs (Bousch, 2014) and conjectured optimal for more.
+pub fn frame_stewart_moves(discs: usize, pegs: usize) -> Result<u64, HanoiError> {
+    Ok(frame_stewart_table(discs, pegs)?[pegs][discs].0)
+}
+
+/// [`frame_stewart`]
+/// Moves of the Frame–Stewart strategy that take `discs` discs from peg `from` to `to`
+/// using `pegs` pegs.
+pub fn frame_stewart(
+    discs: usize,
+    pegs: usize,
+    from: usize,
+    to: usize,
+) -> Result<Vec<HanoiMove>, HanoiError> {
+    let table = frame_stewart_table(discs, pegs)?;
+    if let Some(peg) = [from, to].into_iter().find(|peg| *peg >= pegs) {
+        return Err(HanoiError::UnknownPeg(peg));
+    }
+    let mut moves = vec![];
+    let spare = (0..pegs)
+        .filter(|p| *p != from && *p != to)
+        .collect::<Vec<usize>>();
+    solve(&table, discs, from, to, &spare, &mut moves);
+    Ok(moves)
+}
+
+/// `table[k][n] = (FS(n, k), best split t)`, only meaningful for `k >= 3`.
+fn frame_stewart_table(discs: usize, pegs: usize) -> Result<Vec<Vec<(u64, usize)>>, HanoiError> {
+    if pegs < 3 {
+        return Err(HanoiError::NotEnoughPegs(pegs));
+    }
+    let mut table = vec![vec![(0, 0); discs + 1]; pegs + 1];
+    for n in 1..=discs {
+        table[3][n] = (2 * table[3][n - 1].0 + 1, n - 1);
+    }
+    for k in 4..=pegs {
+        for n in 1..=discs {
+            table[k][n] = (0..n)
+                .map(|t| (2 * table[k][t].0 + table[k - 1][n - t].0, t))
+                .min()
+                .expect("there is always at least one split");
+        }
+    }
+    Ok(table)
+}
+
+fn solve(
+    table: &[Vec<(u64, usize)>],
+    discs: usize,
+    from: usize,
+    to: usize,
+    spare: &[usize],
+    moves: &mut Vec<HanoiMove>,
+) {
+    match discs {
+        0 => {}
+        1 => moves.push(HanoiMove { from, to }),
+        _ => {
+            let pegs = spare.len() + 2;
+            // move the `t` smallest discs to a spare peg (the discs being moved are the
+            // smallest ones, the rest of the pegs are free for them).
+            let t = table[pegs][discs].1;
+            // `frame_stewart_table` checked there are at least 3 pegs, so a spare one.
+            let Some((aside, others)) = spare.split_first() else {
+                return;
+            };
+            let with_to = others.iter().copied().chain([to]).collect::<Vec<usize>>();
+            solve(table, t, from, *aside, &with_to, moves);
+            // the bigger discs can't use the peg holding the smaller ones.
+            solve(table, discs - t, from, to, others, moves);
+            let with_from = others.iter().copied().chain([from]).collect::<Vec<usize>>();
+            solve(table, t, *aside, to, &with_from, moves);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hanoi::{HanoiProblem, HanoiState};
+    use crate::search::a_star;
+
+    #[test]
+    fn hanoi_frame_stewart_moves_known_values() {
+        assert_eq!(frame_stewart_moves(10, 3).unwrap(), 1023);
+        let four_pegs = (0..=8)
+            .map(|n| frame_stewart_moves(n, 4).unwrap())
+            .collect::<Vec<u64>>();
+        assert_eq!(four_pegs, vec![0, 1, 3, 5, 9, 13, 17, 25, 33]);
+        assert_eq!(frame_stewart_moves(5, 5).unwrap(), 11);
+        assert_eq!(frame_stewart_moves(3, 2), Err(HanoiError::NotEnoughPegs(2)));
+        assert_eq!(frame_stewart(3, 2, 0, 1), Err(HanoiError::NotEnoughPegs(2)));
+        assert_eq!(frame_stewart(3, 3, 0, 3), Err(HanoiError::UnknownPeg(3)));
+    }
+
+    #[test]
+    fn hanoi_frame_stewart_moves_are_legal_and_complete() {
+        for (discs, pegs) in [(6, 3), (8, 4), (7, 5)] {
+            let moves = frame_stewart(discs, pegs, 0, pegs - 1).unwrap();
+
+            let end = moves
+                .iter()
+                .try_fold(HanoiState::stacked(discs, pegs, 0).unwrap(), |state, m| {
+                    state.apply(*m)
+                })
+                .unwrap();
+
+            assert!(end.is_stacked_on(pegs - 1));
+            assert_eq!(
+                moves.len() as u64,
+                frame_stewart_moves(discs, pegs).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn hanoi_a_star_matches_frame_stewart() {
+        for (discs, pegs) in [(4, 3), (5, 4), (4, 5)] {
+            let problem = HanoiProblem::new(discs, pegs).unwrap();
+
+            let solution = a_star(&problem, |s| problem.heuristic(s)).unwrap();
+
+            assert_eq!(
+                u64::from(solution.cost),
+                frame_stewart_moves(discs, pegs).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,18 @@
+pub mod frame_stewart;
+pub mod problem;
+pub mod state;
+
+pub use frame_stewart::*;
+pub use problem::*;
+pub use state::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum HanoiError {
+    #[error("Towers of Hanoi needs at least 3 pegs")]
+    NotEnoughPegs(usize),
+    #[error("Peg does not exist")]
+    UnknownPeg(usize),
+}
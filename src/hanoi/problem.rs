@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+
+use super::{HanoiError, HanoiMove, HanoiState};
+use crate::search::{HeuristicRegistry, SearchProblem, Successor};
+
+/// [`HanoiProblem`]
+/// Move every disc from peg `0` to the last peg, every move costs 1.
+/// Pegs other than the target are interchangeable, so states are canonicalized by
+/// renaming them.
+#[derive(Debug, Clone)]
+pub struct HanoiProblem {
+    pub start: HanoiState,
+}
+
+impl HanoiProblem {
+    /// `discs` discs stacked on peg `0`, an error with fewer than 3 pegs.
+    pub fn new(discs: usize, pegs: usize) -> Result<Self, HanoiError> {
+        Ok(Self {
+            start: HanoiState::stacked(discs, pegs, 0)?,
+        })
+    }
+
+    pub fn target(&self) -> usize {
+        self.start.pegs() - 1
+    }
+
+    /// Admissible heuristic: discs that are not on the target peg yet.
+    pub fn heuristic(&self, state: &HanoiState) -> f64 {
+        state.discs_off(self.target()) as f64
+    }
+
+    /// [`heuristics`]
+    /// `"discs_off"`, see [`HanoiProblem::heuristic`].
+    pub fn heuristics() -> HeuristicRegistry<Self> {
+        let mut registry = HeuristicRegistry::new();
+        registry.register("discs_off", |problem: &HanoiProblem| {
+            let problem = problem.clone();
+            Box::new(move |state| problem.heuristic(state))
+        });
+        registry
+    }
+}
+
+impl SearchProblem for HanoiProblem {
+    type State = HanoiState;
+    type Action = HanoiMove;
+    type Cost = u32;
+    type Successors<'a> = std::vec::IntoIter<Successor<HanoiState, HanoiMove>>;
+
+    fn initial_state(&self) -> HanoiState {
+        self.start.clone()
+    }
+
+    fn is_goal(&self, state: &HanoiState) -> bool {
+        state.is_stacked_on(self.target())
+    }
+
+    fn successors<'a>(&'a self, state: &'a HanoiState) -> Self::Successors<'a> {
+        state
+            .moves()
+            .into_iter()
+            .map(|(movement, state)| Successor::new(movement, state, 1))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn canonicalize<'a>(&self, state: &'a HanoiState) -> Cow<'a, HanoiState> {
+        Cow::Owned(state.relabel_pegs(&[self.target()]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::search::{a_star, bfs_with_closed_set};
+
+    #[test]
+    fn hanoi_canonicalize_keeps_optimal_length_with_fewer_states() {
+        let problem = HanoiProblem::new(4, 4).unwrap();
+        let mut discovered = HashSet::new();
+        let solution = bfs_with_closed_set(&problem, &mut discovered).unwrap();
+
+        assert_eq!(solution.len(), 9);
+        assert_eq!(a_star(&problem, |s| problem.heuristic(s)).unwrap().len(), 9);
+        // 4^4 states without the symmetry.
+        assert!(discovered.len() < 256 / 3, "{}", discovered.len());
+        assert_eq!(
+            problem.canonicalize(&problem.start).as_ref(),
+            &HanoiState::stacked(4, 4, 0).unwrap()
+        );
+        assert!(matches!(
+            HanoiProblem::new(4, 2),
+            Err(HanoiError::NotEnoughPegs(2))
+        ));
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::Display;
+
+use super::HanoiError;
+
+/// [`HanoiMove`]
+/// Moves the top disc of peg `from` to peg `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HanoiMove {
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Display for HanoiMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.from, self.to)
+    }
+}
+
+/// [`HanoiState`]
+/// ## Towers of Hanoi with any number of pegs.
+/// `discs[i]` is the peg of disc `i`, disc `0` being the smallest. Since a disc can only
+/// rest on bigger ones, knowing the peg of every disc is enough to know the stacks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HanoiState {
+    pegs: usize,
+    discs: Vec<usize>,
+}
+
+impl HanoiState {
+    /// Every disc on peg `peg`.
+    pub fn stacked(discs: usize, pegs: usize, peg: usize) -> Result<Self, HanoiError> {
+        if pegs < 3 {
+            return Err(HanoiError::NotEnoughPegs(pegs));
+        }
+        if peg >= pegs {
+            return Err(HanoiError::UnknownPeg(peg));
+        }
+        Ok(Self {
+            pegs,
+            discs: vec![peg; discs],
+        })
+    }
+
+    pub fn pegs(&self) -> usize {
+        self.pegs
+    }
+
+    pub fn discs(&self) -> &[usize] {
+        &self.discs
+    }
+
+    /// Smallest disc on `peg`.
+    pub fn top(&self, peg: usize) -> Option<usize> {
+        self.discs.iter().position(|p| *p == peg)
+    }
+
+    pub fn is_stacked_on(&self, peg: usize) -> bool {
+        self.discs.iter().all(|p| *p == peg)
+    }
+
+    /// Returns `None` if `from` is empty or its top disc is bigger than the top of `to`.
+    pub fn apply(&self, movement: HanoiMove) -> Option<HanoiState> {
+        if movement.to >= self.pegs || movement.from == movement.to {
+            return None;
+        }
+        let disc = self.top(movement.from)?;
+        if self.top(movement.to).is_some_and(|top| top < disc) {
+            return None;
+        }
+        let mut state = self.clone();
+        state.discs[disc] = movement.to;
+        Some(state)
+    }
+
+    pub fn moves(&self) -> Vec<(HanoiMove, HanoiState)> {
+        (0..self.pegs)
+            .flat_map(|from| (0..self.pegs).map(move |to| HanoiMove { from, to }))
+            .filter_map(|movement| self.apply(movement).map(|state| (movement, state)))
+            .collect()
+    }
+
+    /// Renames every peg but the `fixed` ones in order of first use from the smallest
+    /// disc, so that states differing only by a permutation of those pegs are equal.
+    pub fn relabel_pegs(&self, fixed: &[usize]) -> HanoiState {
+        let mut spare = (0..self.pegs).filter(|peg| !fixed.contains(peg));
+        let mut labels: Vec<Option<usize>> = (0..self.pegs)
+            .map(|peg| fixed.contains(&peg).then_some(peg))
+            .collect();
+        let discs = self
+            .discs
+            .iter()
+            .map(|peg| *labels[*peg].get_or_insert_with(|| spare.next().unwrap()))
+            .collect();
+        HanoiState {
+            pegs: self.pegs,
+            discs,
+        }
+    }
+
+    /// Discs not on `peg`, each one still needs at least one move.
+    pub fn discs_off(&self, peg: usize) -> usize {
+        self.discs.iter().filter(|p| **p != peg).count()
+    }
+}
+
+/// One line per peg with its discs from bottom to top, e.g. `0: 3 2 1`.
+impl Display for HanoiState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for peg in 0..self.pegs {
+            let stack = (0..self.discs.len())
+                .rev()
+                .filter(|disc| self.discs[*disc] == peg)
+                .map(|disc| (disc + 1).to_string())
+                .collect::<Vec<String>>();
+            writeln!(f, "{}: {}", peg, stack.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hanoi_only_smaller_discs_go_on_top() {
+        let state = HanoiState::stacked(3, 4, 0).unwrap();
+        let state = state.apply(HanoiMove { from: 0, to: 1 }).unwrap();
+
+        assert_eq!(state.top(0), Some(1));
+        assert_eq!(state.apply(HanoiMove { from: 0, to: 1 }), None);
+        assert_eq!(state.apply(HanoiMove { from: 2, to: 1 }), None);
+        // the top of peg 0 can go to the two empty pegs, the smallest disc anywhere.
+        assert_eq!(state.moves().len(), 5);
+        assert_eq!(state.to_string(), "0: 3 2\n1: 1\n2: \n3: \n");
+        // with pegs 0 and 3 fixed, pegs 1 and 2 are interchangeable.
+        let other = HanoiState::stacked(3, 4, 0)
+            .unwrap()
+            .apply(HanoiMove { from: 0, to: 2 })
+            .unwrap();
+        assert_ne!(state, other);
+        assert_eq!(state.relabel_pegs(&[0, 3]), other.relabel_pegs(&[0, 3]));
+        assert_eq!(
+            HanoiState::stacked(3, 2, 0),
+            Err(HanoiError::NotEnoughPegs(2))
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use super::{JobShopInstance, Schedule};
+
+/// [`ExactSolution`]
+/// Optimal schedule found by [`branch_and_bound`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExactSolution {
+    /// Operation sequence (job repetition encoding) that produces `schedule`.
+    pub sequence: Vec<usize>,
+    pub schedule: Schedule,
+    /// Number of nodes of the search tree that were explored.
+    pub nodes_explored: usize,
+}
+
+/// [`branch_and_bound`]
+/// ## Exact job-shop solver for tiny instances.
+/// Explores active schedules with the Giffler & Thompson branching rule: at every node, the
+/// operation that can finish first fixes a machine, and the tree branches on every
+/// operation of that machine that could start before that time.
+/// Nodes are pruned when the lower bound (the largest of "machine ready + remaining work
+/// on the machine" and "job ready + remaining work of the job") can't beat the incumbent.
+/// The exploration is exponential, only use it on instances with a handful of jobs.
+pub fn branch_and_bound(instance: &JobShopInstance) -> ExactSolution {
+    let incumbent_sequence = instance.round_robin_sequence();
+    let incumbent = instance
+        .decode(&incumbent_sequence)
+        .expect("round robin sequence is always valid");
+
+    let mut search = Search {
+        instance,
+        best_makespan: incumbent.makespan,
+        best_sequence: incumbent_sequence,
+        nodes_explored: 0,
+    };
+    let mut node = Node {
+        next_operation: vec![0; instance.jobs().len()],
+        job_ready: vec![0; instance.jobs().len()],
+        machine_ready: vec![0; instance.machines()],
+        sequence: Vec::with_capacity(instance.operation_count()),
+    };
+    search.explore(&mut node);
+
+    let schedule = instance
+        .decode(&search.best_sequence)
+        .expect("best sequence is always valid");
+    ExactSolution {
+        sequence: search.best_sequence,
+        schedule,
+        nodes_explored: search.nodes_explored,
+    }
+}
+
+struct Node {
+    next_operation: Vec<usize>,
+    job_ready: Vec<u32>,
+    machine_ready: Vec<u32>,
+    sequence: Vec<usize>,
+}
+
+struct Search<'a> {
+    instance: &'a JobShopInstance,
+    best_makespan: u32,
+    best_sequence: Vec<usize>,
+    nodes_explored: usize,
+}
+
+impl Search<'_> {
+    fn explore(&mut self, node: &mut Node) {
+        self.nodes_explored += 1;
+        let jobs = self.instance.jobs();
+
+        if node.sequence.len() == self.instance.operation_count() {
+            let makespan = node.job_ready.iter().copied().max().unwrap_or(0);
+            if makespan < self.best_makespan {
+                self.best_makespan = makespan;
+                self.best_sequence = node.sequence.clone();
+            }
+            return;
+        }
+        if self.lower_bound(node) >= self.best_makespan {
+            return;
+        }
+
+        // earliest start of the next operation of each unfinished job.
+        let schedulable = (0..jobs.len())
+            .filter(|j| node.next_operation[*j] < jobs[*j].len())
+            .map(|j| {
+                let operation = jobs[j][node.next_operation[j]];
+                let start = node.job_ready[j].max(node.machine_ready[operation.machine]);
+                (j, operation, start)
+            })
+            .collect::<Vec<_>>();
+        let (_, first_operation, first_start) = *schedulable
+            .iter()
+            .min_by_key(|(_, operation, start)| start + operation.duration)
+            .expect("there is at least one unfinished job");
+        let earliest_completion = first_start + first_operation.duration;
+
+        let conflict_set = schedulable
+            .iter()
+            .filter(|(_, operation, start)| {
+                operation.machine == first_operation.machine && *start < earliest_completion
+            })
+            .copied()
+            .collect::<Vec<_>>();
+
+        for (job, operation, start) in conflict_set {
+            let previous_job_ready = node.job_ready[job];
+            let previous_machine_ready = node.machine_ready[operation.machine];
+
+            node.job_ready[job] = start + operation.duration;
+            node.machine_ready[operation.machine] = start + operation.duration;
+            node.next_operation[job] += 1;
+            node.sequence.push(job);
+
+            self.explore(node);
+
+            node.sequence.pop();
+            node.next_operation[job] -= 1;
+            node.machine_ready[operation.machine] = previous_machine_ready;
+            node.job_ready[job] = previous_job_ready;
+        }
+    }
+
+    fn lower_bound(&self, node: &Node) -> u32 {
+        let jobs = self.instance.jobs();
+        let mut machine_bound = node.machine_ready.clone();
+        let mut job_bound = 0;
+        for (j, job) in jobs.iter().enumerate() {
+            let remaining = &job[node.next_operation[j]..];
+            job_bound = job_bound
+                .max(node.job_ready[j] + remaining.iter().map(|op| op.duration).sum::<u32>());
+            for operation in remaining {
+                machine_bound[operation.machine] += operation.duration;
+            }
+        }
+        machine_bound.into_iter().max().unwrap_or(0).max(job_bound)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SMALL_INSTANCE: &str = "3 3
+        0 3 1 2 2 2
+        0 2 2 1 1 4
+        1 4 2 3";
+
+    fn brute_force(
+        instance: &JobShopInstance,
+        remaining: &mut Vec<usize>,
+        sequence: &mut Vec<usize>,
+    ) -> u32 {
+        if remaining.iter().all(|r| *r == 0) {
+            return instance.decode(sequence).unwrap().makespan;
+        }
+        let mut best = u32::MAX;
+        for job in 0..remaining.len() {
+            if remaining[job] == 0 {
+                continue;
+            }
+            remaining[job] -= 1;
+            sequence.push(job);
+            best = best.min(brute_force(instance, remaining, sequence));
+            sequence.pop();
+            remaining[job] += 1;
+        }
+        best
+    }
+
+    #[test]
+    fn job_shop_branch_and_bound_finds_optimal_makespan() {
+        let instance = JobShopInstance::try_from(SMALL_INSTANCE).unwrap();
+        let mut remaining = instance.jobs().iter().map(|job| job.len()).collect();
+
+        let solution = branch_and_bound(&instance);
+
+        assert_eq!(solution.schedule.makespan, 11);
+        assert_eq!(
+            solution.schedule.makespan,
+            brute_force(&instance, &mut remaining, &mut vec![])
+        );
+        assert_eq!(instance.decode(&solution.sequence), Some(solution.schedule));
+    }
+
+    #[test]
+    fn job_shop_branch_and_bound_solves_single_machine_without_idle_time() {
+        let instance = JobShopInstance::try_from("3 1\n0 4\n0 2\n0 3").unwrap();
+
+        let solution = branch_and_bound(&instance);
+
+        assert_eq!(solution.schedule.makespan, 9);
+    }
+}
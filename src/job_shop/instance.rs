@@ -0,0 +1,223 @@
+use super::JobShopError;
+
+/// [`Operation`]
+/// A step of a job: it must run on `machine` for `duration` time units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Operation {
+    pub machine: usize,
+    pub duration: u32,
+}
+
+impl Operation {
+    pub fn new(machine: usize, duration: u32) -> Self {
+        Self { machine, duration }
+    }
+}
+
+/// [`JobShopInstance`]
+/// ## Job-shop scheduling instance.
+/// Every job is an ordered list of operations, an operation can only start after the
+/// previous operation of the same job finished, and a machine runs one operation at a time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobShopInstance {
+    jobs: Vec<Vec<Operation>>,
+    machines: usize,
+}
+
+impl JobShopInstance {
+    pub fn new(jobs: Vec<Vec<Operation>>, machines: usize) -> Result<Self, JobShopError> {
+        if jobs.is_empty() || jobs.iter().any(|job| job.is_empty()) {
+            return Err(JobShopError::EmptyJob);
+        }
+        if let Some(operation) = jobs.iter().flatten().find(|op| op.machine >= machines) {
+            return Err(JobShopError::UnknownMachine(operation.machine));
+        }
+        Ok(Self { jobs, machines })
+    }
+
+    pub fn jobs(&self) -> &[Vec<Operation>] {
+        &self.jobs
+    }
+
+    pub fn machines(&self) -> usize {
+        self.machines
+    }
+
+    pub fn operation_count(&self) -> usize {
+        self.jobs.iter().map(|job| job.len()).sum()
+    }
+
+    /// [`decode`]
+    /// ## Builds the semi-active schedule of an operation sequence.
+    /// `sequence` is a "job repetition" encoding: job `j` appears once for each of its
+    /// operations, and its `k`-th occurrence means "schedule the `k`-th operation of job `j`".
+    /// Every operation starts as soon as both its job and its machine are free.
+    /// Returns `None` if `sequence` is not a valid encoding.
+    pub fn decode(&self, sequence: &[usize]) -> Option<Schedule> {
+        let mut next_operation = vec![0; self.jobs.len()];
+        let mut job_ready = vec![0u32; self.jobs.len()];
+        let mut machine_ready = vec![0u32; self.machines];
+        let mut start_times = self
+            .jobs
+            .iter()
+            .map(|job| vec![0u32; job.len()])
+            .collect::<Vec<Vec<u32>>>();
+
+        for job in sequence {
+            let operation = *self.jobs.get(*job)?.get(next_operation[*job])?;
+            let start = job_ready[*job].max(machine_ready[operation.machine]);
+            let end = start + operation.duration;
+            start_times[*job][next_operation[*job]] = start;
+            job_ready[*job] = end;
+            machine_ready[operation.machine] = end;
+            next_operation[*job] += 1;
+        }
+
+        let complete = next_operation
+            .iter()
+            .zip(&self.jobs)
+            .all(|(next, job)| *next == job.len());
+        if !complete {
+            return None;
+        }
+        Some(Schedule {
+            start_times,
+            makespan: job_ready.into_iter().max().unwrap_or(0),
+        })
+    }
+
+    /// Sequence that schedules the jobs round robin, a valid (but usually poor) solution.
+    pub fn round_robin_sequence(&self) -> Vec<usize> {
+        let longest = self.jobs.iter().map(|job| job.len()).max().unwrap_or(0);
+        (0..longest)
+            .flat_map(|k| {
+                self.jobs
+                    .iter()
+                    .enumerate()
+                    .filter(move |(_, job)| job.len() > k)
+                    .map(|(j, _)| j)
+            })
+            .collect()
+    }
+}
+
+/// [TryFrom<&str>]
+/// Accepts the standard OR-Library format:
+/// first line `n_jobs n_machines`, then one line per job with `machine duration` pairs.
+/// # Example:
+/// ```text
+/// 2 2
+/// 0 3 1 2
+/// 1 4 0 1
+/// ```
+impl TryFrom<&str> for JobShopInstance {
+    type Error = JobShopError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parse_error = |message: &str| JobShopError::ParseFromStringError(message.into());
+        let mut lines = value
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+        let header = lines
+            .next()
+            .ok_or_else(|| parse_error("Missing header line"))?
+            .split_whitespace()
+            .map(|n| n.parse::<usize>())
+            .collect::<Result<Vec<usize>, _>>()
+            .map_err(|_| parse_error("Invalid header line"))?;
+        let (n_jobs, machines) = match header[..] {
+            [n_jobs, machines] => (n_jobs, machines),
+            _ => {
+                return Err(parse_error(
+                    "Header must contain number of jobs and machines",
+                ))
+            }
+        };
+
+        let jobs = lines
+            .take(n_jobs)
+            .map(|line| {
+                let numbers = line
+                    .split_whitespace()
+                    .map(|n| n.parse::<u32>())
+                    .collect::<Result<Vec<u32>, _>>()
+                    .map_err(|_| parse_error("Invalid operation"))?;
+                if numbers.len() % 2 != 0 {
+                    return Err(parse_error("Operations must be machine duration pairs"));
+                }
+                Ok(numbers
+                    .chunks(2)
+                    .map(|pair| Operation::new(pair[0] as usize, pair[1]))
+                    .collect())
+            })
+            .collect::<Result<Vec<Vec<Operation>>, JobShopError>>()?;
+        if jobs.len() != n_jobs {
+            return Err(parse_error("Missing job lines"));
+        }
+
+        JobShopInstance::new(jobs, machines)
+    }
+}
+
+/// [`Schedule`]
+/// Start time of every operation (`start_times[job][operation]`) and the makespan, the
+/// time at which the last operation finishes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schedule {
+    pub start_times: Vec<Vec<u32>>,
+    pub makespan: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SMALL_INSTANCE: &str = "3 3
+        0 3 1 2 2 2
+        0 2 2 1 1 4
+        1 4 2 3";
+
+    #[test]
+    fn job_shop_tryfrom_str_works_as_expected() {
+        let instance = JobShopInstance::try_from(SMALL_INSTANCE).unwrap();
+
+        assert_eq!(instance.jobs().len(), 3);
+        assert_eq!(instance.machines(), 3);
+        assert_eq!(instance.operation_count(), 8);
+        assert_eq!(instance.jobs()[2][1], Operation::new(2, 3));
+    }
+
+    #[test]
+    fn job_shop_tryfrom_str_returns_error_when_format_is_invalid() {
+        assert!(JobShopInstance::try_from("").is_err());
+        assert!(JobShopInstance::try_from("1 1\n0").is_err());
+        assert!(JobShopInstance::try_from("2 1\n0 1").is_err());
+        assert_eq!(
+            JobShopInstance::try_from("1 1\n3 1"),
+            Err(JobShopError::UnknownMachine(3))
+        );
+    }
+
+    #[test]
+    fn job_shop_decode_builds_semi_active_schedule() {
+        let instance = JobShopInstance::try_from(SMALL_INSTANCE).unwrap();
+
+        let schedule = instance.decode(&[0, 1, 2, 0, 1, 2, 0, 1]).unwrap();
+
+        assert_eq!(schedule.start_times[0], vec![0, 4, 9]);
+        assert_eq!(schedule.start_times[1], vec![3, 5, 6]);
+        assert_eq!(schedule.start_times[2], vec![0, 6]);
+        assert_eq!(schedule.makespan, 11);
+    }
+
+    #[test]
+    fn job_shop_decode_returns_none_for_invalid_sequences() {
+        let instance = JobShopInstance::try_from(SMALL_INSTANCE).unwrap();
+
+        assert_eq!(instance.decode(&[0, 1, 2]), None);
+        assert_eq!(instance.decode(&[0, 0, 0, 0, 1, 1, 1, 2, 2]), None);
+        assert!(instance.decode(&instance.round_robin_sequence()).is_some());
+    }
+}
@@ -0,0 +1,20 @@
+pub mod branch_and_bound;
+pub mod instance;
+pub mod neighborhood;
+
+pub use branch_and_bound::*;
+pub use instance::*;
+pub use neighborhood::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum JobShopError {
+    #[error("Every job must have at least one operation")]
+    EmptyJob,
+    #[error("Operation uses a machine that does not exist")]
+    UnknownMachine(usize),
+    #[error("Error when trying to parse from JobShopInstance string")]
+    ParseFromStringError(String),
+}
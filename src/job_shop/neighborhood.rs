@@ -0,0 +1,139 @@
+use super::JobShopInstance;
+use crate::local_search::LocalSearchProblem;
+use crate::rng::Rng;
+
+/// [`Neighborhood`]
+/// Moves applied to the operation sequence (see [`JobShopInstance::decode`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// Swap two adjacent positions of different jobs.
+    AdjacentSwap,
+    /// Swap any two positions of different jobs.
+    Swap,
+    /// Remove an element and insert it at another position.
+    Insertion,
+}
+
+/// [`JobShopLocalSearch`]
+/// Exposes a [`JobShopInstance`] as a [`LocalSearchProblem`] minimizing the makespan.
+#[derive(Debug, Clone)]
+pub struct JobShopLocalSearch<'a> {
+    pub instance: &'a JobShopInstance,
+    pub neighborhood: Neighborhood,
+}
+
+impl<'a> JobShopLocalSearch<'a> {
+    pub fn new(instance: &'a JobShopInstance, neighborhood: Neighborhood) -> Self {
+        Self {
+            instance,
+            neighborhood,
+        }
+    }
+}
+
+impl LocalSearchProblem for JobShopLocalSearch<'_> {
+    type State = Vec<usize>;
+
+    fn random_state(&self, rng: &mut Rng) -> Vec<usize> {
+        let mut sequence = self.instance.round_robin_sequence();
+        rng.shuffle(&mut sequence);
+        sequence
+    }
+
+    fn neighbors(&self, state: &Vec<usize>) -> Vec<Vec<usize>> {
+        let n = state.len();
+        let mut neighbors = vec![];
+        match self.neighborhood {
+            Neighborhood::AdjacentSwap => {
+                for i in 0..n.saturating_sub(1) {
+                    if state[i] != state[i + 1] {
+                        let mut neighbor = state.clone();
+                        neighbor.swap(i, i + 1);
+                        neighbors.push(neighbor);
+                    }
+                }
+            }
+            Neighborhood::Swap => {
+                for i in 0..n {
+                    for j in i + 1..n {
+                        if state[i] != state[j] {
+                            let mut neighbor = state.clone();
+                            neighbor.swap(i, j);
+                            neighbors.push(neighbor);
+                        }
+                    }
+                }
+            }
+            Neighborhood::Insertion => {
+                for from in 0..n {
+                    for to in 0..n {
+                        // moving to the previous position is the same as moving the
+                        // previous element to the next position, skip the duplicate.
+                        if from == to || to + 1 == from {
+                            continue;
+                        }
+                        let mut neighbor = state.clone();
+                        let job = neighbor.remove(from);
+                        neighbor.insert(to, job);
+                        if neighbor != *state {
+                            neighbors.push(neighbor);
+                        }
+                    }
+                }
+            }
+        }
+        neighbors
+    }
+
+    fn cost(&self, state: &Vec<usize>) -> f64 {
+        self.instance
+            .decode(state)
+            .map(|schedule| f64::from(schedule.makespan))
+            .unwrap_or(f64::INFINITY)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::local_search::landscape::basin_sizes;
+
+    const SMALL_INSTANCE: &str = "3 3
+        0 3 1 2 2 2
+        0 2 2 1 1 4
+        1 4 2 3";
+
+    #[test]
+    fn job_shop_neighbors_are_valid_sequences() {
+        let instance = JobShopInstance::try_from(SMALL_INSTANCE).unwrap();
+        let start = instance.round_robin_sequence();
+
+        for neighborhood in [
+            Neighborhood::AdjacentSwap,
+            Neighborhood::Swap,
+            Neighborhood::Insertion,
+        ] {
+            let problem = JobShopLocalSearch::new(&instance, neighborhood);
+            let neighbors = problem.neighbors(&start);
+            assert!(!neighbors.is_empty());
+            for neighbor in neighbors {
+                assert_ne!(neighbor, start);
+                assert!(problem.cost(&neighbor).is_finite());
+            }
+        }
+    }
+
+    #[test]
+    fn job_shop_descent_reaches_optimal_makespan() {
+        let instance = JobShopInstance::try_from(SMALL_INSTANCE).unwrap();
+        let problem = JobShopLocalSearch::new(&instance, Neighborhood::Insertion);
+
+        let basins = basin_sizes(&problem, 30, &mut Rng::new(3));
+        let best = basins
+            .iter()
+            .map(|basin| basin.cost)
+            .fold(f64::INFINITY, f64::min);
+
+        assert_eq!(best, 11.0);
+    }
+}
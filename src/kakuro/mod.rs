@@ -0,0 +1,20 @@
+pub mod partitions;
+pub mod puzzle;
+pub mod solver;
+
+pub use partitions::*;
+pub use puzzle::*;
+pub use solver::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum KakuroError {
+    #[error("Every row of the puzzle must have the same number of cells")]
+    InvalidShape,
+    #[error("Every run of white cells must have a clue")]
+    MissingClue(usize, usize),
+    #[error("Error when trying to parse from Kakuro string")]
+    ParseFromStringError(String),
+}
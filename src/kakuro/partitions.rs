@@ -0,0 +1,43 @@
+/// [`Digits`]
+/// Set of digits `1..=9`, bit `d` is set when digit `d` is in the set.
+pub type Digits = u16;
+
+pub const ALL_DIGITS: Digits = 0b11_1111_1110;
+
+/// [`sum_partitions`]
+/// ## Sum-partition table.
+/// Every set of `length` distinct digits adding up to `sum`, e.g. a run of 2 cells with
+/// sum 4 can only be `{1, 3}`.
+pub fn sum_partitions(length: usize, sum: u32) -> Vec<Digits> {
+    (0..1u16 << 9)
+        .map(|subset| subset << 1)
+        .filter(|digits| digits.count_ones() as usize == length && digits_sum(*digits) == sum)
+        .collect()
+}
+
+/// Digits that appear in at least one partition of `sum` in `length` cells.
+pub fn possible_digits(length: usize, sum: u32) -> Digits {
+    sum_partitions(length, sum)
+        .into_iter()
+        .fold(0, |union, digits| union | digits)
+}
+
+pub fn digits_sum(digits: Digits) -> u32 {
+    (1..=9).filter(|d| digits & (1 << d) != 0).sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn kakuro_sum_partitions_known_values() {
+        assert_eq!(sum_partitions(2, 4), vec![0b1010]);
+        assert_eq!(sum_partitions(2, 3), vec![0b110]);
+        assert_eq!(sum_partitions(9, 45), vec![ALL_DIGITS]);
+        assert!(sum_partitions(2, 18).is_empty());
+        assert_eq!(sum_partitions(3, 10).len(), 4);
+        // 16 in two cells is always 7 + 9.
+        assert_eq!(possible_digits(2, 16), (1 << 7) | (1 << 9));
+    }
+}
@@ -0,0 +1,198 @@
+use super::KakuroError;
+
+/// [`KakuroCell`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KakuroCell {
+    /// A black cell, optionally with the sums of the run below it and of the run to its
+    /// right.
+    Block {
+        down: Option<u32>,
+        across: Option<u32>,
+    },
+    White,
+}
+
+/// [`Run`]
+/// Consecutive white cells that must hold distinct digits adding up to `sum`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub sum: u32,
+    pub cells: Vec<(usize, usize)>,
+}
+
+/// [`Kakuro`]
+/// ## Kakuro puzzle.
+/// Fill every white cell with a digit `1..=9` so that every run adds up to its clue
+/// without repeating digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Kakuro {
+    rows: usize,
+    columns: usize,
+    cells: Vec<KakuroCell>,
+    runs: Vec<Run>,
+}
+
+impl Kakuro {
+    pub fn new(rows: Vec<Vec<KakuroCell>>) -> Result<Self, KakuroError> {
+        let columns = rows.first().map(|row| row.len()).unwrap_or(0);
+        if columns == 0 || rows.iter().any(|row| row.len() != columns) {
+            return Err(KakuroError::InvalidShape);
+        }
+        let mut kakuro = Self {
+            rows: rows.len(),
+            columns,
+            cells: rows.into_iter().flatten().collect(),
+            runs: vec![],
+        };
+        kakuro.runs = kakuro.find_runs()?;
+        Ok(kakuro)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> KakuroCell {
+        self.cells[row * self.columns + column]
+    }
+
+    pub fn runs(&self) -> &[Run] {
+        &self.runs
+    }
+
+    /// White cells in reading order.
+    pub fn white_cells(&self) -> Vec<(usize, usize)> {
+        (0..self.rows)
+            .flat_map(|r| (0..self.columns).map(move |c| (r, c)))
+            .filter(|(r, c)| self.get(*r, *c) == KakuroCell::White)
+            .collect()
+    }
+
+    fn find_runs(&self) -> Result<Vec<Run>, KakuroError> {
+        let mut runs = vec![];
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                if self.get(row, column) != KakuroCell::White {
+                    continue;
+                }
+                let starts_across = column == 0 || self.get(row, column - 1) != KakuroCell::White;
+                let starts_down = row == 0 || self.get(row - 1, column) != KakuroCell::White;
+                if starts_across {
+                    let cells = (column..self.columns)
+                        .take_while(|c| self.get(row, *c) == KakuroCell::White)
+                        .map(|c| (row, c))
+                        .collect();
+                    let clue = column.checked_sub(1).and_then(|c| match self.get(row, c) {
+                        KakuroCell::Block { across, .. } => across,
+                        KakuroCell::White => None,
+                    });
+                    let sum = clue.ok_or(KakuroError::MissingClue(row, column))?;
+                    runs.push(Run { sum, cells });
+                }
+                if starts_down {
+                    let cells = (row..self.rows)
+                        .take_while(|r| self.get(*r, column) == KakuroCell::White)
+                        .map(|r| (r, column))
+                        .collect();
+                    let clue = row.checked_sub(1).and_then(|r| match self.get(r, column) {
+                        KakuroCell::Block { down, .. } => down,
+                        KakuroCell::White => None,
+                    });
+                    let sum = clue.ok_or(KakuroError::MissingClue(row, column))?;
+                    runs.push(Run { sum, cells });
+                }
+            }
+        }
+        Ok(runs)
+    }
+}
+
+/// [TryFrom<&str>]
+/// One line per row with cells separated by spaces: `.` is a white cell, `#` a black
+/// cell without clues and `down\across` a black cell with clues, either side may be
+/// empty (e.g. `16\`, `\7`, `12\5`).
+impl TryFrom<&str> for Kakuro {
+    type Error = KakuroError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parse_error = |message: String| KakuroError::ParseFromStringError(message);
+        let clue = |text: &str| match text {
+            "" => Ok(None),
+            _ => text
+                .parse::<u32>()
+                .map(Some)
+                .map_err(|_| parse_error(format!("Invalid clue {}", text))),
+        };
+        let rows = value
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|cell| match cell {
+                        "." => Ok(KakuroCell::White),
+                        "#" => Ok(KakuroCell::Block {
+                            down: None,
+                            across: None,
+                        }),
+                        _ => match cell.split_once('\\') {
+                            Some((down, across)) => Ok(KakuroCell::Block {
+                                down: clue(down)?,
+                                across: clue(across)?,
+                            }),
+                            None => Err(parse_error(format!("Invalid cell {}", cell))),
+                        },
+                    })
+                    .collect::<Result<Vec<KakuroCell>, _>>()
+            })
+            .collect::<Result<Vec<Vec<KakuroCell>>, _>>()?;
+        Kakuro::new(rows)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn kakuro_try_from_finds_runs() {
+        let kakuro = Kakuro::try_from(
+            "#   4\\ 11\\
+             \\3 .   .
+             \\12 .  .",
+        )
+        .unwrap();
+
+        assert_eq!(kakuro.white_cells().len(), 4);
+        assert_eq!(
+            kakuro.runs(),
+            &[
+                Run {
+                    sum: 3,
+                    cells: vec![(1, 1), (1, 2)]
+                },
+                Run {
+                    sum: 4,
+                    cells: vec![(1, 1), (2, 1)]
+                },
+                Run {
+                    sum: 11,
+                    cells: vec![(1, 2), (2, 2)]
+                },
+                Run {
+                    sum: 12,
+                    cells: vec![(2, 1), (2, 2)]
+                },
+            ]
+        );
+        assert_eq!(
+            Kakuro::try_from("# #\n# ."),
+            Err(KakuroError::MissingClue(1, 1))
+        );
+        assert!(Kakuro::try_from("# x").is_err());
+    }
+}
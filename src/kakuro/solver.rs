@@ -0,0 +1,154 @@
+use std::fmt::Display;
+
+use super::{possible_digits, sum_partitions, Digits, Kakuro, KakuroCell, ALL_DIGITS};
+use crate::csp::{self, Csp, CspStats};
+
+/// [`KakuroSolution`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct KakuroSolution {
+    /// Digit of every cell, `0` for black cells.
+    pub digits: Vec<Vec<u8>>,
+    pub stats: CspStats,
+}
+
+impl Display for KakuroSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in &self.digits {
+            let row = row
+                .iter()
+                .map(|digit| match digit {
+                    0 => '#',
+                    d => (b'0' + d) as char,
+                })
+                .collect::<String>();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// [`build_csp`]
+/// ## Kakuro as a CSP.
+/// One variable per white cell (in [`Kakuro::white_cells`] order). Domains start with the
+/// digits allowed by the sum-partition tables of both runs of the cell, e.g. a cell in a
+/// run of 2 adding up to 16 can only be 7 or 9.
+/// Every run gets an all-different constraint and a sum constraint: the digits already
+/// decided must be part of some partition of the run's sum.
+pub fn build_csp(kakuro: &Kakuro) -> Csp<u8> {
+    let cells = kakuro.white_cells();
+    let index = |cell: &(usize, usize)| cells.binary_search(cell).unwrap();
+
+    let mut allowed = vec![ALL_DIGITS; cells.len()];
+    for run in kakuro.runs() {
+        let digits = possible_digits(run.cells.len(), run.sum);
+        for cell in &run.cells {
+            allowed[index(cell)] &= digits;
+        }
+    }
+    let domains = allowed
+        .into_iter()
+        .map(|digits| (1..=9).filter(|d| digits & (1 << d) != 0).collect())
+        .collect();
+
+    let mut csp = Csp::new(domains);
+    for run in kakuro.runs() {
+        let scope = run.cells.iter().map(index).collect::<Vec<usize>>();
+        csp.add_all_different(&scope);
+        let partitions = sum_partitions(run.cells.len(), run.sum);
+        csp.add_constraint(scope, move |values| {
+            let decided = values
+                .iter()
+                .flatten()
+                .fold(0 as Digits, |digits, d| digits | (1 << **d));
+            partitions
+                .iter()
+                .any(|partition| partition & decided == decided)
+        });
+    }
+    csp
+}
+
+/// [`solve`]
+/// Solves the puzzle with the CSP of [`build_csp`]. Returns `None` if it has no solution.
+pub fn solve(kakuro: &Kakuro) -> Option<KakuroSolution> {
+    let solution = csp::solve(&build_csp(kakuro))?;
+    let mut digits = vec![vec![0; kakuro.columns()]; kakuro.rows()];
+    for ((row, column), digit) in kakuro.white_cells().into_iter().zip(solution.values) {
+        digits[row][column] = digit;
+    }
+    Some(KakuroSolution {
+        digits,
+        stats: solution.stats,
+    })
+}
+
+/// Whether `digits` fills every white cell and satisfies every run of `kakuro`.
+pub fn is_solution(kakuro: &Kakuro, digits: &[Vec<u8>]) -> bool {
+    let filled = kakuro
+        .white_cells()
+        .iter()
+        .all(|(r, c)| (1..=9).contains(&digits[*r][*c]));
+    filled
+        && kakuro.runs().iter().all(|run| {
+            let values = run.cells.iter().map(|(r, c)| digits[*r][*c]);
+            let set = values.clone().fold(0 as Digits, |set, d| set | (1 << d));
+            values.clone().map(u32::from).sum::<u32>() == run.sum
+                && set.count_ones() as usize == run.cells.len()
+        })
+        && digits.iter().enumerate().all(|(r, row)| {
+            row.iter()
+                .enumerate()
+                .all(|(c, d)| *d == 0 || kakuro.get(r, c) == KakuroCell::White)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SMALL: &str = "#   4\\ 11\\
+                         \\3 .   .
+                         \\12 .  .";
+
+    #[test]
+    fn kakuro_solve_small_puzzle() {
+        let kakuro = Kakuro::try_from(SMALL).unwrap();
+
+        let solution = solve(&kakuro).unwrap();
+
+        assert_eq!(solution.to_string(), "###\n#12\n#39\n");
+        assert!(is_solution(&kakuro, &solution.digits));
+    }
+
+    #[test]
+    fn kakuro_build_csp_prunes_with_partition_tables() {
+        let kakuro = Kakuro::try_from(SMALL).unwrap();
+
+        let csp = build_csp(&kakuro);
+
+        // (1, 1) is in runs 3 = {1, 2} and 4 = {1, 3}.
+        assert_eq!(csp.domain(0), &[1]);
+        // (1, 2) is in runs 3 = {1, 2} and 11, which can't use 1.
+        assert_eq!(csp.domain(1), &[2]);
+        // 12 in two cells can't use 1, 2 or 6.
+        assert_eq!(csp.domain(3), &[3, 4, 5, 7, 8, 9]);
+    }
+
+    #[test]
+    fn kakuro_solve_larger_puzzle_with_search() {
+        let kakuro = Kakuro::try_from(
+            "#   19\\ 27\\ #    21\\ 20\\
+             \\3  .   .   1\\9 .    .
+             \\24 .   .   .    .    .
+             \\7  .   .   1\\5 .    .
+             \\26 .   .   .    .    .
+             \\8  .   .   \\7  .    .",
+        )
+        .unwrap();
+
+        let solution = solve(&kakuro).unwrap();
+
+        assert!(is_solution(&kakuro, &solution.digits));
+        assert!(!is_solution(&kakuro, &vec![vec![0; 6]; 6]));
+    }
+}
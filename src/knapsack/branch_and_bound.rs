@@ -0,0 +1,157 @@
+use super::{KnapsackInstance, KnapsackSolution};
+use crate::branch_and_bound::{BranchAndBound, BranchAndBoundProblem, Exploration, Sense};
+
+/// [`BranchAndBoundOutcome`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchAndBoundOutcome {
+    pub solution: KnapsackSolution,
+    /// Number of nodes removed from the priority queue.
+    pub nodes_explored: usize,
+}
+
+/// [`branch_and_bound`]
+/// ## Best-first branch and bound solver.
+/// Items are considered in decreasing value/weight ratio, each node decides whether the
+/// next item is taken or not.
+/// The bound of a node is its fractional relaxation: the value obtained by filling the
+/// remaining capacity greedily, taking a fraction of the first item that doesn't fit.
+/// The node with the highest bound is always explored first, and nodes whose bound can't
+/// beat the best solution found so far are discarded.
+pub fn branch_and_bound(instance: &KnapsackInstance) -> BranchAndBoundOutcome {
+    let items = instance.items();
+    let mut order = (0..items.len()).collect::<Vec<usize>>();
+    order.sort_by(|a, b| items[*b].ratio().total_cmp(&items[*a].ratio()));
+
+    let problem = KnapsackTree { instance, order };
+    let outcome = BranchAndBound::new(Exploration::BestFirst).solve(&problem);
+
+    let mut selected = vec![false; items.len()];
+    let taken = outcome.best.map(|best| best.solution).unwrap_or_default();
+    for (position, taken) in taken.into_iter().enumerate() {
+        selected[problem.order[position]] = taken;
+    }
+    let (weight, value) = instance.evaluate(&selected);
+    BranchAndBoundOutcome {
+        solution: KnapsackSolution {
+            selected,
+            value,
+            weight,
+        },
+        nodes_explored: outcome.stats.nodes_explored,
+    }
+}
+
+struct KnapsackTree<'a> {
+    instance: &'a KnapsackInstance,
+    order: Vec<usize>,
+}
+
+/// Node of the search tree, `taken[k]` is the decision for the `k`-th item in ratio order.
+#[derive(Clone)]
+struct Node {
+    weight: u32,
+    value: u32,
+    taken: Vec<bool>,
+}
+
+impl BranchAndBoundProblem for KnapsackTree<'_> {
+    type Node = Node;
+    type Solution = Vec<bool>;
+
+    const SENSE: Sense = Sense::Maximize;
+
+    fn root(&self) -> Node {
+        Node {
+            weight: 0,
+            value: 0,
+            taken: vec![],
+        }
+    }
+
+    fn branch(&self, node: &Node) -> Vec<Node> {
+        let Some(index) = self.order.get(node.taken.len()) else {
+            return vec![];
+        };
+        let item = self.instance.items()[*index];
+        let mut children = vec![];
+        if node.weight + item.weight <= self.instance.capacity() {
+            let mut taken = node.taken.clone();
+            taken.push(true);
+            children.push(Node {
+                weight: node.weight + item.weight,
+                value: node.value + item.value,
+                taken,
+            });
+        }
+        let mut taken = node.taken.clone();
+        taken.push(false);
+        children.push(Node { taken, ..*node });
+        children
+    }
+
+    fn bound(&self, node: &Node) -> f64 {
+        let mut remaining = self.instance.capacity() - node.weight;
+        let mut bound = f64::from(node.value);
+        for index in &self.order[node.taken.len()..] {
+            let item = self.instance.items()[*index];
+            if item.weight <= remaining {
+                remaining -= item.weight;
+                bound += f64::from(item.value);
+            } else {
+                bound += f64::from(item.value) * f64::from(remaining) / f64::from(item.weight);
+                break;
+            }
+        }
+        bound
+    }
+
+    /// Every node is feasible, the items not decided yet are left out.
+    fn solution(&self, node: &Node) -> Option<(Vec<bool>, f64)> {
+        Some((node.taken.clone(), f64::from(node.value)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::knapsack::{dynamic_programming, Item};
+    use crate::rng::Rng;
+
+    #[test]
+    fn knapsack_branch_and_bound_finds_optimal_value() {
+        let instance = KnapsackInstance::try_from("4 10\n5 10\n4 40\n6 30\n3 50").unwrap();
+
+        let outcome = branch_and_bound(&instance);
+
+        assert_eq!(outcome.solution.value, 90);
+        assert_eq!(outcome.solution.selected, vec![false, true, false, true]);
+    }
+
+    #[test]
+    fn knapsack_branch_and_bound_matches_dynamic_programming_on_random_instances() {
+        let mut rng = Rng::new(11);
+        for _ in 0..30 {
+            let items = (0..12)
+                .map(|_| Item::new(rng.gen_range(1..30) as u32, rng.gen_range(0..50) as u32))
+                .collect();
+            let capacity = rng.gen_range(0..100) as u32;
+            let instance = KnapsackInstance::new(items, capacity).unwrap();
+
+            let outcome = branch_and_bound(&instance);
+            let baseline = dynamic_programming(&instance);
+
+            assert_eq!(outcome.solution.value, baseline.value);
+            assert!(instance.is_feasible(&outcome.solution.selected));
+        }
+    }
+
+    #[test]
+    fn knapsack_branch_and_bound_handles_items_that_never_fit() {
+        let instance = KnapsackInstance::try_from("2 3\n5 10\n4 40").unwrap();
+
+        let outcome = branch_and_bound(&instance);
+
+        assert_eq!(outcome.solution.value, 0);
+        assert_eq!(outcome.solution.selected, vec![false, false]);
+    }
+}
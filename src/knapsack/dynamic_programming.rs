@@ -0,0 +1,54 @@
+use super::{KnapsackInstance, KnapsackSolution};
+
+/// [`dynamic_programming`]
+/// ## Classic `O(n * capacity)` dynamic programming solver.
+/// Slow and memory hungry for big capacities, but simple enough to trust: it is the
+/// baseline used to check the optimality of the other solvers.
+pub fn dynamic_programming(instance: &KnapsackInstance) -> KnapsackSolution {
+    let items = instance.items();
+    let capacity = instance.capacity() as usize;
+    // best[i][w] = best value using the first `i` items with capacity `w`.
+    let mut best = vec![vec![0u32; capacity + 1]; items.len() + 1];
+
+    for (i, item) in items.iter().enumerate() {
+        let weight = item.weight as usize;
+        for w in 0..=capacity {
+            best[i + 1][w] = best[i][w];
+            if weight <= w {
+                best[i + 1][w] = best[i + 1][w].max(best[i][w - weight] + item.value);
+            }
+        }
+    }
+
+    let mut selected = vec![false; items.len()];
+    let mut w = capacity;
+    for i in (0..items.len()).rev() {
+        if best[i + 1][w] != best[i][w] {
+            selected[i] = true;
+            w -= items[i].weight as usize;
+        }
+    }
+
+    let (weight, value) = instance.evaluate(&selected);
+    KnapsackSolution {
+        selected,
+        value,
+        weight,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn knapsack_dynamic_programming_finds_optimal_value() {
+        let instance = KnapsackInstance::try_from("4 10\n5 10\n4 40\n6 30\n3 50").unwrap();
+
+        let solution = dynamic_programming(&instance);
+
+        assert_eq!(solution.value, 90);
+        assert_eq!(solution.selected, vec![false, true, false, true]);
+        assert_eq!(solution.weight, 7);
+    }
+}
@@ -0,0 +1,144 @@
+use super::KnapsackError;
+
+/// [`Item`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Item {
+    pub weight: u32,
+    pub value: u32,
+}
+
+impl Item {
+    pub fn new(weight: u32, value: u32) -> Self {
+        Self { weight, value }
+    }
+
+    pub fn ratio(&self) -> f64 {
+        f64::from(self.value) / f64::from(self.weight)
+    }
+}
+
+/// [`KnapsackInstance`]
+/// ## 0/1 knapsack instance.
+/// Choose a subset of `items` with total weight at most `capacity` maximizing total value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnapsackInstance {
+    items: Vec<Item>,
+    capacity: u32,
+}
+
+impl KnapsackInstance {
+    pub fn new(items: Vec<Item>, capacity: u32) -> Result<Self, KnapsackError> {
+        if let Some(index) = items.iter().position(|item| item.weight == 0) {
+            return Err(KnapsackError::ZeroWeightItem(index));
+        }
+        Ok(Self { items, capacity })
+    }
+
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// [`evaluate`]
+    /// Returns `(weight, value)` of the selection, `selected[i]` tells if item `i` is taken.
+    pub fn evaluate(&self, selected: &[bool]) -> (u32, u32) {
+        self.items
+            .iter()
+            .zip(selected)
+            .filter(|(_, selected)| **selected)
+            .fold((0, 0), |(weight, value), (item, _)| {
+                (weight + item.weight, value + item.value)
+            })
+    }
+
+    pub fn is_feasible(&self, selected: &[bool]) -> bool {
+        selected.len() == self.items.len() && self.evaluate(selected).0 <= self.capacity
+    }
+}
+
+/// [TryFrom<&str>]
+/// Accepts the format:
+/// first line `n_items capacity`, then one line per item with `weight value`.
+impl TryFrom<&str> for KnapsackInstance {
+    type Error = KnapsackError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parse_error = |message: &str| KnapsackError::ParseFromStringError(message.into());
+        let mut lines = value
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|n| n.parse::<u32>())
+                    .collect::<Result<Vec<u32>, _>>()
+                    .map_err(|_| parse_error("Invalid number"))
+            });
+
+        let (n_items, capacity) = match lines.next().transpose()?.as_deref() {
+            Some([n_items, capacity]) => (*n_items as usize, *capacity),
+            _ => {
+                return Err(parse_error(
+                    "Header must contain number of items and capacity",
+                ))
+            }
+        };
+        let items = lines
+            .take(n_items)
+            .map(|line| match line?[..] {
+                [weight, value] => Ok(Item::new(weight, value)),
+                _ => Err(parse_error("Item must contain weight and value")),
+            })
+            .collect::<Result<Vec<Item>, KnapsackError>>()?;
+        if items.len() != n_items {
+            return Err(parse_error("Missing item lines"));
+        }
+
+        KnapsackInstance::new(items, capacity)
+    }
+}
+
+/// [`KnapsackSolution`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnapsackSolution {
+    /// `selected[i]` tells if item `i` is in the knapsack.
+    pub selected: Vec<bool>,
+    pub value: u32,
+    pub weight: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn knapsack_tryfrom_str_works_as_expected() {
+        let instance = KnapsackInstance::try_from("2 10\n5 10\n4 40").unwrap();
+
+        assert_eq!(instance.capacity(), 10);
+        assert_eq!(instance.items(), &[Item::new(5, 10), Item::new(4, 40)]);
+    }
+
+    #[test]
+    fn knapsack_tryfrom_str_returns_error_when_format_is_invalid() {
+        assert!(KnapsackInstance::try_from("").is_err());
+        assert!(KnapsackInstance::try_from("2 10\n5 10").is_err());
+        assert!(KnapsackInstance::try_from("1 10\n5").is_err());
+        assert_eq!(
+            KnapsackInstance::try_from("1 10\n0 5"),
+            Err(KnapsackError::ZeroWeightItem(0))
+        );
+    }
+
+    #[test]
+    fn knapsack_evaluate_sums_selected_items() {
+        let instance = KnapsackInstance::try_from("3 10\n5 10\n4 40\n6 30").unwrap();
+
+        assert_eq!(instance.evaluate(&[true, false, true]), (11, 40));
+        assert!(!instance.is_feasible(&[true, false, true]));
+        assert!(instance.is_feasible(&[false, true, true]));
+    }
+}
@@ -0,0 +1,18 @@
+pub mod branch_and_bound;
+pub mod dynamic_programming;
+pub mod instance;
+
+pub use branch_and_bound::*;
+pub use dynamic_programming::*;
+pub use instance::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum KnapsackError {
+    #[error("Item weight must be greater than zero")]
+    ZeroWeightItem(usize),
+    #[error("Error when trying to parse from KnapsackInstance string")]
+    ParseFromStringError(String),
+}
@@ -1,2 +1,38 @@
+pub mod bin_packing;
+pub mod boggle;
+pub mod branch_and_bound;
+#[cfg(feature = "config")]
+pub mod campaign;
 pub mod cannibals;
+#[cfg(feature = "cli")]
+pub mod cli;
+#[cfg(feature = "config")]
+pub mod config;
+pub mod crossing;
+pub mod crossword;
+pub mod csp;
+pub mod cvrp;
+pub mod datasets;
+pub mod futoshiki;
+pub mod game_24;
+pub mod genetic;
+pub mod hanoi;
+pub mod job_shop;
+pub mod kakuro;
+pub mod knapsack;
+pub mod local_search;
+pub mod magic_square;
+pub mod minesweeper;
+pub mod n_puzzle;
+pub mod nonogram;
+#[cfg(feature = "plots")]
+pub mod plots;
+pub mod rng;
+pub mod romania;
+pub mod rush_hour;
+pub mod search;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+pub mod terminal;
+pub mod word_ladder;
 pub use cannibals::*;
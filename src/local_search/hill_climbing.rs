@@ -0,0 +1,170 @@
+use super::{LocalSearch, LocalSearchOutcome, LocalSearchProblem, LocalSearchStats};
+use crate::rng::Rng;
+
+/// [`SteepestAscent`]
+/// ## Steepest-ascent hill climbing.
+/// Moves to the best neighbor as long as it is strictly better than the current state,
+/// so it stops at the first local optimum or plateau. Up to `sideways_moves` moves to an
+/// equally good neighbor are allowed in a row to walk across plateaus.
+/// Random-restart hill climbing is [`LocalSearch::with_restarts`].
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::local_search::*;
+/// # use algoritmos_rust::rng::Rng;
+/// let start = WorldState::try_from("0 0 3 3 right").unwrap();
+/// let mut rng = Rng::new(0);
+/// let stuck = SteepestAscent::default().search(&CannibalsLocalSearch, start, &mut rng);
+/// assert!(!stuck.best_state.is_solution());
+/// assert!(stuck.stats.iterations < 11);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SteepestAscent {
+    pub sideways_moves: usize,
+}
+
+impl SteepestAscent {
+    pub fn new(sideways_moves: usize) -> Self {
+        Self { sideways_moves }
+    }
+}
+
+impl LocalSearch for SteepestAscent {
+    /// Ties between the best neighbors go to the first one listed.
+    fn search<P: LocalSearchProblem>(
+        &self,
+        problem: &P,
+        initial_state: P::State,
+        _rng: &mut Rng,
+    ) -> LocalSearchOutcome<P::State> {
+        let mut stats = LocalSearchStats::default();
+        let mut state = initial_state;
+        let mut cost = problem.cost(&state);
+        stats.evaluations += 1;
+        let mut sideways = 0;
+        loop {
+            let best_neighbor = problem
+                .neighbors(&state)
+                .into_iter()
+                .map(|neighbor| {
+                    stats.evaluations += 1;
+                    (problem.cost(&neighbor), neighbor)
+                })
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+            match best_neighbor {
+                Some((neighbor_cost, neighbor)) if neighbor_cost < cost => {
+                    (state, cost) = (neighbor, neighbor_cost);
+                    sideways = 0;
+                }
+                Some((neighbor_cost, neighbor))
+                    if neighbor_cost == cost && sideways < self.sideways_moves =>
+                {
+                    state = neighbor;
+                    sideways += 1;
+                }
+                _ => break,
+            }
+            stats.iterations += 1;
+        }
+        LocalSearchOutcome {
+            best_state: state,
+            best_cost: cost,
+            stats,
+        }
+    }
+}
+
+/// [`FirstChoice`]
+/// ## First-choice hill climbing.
+/// Evaluates the neighbors in random order and moves to the first one that is strictly
+/// better than the current state, stopping when none is. Cheaper than
+/// [`SteepestAscent`] when states have many neighbors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FirstChoice;
+
+impl LocalSearch for FirstChoice {
+    fn search<P: LocalSearchProblem>(
+        &self,
+        problem: &P,
+        initial_state: P::State,
+        rng: &mut Rng,
+    ) -> LocalSearchOutcome<P::State> {
+        let mut stats = LocalSearchStats::default();
+        let mut state = initial_state;
+        let mut cost = problem.cost(&state);
+        stats.evaluations += 1;
+        loop {
+            let mut neighbors = problem.neighbors(&state);
+            rng.shuffle(&mut neighbors);
+            let better = neighbors.into_iter().find_map(|neighbor| {
+                stats.evaluations += 1;
+                let neighbor_cost = problem.cost(&neighbor);
+                (neighbor_cost < cost).then_some((neighbor, neighbor_cost))
+            });
+            let Some((neighbor, neighbor_cost)) = better else {
+                break;
+            };
+            (state, cost) = (neighbor, neighbor_cost);
+            stats.iterations += 1;
+        }
+        LocalSearchOutcome {
+            best_state: state,
+            best_cost: cost,
+            stats,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A slope down to a plateau at index 3..6, then down to the minimum at index 8.
+    struct Terraces;
+
+    const TERRACES: [f64; 10] = [6.0, 5.0, 4.0, 3.0, 3.0, 3.0, 3.0, 2.0, 0.0, 1.0];
+
+    impl LocalSearchProblem for Terraces {
+        type State = usize;
+
+        fn random_state(&self, rng: &mut Rng) -> usize {
+            rng.gen_range(0..TERRACES.len())
+        }
+
+        fn neighbors(&self, state: &usize) -> Vec<usize> {
+            let mut neighbors = vec![];
+            if *state + 1 < TERRACES.len() {
+                neighbors.push(state + 1);
+            }
+            if *state > 0 {
+                neighbors.push(state - 1);
+            }
+            neighbors
+        }
+
+        fn cost(&self, state: &usize) -> f64 {
+            TERRACES[*state]
+        }
+    }
+
+    #[test]
+    fn steepest_ascent_needs_sideways_moves_to_cross_plateaus() {
+        let stuck = SteepestAscent::default().search(&Terraces, 0, &mut Rng::new(0));
+        let crossing = SteepestAscent::new(3).search(&Terraces, 0, &mut Rng::new(0));
+
+        assert_eq!((stuck.best_state, stuck.best_cost), (3, 3.0));
+        assert_eq!(stuck.stats.iterations, 3);
+        assert_eq!((crossing.best_state, crossing.best_cost), (8, 0.0));
+        assert_eq!(crossing.stats.iterations, 8);
+    }
+
+    #[test]
+    fn first_choice_stops_when_no_neighbor_is_better() {
+        let outcome = FirstChoice.search(&Terraces, 9, &mut Rng::new(1));
+
+        assert_eq!((outcome.best_state, outcome.best_cost), (8, 0.0));
+        assert_eq!(outcome.stats.iterations, 1);
+        let stuck = FirstChoice.search(&Terraces, 1, &mut Rng::new(1));
+        assert_eq!(stuck.best_state, 3);
+    }
+}
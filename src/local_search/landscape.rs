@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::LocalSearchProblem;
+use crate::rng::Rng;
+
+/// [`LandscapeConfig`]
+/// Sampling parameters used by [`analyze`].
+#[derive(Debug, Clone, Copy)]
+pub struct LandscapeConfig {
+    /// Number of steps of the random walk used to compute the autocorrelation.
+    pub walk_length: usize,
+    /// Largest lag for which the autocorrelation is computed.
+    pub max_lag: usize,
+    /// Number of random states sampled to estimate local optima density and basins.
+    pub samples: usize,
+}
+
+impl Default for LandscapeConfig {
+    fn default() -> Self {
+        Self {
+            walk_length: 1000,
+            max_lag: 10,
+            samples: 200,
+        }
+    }
+}
+
+/// [`Basin`]
+/// A local optimum and how many sampled states descend into it.
+#[derive(Debug, Clone)]
+pub struct Basin<S> {
+    pub optimum: S,
+    pub cost: f64,
+    pub size: usize,
+}
+
+/// [`LandscapeReport`]
+/// ## Summary of the fitness landscape of a problem + neighborhood.
+/// Rules of thumb when picking a metaheuristic:
+/// * autocorrelation close to `1` (long correlation length) means a smooth landscape,
+///   where plain hill climbing does well;
+/// * a high local optima density, or many small basins, means a rugged landscape,
+///   where random restarts, simulated annealing or populations pay off.
+#[derive(Debug, Clone)]
+pub struct LandscapeReport<S> {
+    /// Autocorrelation of the cost along a random walk, `autocorrelation[i]` is lag `i + 1`.
+    pub autocorrelation: Vec<f64>,
+    /// `-1 / ln(|r(1)|)`, infinite if the walk is perfectly correlated.
+    pub correlation_length: f64,
+    /// Fraction of sampled states that are local optima.
+    pub local_optima_density: f64,
+    /// Basins of attraction found by steepest descent, biggest first.
+    pub basins: Vec<Basin<S>>,
+}
+
+/// [`analyze`]
+/// Computes all the landscape measures of this module with a single seed.
+pub fn analyze<P>(problem: &P, config: &LandscapeConfig, seed: u64) -> LandscapeReport<P::State>
+where
+    P: LocalSearchProblem,
+    P::State: Hash + Eq,
+{
+    let mut rng = Rng::new(seed);
+    let autocorrelation =
+        random_walk_autocorrelation(problem, config.walk_length, config.max_lag, &mut rng);
+    let correlation_length = autocorrelation
+        .first()
+        .map(|r1| correlation_length(*r1))
+        .unwrap_or(0.0);
+    let local_optima_density = local_optima_density(problem, config.samples, &mut rng);
+    let basins = basin_sizes(problem, config.samples, &mut rng);
+
+    LandscapeReport {
+        autocorrelation,
+        correlation_length,
+        local_optima_density,
+        basins,
+    }
+}
+
+/// [`random_walk_autocorrelation`]
+/// Performs a random walk of `walk_length` steps and returns the autocorrelation of the
+/// cost series for lags `1..=max_lag`.
+/// The walk stops early if it reaches a state without neighbors.
+pub fn random_walk_autocorrelation<P: LocalSearchProblem>(
+    problem: &P,
+    walk_length: usize,
+    max_lag: usize,
+    rng: &mut Rng,
+) -> Vec<f64> {
+    let mut state = problem.random_state(rng);
+    let mut costs = vec![problem.cost(&state)];
+
+    for _ in 0..walk_length {
+        let neighbors = problem.neighbors(&state);
+        match rng.choose(&neighbors) {
+            Some(neighbor) => state = neighbor.clone(),
+            None => break,
+        }
+        costs.push(problem.cost(&state));
+    }
+
+    (1..=max_lag)
+        .map(|lag| autocorrelation(&costs, lag))
+        .collect()
+}
+
+/// [`local_optima_density`]
+/// Fraction of `samples` random states with no strictly better neighbor.
+pub fn local_optima_density<P: LocalSearchProblem>(
+    problem: &P,
+    samples: usize,
+    rng: &mut Rng,
+) -> f64 {
+    if samples == 0 {
+        return 0.0;
+    }
+    let local_optima = (0..samples)
+        .filter(|_| {
+            let state = problem.random_state(rng);
+            is_local_optimum(problem, &state)
+        })
+        .count();
+    local_optima as f64 / samples as f64
+}
+
+/// [`basin_sizes`]
+/// Descends from `samples` random states with steepest descent and groups them by the
+/// local optimum they end up at.
+pub fn basin_sizes<P>(problem: &P, samples: usize, rng: &mut Rng) -> Vec<Basin<P::State>>
+where
+    P: LocalSearchProblem,
+    P::State: Hash + Eq,
+{
+    let mut basins: HashMap<P::State, Basin<P::State>> = HashMap::new();
+
+    for _ in 0..samples {
+        let (optimum, cost) = steepest_descent(problem, problem.random_state(rng));
+        basins
+            .entry(optimum.clone())
+            .or_insert(Basin {
+                optimum,
+                cost,
+                size: 0,
+            })
+            .size += 1;
+    }
+
+    let mut basins = basins.into_values().collect::<Vec<_>>();
+    basins.sort_by(|a, b| b.size.cmp(&a.size).then(a.cost.total_cmp(&b.cost)));
+    basins
+}
+
+fn is_local_optimum<P: LocalSearchProblem>(problem: &P, state: &P::State) -> bool {
+    let cost = problem.cost(state);
+    problem
+        .neighbors(state)
+        .iter()
+        .all(|neighbor| problem.cost(neighbor) >= cost)
+}
+
+fn steepest_descent<P: LocalSearchProblem>(problem: &P, state: P::State) -> (P::State, f64) {
+    let mut state = state;
+    let mut cost = problem.cost(&state);
+    loop {
+        let best_neighbor = problem
+            .neighbors(&state)
+            .into_iter()
+            .map(|neighbor| (problem.cost(&neighbor), neighbor))
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+        match best_neighbor {
+            Some((neighbor_cost, neighbor)) if neighbor_cost < cost => {
+                state = neighbor;
+                cost = neighbor_cost;
+            }
+            _ => return (state, cost),
+        }
+    }
+}
+
+fn autocorrelation(series: &[f64], lag: usize) -> f64 {
+    if series.len() <= lag {
+        return 0.0;
+    }
+    let n = series.len() as f64;
+    let mean = series.iter().sum::<f64>() / n;
+    let variance = series.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    if variance == 0.0 {
+        return 1.0;
+    }
+    let covariance = series
+        .windows(lag + 1)
+        .map(|w| (w[0] - mean) * (w[lag] - mean))
+        .sum::<f64>()
+        / (n - lag as f64);
+    covariance / variance
+}
+
+fn correlation_length(r1: f64) -> f64 {
+    let r1 = r1.abs();
+    if r1 >= 1.0 {
+        f64::INFINITY
+    } else if r1 == 0.0 {
+        0.0
+    } else {
+        -1.0 / r1.ln()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Integers in `0..size` with `x ± 1` neighborhood.
+    struct Line {
+        costs: Vec<f64>,
+    }
+
+    impl LocalSearchProblem for Line {
+        type State = usize;
+
+        fn random_state(&self, rng: &mut Rng) -> usize {
+            rng.gen_range(0..self.costs.len())
+        }
+
+        fn neighbors(&self, state: &usize) -> Vec<usize> {
+            let mut neighbors = vec![];
+            if *state > 0 {
+                neighbors.push(state - 1);
+            }
+            if *state + 1 < self.costs.len() {
+                neighbors.push(state + 1);
+            }
+            neighbors
+        }
+
+        fn cost(&self, state: &usize) -> f64 {
+            self.costs[*state]
+        }
+    }
+
+    fn smooth_line() -> Line {
+        Line {
+            costs: (0..50).map(|x| ((x as f64) - 25.0).powi(2)).collect(),
+        }
+    }
+
+    fn rugged_line() -> Line {
+        Line {
+            costs: (0..50)
+                .map(|x| if x % 2 == 0 { 0.0 } else { 10.0 })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn landscape_smooth_problem_has_single_basin() {
+        let report = analyze(&smooth_line(), &LandscapeConfig::default(), 1);
+
+        assert_eq!(report.basins.len(), 1);
+        assert_eq!(report.basins[0].optimum, 25);
+        assert_eq!(report.basins[0].size, LandscapeConfig::default().samples);
+        assert!(report.local_optima_density < 0.1);
+        assert!(report.autocorrelation[0] > 0.9);
+    }
+
+    #[test]
+    fn landscape_rugged_problem_has_many_optima_and_negative_correlation() {
+        let report = analyze(&rugged_line(), &LandscapeConfig::default(), 1);
+
+        assert!(report.basins.len() > 10);
+        assert!(report.local_optima_density > 0.3);
+        assert!(report.autocorrelation[0] < -0.9);
+    }
+
+    #[test]
+    fn landscape_autocorrelation_of_constant_series_is_one() {
+        assert_eq!(autocorrelation(&[2.0, 2.0, 2.0, 2.0], 1), 1.0);
+    }
+}
@@ -0,0 +1,76 @@
+pub mod hill_climbing;
+pub mod landscape;
+pub mod restarts;
+pub mod tuner;
+
+pub use hill_climbing::*;
+pub use restarts::*;
+
+use crate::rng::Rng;
+
+/// [`LocalSearchProblem`]
+/// ## A problem that can be explored by local search.
+/// Local search does not care about paths: it only needs a way to create a starting
+/// state, to list the neighbors of a state, and to evaluate how good a state is.
+/// As with [`crate::WorldState::get_heuristic`], the lower the cost, the better the state.
+pub trait LocalSearchProblem {
+    type State: Clone;
+
+    /// Generates a random starting state.
+    fn random_state(&self, rng: &mut Rng) -> Self::State;
+
+    /// Lists all the states reachable from `state` with a single move of the neighborhood.
+    fn neighbors(&self, state: &Self::State) -> Vec<Self::State>;
+
+    /// Evaluates the state, lower is better.
+    fn cost(&self, state: &Self::State) -> f64;
+}
+
+/// [`LocalSearchStats`]
+/// Counters collected during a local search run.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LocalSearchStats {
+    /// Number of moves (accepted steps) performed.
+    pub iterations: usize,
+    /// Number of times the cost function was evaluated.
+    pub evaluations: usize,
+}
+
+impl std::ops::AddAssign for LocalSearchStats {
+    fn add_assign(&mut self, other: Self) {
+        self.iterations += other.iterations;
+        self.evaluations += other.evaluations;
+    }
+}
+
+/// [`LocalSearchOutcome`]
+/// The best state found by a local search run, along with its cost and statistics.
+#[derive(Debug, Clone)]
+pub struct LocalSearchOutcome<S> {
+    pub best_state: S,
+    pub best_cost: f64,
+    pub stats: LocalSearchStats,
+}
+
+/// [`LocalSearch`]
+/// ## A local search algorithm.
+/// Implementors start from `initial_state` and try to reach lower cost states of `problem`.
+/// `rng` is the only source of randomness allowed, so runs are reproducible given a seed.
+pub trait LocalSearch {
+    fn search<P: LocalSearchProblem>(
+        &self,
+        problem: &P,
+        initial_state: P::State,
+        rng: &mut Rng,
+    ) -> LocalSearchOutcome<P::State>;
+
+    /// [`with_restarts`]
+    /// Wraps this search into [`WithRestarts`], that runs it `restarts` times from random
+    /// states generated with `seed` and keeps the best result.
+    fn with_restarts(self, restarts: usize, seed: u64) -> WithRestarts<Self>
+    where
+        Self: Sized,
+    {
+        WithRestarts::new(self, restarts, seed)
+    }
+}
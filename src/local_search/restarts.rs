@@ -0,0 +1,246 @@
+use super::{LocalSearch, LocalSearchOutcome, LocalSearchProblem, LocalSearchStats};
+use crate::rng::Rng;
+
+/// [`WithRestarts`]
+/// ## Random-restart combinator.
+/// Runs the wrapped local search several times, each run starting from a fresh random
+/// state, and keeps track of the best state found so far across all the runs.
+/// Built with [`LocalSearch::with_restarts`].
+#[derive(Debug, Clone)]
+pub struct WithRestarts<L> {
+    search: L,
+    restarts: usize,
+    seed: u64,
+}
+
+impl<L: LocalSearch> WithRestarts<L> {
+    /// `restarts` is the total number of runs, a value of `0` still performs one run.
+    pub fn new(search: L, restarts: usize, seed: u64) -> Self {
+        Self {
+            search,
+            restarts: restarts.max(1),
+            seed,
+        }
+    }
+
+    /// [`run`]
+    /// Runs all the restarts with the configured seed, every restart starting from
+    /// [`LocalSearchProblem::random_state`].
+    pub fn run<P: LocalSearchProblem>(&self, problem: &P) -> RestartsOutcome<P::State> {
+        let mut rng = Rng::new(self.seed);
+        let initial_state = problem.random_state(&mut rng);
+        self.run_from(problem, initial_state, &mut rng)
+    }
+
+    fn run_from<P: LocalSearchProblem>(
+        &self,
+        problem: &P,
+        initial_state: P::State,
+        rng: &mut Rng,
+    ) -> RestartsOutcome<P::State> {
+        let mut stats = LocalSearchStats::default();
+        let mut costs = Vec::with_capacity(self.restarts);
+        let mut best_so_far = Vec::with_capacity(self.restarts);
+        let mut best: Option<(usize, LocalSearchOutcome<P::State>)> = None;
+
+        let mut next_initial_state = Some(initial_state);
+        for restart in 0..self.restarts {
+            let start = next_initial_state
+                .take()
+                .unwrap_or_else(|| problem.random_state(rng));
+            let outcome = self.search.search(problem, start, rng);
+
+            stats += outcome.stats;
+            costs.push(outcome.best_cost);
+
+            let improved = match &best {
+                Some((_, best_outcome)) => outcome.best_cost < best_outcome.best_cost,
+                None => true,
+            };
+            if improved {
+                best = Some((restart, outcome));
+            }
+            if let Some((_, best_outcome)) = &best {
+                best_so_far.push(best_outcome.best_cost);
+            }
+        }
+
+        let (best_restart, best) = best.expect("at least one restart is always performed");
+        RestartsOutcome {
+            best_state: best.best_state,
+            best_cost: best.best_cost,
+            best_restart,
+            costs_per_restart: costs,
+            best_so_far,
+            stats,
+        }
+    }
+}
+
+impl<L: LocalSearch> LocalSearch for WithRestarts<L> {
+    /// The first restart starts from `initial_state`, the others from random states
+    /// drawn from `rng` (the configured seed is only used by [`WithRestarts::run`]).
+    fn search<P: LocalSearchProblem>(
+        &self,
+        problem: &P,
+        initial_state: P::State,
+        rng: &mut Rng,
+    ) -> LocalSearchOutcome<P::State> {
+        let outcome = self.run_from(problem, initial_state, rng);
+        LocalSearchOutcome {
+            best_state: outcome.best_state,
+            best_cost: outcome.best_cost,
+            stats: outcome.stats,
+        }
+    }
+}
+
+/// [`RestartsOutcome`]
+/// Result of a [`WithRestarts`] run with the statistics aggregated across restarts.
+#[derive(Debug, Clone)]
+pub struct RestartsOutcome<S> {
+    pub best_state: S,
+    pub best_cost: f64,
+    /// Index of the restart that found `best_state`.
+    pub best_restart: usize,
+    /// Best cost found by each individual restart.
+    pub costs_per_restart: Vec<f64>,
+    /// Best cost found so far after each restart (non increasing).
+    pub best_so_far: Vec<f64>,
+    /// Sum of the statistics of all the restarts.
+    pub stats: LocalSearchStats,
+}
+
+impl<S> RestartsOutcome<S> {
+    pub fn restarts(&self) -> usize {
+        self.costs_per_restart.len()
+    }
+
+    pub fn mean_cost(&self) -> f64 {
+        self.costs_per_restart.iter().sum::<f64>() / self.restarts() as f64
+    }
+
+    pub fn worst_cost(&self) -> f64 {
+        self.costs_per_restart
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 1-D landscape with a local minimum at index 2 (cost 3) and the global
+    /// minimum at index 7 (cost 0).
+    struct Valleys;
+
+    const VALLEYS: [f64; 10] = [5.0, 4.0, 3.0, 4.0, 6.0, 4.0, 2.0, 0.0, 2.0, 5.0];
+
+    impl LocalSearchProblem for Valleys {
+        type State = usize;
+
+        fn random_state(&self, rng: &mut Rng) -> usize {
+            rng.gen_range(0..VALLEYS.len())
+        }
+
+        fn neighbors(&self, state: &usize) -> Vec<usize> {
+            let mut neighbors = vec![];
+            if *state > 0 {
+                neighbors.push(state - 1);
+            }
+            if *state + 1 < VALLEYS.len() {
+                neighbors.push(state + 1);
+            }
+            neighbors
+        }
+
+        fn cost(&self, state: &usize) -> f64 {
+            VALLEYS[*state]
+        }
+    }
+
+    /// Simple descent that stops at the first local minimum.
+    struct Descent;
+
+    impl LocalSearch for Descent {
+        fn search<P: LocalSearchProblem>(
+            &self,
+            problem: &P,
+            initial_state: P::State,
+            _rng: &mut Rng,
+        ) -> LocalSearchOutcome<P::State> {
+            let mut stats = LocalSearchStats::default();
+            let mut state = initial_state;
+            let mut cost = problem.cost(&state);
+            stats.evaluations += 1;
+            loop {
+                let best_neighbor = problem
+                    .neighbors(&state)
+                    .into_iter()
+                    .map(|n| {
+                        stats.evaluations += 1;
+                        (problem.cost(&n), n)
+                    })
+                    .min_by(|a, b| a.0.total_cmp(&b.0));
+                match best_neighbor {
+                    Some((neighbor_cost, neighbor)) if neighbor_cost < cost => {
+                        state = neighbor;
+                        cost = neighbor_cost;
+                        stats.iterations += 1;
+                    }
+                    _ => break,
+                }
+            }
+            LocalSearchOutcome {
+                best_state: state,
+                best_cost: cost,
+                stats,
+            }
+        }
+    }
+
+    #[test]
+    fn with_restarts_single_run_can_get_stuck_in_local_minimum() {
+        let outcome = Descent.search(&Valleys, 1, &mut Rng::new(0));
+
+        assert_eq!(outcome.best_state, 2);
+        assert_eq!(outcome.best_cost, 3.0);
+    }
+
+    #[test]
+    fn with_restarts_finds_global_minimum_and_aggregates_stats() {
+        let outcome = Descent.with_restarts(10, 42).run(&Valleys);
+
+        assert_eq!(outcome.best_state, 7);
+        assert_eq!(outcome.best_cost, 0.0);
+        assert_eq!(outcome.restarts(), 10);
+        assert_eq!(outcome.costs_per_restart[outcome.best_restart], 0.0);
+        assert!(outcome.mean_cost() >= outcome.best_cost);
+        assert!(outcome.worst_cost() >= outcome.mean_cost());
+        assert!(outcome.stats.evaluations >= 10);
+        assert!(
+            outcome.best_so_far.windows(2).all(|w| w[1] <= w[0]),
+            "best so far must never increase"
+        );
+    }
+
+    #[test]
+    fn with_restarts_is_reproducible_given_the_seed() {
+        let outcome_1 = Descent.with_restarts(5, 7).run(&Valleys);
+        let outcome_2 = Descent.with_restarts(5, 7).run(&Valleys);
+
+        assert_eq!(outcome_1.costs_per_restart, outcome_2.costs_per_restart);
+        assert_eq!(outcome_1.stats, outcome_2.stats);
+    }
+
+    #[test]
+    fn with_restarts_as_local_search_starts_from_given_state() {
+        let restarts = Descent.with_restarts(1, 0);
+
+        let outcome = restarts.search(&Valleys, 1, &mut Rng::new(0));
+
+        assert_eq!(outcome.best_state, 2);
+    }
+}
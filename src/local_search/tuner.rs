@@ -0,0 +1,244 @@
+use crate::rng::Rng;
+
+/// [`Tuner`]
+/// ## Random-search / grid-search tuner for metaheuristic configurations.
+/// Every configuration is evaluated `runs_per_instance` times on every training instance.
+/// The seeds passed to the evaluation function only depend on the instance and the run,
+/// so every configuration faces exactly the same random scenarios (common random numbers),
+/// which makes the comparison between configurations fairer.
+///
+/// The evaluation function receives `(config, instance, seed)` and returns the cost reached,
+/// lower is better.
+#[derive(Debug, Clone, Copy)]
+pub struct Tuner {
+    runs_per_instance: usize,
+    seed: u64,
+}
+
+impl Tuner {
+    pub fn new(runs_per_instance: usize, seed: u64) -> Self {
+        Self {
+            runs_per_instance: runs_per_instance.max(1),
+            seed,
+        }
+    }
+
+    /// [`grid_search`]
+    /// Evaluates every configuration in `configs`.
+    pub fn grid_search<C, I, F>(
+        &self,
+        configs: &[C],
+        instances: &[I],
+        evaluate: F,
+    ) -> TuningReport<C>
+    where
+        C: Clone,
+        F: Fn(&C, &I, u64) -> f64,
+    {
+        let seeds = self.run_seeds(instances.len());
+        let evaluations = configs
+            .iter()
+            .map(|config| self.evaluate_config(config.clone(), instances, &seeds, &evaluate))
+            .collect();
+        TuningReport::new(evaluations)
+    }
+
+    /// [`random_search`]
+    /// Evaluates `samples` configurations drawn with `sample_config`.
+    pub fn random_search<C, I, S, F>(
+        &self,
+        samples: usize,
+        mut sample_config: S,
+        instances: &[I],
+        evaluate: F,
+    ) -> TuningReport<C>
+    where
+        S: FnMut(&mut Rng) -> C,
+        F: Fn(&C, &I, u64) -> f64,
+    {
+        let seeds = self.run_seeds(instances.len());
+        // Configurations are sampled from a different stream than the run seeds.
+        let mut config_rng = Rng::new(self.seed ^ 0x5EED_C0F1_6000_0000);
+        let evaluations = (0..samples)
+            .map(|_| {
+                let config = sample_config(&mut config_rng);
+                self.evaluate_config(config, instances, &seeds, &evaluate)
+            })
+            .collect();
+        TuningReport::new(evaluations)
+    }
+
+    fn run_seeds(&self, instances: usize) -> Vec<Vec<u64>> {
+        let mut rng = Rng::new(self.seed);
+        (0..instances)
+            .map(|_| {
+                (0..self.runs_per_instance)
+                    .map(|_| rng.next_u64())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn evaluate_config<C, I, F>(
+        &self,
+        config: C,
+        instances: &[I],
+        seeds: &[Vec<u64>],
+        evaluate: &F,
+    ) -> ConfigEvaluation<C>
+    where
+        F: Fn(&C, &I, u64) -> f64,
+    {
+        let costs = instances
+            .iter()
+            .zip(seeds)
+            .flat_map(|(instance, instance_seeds)| {
+                instance_seeds
+                    .iter()
+                    .map(|seed| evaluate(&config, instance, *seed))
+                    .collect::<Vec<f64>>()
+            })
+            .collect::<Vec<f64>>();
+        ConfigEvaluation::new(config, costs)
+    }
+}
+
+/// [`ConfigEvaluation`]
+/// Costs reached by one configuration over all instances and runs, and their summary.
+#[derive(Debug, Clone)]
+pub struct ConfigEvaluation<C> {
+    pub config: C,
+    pub costs: Vec<f64>,
+    pub mean_cost: f64,
+    pub std_dev: f64,
+    /// 95% confidence interval of the mean cost (Student's t).
+    pub confidence_interval: (f64, f64),
+}
+
+impl<C> ConfigEvaluation<C> {
+    fn new(config: C, costs: Vec<f64>) -> Self {
+        let n = costs.len() as f64;
+        let mean_cost = if costs.is_empty() {
+            f64::NAN
+        } else {
+            costs.iter().sum::<f64>() / n
+        };
+        let std_dev = if costs.len() > 1 {
+            (costs.iter().map(|c| (c - mean_cost).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+        } else {
+            0.0
+        };
+        let half_width = if costs.len() > 1 {
+            t_critical_95(costs.len() - 1) * std_dev / n.sqrt()
+        } else {
+            f64::INFINITY
+        };
+        Self {
+            config,
+            costs,
+            mean_cost,
+            std_dev,
+            confidence_interval: (mean_cost - half_width, mean_cost + half_width),
+        }
+    }
+}
+
+/// [`TuningReport`]
+/// All the evaluated configurations, best (lowest mean cost) first.
+#[derive(Debug, Clone)]
+pub struct TuningReport<C> {
+    pub evaluations: Vec<ConfigEvaluation<C>>,
+}
+
+impl<C> TuningReport<C> {
+    fn new(mut evaluations: Vec<ConfigEvaluation<C>>) -> Self {
+        evaluations.sort_by(|a, b| a.mean_cost.total_cmp(&b.mean_cost));
+        Self { evaluations }
+    }
+
+    pub fn best(&self) -> Option<&ConfigEvaluation<C>> {
+        self.evaluations.first()
+    }
+
+    /// [`is_best_significant`]
+    /// Whether the confidence interval of the best configuration does not overlap with the
+    /// interval of the runner-up, meaning the difference is unlikely to be noise.
+    pub fn is_best_significant(&self) -> bool {
+        match (self.evaluations.first(), self.evaluations.get(1)) {
+            (Some(best), Some(second)) => best.confidence_interval.1 < second.confidence_interval.0,
+            (Some(_), None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Two tailed 95% critical value of Student's t distribution.
+fn t_critical_95(degrees_of_freedom: usize) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+        2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+        2.052, 2.048, 2.045, 2.042,
+    ];
+    match degrees_of_freedom {
+        0 => f64::INFINITY,
+        df if df <= TABLE.len() => TABLE[df - 1],
+        _ => 1.96,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Pretend metaheuristic whose cost is minimized when `config == instance` plus some
+    /// seeded noise.
+    fn evaluate(config: &f64, instance: &f64, seed: u64) -> f64 {
+        let noise = Rng::new(seed).gen_f64() * 0.1;
+        (config - instance).powi(2) + noise
+    }
+
+    #[test]
+    fn tuner_grid_search_finds_best_configuration() {
+        let tuner = Tuner::new(5, 42);
+        let configs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let instances = [1.8, 2.0, 2.2];
+
+        let report = tuner.grid_search(&configs, &instances, evaluate);
+        let best = report.best().unwrap();
+
+        assert_eq!(best.config, 2.0);
+        assert_eq!(best.costs.len(), 15);
+        assert!(best.confidence_interval.0 <= best.mean_cost);
+        assert!(best.confidence_interval.1 >= best.mean_cost);
+        assert!(report.is_best_significant());
+    }
+
+    #[test]
+    fn tuner_random_search_evaluates_requested_number_of_samples() {
+        let tuner = Tuner::new(3, 7);
+        let instances = [5.0];
+
+        let report = tuner.random_search(20, |rng| rng.gen_f64() * 10.0, &instances, evaluate);
+
+        assert_eq!(report.evaluations.len(), 20);
+        let best = report.best().unwrap();
+        assert!(
+            (best.config - 5.0).abs() < 1.5,
+            "best config {}",
+            best.config
+        );
+        assert!(report
+            .evaluations
+            .windows(2)
+            .all(|w| w[0].mean_cost <= w[1].mean_cost));
+    }
+
+    #[test]
+    fn tuner_uses_same_seeds_for_every_configuration() {
+        let tuner = Tuner::new(4, 1);
+
+        let report = tuner.grid_search(&[1.0, 1.0], &[1.0], evaluate);
+
+        assert_eq!(report.evaluations[0].costs, report.evaluations[1].costs);
+    }
+}
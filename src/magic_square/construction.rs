@@ -0,0 +1,81 @@
+use super::{MagicSquare, MagicSquareError};
+
+/// [`construct`]
+/// Direct construction for odd ([`siamese`]) and doubly-even ([`doubly_even`]) orders.
+/// Singly-even orders (6, 10, ...) are not supported, use the [`search`](super::search)
+/// for small ones.
+pub fn construct(order: usize) -> Result<MagicSquare, MagicSquareError> {
+    match order {
+        n if n % 2 == 1 => Ok(siamese(n)),
+        n if n > 0 && n.is_multiple_of(4) => Ok(doubly_even(n)),
+        n => Err(MagicSquareError::UnsupportedOrder(n)),
+    }
+}
+
+/// [`siamese`]
+/// ## De la Loubère (siamese) method, for odd orders.
+/// Starts with 1 in the middle of the top row and places every next number up and to the
+/// right (wrapping around the edges), moving down instead when that cell is taken.
+pub fn siamese(order: usize) -> MagicSquare {
+    assert!(
+        order % 2 == 1,
+        "the siamese method only works for odd orders"
+    );
+    let n = order;
+    let mut cells = vec![0; n * n];
+    let (mut row, mut column) = (0, n / 2);
+    for number in 1..=(n * n) as u32 {
+        cells[row * n + column] = number;
+        let (up, right) = ((row + n - 1) % n, (column + 1) % n);
+        if cells[up * n + right] == 0 {
+            (row, column) = (up, right);
+        } else {
+            row = (row + 1) % n;
+        }
+    }
+    MagicSquare::new(n, cells).expect("cells has order * order elements")
+}
+
+/// [`doubly_even`]
+/// ## Construction for orders multiple of 4.
+/// Writes `1..=n²` in reading order, then replaces every number on the diagonals of each
+/// 4x4 block by its complement `n² + 1 - k`.
+pub fn doubly_even(order: usize) -> MagicSquare {
+    assert!(
+        order > 0 && order.is_multiple_of(4),
+        "order must be a multiple of 4"
+    );
+    let n = order;
+    let cells = (0..n * n)
+        .map(|index| {
+            let (row, column) = (index / n % 4, index % n % 4);
+            let number = index as u32 + 1;
+            if row == column || row + column == 3 {
+                (n * n) as u32 + 1 - number
+            } else {
+                number
+            }
+        })
+        .collect();
+    MagicSquare::new(n, cells).expect("cells has order * order elements")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn magic_square_constructions_are_magic() {
+        for order in [1, 3, 4, 5, 7, 8, 9, 12] {
+            assert_eq!(
+                construct(order).unwrap().verify(),
+                Ok(()),
+                "order {}",
+                order
+            );
+        }
+        assert_eq!(siamese(3).to_string(), "8 1 6\n3 5 7\n4 9 2\n");
+        assert_eq!(construct(6), Err(MagicSquareError::UnsupportedOrder(6)));
+        assert_eq!(construct(0), Err(MagicSquareError::UnsupportedOrder(0)));
+    }
+}
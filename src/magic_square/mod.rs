@@ -0,0 +1,30 @@
+pub mod construction;
+pub mod search;
+pub mod square;
+
+pub use construction::*;
+pub use search::*;
+pub use square::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum MagicSquareError {
+    #[error("Square must have order * order cells")]
+    InvalidShape,
+    #[error("Square must contain every number from 1 to order^2 exactly once")]
+    NotAPermutation,
+    #[error("Row does not add up to the magic constant")]
+    Row(usize, u32),
+    #[error("Column does not add up to the magic constant")]
+    Column(usize, u32),
+    #[error("Diagonal does not add up to the magic constant")]
+    Diagonal(u32),
+    #[error("Anti-diagonal does not add up to the magic constant")]
+    AntiDiagonal(u32),
+    #[error("No direct construction for this order")]
+    UnsupportedOrder(usize),
+    #[error("Error when trying to parse from MagicSquare string")]
+    ParseFromStringError(String),
+}
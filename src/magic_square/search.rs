@@ -0,0 +1,119 @@
+use super::{magic_constant, MagicSquare};
+
+/// [`search`]
+/// ## Backtracking search for a magic square of any order.
+/// Cells are filled in reading order. The last cell of every row and column is not
+/// chosen but computed from the magic constant, and a partial row or column is abandoned
+/// as soon as the numbers left can't complete it (too small or too big).
+/// Practical up to order 4, use [`construct`](super::construct) for bigger ones. Orders 0
+/// and 2 have no magic square and return `None`.
+pub fn search(order: usize) -> Option<MagicSquare> {
+    if order == 0 {
+        return None;
+    }
+    let mut search = Search {
+        n: order,
+        constant: magic_constant(order),
+        cells: vec![0; order * order],
+        used: vec![false; order * order + 1],
+    };
+    search
+        .fill(0)
+        .then(|| MagicSquare::new(order, search.cells).expect("cells has order * order elements"))
+}
+
+struct Search {
+    n: usize,
+    constant: u32,
+    cells: Vec<u32>,
+    used: Vec<bool>,
+}
+
+impl Search {
+    fn fill(&mut self, index: usize) -> bool {
+        let n = self.n;
+        if index == n * n {
+            return self.diagonals_ok();
+        }
+        let (row, column) = (index / n, index % n);
+
+        let candidates = if column == n - 1 {
+            self.remaining(self.row_sum(row)).into_iter().collect()
+        } else if row == n - 1 {
+            self.remaining(self.column_sum(column))
+                .into_iter()
+                .collect()
+        } else {
+            (1..=(n * n) as u32)
+                .filter(|k| !self.used[*k as usize])
+                .collect::<Vec<u32>>()
+        };
+
+        for number in candidates {
+            if self.used[number as usize] {
+                continue;
+            }
+            self.cells[index] = number;
+            self.used[number as usize] = true;
+            if self.consistent(row, column) && self.fill(index + 1) {
+                return true;
+            }
+            self.used[number as usize] = false;
+            self.cells[index] = 0;
+        }
+        false
+    }
+
+    /// The number that completes a line whose other cells add up to `partial`.
+    fn remaining(&self, partial: u32) -> Option<u32> {
+        self.constant
+            .checked_sub(partial)
+            .filter(|k| (1..=(self.n * self.n) as u32).contains(k))
+    }
+
+    fn row_sum(&self, row: usize) -> u32 {
+        self.cells[row * self.n..(row + 1) * self.n].iter().sum()
+    }
+
+    fn column_sum(&self, column: usize) -> u32 {
+        (0..self.n).map(|r| self.cells[r * self.n + column]).sum()
+    }
+
+    /// Whether the row and column of the last placed cell can still reach the constant.
+    fn consistent(&self, row: usize, column: usize) -> bool {
+        let n = self.n;
+        let max = (n * n) as u32;
+        let feasible = |sum: u32, filled: usize| {
+            let left = (n - filled) as u32;
+            // the smallest and biggest sums `left` distinct numbers can have.
+            let low = left * (left + 1) / 2;
+            let high = left * (2 * max - left + 1) / 2;
+            sum + low <= self.constant && sum + high >= self.constant
+        };
+        let row_ok = column == n - 1 || feasible(self.row_sum(row), column + 1);
+        let column_ok = row == n - 1 || feasible(self.column_sum(column), row + 1);
+        row_ok && column_ok
+    }
+
+    fn diagonals_ok(&self) -> bool {
+        let n = self.n;
+        let diagonal = (0..n).map(|i| self.cells[i * n + i]).sum::<u32>();
+        let anti_diagonal = (0..n).map(|i| self.cells[i * n + n - 1 - i]).sum::<u32>();
+        diagonal == self.constant && anti_diagonal == self.constant
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn magic_square_search_finds_magic_squares() {
+        for order in [1, 3, 4] {
+            let square = search(order).unwrap();
+            assert_eq!(square.verify(), Ok(()), "order {}", order);
+        }
+        assert_eq!(search(2), None);
+        assert_eq!(search(0), None);
+    }
+}
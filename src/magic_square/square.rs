@@ -0,0 +1,138 @@
+use std::fmt::Display;
+
+use super::MagicSquareError;
+
+/// [`magic_constant`]
+/// Sum of every row, column and diagonal of a magic square of order `n`: `n(n² + 1) / 2`.
+pub fn magic_constant(order: usize) -> u32 {
+    (order * (order * order + 1) / 2) as u32
+}
+
+/// [`MagicSquare`]
+/// An `order x order` grid of numbers, not necessarily magic (see [`MagicSquare::verify`]).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MagicSquare {
+    order: usize,
+    cells: Vec<u32>,
+}
+
+impl MagicSquare {
+    pub fn new(order: usize, cells: Vec<u32>) -> Result<Self, MagicSquareError> {
+        if order == 0 || cells.len() != order * order {
+            return Err(MagicSquareError::InvalidShape);
+        }
+        Ok(Self { order, cells })
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    pub fn cells(&self) -> &[u32] {
+        &self.cells
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> u32 {
+        self.cells[row * self.order + column]
+    }
+
+    /// [`verify`]
+    /// ## Checks that the square is a normal magic square.
+    /// It must contain `1..=order²` exactly once, and every row, column and both
+    /// diagonals must add up to [`magic_constant`]. The error tells the first line that
+    /// fails and its sum.
+    pub fn verify(&self) -> Result<(), MagicSquareError> {
+        let n = self.order;
+        let mut sorted = self.cells.clone();
+        sorted.sort();
+        if sorted != (1..=(n * n) as u32).collect::<Vec<u32>>() {
+            return Err(MagicSquareError::NotAPermutation);
+        }
+        let constant = magic_constant(n);
+        for i in 0..n {
+            let row = (0..n).map(|c| self.get(i, c)).sum::<u32>();
+            if row != constant {
+                return Err(MagicSquareError::Row(i, row));
+            }
+            let column = (0..n).map(|r| self.get(r, i)).sum::<u32>();
+            if column != constant {
+                return Err(MagicSquareError::Column(i, column));
+            }
+        }
+        let diagonal = (0..n).map(|i| self.get(i, i)).sum::<u32>();
+        if diagonal != constant {
+            return Err(MagicSquareError::Diagonal(diagonal));
+        }
+        let anti_diagonal = (0..n).map(|i| self.get(i, n - 1 - i)).sum::<u32>();
+        if anti_diagonal != constant {
+            return Err(MagicSquareError::AntiDiagonal(anti_diagonal));
+        }
+        Ok(())
+    }
+
+    pub fn is_magic(&self) -> bool {
+        self.verify().is_ok()
+    }
+}
+
+/// [TryFrom<&str>]
+/// One line per row with numbers separated by spaces, the order is the number of rows.
+impl TryFrom<&str> for MagicSquare {
+    type Error = MagicSquareError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let rows = value
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|n| n.parse::<u32>())
+                    .collect::<Result<Vec<u32>, _>>()
+            })
+            .collect::<Result<Vec<Vec<u32>>, _>>()
+            .map_err(|_| MagicSquareError::ParseFromStringError("Invalid number".into()))?;
+        if rows.iter().any(|row| row.len() != rows.len()) {
+            return Err(MagicSquareError::InvalidShape);
+        }
+        MagicSquare::new(rows.len(), rows.concat())
+    }
+}
+
+impl Display for MagicSquare {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = (self.order * self.order).to_string().len();
+        for row in self.cells.chunks(self.order) {
+            let row = row
+                .iter()
+                .map(|n| format!("{:>width$}", n, width = width))
+                .collect::<Vec<String>>();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn magic_square_verify_reports_failing_line() {
+        let lo_shu = MagicSquare::try_from("2 7 6\n9 5 1\n4 3 8").unwrap();
+        assert_eq!(lo_shu.verify(), Ok(()));
+        assert_eq!(magic_constant(3), 15);
+
+        let rows_only = MagicSquare::try_from("2 7 6\n1 5 9\n4 3 8").unwrap();
+        assert_eq!(rows_only.verify(), Err(MagicSquareError::Column(0, 7)));
+
+        let not_magic = MagicSquare::try_from("1 2 3\n4 5 6\n7 8 9").unwrap();
+        assert_eq!(not_magic.verify(), Err(MagicSquareError::Row(0, 6)));
+
+        let repeated = MagicSquare::try_from("5 5 5\n5 5 5\n5 5 5").unwrap();
+        assert_eq!(repeated.verify(), Err(MagicSquareError::NotAPermutation));
+        assert_eq!(
+            MagicSquare::try_from("1 2\n3"),
+            Err(MagicSquareError::InvalidShape)
+        );
+    }
+}
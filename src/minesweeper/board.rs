@@ -0,0 +1,134 @@
+use std::fmt::Display;
+
+use super::MinesweeperError;
+
+/// [`Cell`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Hidden,
+    /// A hidden cell marked by the player, treated as a known mine.
+    Flagged,
+    /// Number of mines among the (up to 8) neighbors.
+    Revealed(u8),
+}
+
+/// [`MinesweeperBoard`]
+/// Partially revealed minesweeper board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinesweeperBoard {
+    rows: usize,
+    columns: usize,
+    cells: Vec<Cell>,
+}
+
+impl MinesweeperBoard {
+    pub fn new(rows: Vec<Vec<Cell>>) -> Result<Self, MinesweeperError> {
+        let columns = rows.first().map(|row| row.len()).unwrap_or(0);
+        if columns == 0 || rows.iter().any(|row| row.len() != columns) {
+            return Err(MinesweeperError::InvalidShape);
+        }
+        Ok(Self {
+            rows: rows.len(),
+            columns,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Cell {
+        self.cells[row * self.columns + column]
+    }
+
+    /// Positions of the (up to 8) cells around `(row, column)`.
+    pub fn neighbors(&self, row: usize, column: usize) -> Vec<(usize, usize)> {
+        let mut neighbors = vec![];
+        for r in row.saturating_sub(1)..=(row + 1).min(self.rows - 1) {
+            for c in column.saturating_sub(1)..=(column + 1).min(self.columns - 1) {
+                if (r, c) != (row, column) {
+                    neighbors.push((r, c));
+                }
+            }
+        }
+        neighbors
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.rows).flat_map(|r| (0..self.columns).map(move |c| (r, c)))
+    }
+}
+
+/// [TryFrom<&str>]
+/// One line per row: `#` is a hidden cell, `F` a flag, `.` or `0` an empty revealed cell
+/// and `1`..`8` a revealed number. Spaces are ignored.
+impl TryFrom<&str> for MinesweeperBoard {
+    type Error = MinesweeperError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let rows = value
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .filter(|c| !c.is_whitespace())
+                    .map(|c| match c {
+                        '#' => Ok(Cell::Hidden),
+                        'F' => Ok(Cell::Flagged),
+                        '.' => Ok(Cell::Revealed(0)),
+                        '0'..='8' => Ok(Cell::Revealed(c as u8 - b'0')),
+                        _ => Err(MinesweeperError::ParseFromStringError(format!(
+                            "Invalid cell {}",
+                            c
+                        ))),
+                    })
+                    .collect::<Result<Vec<Cell>, _>>()
+            })
+            .filter(|row| !matches!(row, Ok(row) if row.is_empty()))
+            .collect::<Result<Vec<Vec<Cell>>, _>>()?;
+        MinesweeperBoard::new(rows)
+    }
+}
+
+impl Display for MinesweeperBoard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.columns) {
+            let row = row
+                .iter()
+                .map(|cell| match cell {
+                    Cell::Hidden => '#',
+                    Cell::Flagged => 'F',
+                    Cell::Revealed(0) => '.',
+                    Cell::Revealed(n) => (b'0' + n) as char,
+                })
+                .collect::<String>();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn minesweeper_try_from_round_trips() {
+        let text = "1#F\n.23\n";
+        let board = MinesweeperBoard::try_from(text).unwrap();
+
+        assert_eq!(board.get(0, 2), Cell::Flagged);
+        assert_eq!(board.get(1, 2), Cell::Revealed(3));
+        assert_eq!(board.to_string(), text);
+        assert_eq!(board.neighbors(0, 0), vec![(0, 1), (1, 0), (1, 1)]);
+        assert!(MinesweeperBoard::try_from("1x").is_err());
+        assert_eq!(
+            MinesweeperBoard::try_from("11\n1"),
+            Err(MinesweeperError::InvalidShape)
+        );
+    }
+}
@@ -0,0 +1,445 @@
+use std::collections::HashMap;
+
+use super::{Cell, MinesweeperBoard, MinesweeperError};
+
+/// [`Analysis`]
+/// What can be deduced about the hidden cells of a board.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// Hidden cells that are mine-free in every placement consistent with the board.
+    pub safe: Vec<(usize, usize)>,
+    /// Hidden cells that hold a mine in every consistent placement.
+    pub mines: Vec<(usize, usize)>,
+    /// Probability of a mine for every hidden (not flagged) cell whose probability is
+    /// known, sorted by position.
+    /// Cells away from the numbers (not on the frontier) only get a probability when the
+    /// total number of mines is known.
+    pub probabilities: Vec<((usize, usize), f64)>,
+}
+
+impl Analysis {
+    /// The hidden cell least likely to hold a mine.
+    pub fn safest_cell(&self) -> Option<(usize, usize)> {
+        self.probabilities
+            .iter()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(position, _)| *position)
+    }
+}
+
+/// [`analyze`]
+/// ## Minesweeper inference by model counting.
+/// Every revealed number is a constraint: its hidden neighbors hold exactly "number minus
+/// flagged neighbors" mines. The hidden cells touching a number (the frontier) are split
+/// into independent groups that share no constraint, and every consistent mine placement
+/// of each group is enumerated by backtracking.
+///
+/// Without `total_mines` every consistent placement of a group is equally likely. With it,
+/// placements are weighted by the number of ways the remaining mines can be spread over
+/// the cells away from the frontier, which also gives those cells a probability.
+///
+/// The enumeration is exponential in the size of a group, fine for real game positions
+/// where groups rarely have more than a few dozen cells.
+pub fn analyze(
+    board: &MinesweeperBoard,
+    total_mines: Option<usize>,
+) -> Result<Analysis, MinesweeperError> {
+    let constraints = constraints(board)?;
+
+    let mut frontier = constraints
+        .iter()
+        .flat_map(|constraint| constraint.cells.iter().copied())
+        .collect::<Vec<(usize, usize)>>();
+    frontier.sort();
+    frontier.dedup();
+    let others = board
+        .positions()
+        .filter(|(r, c)| {
+            board.get(*r, *c) == Cell::Hidden && frontier.binary_search(&(*r, *c)).is_err()
+        })
+        .collect::<Vec<(usize, usize)>>();
+
+    let groups = split_groups(&frontier, &constraints)
+        .into_iter()
+        .map(|(cells, constraints)| count_models(cells, &constraints))
+        .collect::<Vec<GroupCount>>();
+    if groups.iter().any(|group| group.total() == 0.0) {
+        return Err(MinesweeperError::Inconsistent);
+    }
+
+    let mut probabilities = HashMap::new();
+    match total_mines {
+        None => {
+            for group in &groups {
+                let total = group.total();
+                for (index, cell) in group.cells.iter().enumerate() {
+                    let mines = group
+                        .cell_counts
+                        .iter()
+                        .map(|counts| counts[index])
+                        .sum::<f64>();
+                    probabilities.insert(*cell, mines / total);
+                }
+            }
+        }
+        Some(total_mines) => {
+            let flagged = board
+                .positions()
+                .filter(|(r, c)| board.get(*r, *c) == Cell::Flagged)
+                .count();
+            let remaining = total_mines
+                .checked_sub(flagged)
+                .ok_or(MinesweeperError::Inconsistent)?;
+            let rest = others.len();
+            // weight of a frontier placement with `k` mines.
+            let weight = |k: usize| match remaining.checked_sub(k) {
+                Some(m) if m <= rest => binomial(rest, m),
+                _ => 0.0,
+            };
+
+            let all = groups
+                .iter()
+                .fold(vec![1.0], |acc, group| convolve(&acc, &group.counts));
+            let total = all
+                .iter()
+                .enumerate()
+                .map(|(k, count)| count * weight(k))
+                .sum::<f64>();
+            if total == 0.0 {
+                return Err(MinesweeperError::Inconsistent);
+            }
+
+            for (g, group) in groups.iter().enumerate() {
+                let without = groups
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, _)| *other != g)
+                    .fold(vec![1.0], |acc, (_, other)| convolve(&acc, &other.counts));
+                for (index, cell) in group.cells.iter().enumerate() {
+                    let mut mines = 0.0;
+                    for (k, counts) in group.cell_counts.iter().enumerate() {
+                        for (j, ways) in without.iter().enumerate() {
+                            mines += counts[index] * ways * weight(k + j);
+                        }
+                    }
+                    probabilities.insert(*cell, mines / total);
+                }
+            }
+            if rest > 0 {
+                // by symmetry every cell away from the frontier has the same probability.
+                let mines = all
+                    .iter()
+                    .enumerate()
+                    .map(|(k, count)| count * weight(k) * remaining.saturating_sub(k) as f64)
+                    .sum::<f64>()
+                    / rest as f64;
+                for cell in &others {
+                    probabilities.insert(*cell, mines / total);
+                }
+            }
+        }
+    }
+
+    let mut probabilities = probabilities.into_iter().collect::<Vec<_>>();
+    probabilities.sort_by_key(|(cell, _)| *cell);
+    Ok(Analysis {
+        safe: probabilities
+            .iter()
+            .filter(|(_, p)| *p == 0.0)
+            .map(|(cell, _)| *cell)
+            .collect(),
+        mines: probabilities
+            .iter()
+            .filter(|(_, p)| *p == 1.0)
+            .map(|(cell, _)| *cell)
+            .collect(),
+        probabilities,
+    })
+}
+
+struct Constraint {
+    cells: Vec<(usize, usize)>,
+    mines: usize,
+}
+
+fn constraints(board: &MinesweeperBoard) -> Result<Vec<Constraint>, MinesweeperError> {
+    let mut constraints = vec![];
+    for (row, column) in board.positions() {
+        let Cell::Revealed(number) = board.get(row, column) else {
+            continue;
+        };
+        let neighbors = board.neighbors(row, column);
+        let flagged = neighbors
+            .iter()
+            .filter(|(r, c)| board.get(*r, *c) == Cell::Flagged)
+            .count();
+        let cells = neighbors
+            .into_iter()
+            .filter(|(r, c)| board.get(*r, *c) == Cell::Hidden)
+            .collect::<Vec<(usize, usize)>>();
+        let mines = usize::from(number)
+            .checked_sub(flagged)
+            .filter(|mines| *mines <= cells.len())
+            .ok_or(MinesweeperError::Inconsistent)?;
+        if !cells.is_empty() {
+            constraints.push(Constraint { cells, mines });
+        }
+    }
+    Ok(constraints)
+}
+
+/// Cells of a group and its constraints as (indices into the cells, number of mines).
+type Group = (Vec<(usize, usize)>, Vec<(Vec<usize>, usize)>);
+
+/// Connected components of the frontier, two cells are connected when they appear in the
+/// same constraint. Constraints are rewritten with indices into their group's cells.
+fn split_groups(frontier: &[(usize, usize)], constraints: &[Constraint]) -> Vec<Group> {
+    let index = |cell: &(usize, usize)| frontier.binary_search(cell).unwrap();
+    let mut parent = (0..frontier.len()).collect::<Vec<usize>>();
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+    for constraint in constraints {
+        let first = index(&constraint.cells[0]);
+        for cell in &constraint.cells[1..] {
+            let (a, b) = (root(&mut parent, first), root(&mut parent, index(cell)));
+            parent[a] = b;
+        }
+    }
+
+    let mut groups: HashMap<usize, Group> = HashMap::new();
+    let mut position_in_group = vec![0; frontier.len()];
+    for (i, cell) in frontier.iter().enumerate() {
+        let group = groups.entry(root(&mut parent, i)).or_default();
+        position_in_group[i] = group.0.len();
+        group.0.push(*cell);
+    }
+    for constraint in constraints {
+        let group = root(&mut parent, index(&constraint.cells[0]));
+        let cells = constraint
+            .cells
+            .iter()
+            .map(|cell| position_in_group[index(cell)])
+            .collect();
+        groups
+            .get_mut(&group)
+            .unwrap()
+            .1
+            .push((cells, constraint.mines));
+    }
+    let mut groups = groups.into_values().collect::<Vec<_>>();
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+    groups
+}
+
+/// Placement counts of a group, indexed by the number of mines in the placement.
+struct GroupCount {
+    cells: Vec<(usize, usize)>,
+    counts: Vec<f64>,
+    /// `cell_counts[k][i]`: placements with `k` mines where cell `i` holds a mine.
+    cell_counts: Vec<Vec<f64>>,
+}
+
+impl GroupCount {
+    fn total(&self) -> f64 {
+        self.counts.iter().sum()
+    }
+}
+
+fn count_models(cells: Vec<(usize, usize)>, constraints: &[(Vec<usize>, usize)]) -> GroupCount {
+    let n = cells.len();
+    let mut constraints_of = vec![vec![]; n];
+    for (c, (members, _)) in constraints.iter().enumerate() {
+        for member in members {
+            constraints_of[*member].push(c);
+        }
+    }
+    let mut search = ModelCount {
+        constraints,
+        constraints_of,
+        mines: vec![0; constraints.len()],
+        unassigned: constraints
+            .iter()
+            .map(|(members, _)| members.len())
+            .collect(),
+        assignment: vec![false; n],
+        counts: vec![0.0; n + 1],
+        cell_counts: vec![vec![0.0; n]; n + 1],
+    };
+    search.assign(0);
+    GroupCount {
+        cells,
+        counts: search.counts,
+        cell_counts: search.cell_counts,
+    }
+}
+
+struct ModelCount<'a> {
+    constraints: &'a [(Vec<usize>, usize)],
+    constraints_of: Vec<Vec<usize>>,
+    /// mines already placed and cells still unassigned in every constraint.
+    mines: Vec<usize>,
+    unassigned: Vec<usize>,
+    assignment: Vec<bool>,
+    counts: Vec<f64>,
+    cell_counts: Vec<Vec<f64>>,
+}
+
+impl ModelCount<'_> {
+    fn assign(&mut self, cell: usize) {
+        if cell == self.assignment.len() {
+            let k = self.assignment.iter().filter(|mine| **mine).count();
+            self.counts[k] += 1.0;
+            for (i, mine) in self.assignment.iter().enumerate() {
+                if *mine {
+                    self.cell_counts[k][i] += 1.0;
+                }
+            }
+            return;
+        }
+        for mine in [false, true] {
+            self.assignment[cell] = mine;
+            let mut consistent = true;
+            for c in &self.constraints_of[cell] {
+                self.unassigned[*c] -= 1;
+                self.mines[*c] += usize::from(mine);
+                let target = self.constraints[*c].1;
+                consistent &=
+                    self.mines[*c] <= target && self.mines[*c] + self.unassigned[*c] >= target;
+            }
+            if consistent {
+                self.assign(cell + 1);
+            }
+            for c in &self.constraints_of[cell] {
+                self.unassigned[*c] += 1;
+                self.mines[*c] -= usize::from(mine);
+            }
+        }
+        self.assignment[cell] = false;
+    }
+}
+
+fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut result = vec![0.0; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] += x * y;
+        }
+    }
+    result
+}
+
+fn binomial(n: usize, k: usize) -> f64 {
+    (0..k.min(n - k)).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn minesweeper_analyze_deduces_safe_cells_and_mines() {
+        // the zeros clear (1, 1), so the mine next to the top `1` must be (0, 1).
+        let board = MinesweeperBoard::try_from("1#\n1#\n..").unwrap();
+        let analysis = analyze(&board, None).unwrap();
+        assert_eq!(analysis.mines, vec![(0, 1)]);
+        assert_eq!(analysis.safe, vec![(1, 1)]);
+
+        // only the middle cell is shared by all three numbers.
+        let board = MinesweeperBoard::try_from("1#\n1#\n1#").unwrap();
+        let analysis = analyze(&board, None).unwrap();
+        assert_eq!(analysis.mines, vec![(1, 1)]);
+        assert_eq!(analysis.safe, vec![(0, 1), (2, 1)]);
+        assert_eq!(analysis.safest_cell(), Some((0, 1)));
+    }
+
+    #[test]
+    fn minesweeper_analyze_reports_probabilities() {
+        // one mine hidden in one of two cells.
+        let board = MinesweeperBoard::try_from("1#\n1#").unwrap();
+        let analysis = analyze(&board, None).unwrap();
+        assert_eq!(analysis.probabilities, vec![((0, 1), 0.5), ((1, 1), 0.5)]);
+        assert!(analysis.safe.is_empty() && analysis.mines.is_empty());
+
+        // the third column is away from the frontier and only gets a probability when the
+        // total is known: one frontier mine and one of the two other cells.
+        let board = MinesweeperBoard::try_from("1##\n1##").unwrap();
+        assert_eq!(analyze(&board, None).unwrap().probabilities.len(), 2);
+        let analysis = analyze(&board, Some(2)).unwrap();
+        assert_eq!(analysis.probabilities.len(), 4);
+        assert!(analysis.probabilities.iter().all(|(_, p)| *p == 0.5));
+    }
+
+    #[test]
+    fn minesweeper_analyze_matches_brute_force_with_total_mines() {
+        let board = MinesweeperBoard::try_from("1###\n2###\n#1##").unwrap();
+        let total_mines = 4;
+        let hidden = board
+            .positions()
+            .filter(|(r, c)| board.get(*r, *c) == Cell::Hidden)
+            .collect::<Vec<(usize, usize)>>();
+        let mut consistent = 0.0;
+        let mut mine_counts = vec![0.0; hidden.len()];
+        for mask in 0u32..1 << hidden.len() {
+            if mask.count_ones() as usize != total_mines {
+                continue;
+            }
+            let is_mine = |cell: &(usize, usize)| {
+                hidden
+                    .iter()
+                    .position(|h| h == cell)
+                    .is_some_and(|i| mask & (1 << i) != 0)
+            };
+            let agrees = board.positions().all(|(r, c)| match board.get(r, c) {
+                Cell::Revealed(n) => {
+                    board
+                        .neighbors(r, c)
+                        .iter()
+                        .filter(|cell| is_mine(cell))
+                        .count()
+                        == n as usize
+                }
+                _ => true,
+            });
+            if agrees {
+                consistent += 1.0;
+                for (i, cell) in hidden.iter().enumerate() {
+                    if is_mine(cell) {
+                        mine_counts[i] += 1.0;
+                    }
+                }
+            }
+        }
+
+        let analysis = analyze(&board, Some(total_mines)).unwrap();
+
+        assert_eq!(analysis.probabilities.len(), hidden.len());
+        for ((cell, probability), (hidden_cell, mines)) in analysis
+            .probabilities
+            .iter()
+            .zip(hidden.iter().zip(mine_counts))
+        {
+            assert_eq!(cell, hidden_cell);
+            assert!((probability - mines / consistent).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn minesweeper_analyze_detects_inconsistent_boards() {
+        let board = MinesweeperBoard::try_from("2#\n..").unwrap();
+        assert_eq!(analyze(&board, None), Err(MinesweeperError::Inconsistent));
+
+        let board = MinesweeperBoard::try_from("1#\n1#\n.1").unwrap();
+        assert_eq!(analyze(&board, None), Err(MinesweeperError::Inconsistent));
+
+        let board = MinesweeperBoard::try_from("1#\n1#").unwrap();
+        assert_eq!(
+            analyze(&board, Some(0)),
+            Err(MinesweeperError::Inconsistent)
+        );
+    }
+}
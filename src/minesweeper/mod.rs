@@ -0,0 +1,18 @@
+pub mod board;
+pub mod inference;
+
+pub use board::*;
+pub use inference::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum MinesweeperError {
+    #[error("Every row of the board must have the same number of cells")]
+    InvalidShape,
+    #[error("Error when trying to parse from MinesweeperBoard string")]
+    ParseFromStringError(String),
+    #[error("No placement of mines agrees with the revealed numbers")]
+    Inconsistent,
+}
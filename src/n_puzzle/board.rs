@@ -0,0 +1,420 @@
+use std::fmt::Display;
+
+use thiserror::Error;
+
+use crate::rng::Rng;
+
+pub type BoardResult = Result<Board, NPuzzleError>;
+
+/// [`Slide`]
+/// Direction in which the blank moves (the tile on that side slides into the blank).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Slide {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Slide {
+    pub const ALL: [Slide; 4] = [Slide::Up, Slide::Down, Slide::Left, Slide::Right];
+
+    pub fn opposite(&self) -> Slide {
+        match self {
+            Slide::Up => Slide::Down,
+            Slide::Down => Slide::Up,
+            Slide::Left => Slide::Right,
+            Slide::Right => Slide::Left,
+        }
+    }
+}
+
+/// [`Board`]
+/// ## An N-puzzle board (8-puzzle, 15-puzzle, ...).
+/// Tiles are stored row by row, `0` is the blank.
+/// The goal board has the tiles in increasing order with the blank at the bottom right.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Board {
+    size: usize,
+    tiles: Vec<u8>,
+}
+
+impl Board {
+    /// [`new`]
+    /// Creates a `size x size` board, `tiles` must be a permutation of `0..size * size`.
+    pub fn new(size: usize, tiles: Vec<u8>) -> BoardResult {
+        check_size(size)?;
+        if tiles.len() != size * size {
+            return Err(NPuzzleError::InvalidTiles(
+                "Number of tiles does not match the board size".into(),
+            ));
+        }
+        let mut seen = vec![false; tiles.len()];
+        for tile in &tiles {
+            match seen.get_mut(usize::from(*tile)) {
+                Some(seen) if !*seen => *seen = true,
+                Some(_) => {
+                    return Err(NPuzzleError::InvalidTiles(format!(
+                        "Repeated tile {}",
+                        tile
+                    )))
+                }
+                None => return Err(NPuzzleError::InvalidTiles(format!("Tile {} too big", tile))),
+            }
+        }
+        Ok(Self { size, tiles })
+    }
+
+    /// The solved `size x size` board, sizes go from 2 to 15.
+    pub fn goal(size: usize) -> BoardResult {
+        check_size(size)?;
+        Ok(Self {
+            size,
+            tiles: goal_tiles(size),
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn tiles(&self) -> &[u8] {
+        &self.tiles
+    }
+
+    pub fn is_goal(&self) -> bool {
+        self.tiles == goal_tiles(self.size)
+    }
+
+    pub fn blank_position(&self) -> usize {
+        self.tiles
+            .iter()
+            .position(|tile| *tile == 0)
+            .expect("a valid board always has a blank")
+    }
+
+    /// [`slide`]
+    /// Moves the blank in the given direction, returns `None` if it would leave the board.
+    pub fn slide(&self, slide: Slide) -> Option<Board> {
+        let blank = self.blank_position();
+        let (row, col) = (blank / self.size, blank % self.size);
+        let target = match slide {
+            Slide::Up if row > 0 => blank - self.size,
+            Slide::Down if row + 1 < self.size => blank + self.size,
+            Slide::Left if col > 0 => blank - 1,
+            Slide::Right if col + 1 < self.size => blank + 1,
+            _ => return None,
+        };
+        let mut tiles = self.tiles.clone();
+        tiles.swap(blank, target);
+        Some(Self {
+            size: self.size,
+            tiles,
+        })
+    }
+
+    /// [`get_child_boards`]
+    /// All boards reachable with a single slide.
+    pub fn get_child_boards(&self) -> Vec<(Slide, Board)> {
+        Slide::ALL
+            .into_iter()
+            .filter_map(|slide| self.slide(slide).map(|board| (slide, board)))
+            .collect()
+    }
+
+    /// [`inversions`]
+    /// Number of pairs of (non blank) tiles that are in the wrong relative order.
+    pub fn inversions(&self) -> usize {
+        let tiles = self
+            .tiles
+            .iter()
+            .filter(|tile| **tile != 0)
+            .collect::<Vec<&u8>>();
+        (0..tiles.len())
+            .map(|i| tiles[i + 1..].iter().filter(|t| **t < tiles[i]).count())
+            .sum()
+    }
+
+    /// [`is_solvable`]
+    /// ## Parity based solvability check.
+    /// * odd width: solvable iff the number of inversions is even;
+    /// * even width: solvable iff inversions + row of the blank counted from the bottom
+    ///   (starting at 1) is odd.
+    /// # Example
+    /// ```
+    /// # use algoritmos_rust::n_puzzle::*;
+    /// let solvable: BoardResult = "1 2 3 4 5 6 7 0 8".try_into();
+    /// let unsolvable: BoardResult = "2 1 3 4 5 6 7 8 0".try_into();
+    /// assert!(solvable.unwrap().is_solvable());
+    /// assert!(!unsolvable.unwrap().is_solvable());
+    /// ```
+    pub fn is_solvable(&self) -> bool {
+        let inversions = self.inversions();
+        if !self.size.is_multiple_of(2) {
+            inversions.is_multiple_of(2)
+        } else {
+            let blank_row_from_bottom = self.size - self.blank_position() / self.size;
+            !(inversions + blank_row_from_bottom).is_multiple_of(2)
+        }
+    }
+
+    /// [`random_solvable`]
+    /// ## Shuffled but always solvable board.
+    /// Shuffles all the tiles uniformly and, if the result is unsolvable, swaps two non
+    /// blank tiles, which flips the permutation parity and makes it solvable.
+    pub fn random_solvable(size: usize, rng: &mut Rng) -> BoardResult {
+        let mut tiles = Self::goal(size)?.tiles;
+        rng.shuffle(&mut tiles);
+        let mut board = Self { size, tiles };
+        if !board.is_solvable() {
+            let non_blank = board
+                .tiles
+                .iter()
+                .enumerate()
+                .filter(|(_, tile)| **tile != 0)
+                .map(|(i, _)| i)
+                .take(2)
+                .collect::<Vec<usize>>();
+            board.tiles.swap(non_blank[0], non_blank[1]);
+        }
+        Ok(board)
+    }
+
+    /// [`misplaced_tiles`]
+    /// Number of non blank tiles that are not in their goal position.
+    pub fn misplaced_tiles(&self) -> usize {
+        self.tiles
+            .iter()
+            .enumerate()
+            .filter(|(i, tile)| **tile != 0 && usize::from(**tile) != i + 1)
+            .count()
+    }
+
+    /// [`manhattan_distance`]
+    /// Sum of the horizontal and vertical distances of every tile to its goal position.
+    pub fn manhattan_distance(&self) -> usize {
+        self.tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, tile)| **tile != 0)
+            .map(|(i, tile)| {
+                let goal = usize::from(*tile) - 1;
+                let (row, col) = (i / self.size, i % self.size);
+                let (goal_row, goal_col) = (goal / self.size, goal % self.size);
+                row.abs_diff(goal_row) + col.abs_diff(goal_col)
+            })
+            .sum()
+    }
+}
+
+/// [TryFrom<&str>]
+/// Accepts the tiles separated by spaces, row by row, with `0` as the blank.
+/// The board size is inferred from the number of tiles.
+/// # Examples:
+/// `"1 2 3 4 5 6 7 8 0"` is the 8-puzzle goal board.
+impl TryFrom<&str> for Board {
+    type Error = NPuzzleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let tiles = value
+            .split_whitespace()
+            .map(|tile| tile.parse::<u8>())
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| {
+                NPuzzleError::ParseFromStringError("Error while trying to parse a tile".into())
+            })?;
+        let size = (tiles.len() as f64).sqrt() as usize;
+        if size * size != tiles.len() {
+            return Err(NPuzzleError::ParseFromStringError(
+                "Number of tiles is not a perfect square".into(),
+            ));
+        }
+        Board::new(size, tiles)
+    }
+}
+
+impl From<&Board> for String {
+    fn from(value: &Board) -> Self {
+        value
+            .tiles
+            .iter()
+            .map(|tile| tile.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = (self.size * self.size - 1).to_string().len();
+        for row in self.tiles.chunks(self.size) {
+            let row = row
+                .iter()
+                .map(|tile| match tile {
+                    0 => format!("{:>width$}", "_"),
+                    tile => format!("{:>width$}", tile),
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// Sizes whose tiles fit in a `u8`.
+fn check_size(size: usize) -> Result<(), NPuzzleError> {
+    if (2..=15).contains(&size) {
+        Ok(())
+    } else {
+        Err(NPuzzleError::InvalidSize(size))
+    }
+}
+
+fn goal_tiles(size: usize) -> Vec<u8> {
+    let count = size * size;
+    (1..count as u8).chain(std::iter::once(0)).collect()
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum NPuzzleError {
+    #[error("Invalid board size")]
+    InvalidSize(usize),
+    #[error("Invalid tiles")]
+    InvalidTiles(String),
+    #[error("Error when trying to parse from Board string")]
+    ParseFromStringError(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::{HashSet, VecDeque};
+
+    fn reachable_from_goal(size: usize) -> HashSet<Board> {
+        let mut reachable = HashSet::from([Board::goal(size).unwrap()]);
+        let mut queue = VecDeque::from([Board::goal(size).unwrap()]);
+        while let Some(board) = queue.pop_front() {
+            for (_, child) in board.get_child_boards() {
+                if reachable.insert(child.clone()) {
+                    queue.push_back(child);
+                }
+            }
+        }
+        reachable
+    }
+
+    #[test]
+    fn board_new_returns_error_when_tiles_are_invalid() {
+        assert_eq!(Board::new(1, vec![0]), Err(NPuzzleError::InvalidSize(1)));
+        assert_eq!(Board::goal(0), Err(NPuzzleError::InvalidSize(0)));
+        assert_eq!(Board::goal(16), Err(NPuzzleError::InvalidSize(16)));
+        assert_eq!(
+            Board::random_solvable(0, &mut Rng::new(1)),
+            Err(NPuzzleError::InvalidSize(0))
+        );
+        assert!(matches!(
+            Board::new(2, vec![0, 1, 2]),
+            Err(NPuzzleError::InvalidTiles(_))
+        ));
+        assert!(matches!(
+            Board::new(2, vec![0, 1, 1, 2]),
+            Err(NPuzzleError::InvalidTiles(_))
+        ));
+        assert!(matches!(
+            Board::new(2, vec![0, 1, 2, 4]),
+            Err(NPuzzleError::InvalidTiles(_))
+        ));
+    }
+
+    #[test]
+    fn board_tryfrom_str_works_as_expected() {
+        let board: BoardResult = "1 2 3 4 5 6 7 8 0".try_into();
+        let board = board.unwrap();
+
+        assert_eq!(board.size(), 3);
+        assert!(board.is_goal());
+        assert_eq!(String::from(&board), "1 2 3 4 5 6 7 8 0");
+        assert!(Board::try_from("1 2 3").is_err());
+        assert!(Board::try_from("1 2 a 0").is_err());
+    }
+
+    #[test]
+    fn board_slide_moves_the_blank() {
+        let board = Board::goal(3).unwrap();
+
+        assert_eq!(board.slide(Slide::Down), None);
+        assert_eq!(board.slide(Slide::Right), None);
+        let board = board.slide(Slide::Up).unwrap();
+        assert_eq!(board.tiles(), &[1, 2, 3, 4, 5, 0, 7, 8, 6]);
+        assert_eq!(board.slide(Slide::Down).unwrap(), Board::goal(3).unwrap());
+    }
+
+    #[test]
+    fn board_is_solvable_matches_reachability_for_every_2x2_board() {
+        let reachable = reachable_from_goal(2);
+        let all_boards = [
+            [0, 1, 2, 3],
+            [0, 1, 3, 2],
+            [0, 2, 1, 3],
+            [0, 2, 3, 1],
+            [0, 3, 1, 2],
+            [0, 3, 2, 1],
+        ]
+        .into_iter()
+        .flat_map(|tiles| {
+            // every rotation of each ordering, so the blank visits every position.
+            (0..4).map(move |r| {
+                let mut tiles = tiles.to_vec();
+                tiles.rotate_left(r);
+                Board::new(2, tiles).unwrap()
+            })
+        })
+        .collect::<HashSet<Board>>();
+
+        assert_eq!(all_boards.len(), 24);
+        for board in all_boards {
+            assert_eq!(
+                board.is_solvable(),
+                reachable.contains(&board),
+                "wrong solvability for {:?}",
+                board.tiles()
+            );
+        }
+    }
+
+    #[test]
+    fn board_is_solvable_detects_unsolvable_15_puzzle() {
+        let solvable: BoardResult = "1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 0".try_into();
+        let unsolvable: BoardResult = "1 2 3 4 5 6 7 8 9 10 11 12 13 15 14 0".try_into();
+
+        assert!(solvable.unwrap().is_solvable());
+        assert!(!unsolvable.unwrap().is_solvable());
+    }
+
+    #[test]
+    fn board_random_solvable_is_always_solvable_and_reproducible() {
+        let reachable = reachable_from_goal(3);
+
+        for seed in 0..20 {
+            let board = Board::random_solvable(3, &mut Rng::new(seed)).unwrap();
+            assert!(board.is_solvable());
+            assert!(reachable.contains(&board));
+            assert_eq!(
+                board,
+                Board::random_solvable(3, &mut Rng::new(seed)).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn board_heuristics_return_expected_values() {
+        let board: BoardResult = "8 1 3 4 0 2 7 6 5".try_into();
+        let board = board.unwrap();
+
+        assert_eq!(board.misplaced_tiles(), 5);
+        assert_eq!(board.manhattan_distance(), 10);
+        assert_eq!(Board::goal(4).unwrap().manhattan_distance(), 0);
+    }
+}
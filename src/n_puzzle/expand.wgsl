@@ -0,0 +1,141 @@
+// Breadth-first expansion of bit-packed N-puzzle boards, see gpu.rs.
+// A board is a vec2<u32> with the tile of position i in the nibble i (x holds 0..8).
+
+struct Params {
+    frontier_len: u32,
+    size: u32,
+    table_mask: u32,
+    next_capacity: u32,
+}
+
+@group(0) @binding(0) var<storage, read> params: Params;
+@group(0) @binding(1) var<storage, read> frontier: array<vec2<u32>>;
+@group(0) @binding(2) var<storage, read_write> next: array<vec2<u32>>;
+// Open addressing hash set of every board seen, slot i is (table[2i], table[2i + 1]).
+@group(0) @binding(3) var<storage, read_write> table: array<atomic<u32>>;
+// [boards in next, 1 when a buffer overflowed]
+@group(0) @binding(4) var<storage, read_write> counters: array<atomic<u32>>;
+
+// No board has 8 tiles 15 in a word.
+const EMPTY: u32 = 0xffffffffu;
+
+fn get_tile(board: vec2<u32>, position: u32) -> u32 {
+    if position < 8u {
+        return (board.x >> (4u * position)) & 0xfu;
+    }
+    return (board.y >> (4u * (position - 8u))) & 0xfu;
+}
+
+fn set_tile(board: vec2<u32>, position: u32, tile: u32) -> vec2<u32> {
+    var result = board;
+    if position < 8u {
+        let shift = 4u * position;
+        result.x = (result.x & ~(0xfu << shift)) | (tile << shift);
+    } else {
+        let shift = 4u * (position - 8u);
+        result.y = (result.y & ~(0xfu << shift)) | (tile << shift);
+    }
+    return result;
+}
+
+fn hash(board: vec2<u32>) -> u32 {
+    var h = board.x * 0x9e3779b1u;
+    h = (h ^ (h >> 15u)) ^ (board.y * 0x85ebca77u);
+    h = (h ^ (h >> 13u)) * 0xc2b2ae3du;
+    return h ^ (h >> 16u);
+}
+
+// Whether `board` was not in the table. The low word of a slot is claimed first, the
+// slot belongs to the board that then claims its high word.
+fn insert(board: vec2<u32>) -> bool {
+    var slot = hash(board) & params.table_mask;
+    var probes = 0u;
+    loop {
+        if probes > params.table_mask {
+            atomicStore(&counters[1], 1u);
+            return false;
+        }
+        let low = atomicCompareExchangeWeak(&table[2u * slot], EMPTY, board.x);
+        if !low.exchanged && low.old_value == EMPTY {
+            // spurious failure.
+            continue;
+        }
+        if low.exchanged || low.old_value == board.x {
+            loop {
+                let high = atomicCompareExchangeWeak(&table[2u * slot + 1u], EMPTY, board.y);
+                if high.exchanged {
+                    return true;
+                }
+                if high.old_value != EMPTY {
+                    if high.old_value == board.y {
+                        return false;
+                    }
+                    break;
+                }
+            }
+        }
+        slot = (slot + 1u) & params.table_mask;
+        probes += 1u;
+    }
+    return false;
+}
+
+@compute @workgroup_size(1)
+fn seed() {
+    _ = insert(frontier[0]);
+}
+
+@compute @workgroup_size(64)
+fn expand(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) workgroups: vec3<u32>,
+) {
+    let index = id.x + id.y * workgroups.x * 64u;
+    if index >= params.frontier_len {
+        return;
+    }
+    let board = frontier[index];
+    let size = params.size;
+    var blank = 0u;
+    for (var position = 0u; position < size * size; position += 1u) {
+        if get_tile(board, position) == 0u {
+            blank = position;
+        }
+    }
+    let row = blank / size;
+    let column = blank % size;
+    for (var direction = 0u; direction < 4u; direction += 1u) {
+        var other = 0u;
+        var possible = false;
+        switch direction {
+            case 0u: {
+                possible = row > 0u;
+                other = blank - size;
+            }
+            case 1u: {
+                possible = row + 1u < size;
+                other = blank + size;
+            }
+            case 2u: {
+                possible = column > 0u;
+                other = blank - 1u;
+            }
+            default: {
+                possible = column + 1u < size;
+                other = blank + 1u;
+            }
+        }
+        if !possible {
+            continue;
+        }
+        let child = set_tile(set_tile(board, blank, get_tile(board, other)), other, 0u);
+        if insert(child) {
+            let slot = atomicAdd(&counters[0], 1u);
+            if slot < params.next_capacity {
+                next[slot] = child;
+            } else {
+                atomicStore(&counters[1], 1u);
+            }
+        }
+    }
+}
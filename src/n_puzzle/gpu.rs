@@ -0,0 +1,341 @@
+use std::future::Future;
+use std::sync::mpsc;
+use std::task::{Context, Poll, Waker};
+
+use thiserror::Error;
+use wgpu::util::DeviceExt;
+
+use super::Board;
+
+const SHADER: &str = include_str!("expand.wgsl");
+const WORKGROUP_SIZE: u32 = 64;
+const MAX_WORKGROUPS: u32 = 65535;
+
+/// [`GpuError`]
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum GpuError {
+    #[error("No GPU adapter available: {0}")]
+    NoAdapter(String),
+    #[error("Could not open the GPU device: {0}")]
+    Device(String),
+    #[error("Only boards of size 2 to 4 fit in 64 bits, got {0}")]
+    UnsupportedSize(usize),
+    #[error("More than {0} states are reachable")]
+    CapacityExceeded(usize),
+    #[error("Could not read the results back: {0}")]
+    Readback(String),
+}
+
+/// [`pack`]
+/// The tile of position `i` in the bits `4 * i..4 * i + 4`, for boards up to 4x4.
+pub fn pack(board: &Board) -> u64 {
+    board
+        .tiles()
+        .iter()
+        .enumerate()
+        .fold(0, |packed, (i, tile)| packed | u64::from(*tile) << (4 * i))
+}
+
+/// [`unpack`]
+/// Inverse of [`pack`].
+pub fn unpack(size: usize, packed: u64) -> Board {
+    let tiles = (0..size * size)
+        .map(|i| ((packed >> (4 * i)) & 0xf) as u8)
+        .collect();
+    Board::new(size, tiles).expect("packed boards are valid")
+}
+
+/// [`GpuSweep`]
+/// ## Exhaustive breadth-first sweeps on the GPU (experimental).
+/// Expands whole breadth-first layers of bit-packed boards with a compute shader: every
+/// invocation generates the children of one board and inserts them in a hash set shared
+/// by the whole sweep, kept in GPU memory, so only the boards never seen before make it
+/// to the next layer. Meant for counting the states at every distance of the 15-puzzle
+/// class of domains, where the state space is too big for the CPU searches.
+///
+/// Only the `wgsl` frontend of wgpu is enabled by the `gpu` feature. The backend
+/// (`vulkan`, `metal`, `dx12`, ...) is enabled by depending on wgpu with it, otherwise
+/// [`new`](Self::new) fails with [`GpuError::NoAdapter`].
+pub struct GpuSweep {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    layout: wgpu::BindGroupLayout,
+    seed: wgpu::ComputePipeline,
+    expand: wgpu::ComputePipeline,
+}
+
+impl GpuSweep {
+    pub fn new() -> Result<Self, GpuError> {
+        if wgpu::Instance::enabled_backend_features().is_empty() {
+            return Err(GpuError::NoAdapter("no wgpu backend is enabled".into()));
+        }
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+            .map_err(|error| GpuError::NoAdapter(error.to_string()))?;
+        let (device, queue) = block_on(adapter.request_device(&wgpu::DeviceDescriptor::default()))
+            .map_err(|error| GpuError::Device(error.to_string()))?;
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("expand"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let entry = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("expand"),
+            entries: &[
+                entry(0, true),
+                entry(1, true),
+                entry(2, false),
+                entry(3, false),
+                entry(4, false),
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("expand"),
+            bind_group_layouts: &[Some(&layout)],
+            immediate_size: 0,
+        });
+        let pipeline = |entry_point| {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(entry_point),
+                layout: Some(&pipeline_layout),
+                module: &module,
+                entry_point: Some(entry_point),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        };
+        let (seed, expand) = (pipeline("seed"), pipeline("expand"));
+        Ok(Self {
+            device,
+            queue,
+            layout,
+            seed,
+            expand,
+        })
+    }
+
+    /// [`layer_sizes`]
+    /// Number of boards at every distance (in slides) from `start`, or
+    /// [`GpuError::CapacityExceeded`] when more than `max_states` are reachable.
+    pub fn layer_sizes(&self, start: &Board, max_states: usize) -> Result<Vec<usize>, GpuError> {
+        if !(2..=4).contains(&start.size()) {
+            return Err(GpuError::UnsupportedSize(start.size()));
+        }
+        let max_states = max_states.clamp(1, (u32::MAX / 4) as usize);
+        // at most half full, so probes stay short.
+        let slots = (2 * max_states).next_power_of_two();
+        let storage = wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST;
+        let board_bytes = 8 * max_states as u64;
+        let frontiers = [0, 1].map(|_| {
+            self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("frontier"),
+                size: board_bytes,
+                usage: storage,
+                mapped_at_creation: false,
+            })
+        });
+        let table = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("table"),
+                contents: &vec![0xff; 8 * slots],
+                usage: storage,
+            });
+        let params = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("params"),
+            size: 16,
+            usage: storage,
+            mapped_at_creation: false,
+        });
+        let counters = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("counters"),
+            size: 8,
+            usage: storage | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size: 8,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_groups = [(0, 1), (1, 0)].map(|(current, next)| {
+            let buffers = [
+                &params,
+                &frontiers[current],
+                &frontiers[next],
+                &table,
+                &counters,
+            ];
+            let entries = buffers
+                .iter()
+                .enumerate()
+                .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                    binding: binding as u32,
+                    resource: buffer.as_entire_binding(),
+                })
+                .collect::<Vec<_>>();
+            self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("expand"),
+                layout: &self.layout,
+                entries: &entries,
+            })
+        });
+
+        let words = |values: &[u32]| {
+            values
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect::<Vec<u8>>()
+        };
+        let size = start.size() as u32;
+        self.queue
+            .write_buffer(&frontiers[0], 0, &pack(start).to_le_bytes());
+        let mut layers = vec![1];
+        let mut current = 0;
+        loop {
+            let frontier_len = *layers.last().unwrap() as u32;
+            self.queue.write_buffer(
+                &params,
+                0,
+                &words(&[frontier_len, size, (slots - 1) as u32, max_states as u32]),
+            );
+            self.queue.write_buffer(&counters, 0, &words(&[0, 0]));
+            let mut encoder = self
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+            {
+                let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+                pass.set_bind_group(0, &bind_groups[current], &[]);
+                if layers.len() == 1 {
+                    pass.set_pipeline(&self.seed);
+                    pass.dispatch_workgroups(1, 1, 1);
+                }
+                let workgroups = frontier_len.div_ceil(WORKGROUP_SIZE);
+                pass.set_pipeline(&self.expand);
+                pass.dispatch_workgroups(
+                    workgroups.min(MAX_WORKGROUPS),
+                    workgroups.div_ceil(MAX_WORKGROUPS),
+                    1,
+                );
+            }
+            encoder.copy_buffer_to_buffer(&counters, 0, &readback, 0, 8);
+            self.queue.submit([encoder.finish()]);
+            let [next_len, overflow] = self.read_counters(&readback)?;
+            if overflow != 0 || layers.iter().sum::<usize>() + next_len as usize > max_states {
+                return Err(GpuError::CapacityExceeded(max_states));
+            }
+            if next_len == 0 {
+                return Ok(layers);
+            }
+            layers.push(next_len as usize);
+            current = 1 - current;
+        }
+    }
+
+    fn read_counters(&self, readback: &wgpu::Buffer) -> Result<[u32; 2], GpuError> {
+        let (sender, receiver) = mpsc::channel();
+        readback.map_async(wgpu::MapMode::Read, .., move |result| {
+            let _ = sender.send(result);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .map_err(|error| GpuError::Readback(error.to_string()))?;
+        receiver
+            .recv()
+            .map_err(|error| GpuError::Readback(error.to_string()))?
+            .map_err(|error| GpuError::Readback(error.to_string()))?;
+        let counters = {
+            let view = readback
+                .get_mapped_range(..)
+                .map_err(|error| GpuError::Readback(error.to_string()))?;
+            let word = |i: usize| u32::from_le_bytes(view[4 * i..4 * i + 4].try_into().unwrap());
+            [word(0), word(1)]
+        };
+        readback.unmap();
+        Ok(counters)
+    }
+}
+
+/// Runs `future` on the current thread, wgpu futures are ready as soon as the device
+/// has been polled.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+        std::thread::yield_now();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{HashSet, VecDeque};
+
+    use super::*;
+
+    #[test]
+    fn gpu_pack_round_trips_boards() {
+        for board in ["1 2 3 4 5 6 7 8 0", "8 6 7 2 5 4 3 0 1", "1 0 3 2"] {
+            let board = Board::try_from(board).unwrap();
+            assert_eq!(unpack(board.size(), pack(&board)), board);
+        }
+        let fifteen = Board::goal(4).unwrap();
+        assert_eq!(pack(&fifteen), 0x0fed_cba9_8765_4321);
+    }
+
+    #[test]
+    fn gpu_shader_is_valid_wgsl() {
+        use wgpu::naga;
+
+        let module = naga::front::wgsl::parse_str(SHADER).unwrap();
+        naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::empty(),
+        )
+        .validate(&module)
+        .unwrap();
+    }
+
+    /// Skipped when no GPU backend is available.
+    #[test]
+    fn gpu_layer_sizes_match_the_cpu_sweep() {
+        let Ok(sweep) = GpuSweep::new() else {
+            return;
+        };
+        let start = Board::goal(3).unwrap();
+        let mut seen = HashSet::from([start.clone()]);
+        let mut queue = VecDeque::from([(start.clone(), 0)]);
+        let mut expected = vec![];
+        while let Some((board, depth)) = queue.pop_front() {
+            if expected.len() == depth {
+                expected.push(0);
+            }
+            expected[depth] += 1;
+            for (_, child) in board.get_child_boards() {
+                if seen.insert(child.clone()) {
+                    queue.push_back((child, depth + 1));
+                }
+            }
+        }
+
+        assert_eq!(sweep.layer_sizes(&start, 181_440), Ok(expected));
+        assert_eq!(
+            sweep.layer_sizes(&start, 1000),
+            Err(GpuError::CapacityExceeded(1000))
+        );
+    }
+}
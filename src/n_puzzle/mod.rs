@@ -0,0 +1,11 @@
+pub mod batch;
+pub mod board;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+pub mod problem;
+
+pub use batch::*;
+pub use board::*;
+#[cfg(feature = "gpu")]
+pub use gpu::*;
+pub use problem::*;
@@ -0,0 +1,81 @@
+use super::{Board, Slide};
+use crate::search::{HeuristicRegistry, SearchProblem, Successor};
+
+/// [`NPuzzleProblem`]
+/// Sliding tile puzzle: reach the goal board from `start`, every slide costs 1.
+#[derive(Debug, Clone)]
+pub struct NPuzzleProblem {
+    pub start: Board,
+}
+
+impl NPuzzleProblem {
+    pub fn new(start: Board) -> Self {
+        Self { start }
+    }
+
+    /// [`heuristics`]
+    /// `"manhattan"` and `"misplaced"` tiles, both admissible.
+    pub fn heuristics() -> HeuristicRegistry<Self> {
+        let mut registry = HeuristicRegistry::new();
+        registry
+            .register("manhattan", |_| {
+                Box::new(|board: &Board| board.manhattan_distance() as f64)
+            })
+            .register("misplaced", |_| {
+                Box::new(|board: &Board| board.misplaced_tiles() as f64)
+            });
+        registry
+    }
+}
+
+impl SearchProblem for NPuzzleProblem {
+    type State = Board;
+    type Action = Slide;
+    type Cost = u32;
+    type Successors<'a> = std::vec::IntoIter<Successor<Board, Slide>>;
+
+    fn initial_state(&self) -> Board {
+        self.start.clone()
+    }
+
+    fn is_goal(&self, state: &Board) -> bool {
+        state.is_goal()
+    }
+
+    fn successors<'a>(&'a self, state: &'a Board) -> Self::Successors<'a> {
+        state
+            .get_child_boards()
+            .into_iter()
+            .map(|(slide, board)| Successor::new(slide, board, 1))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::search::a_star;
+
+    #[test]
+    fn n_puzzle_a_star_with_manhattan_solves_random_boards_optimally() {
+        for seed in 0..3 {
+            let start = Board::random_solvable(3, &mut Rng::new(seed)).unwrap();
+            let problem = NPuzzleProblem::new(start.clone());
+
+            let manhattan = a_star(&problem, |b| b.manhattan_distance() as f64).unwrap();
+            let misplaced = a_star(&problem, |b| b.misplaced_tiles() as f64).unwrap();
+
+            assert!(manhattan.goal().is_goal());
+            assert_eq!(manhattan.cost, misplaced.cost);
+            assert!(manhattan.cost as usize >= start.manhattan_distance());
+
+            let replayed = manhattan
+                .actions
+                .iter()
+                .try_fold(start, |board, slide| board.slide(*slide));
+            assert_eq!(replayed, Some(Board::goal(3).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,18 @@
+pub mod puzzle;
+pub mod solver;
+
+pub use puzzle::*;
+pub use solver::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum NonogramError {
+    #[error("Clues don't match the declared width or height")]
+    InvalidDimensions,
+    #[error("A clue doesn't fit in its line")]
+    ClueTooLong(usize),
+    #[error("Error when trying to parse from Nonogram string")]
+    ParseFromStringError(String),
+}
@@ -0,0 +1,153 @@
+use super::NonogramError;
+
+/// [`Nonogram`]
+/// ## Nonogram (picross) puzzle.
+/// Every row and column has a clue: the lengths of the runs of filled cells in that line,
+/// in order, separated by at least one empty cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Nonogram {
+    rows: Vec<Vec<usize>>,
+    columns: Vec<Vec<usize>>,
+}
+
+impl Nonogram {
+    /// Empty clues (no filled cells in the line) may be written as `vec![]` or `vec![0]`.
+    pub fn new(rows: Vec<Vec<usize>>, columns: Vec<Vec<usize>>) -> Result<Self, NonogramError> {
+        if rows.is_empty() || columns.is_empty() {
+            return Err(NonogramError::InvalidDimensions);
+        }
+        let normalize = |clues: Vec<Vec<usize>>| {
+            clues
+                .into_iter()
+                .map(|clue| clue.into_iter().filter(|run| *run > 0).collect())
+                .collect::<Vec<Vec<usize>>>()
+        };
+        let (rows, columns) = (normalize(rows), normalize(columns));
+        let min_length =
+            |clue: &Vec<usize>| clue.iter().sum::<usize>() + clue.len().saturating_sub(1);
+        if let Some(row) = rows
+            .iter()
+            .position(|clue| min_length(clue) > columns.len())
+        {
+            return Err(NonogramError::ClueTooLong(row));
+        }
+        if let Some(column) = columns
+            .iter()
+            .position(|clue| min_length(clue) > rows.len())
+        {
+            return Err(NonogramError::ClueTooLong(rows.len() + column));
+        }
+        Ok(Self { rows, columns })
+    }
+
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn row_clues(&self) -> &[Vec<usize>] {
+        &self.rows
+    }
+
+    pub fn column_clues(&self) -> &[Vec<usize>] {
+        &self.columns
+    }
+}
+
+/// [TryFrom<&str>]
+/// Accepts the `.non` format: optional `width n` and `height n` lines, then a `rows`
+/// section and a `columns` section with one comma separated clue per line (`0` for a line
+/// without filled cells).
+/// Other keys (`title`, `catalogue`, `goal`...) and `#` comments are ignored.
+impl TryFrom<&str> for Nonogram {
+    type Error = NonogramError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parse_error = |message: &str| NonogramError::ParseFromStringError(message.into());
+        let (mut width, mut height) = (None, None);
+        let (mut rows, mut columns) = (vec![], vec![]);
+        let mut section: Option<&mut Vec<Vec<usize>>> = None;
+
+        for line in value.lines().map(|line| line.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some("rows"), None) => section = Some(&mut rows),
+                (Some("columns"), None) => section = Some(&mut columns),
+                (Some(key @ ("width" | "height")), Some(n)) => {
+                    let n = n
+                        .parse::<usize>()
+                        .map_err(|_| parse_error("Invalid size"))?;
+                    if key == "width" {
+                        width = Some(n);
+                    } else {
+                        height = Some(n);
+                    }
+                }
+                (Some(first), _) if !first.starts_with(|c: char| c.is_ascii_digit()) => {
+                    section = None;
+                }
+                _ => {
+                    if let Some(clues) = section.as_mut() {
+                        let clue = line
+                            .split(',')
+                            .map(|run| run.trim())
+                            .filter(|run| !run.is_empty())
+                            .map(|run| run.parse::<usize>())
+                            .collect::<Result<Vec<usize>, _>>()
+                            .map_err(|_| parse_error("Invalid clue"))?;
+                        clues.push(clue);
+                    }
+                }
+            }
+        }
+
+        if width.is_some_and(|w| w != columns.len()) || height.is_some_and(|h| h != rows.len()) {
+            return Err(NonogramError::InvalidDimensions);
+        }
+        Nonogram::new(rows, columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn nonogram_try_from_reads_non_format() {
+        let nonogram = Nonogram::try_from(
+            "title Heart
+            width 3
+            height 2
+            rows
+            1,1
+            3
+            columns
+            2
+            1
+            2",
+        )
+        .unwrap();
+
+        assert_eq!(nonogram.row_clues(), &[vec![1, 1], vec![3]]);
+        assert_eq!(nonogram.column_clues(), &[vec![2], vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn nonogram_try_from_rejects_invalid_puzzles() {
+        assert_eq!(
+            Nonogram::try_from("width 2\nheight 1\nrows\n1\ncolumns\n1"),
+            Err(NonogramError::InvalidDimensions)
+        );
+        assert_eq!(
+            Nonogram::try_from("rows\n2,1\ncolumns\n1\n1\n1"),
+            Err(NonogramError::ClueTooLong(0))
+        );
+        assert!(Nonogram::try_from("rows\n1,x\ncolumns\n1").is_err());
+    }
+}
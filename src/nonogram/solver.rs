@@ -0,0 +1,308 @@
+use std::fmt::Display;
+
+use super::Nonogram;
+
+/// [`Cell`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Unknown,
+    Filled,
+    Empty,
+}
+
+/// [`Grid`]
+/// Cells of a (possibly partially) solved nonogram, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Grid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::Unknown; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Cell {
+        self.cells[row * self.width + column]
+    }
+
+    pub fn is_complete(&self) -> bool {
+        !self.cells.contains(&Cell::Unknown)
+    }
+
+    pub fn row(&self, row: usize) -> Vec<Cell> {
+        self.cells[row * self.width..(row + 1) * self.width].to_vec()
+    }
+
+    pub fn column(&self, column: usize) -> Vec<Cell> {
+        (0..self.height).map(|row| self.get(row, column)).collect()
+    }
+
+    fn set(&mut self, row: usize, column: usize, cell: Cell) {
+        self.cells[row * self.width + column] = cell;
+    }
+}
+
+/// Filled cells as `#`, empty cells as `.` and unknown cells as `?`.
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width) {
+            let row = row
+                .iter()
+                .map(|cell| match cell {
+                    Cell::Unknown => '?',
+                    Cell::Filled => '#',
+                    Cell::Empty => '.',
+                })
+                .collect::<String>();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// [`NonogramSolution`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonogramSolution {
+    pub grid: Grid,
+    /// Number of times the solver had to guess a cell value after propagation got stuck
+    /// (`0` means the puzzle is solvable by line logic alone).
+    pub guesses: usize,
+}
+
+/// [`solve_line`]
+/// ## Line solver.
+/// Returns the line with every cell that has the same value in all the arrangements of
+/// `clue` compatible with the known cells of `line` fixed to that value, or `None` when
+/// there is no compatible arrangement.
+/// Uses dynamic programming over `(cell, run)` pairs, so it's polynomial on the line
+/// length even when the number of arrangements is huge.
+pub fn solve_line(clue: &[usize], line: &[Cell]) -> Option<Vec<Cell>> {
+    let (n, k) = (line.len(), clue.len());
+    // a run of `clue[j]` cells can start at `i`.
+    let run_fits = |i: usize, j: usize| {
+        let end = i + clue[j];
+        end <= n
+            && line[i..end].iter().all(|cell| *cell != Cell::Empty)
+            && line.get(end) != Some(&Cell::Filled)
+    };
+    // `fits[i][j]`: cells `i..` can hold runs `j..`.
+    let mut fits = vec![vec![false; k + 1]; n + 2];
+    fits[n][k] = true;
+    fits[n + 1][k] = true;
+    for i in (0..n).rev() {
+        for j in 0..=k {
+            let skip = line[i] != Cell::Filled && fits[i + 1][j];
+            let place = j < k && run_fits(i, j) && fits[(i + clue[j] + 1).min(n + 1)][j + 1];
+            fits[i][j] = skip || place;
+        }
+    }
+    if !fits[0][0] {
+        return None;
+    }
+
+    let (mut can_fill, mut can_empty) = (vec![false; n], vec![false; n]);
+    let mut reachable = vec![vec![false; k + 1]; n + 2];
+    reachable[0][0] = true;
+    for i in 0..n {
+        for j in 0..=k {
+            if !reachable[i][j] {
+                continue;
+            }
+            if line[i] != Cell::Filled && fits[i + 1][j] {
+                can_empty[i] = true;
+                reachable[i + 1][j] = true;
+            }
+            if j < k && run_fits(i, j) {
+                let next = (i + clue[j] + 1).min(n + 1);
+                if fits[next][j + 1] {
+                    can_fill[i..i + clue[j]].iter_mut().for_each(|c| *c = true);
+                    if let Some(separator) = can_empty.get_mut(i + clue[j]) {
+                        *separator = true;
+                    }
+                    reachable[next][j + 1] = true;
+                }
+            }
+        }
+    }
+
+    Some(
+        can_fill
+            .into_iter()
+            .zip(can_empty)
+            .map(|(fill, empty)| match (fill, empty) {
+                (true, false) => Cell::Filled,
+                (false, true) => Cell::Empty,
+                _ => Cell::Unknown,
+            })
+            .collect(),
+    )
+}
+
+/// [`propagate`]
+/// Runs the line solver on every row and column until no cell changes.
+/// Returns `false` if a contradiction was found.
+pub fn propagate(nonogram: &Nonogram, grid: &mut Grid) -> bool {
+    let (height, width) = (grid.height(), grid.width());
+    let mut dirty_rows = vec![true; height];
+    let mut dirty_columns = vec![true; width];
+    while dirty_rows.contains(&true) || dirty_columns.contains(&true) {
+        let rows = std::mem::replace(&mut dirty_rows, vec![false; height]);
+        for row in (0..height).filter(|row| rows[*row]) {
+            let Some(solved) = solve_line(&nonogram.row_clues()[row], &grid.row(row)) else {
+                return false;
+            };
+            for (column, cell) in solved.into_iter().enumerate() {
+                if grid.get(row, column) != cell {
+                    grid.set(row, column, cell);
+                    dirty_columns[column] = true;
+                }
+            }
+        }
+        let columns = std::mem::replace(&mut dirty_columns, vec![false; width]);
+        for column in (0..width).filter(|column| columns[*column]) {
+            let Some(solved) = solve_line(&nonogram.column_clues()[column], &grid.column(column))
+            else {
+                return false;
+            };
+            for (row, cell) in solved.into_iter().enumerate() {
+                if grid.get(row, column) != cell {
+                    grid.set(row, column, cell);
+                    dirty_rows[row] = true;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// [`solve`]
+/// ## Constraint propagation plus backtracking.
+/// Propagates line constraints, and when they are not enough guesses the first unknown
+/// cell (filled first, then empty) and recurses.
+/// Returns the first solution found, or `None` if the puzzle has no solution.
+pub fn solve(nonogram: &Nonogram) -> Option<NonogramSolution> {
+    let mut guesses = 0;
+    let grid = backtrack(
+        nonogram,
+        Grid::new(nonogram.width(), nonogram.height()),
+        &mut guesses,
+    )?;
+    Some(NonogramSolution { grid, guesses })
+}
+
+fn backtrack(nonogram: &Nonogram, mut grid: Grid, guesses: &mut usize) -> Option<Grid> {
+    if !propagate(nonogram, &mut grid) {
+        return None;
+    }
+    let Some(unknown) = grid.cells.iter().position(|cell| *cell == Cell::Unknown) else {
+        return Some(grid);
+    };
+    let (row, column) = (unknown / grid.width(), unknown % grid.width());
+    for guess in [Cell::Filled, Cell::Empty] {
+        *guesses += 1;
+        let mut guessed = grid.clone();
+        guessed.set(row, column, guess);
+        if let Some(solution) = backtrack(nonogram, guessed, guesses) {
+            return Some(solution);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Cell::*;
+
+    fn clues_of(picture: &[&str]) -> Nonogram {
+        let runs = |line: Vec<bool>| {
+            line.split(|filled| !filled)
+                .map(|run| run.len())
+                .filter(|len| *len > 0)
+                .collect::<Vec<usize>>()
+        };
+        let rows = picture
+            .iter()
+            .map(|row| runs(row.chars().map(|c| c == '#').collect()))
+            .collect();
+        let columns = (0..picture[0].len())
+            .map(|column| {
+                runs(
+                    picture
+                        .iter()
+                        .map(|row| row.as_bytes()[column] == b'#')
+                        .collect(),
+                )
+            })
+            .collect();
+        Nonogram::new(rows, columns).unwrap()
+    }
+
+    #[test]
+    fn nonogram_solve_line_finds_overlaps() {
+        // a run of 3 in 5 cells always covers the middle cell.
+        assert_eq!(
+            solve_line(&[3], &[Unknown; 5]),
+            Some(vec![Unknown, Unknown, Filled, Unknown, Unknown])
+        );
+        assert_eq!(
+            solve_line(&[1, 1], &[Unknown, Filled, Unknown, Unknown]),
+            Some(vec![Empty, Filled, Empty, Filled])
+        );
+        assert_eq!(
+            solve_line(&[], &[Unknown, Unknown]),
+            Some(vec![Empty, Empty])
+        );
+        assert_eq!(solve_line(&[2], &[Filled, Empty, Unknown]), None);
+    }
+
+    #[test]
+    fn nonogram_solve_uses_only_propagation_on_line_solvable_puzzle() {
+        let picture = [".###.", "##.##", "#####", "##.##", ".###."];
+        let nonogram = clues_of(&picture);
+
+        let solution = solve(&nonogram).unwrap();
+
+        assert_eq!(solution.guesses, 0);
+        assert_eq!(solution.grid.to_string(), picture.join("\n") + "\n");
+    }
+
+    #[test]
+    fn nonogram_solve_backtracks_on_ambiguous_puzzle() {
+        // the diagonals have the same clues, line logic can't pick one.
+        let nonogram = clues_of(&["#.", ".#"]);
+
+        let solution = solve(&nonogram).unwrap();
+
+        assert!(solution.guesses > 0);
+        assert!(solution.grid.is_complete());
+        for row in 0..2 {
+            assert_eq!(
+                solve_line(&nonogram.row_clues()[row], &solution.grid.row(row)),
+                Some(solution.grid.row(row))
+            );
+        }
+    }
+
+    #[test]
+    fn nonogram_solve_returns_none_when_unsolvable() {
+        let nonogram = Nonogram::new(vec![vec![1], vec![]], vec![vec![], vec![]]).unwrap();
+
+        assert_eq!(solve(&nonogram), None);
+    }
+}
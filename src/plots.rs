@@ -0,0 +1,130 @@
+//! Charts of a [`SearchRecording`], enabled by the `plots` feature.
+use std::path::Path;
+
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use thiserror::Error;
+
+use crate::search::SearchRecording;
+
+const SIZE: (u32, u32) = (800, 480);
+
+/// [`plot_frontier_size`]
+/// ## Frontier size over time.
+/// Line chart of the frontier size after every expansion. The format, SVG or PNG, is
+/// chosen by the extension of `path`.
+pub fn plot_frontier_size<C>(recording: &SearchRecording<C>, path: &Path) -> Result<(), PlotError> {
+    let points = recording
+        .expansions
+        .iter()
+        .enumerate()
+        .map(|(i, expansion)| (i as f64, expansion.frontier as f64))
+        .collect::<Vec<(f64, f64)>>();
+    draw(path, "Frontier size", "frontier size", &points)
+}
+
+/// [`plot_f_values`]
+/// ## f-value of the expanded nodes.
+/// One point per expansion, with A* and a consistent heuristic the f-values never
+/// decrease.
+pub fn plot_f_values<C>(recording: &SearchRecording<C>, path: &Path) -> Result<(), PlotError> {
+    let points = recording
+        .expansions
+        .iter()
+        .enumerate()
+        .map(|(i, expansion)| (i as f64, expansion.f))
+        .collect::<Vec<(f64, f64)>>();
+    draw(path, "f-value of expanded nodes", "f", &points)
+}
+
+fn draw(
+    path: &Path,
+    caption: &str,
+    y_description: &str,
+    points: &[(f64, f64)],
+) -> Result<(), PlotError> {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("svg") => {
+            let area = SVGBackend::new(path, SIZE).into_drawing_area();
+            line_chart(&area, caption, y_description, points)
+        }
+        Some("png") => {
+            let area = BitMapBackend::new(path, SIZE).into_drawing_area();
+            line_chart(&area, caption, y_description, points)
+        }
+        extension => Err(PlotError::UnsupportedFormat(
+            extension.unwrap_or_default().to_string(),
+        )),
+    }
+}
+
+fn line_chart<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    caption: &str,
+    y_description: &str,
+    points: &[(f64, f64)],
+) -> Result<(), PlotError> {
+    let drawing =
+        |error: DrawingAreaErrorKind<DB::ErrorType>| PlotError::Drawing(error.to_string());
+    area.fill(&WHITE).map_err(drawing)?;
+    let x_max = (points.len() as f64).max(1.0);
+    let y_max = points.iter().map(|(_, y)| *y).fold(1.0, f64::max);
+    let mut chart = ChartBuilder::on(area)
+        .caption(caption, ("sans-serif", 24))
+        .margin(12)
+        .x_label_area_size(36)
+        .y_label_area_size(48)
+        .build_cartesian_2d(0.0..x_max, 0.0..y_max * 1.05)
+        .map_err(drawing)?;
+    chart
+        .configure_mesh()
+        .x_desc("expansions")
+        .y_desc(y_description)
+        .draw()
+        .map_err(drawing)?;
+    chart
+        .draw_series(LineSeries::new(points.iter().copied(), &BLUE))
+        .map_err(drawing)?;
+    area.present().map_err(drawing)
+}
+
+/// [`PlotError`]
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum PlotError {
+    #[error("Unsupported chart format {0}, expected svg or png")]
+    UnsupportedFormat(String),
+    #[error("Error when drawing the chart: {0}")]
+    Drawing(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+    use crate::search::a_star_traced;
+
+    fn recording() -> SearchRecording {
+        let problem = NPuzzleProblem::new(Board::try_from("4 1 3 7 2 6 0 5 8").unwrap());
+        let mut recording = SearchRecording::new();
+        a_star_traced(&problem, |b| b.manhattan_distance() as f64, &mut recording);
+        recording
+    }
+
+    #[test]
+    fn plots_write_svg_and_png_charts() {
+        let directory = std::env::temp_dir();
+        let svg = directory.join("algoritmos_rust_frontier.svg");
+        let png = directory.join("algoritmos_rust_f_values.png");
+
+        plot_frontier_size(&recording(), &svg).unwrap();
+        plot_f_values(&recording(), &png).unwrap();
+
+        assert!(std::fs::read_to_string(&svg).unwrap().contains("<svg"));
+        assert!(std::fs::metadata(&png).unwrap().len() > 0);
+        assert_eq!(
+            plot_f_values(&recording(), Path::new("chart.gif")),
+            Err(PlotError::UnsupportedFormat("gif".into()))
+        );
+    }
+}
@@ -0,0 +1,144 @@
+/// [`Rng`]
+/// ## Small seedable pseudo random number generator.
+/// It is a `SplitMix64` generator: fast, good enough for search and metaheuristics,
+/// and (most important here) it produces exactly the same sequence for the same seed
+/// on every platform, which keeps experiments reproducible.
+/// # Example
+/// ```
+/// # use algoritmos_rust::rng::Rng;
+/// let mut rng_1 = Rng::new(42);
+/// let mut rng_2 = Rng::new(42);
+/// assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// [`gen_f64`]
+    /// Returns a number uniformly distributed in `[0, 1)`.
+    pub fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// [`gen_range`]
+    /// Returns a number uniformly distributed in `range`.
+    /// Panics if the range is empty.
+    pub fn gen_range(&mut self, range: std::ops::Range<usize>) -> usize {
+        assert!(range.start < range.end, "gen_range called with empty range");
+        let span = (range.end - range.start) as u64;
+        range.start + (self.next_u64() % span) as usize
+    }
+
+    /// [`gen_bool`]
+    /// Returns `true` with probability `p`.
+    pub fn gen_bool(&mut self, p: f64) -> bool {
+        self.gen_f64() < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        Some(&items[self.gen_range(0..items.len())])
+    }
+
+    /// [`choose_weighted`]
+    /// Index of a weight chosen with probability proportional to it. Negative and `NaN`
+    /// weights count as 0, `None` when no weight is positive.
+    pub fn choose_weighted(&mut self, weights: &[f64]) -> Option<usize> {
+        let weight = |w: f64| if w > 0.0 { w } else { 0.0 };
+        let total = weights.iter().map(|w| weight(*w)).sum::<f64>();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut target = self.gen_f64() * total;
+        let mut last = 0;
+        for (i, w) in weights.iter().enumerate() {
+            let w = weight(*w);
+            if w == 0.0 {
+                continue;
+            }
+            if target < w {
+                return Some(i);
+            }
+            target -= w;
+            last = i;
+        }
+        // rounding errors.
+        Some(last)
+    }
+
+    /// [`shuffle`]
+    /// Fisher-Yates shuffle in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.gen_range(0..i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rng_same_seed_generates_same_sequence() {
+        let mut rng_1 = Rng::new(7);
+        let mut rng_2 = Rng::new(7);
+
+        for _ in 0..100 {
+            assert_eq!(rng_1.next_u64(), rng_2.next_u64());
+        }
+    }
+
+    #[test]
+    fn rng_gen_range_stays_inside_range() {
+        let mut rng = Rng::new(1);
+
+        for _ in 0..1000 {
+            let value = rng.gen_range(3..8);
+            assert!((3..8).contains(&value), "{} out of range", value);
+        }
+    }
+
+    #[test]
+    fn rng_choose_weighted_follows_weights() {
+        let mut rng = Rng::new(5);
+        let mut counts = [0; 3];
+
+        for _ in 0..10_000 {
+            counts[rng.choose_weighted(&[1.0, 0.0, 3.0]).unwrap()] += 1;
+        }
+
+        assert_eq!(counts[1], 0);
+        assert!((2300..2700).contains(&counts[0]), "{:?}", counts);
+        assert_eq!(rng.choose_weighted(&[0.0, -1.0, f64::NAN]), None);
+        assert_eq!(rng.choose_weighted(&[]), None);
+    }
+
+    #[test]
+    fn rng_shuffle_keeps_all_elements() {
+        let mut rng = Rng::new(3);
+        let mut items: Vec<u8> = (0..20).collect();
+
+        rng.shuffle(&mut items);
+        items.sort();
+
+        assert_eq!(items, (0..20).collect::<Vec<u8>>());
+    }
+}
@@ -0,0 +1,216 @@
+use std::fmt::Display;
+
+use thiserror::Error;
+
+/// [`City`]
+/// The 20 cities of the Romania road map from AIMA (Russell & Norvig, figure 3.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum City {
+    Arad,
+    Bucharest,
+    Craiova,
+    Drobeta,
+    Eforie,
+    Fagaras,
+    Giurgiu,
+    Hirsova,
+    Iasi,
+    Lugoj,
+    Mehadia,
+    Neamt,
+    Oradea,
+    Pitesti,
+    RimnicuVilcea,
+    Sibiu,
+    Timisoara,
+    Urziceni,
+    Vaslui,
+    Zerind,
+}
+
+/// Road distances (in km) between neighboring cities, every road can be used in both ways.
+pub const ROADS: [(City, City, u32); 23] = [
+    (City::Arad, City::Zerind, 75),
+    (City::Arad, City::Sibiu, 140),
+    (City::Arad, City::Timisoara, 118),
+    (City::Zerind, City::Oradea, 71),
+    (City::Oradea, City::Sibiu, 151),
+    (City::Timisoara, City::Lugoj, 111),
+    (City::Lugoj, City::Mehadia, 70),
+    (City::Mehadia, City::Drobeta, 75),
+    (City::Drobeta, City::Craiova, 120),
+    (City::Craiova, City::RimnicuVilcea, 146),
+    (City::Craiova, City::Pitesti, 138),
+    (City::Sibiu, City::Fagaras, 99),
+    (City::Sibiu, City::RimnicuVilcea, 80),
+    (City::RimnicuVilcea, City::Pitesti, 97),
+    (City::Fagaras, City::Bucharest, 211),
+    (City::Pitesti, City::Bucharest, 101),
+    (City::Bucharest, City::Giurgiu, 90),
+    (City::Bucharest, City::Urziceni, 85),
+    (City::Urziceni, City::Hirsova, 98),
+    (City::Hirsova, City::Eforie, 86),
+    (City::Urziceni, City::Vaslui, 142),
+    (City::Vaslui, City::Iasi, 92),
+    (City::Iasi, City::Neamt, 87),
+];
+
+impl City {
+    pub const ALL: [City; 20] = [
+        City::Arad,
+        City::Bucharest,
+        City::Craiova,
+        City::Drobeta,
+        City::Eforie,
+        City::Fagaras,
+        City::Giurgiu,
+        City::Hirsova,
+        City::Iasi,
+        City::Lugoj,
+        City::Mehadia,
+        City::Neamt,
+        City::Oradea,
+        City::Pitesti,
+        City::RimnicuVilcea,
+        City::Sibiu,
+        City::Timisoara,
+        City::Urziceni,
+        City::Vaslui,
+        City::Zerind,
+    ];
+
+    /// [`neighbors`]
+    /// Returns every city connected to this one by a road and the road distance.
+    pub fn neighbors(&self) -> Vec<(City, u32)> {
+        ROADS
+            .iter()
+            .filter_map(|(a, b, distance)| match (a, b) {
+                (a, b) if a == self => Some((*b, *distance)),
+                (a, b) if b == self => Some((*a, *distance)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// [`straight_line_distance_to_bucharest`]
+    /// The straight line distance heuristic table from AIMA (figure 3.16).
+    pub fn straight_line_distance_to_bucharest(&self) -> u32 {
+        match self {
+            City::Arad => 366,
+            City::Bucharest => 0,
+            City::Craiova => 160,
+            City::Drobeta => 242,
+            City::Eforie => 161,
+            City::Fagaras => 176,
+            City::Giurgiu => 77,
+            City::Hirsova => 151,
+            City::Iasi => 226,
+            City::Lugoj => 244,
+            City::Mehadia => 241,
+            City::Neamt => 234,
+            City::Oradea => 380,
+            City::Pitesti => 100,
+            City::RimnicuVilcea => 193,
+            City::Sibiu => 253,
+            City::Timisoara => 329,
+            City::Urziceni => 80,
+            City::Vaslui => 199,
+            City::Zerind => 374,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            City::Arad => "Arad",
+            City::Bucharest => "Bucharest",
+            City::Craiova => "Craiova",
+            City::Drobeta => "Drobeta",
+            City::Eforie => "Eforie",
+            City::Fagaras => "Fagaras",
+            City::Giurgiu => "Giurgiu",
+            City::Hirsova => "Hirsova",
+            City::Iasi => "Iasi",
+            City::Lugoj => "Lugoj",
+            City::Mehadia => "Mehadia",
+            City::Neamt => "Neamt",
+            City::Oradea => "Oradea",
+            City::Pitesti => "Pitesti",
+            City::RimnicuVilcea => "Rimnicu Vilcea",
+            City::Sibiu => "Sibiu",
+            City::Timisoara => "Timisoara",
+            City::Urziceni => "Urziceni",
+            City::Vaslui => "Vaslui",
+            City::Zerind => "Zerind",
+        }
+    }
+}
+
+/// [TryFrom<&str>]
+/// Accepts the city name ignoring case and spaces, e.g. `"Rimnicu Vilcea"` or `"rimnicuvilcea"`.
+impl TryFrom<&str> for City {
+    type Error = RomaniaError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let normalize = |name: &str| name.replace(' ', "").to_lowercase();
+        let wanted = normalize(value);
+        City::ALL
+            .into_iter()
+            .find(|city| normalize(city.name()) == wanted)
+            .ok_or_else(|| RomaniaError::UnknownCity(value.trim().to_string()))
+    }
+}
+
+impl Display for City {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum RomaniaError {
+    #[error("Unknown city")]
+    UnknownCity(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn city_neighbors_returns_roads_in_both_directions() {
+        let neighbors = City::Sibiu.neighbors();
+
+        assert_eq!(neighbors.len(), 4);
+        assert!(neighbors.contains(&(City::Arad, 140)));
+        assert!(neighbors.contains(&(City::Oradea, 151)));
+        assert!(neighbors.contains(&(City::Fagaras, 99)));
+        assert!(neighbors.contains(&(City::RimnicuVilcea, 80)));
+    }
+
+    #[test]
+    fn city_straight_line_distance_never_overestimates_road_distance() {
+        for (a, b, distance) in ROADS {
+            let (h_a, h_b) = (
+                a.straight_line_distance_to_bucharest(),
+                b.straight_line_distance_to_bucharest(),
+            );
+            assert!(
+                h_a.abs_diff(h_b) <= distance,
+                "heuristic is not consistent between {} and {}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn city_tryfrom_str_works_as_expected() {
+        assert_eq!(City::try_from("Arad"), Ok(City::Arad));
+        assert_eq!(City::try_from("rimnicu vilcea"), Ok(City::RimnicuVilcea));
+        assert_eq!(
+            City::try_from("Paris"),
+            Err(RomaniaError::UnknownCity("Paris".into()))
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod city;
+pub mod route_problem;
+
+pub use city::*;
+pub use route_problem::*;
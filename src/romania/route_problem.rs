@@ -0,0 +1,125 @@
+use super::City;
+use crate::search::{HeuristicRegistry, SearchProblem, Successor};
+
+/// [`RouteProblem`]
+/// ## Route finding on the Romania road map.
+/// States are cities, actions are "drive to city" and the cost of an action is the
+/// road distance.
+/// # Example - greedy vs A* from the textbook
+/// ```
+/// # use algoritmos_rust::romania::*;
+/// # use algoritmos_rust::search::{a_star, greedy_best_first};
+/// let problem = RouteProblem::new(City::Arad, City::Bucharest);
+/// let greedy = greedy_best_first(&problem, |city| problem.heuristic(city)).unwrap();
+/// let optimal = a_star(&problem, |city| problem.heuristic(city)).unwrap();
+/// assert_eq!(greedy.cost, 450);
+/// assert_eq!(optimal.cost, 418);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RouteProblem {
+    pub from: City,
+    pub to: City,
+}
+
+impl RouteProblem {
+    pub fn new(from: City, to: City) -> Self {
+        Self { from, to }
+    }
+
+    /// [`heuristic`]
+    /// Straight line distance to the goal.
+    /// The book only provides the distances to Bucharest, so for any other goal this
+    /// returns `0.0` (which is still admissible, the search just becomes uninformed).
+    pub fn heuristic(&self, city: &City) -> f64 {
+        match self.to {
+            City::Bucharest => f64::from(city.straight_line_distance_to_bucharest()),
+            _ => 0.0,
+        }
+    }
+
+    /// [`heuristics`]
+    /// `"straight_line"`, see [`RouteProblem::heuristic`].
+    pub fn heuristics() -> HeuristicRegistry<Self> {
+        let mut registry = HeuristicRegistry::new();
+        registry.register("straight_line", |problem: &RouteProblem| {
+            let problem = *problem;
+            Box::new(move |city| problem.heuristic(city))
+        });
+        registry
+    }
+}
+
+impl SearchProblem for RouteProblem {
+    type State = City;
+    type Action = City;
+    type Cost = u32;
+    type Successors<'a> = std::vec::IntoIter<Successor<City, City>>;
+
+    fn initial_state(&self) -> City {
+        self.from
+    }
+
+    fn is_goal(&self, state: &City) -> bool {
+        *state == self.to
+    }
+
+    fn successors<'a>(&'a self, state: &'a City) -> Self::Successors<'a> {
+        state
+            .neighbors()
+            .into_iter()
+            .map(|(city, distance)| Successor::new(city, city, distance))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::search::{a_star, greedy_best_first};
+
+    #[test]
+    fn route_problem_greedy_reproduces_textbook_path() {
+        let problem = RouteProblem::new(City::Arad, City::Bucharest);
+
+        let solution = greedy_best_first(&problem, |city| problem.heuristic(city)).unwrap();
+
+        assert_eq!(
+            solution.states,
+            vec![City::Arad, City::Sibiu, City::Fagaras, City::Bucharest]
+        );
+        assert_eq!(solution.cost, 450);
+    }
+
+    #[test]
+    fn route_problem_a_star_reproduces_textbook_path() {
+        let problem = RouteProblem::new(City::Arad, City::Bucharest);
+
+        let solution = a_star(&problem, |city| problem.heuristic(city)).unwrap();
+
+        assert_eq!(
+            solution.states,
+            vec![
+                City::Arad,
+                City::Sibiu,
+                City::RimnicuVilcea,
+                City::Pitesti,
+                City::Bucharest
+            ]
+        );
+        assert_eq!(solution.cost, 418);
+    }
+
+    #[test]
+    fn route_problem_without_heuristic_still_finds_optimal_route() {
+        let problem = RouteProblem::new(City::Neamt, City::Timisoara);
+
+        let solution = a_star(&problem, |city| problem.heuristic(city)).unwrap();
+
+        assert_eq!(*solution.goal(), City::Timisoara);
+        assert_eq!(
+            solution.cost,
+            87 + 92 + 142 + 85 + 101 + 97 + 80 + 140 + 118
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use std::fmt::Display;
+
+use super::RushHourError;
+
+/// Identifier of the vehicle that must reach the exit.
+pub const PRIMARY: char = 'A';
+
+/// [`Vehicle`]
+/// A car (length 2) or truck (length 3), `(row, column)` is its top-left cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vehicle {
+    pub id: char,
+    pub row: usize,
+    pub column: usize,
+    pub length: usize,
+    pub horizontal: bool,
+}
+
+/// [`Move`]
+/// Slides `vehicle` by `steps` cells: right/down when positive, left/up when negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Move {
+    pub vehicle: char,
+    pub steps: i32,
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{:+}", self.vehicle, self.steps)
+    }
+}
+
+/// [`RushHourBoard`]
+/// ## Rush Hour (traffic jam) board.
+/// Vehicles only slide along their orientation, the puzzle is solved when the primary
+/// vehicle `A` reaches the right edge of its row, where the exit is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RushHourBoard {
+    size: usize,
+    /// `.` is an empty cell, `x` a wall and letters are vehicles, row by row.
+    cells: Vec<u8>,
+}
+
+impl RushHourBoard {
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn vehicles(&self) -> Vec<Vehicle> {
+        let mut vehicles: Vec<Vehicle> = vec![];
+        for (index, cell) in self.cells.iter().enumerate() {
+            if !cell.is_ascii_uppercase() || vehicles.iter().any(|v| v.id == *cell as char) {
+                continue;
+            }
+            let (row, column) = (index / self.size, index % self.size);
+            let horizontal = column + 1 < self.size && self.cells[index + 1] == *cell;
+            let stride = if horizontal { 1 } else { self.size };
+            let length = (0..)
+                .map(|k| index + k * stride)
+                .take_while(|i| {
+                    *i < self.cells.len()
+                        && self.cells[*i] == *cell
+                        && (!horizontal || i / self.size == row)
+                })
+                .count();
+            vehicles.push(Vehicle {
+                id: *cell as char,
+                row,
+                column,
+                length,
+                horizontal,
+            });
+        }
+        vehicles.sort_by_key(|v| v.id);
+        vehicles
+    }
+
+    pub fn vehicle(&self, id: char) -> Option<Vehicle> {
+        self.vehicles().into_iter().find(|v| v.id == id)
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.vehicle(PRIMARY)
+            .is_some_and(|primary| primary.column + primary.length == self.size)
+    }
+
+    /// [`moves`]
+    /// Every legal slide, of any distance, of every vehicle.
+    pub fn moves(&self) -> Vec<(Move, RushHourBoard)> {
+        let mut moves = vec![];
+        for vehicle in self.vehicles() {
+            for direction in [-1, 1] {
+                let mut board = self.clone();
+                let mut steps = 0;
+                while let Some(next) = board.slide_one(&vehicle, steps, direction) {
+                    steps += direction;
+                    board = next;
+                    moves.push((
+                        Move {
+                            vehicle: vehicle.id,
+                            steps,
+                        },
+                        board.clone(),
+                    ));
+                }
+            }
+        }
+        moves
+    }
+
+    /// [`apply`]
+    /// Returns `None` if the vehicle doesn't exist or can't slide that far.
+    pub fn apply(&self, movement: Move) -> Option<RushHourBoard> {
+        let vehicle = self.vehicle(movement.vehicle)?;
+        let direction = movement.steps.signum();
+        let mut board = self.clone();
+        for done in 0..movement.steps.abs() {
+            board = board.slide_one(&vehicle, done * direction, direction)?;
+        }
+        Some(board)
+    }
+
+    /// [`blocking_vehicles`]
+    /// ## Blocked-car heuristic.
+    /// `0` when solved, otherwise 1 plus the number of vehicles between the primary vehicle
+    /// and the exit: each of them needs at least one move, and so does the primary vehicle.
+    /// Never overestimates the number of moves left.
+    pub fn blocking_vehicles(&self) -> usize {
+        let Some(primary) = self.vehicle(PRIMARY) else {
+            return 0;
+        };
+        if self.is_solved() {
+            return 0;
+        }
+        let row = &self.cells[primary.row * self.size..(primary.row + 1) * self.size];
+        let mut blocking = row[primary.column + primary.length..]
+            .iter()
+            .filter(|cell| cell.is_ascii_uppercase())
+            .collect::<Vec<&u8>>();
+        blocking.dedup();
+        1 + blocking.len()
+    }
+
+    /// The board after moving `vehicle`, already moved by `offset`, one more cell.
+    fn slide_one(&self, vehicle: &Vehicle, offset: i32, direction: i32) -> Option<RushHourBoard> {
+        let (row, column) = if vehicle.horizontal {
+            (vehicle.row as i32, vehicle.column as i32 + offset)
+        } else {
+            (vehicle.row as i32 + offset, vehicle.column as i32)
+        };
+        let length = vehicle.length as i32;
+        let (leaving, entering) = match (vehicle.horizontal, direction) {
+            (true, 1) => ((row, column), (row, column + length)),
+            (true, _) => ((row, column + length - 1), (row, column - 1)),
+            (false, 1) => ((row, column), (row + length, column)),
+            (false, _) => ((row + length - 1, column), (row - 1, column)),
+        };
+        let size = self.size as i32;
+        if !(0..size).contains(&entering.0) || !(0..size).contains(&entering.1) {
+            return None;
+        }
+        let index = |(r, c): (i32, i32)| (r * size + c) as usize;
+        if self.cells[index(entering)] != b'.' {
+            return None;
+        }
+        let mut board = self.clone();
+        board.cells[index(entering)] = vehicle.id as u8;
+        board.cells[index(leaving)] = b'.';
+        Some(board)
+    }
+}
+
+/// [TryFrom<&str>]
+/// Accepts the usual board string: `size * size` cells row by row, optionally split in
+/// lines, where `.` or `o` is empty, `x` is a wall and uppercase letters are vehicles
+/// (`A` is the primary vehicle), e.g. `"....B.\n....B.\nAA..B.\n......\n......\n......"`.
+impl TryFrom<&str> for RushHourBoard {
+    type Error = RushHourError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let cells = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c {
+                '.' | 'o' => Ok(b'.'),
+                'x' => Ok(b'x'),
+                'A'..='Z' => Ok(c as u8),
+                _ => Err(RushHourError::ParseFromStringError(format!(
+                    "Invalid cell {}",
+                    c
+                ))),
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        let size = (1..=cells.len())
+            .find(|s| s * s >= cells.len())
+            .unwrap_or(0);
+        if size < 2 || size * size != cells.len() {
+            return Err(RushHourError::InvalidSize(cells.len()));
+        }
+
+        let board = RushHourBoard { size, cells };
+        for vehicle in board.vehicles() {
+            let occupied = board
+                .cells
+                .iter()
+                .filter(|cell| **cell == vehicle.id as u8)
+                .count();
+            if vehicle.length < 2 || occupied != vehicle.length {
+                return Err(RushHourError::InvalidVehicle(vehicle.id));
+            }
+        }
+        match board.vehicle(PRIMARY) {
+            Some(primary) if primary.horizontal => Ok(board),
+            _ => Err(RushHourError::MissingPrimary),
+        }
+    }
+}
+
+impl Display for RushHourBoard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.size) {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SIMPLE: &str = "....B.
+                          ....B.
+                          AA..B.
+                          ......
+                          ......
+                          ......";
+
+    #[test]
+    fn rush_hour_try_from_validates_boards() {
+        let board = RushHourBoard::try_from(SIMPLE).unwrap();
+        assert_eq!(board.size(), 6);
+        assert_eq!(
+            board.vehicles(),
+            vec![
+                Vehicle {
+                    id: 'A',
+                    row: 2,
+                    column: 0,
+                    length: 2,
+                    horizontal: true
+                },
+                Vehicle {
+                    id: 'B',
+                    row: 0,
+                    column: 4,
+                    length: 3,
+                    horizontal: false
+                },
+            ]
+        );
+        assert_eq!(
+            RushHourBoard::try_from("AA.B\n...B\n.B..\n...."),
+            Err(RushHourError::InvalidVehicle('B'))
+        );
+        assert_eq!(
+            RushHourBoard::try_from("A...\nA...\n....\n...."),
+            Err(RushHourError::MissingPrimary)
+        );
+        assert_eq!(
+            RushHourBoard::try_from("AA..."),
+            Err(RushHourError::InvalidSize(5))
+        );
+        // single line format with `o` for empty cells and `x` for walls.
+        let compact = RushHourBoard::try_from("ooooBoooooBoAAooBoxooooooooooooooooo").unwrap();
+        assert_eq!(compact.to_string().lines().nth(3), Some("x....."));
+        assert_eq!(compact.vehicles(), board.vehicles());
+    }
+
+    #[test]
+    fn rush_hour_moves_slide_only_along_orientation() {
+        let board = RushHourBoard::try_from(SIMPLE).unwrap();
+
+        let moves = board
+            .moves()
+            .into_iter()
+            .map(|(m, _)| m.to_string())
+            .collect::<Vec<String>>();
+
+        assert_eq!(moves, vec!["A+1", "A+2", "B+1", "B+2", "B+3"]);
+        assert_eq!(board.blocking_vehicles(), 2);
+
+        let solved = board
+            .apply(Move {
+                vehicle: 'B',
+                steps: 3,
+            })
+            .and_then(|b| {
+                b.apply(Move {
+                    vehicle: 'A',
+                    steps: 4,
+                })
+            })
+            .unwrap();
+        assert!(solved.is_solved());
+        assert_eq!(solved.blocking_vehicles(), 0);
+        assert_eq!(
+            board.apply(Move {
+                vehicle: 'A',
+                steps: 3
+            }),
+            None
+        );
+    }
+}
@@ -0,0 +1,20 @@
+pub mod board;
+pub mod problem;
+
+pub use board::*;
+pub use problem::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum RushHourError {
+    #[error("Board must be a square with at least 2 cells per side")]
+    InvalidSize(usize),
+    #[error("Vehicle cells must form a straight line of at least 2 cells")]
+    InvalidVehicle(char),
+    #[error("Board must contain a horizontal primary vehicle `A`")]
+    MissingPrimary,
+    #[error("Error when trying to parse from RushHourBoard string")]
+    ParseFromStringError(String),
+}
@@ -0,0 +1,71 @@
+use super::{Move, RushHourBoard};
+use crate::search::{SearchProblem, Successor};
+
+/// [`RushHourProblem`]
+/// Free the primary vehicle: every slide costs 1, whatever the distance.
+#[derive(Debug, Clone)]
+pub struct RushHourProblem {
+    pub start: RushHourBoard,
+}
+
+impl RushHourProblem {
+    pub fn new(start: RushHourBoard) -> Self {
+        Self { start }
+    }
+}
+
+impl SearchProblem for RushHourProblem {
+    type State = RushHourBoard;
+    type Action = Move;
+    type Cost = u32;
+    type Successors<'a> = std::vec::IntoIter<Successor<RushHourBoard, Move>>;
+
+    fn initial_state(&self) -> RushHourBoard {
+        self.start.clone()
+    }
+
+    fn is_goal(&self, state: &RushHourBoard) -> bool {
+        state.is_solved()
+    }
+
+    fn successors<'a>(&'a self, state: &'a RushHourBoard) -> Self::Successors<'a> {
+        state
+            .moves()
+            .into_iter()
+            .map(|(movement, board)| Successor::new(movement, board, 1))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::search::a_star;
+
+    #[test]
+    fn rush_hour_a_star_with_blocking_heuristic_is_optimal() {
+        let start = RushHourBoard::try_from(
+            "BBB..C
+             ....DC
+             AA..DC
+             E...FF
+             E.GG..
+             E.....",
+        )
+        .unwrap();
+        let problem = RushHourProblem::new(start.clone());
+
+        let informed = a_star(&problem, |b| b.blocking_vehicles() as f64).unwrap();
+        let uninformed = a_star(&problem, |_| 0.0).unwrap();
+
+        assert!(informed.goal().is_solved());
+        assert_eq!(informed.cost, uninformed.cost);
+        assert!(informed.cost as usize >= start.blocking_vehicles());
+        let replayed = informed
+            .actions
+            .iter()
+            .try_fold(start, |board, movement| board.apply(*movement));
+        assert_eq!(replayed.as_ref(), Some(informed.goal()));
+    }
+}
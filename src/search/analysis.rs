@@ -0,0 +1,270 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use super::{Cost, SearchProblem};
+
+/// [`StateSpace`]
+/// ## Every state reachable from the initial state of a [`SearchProblem`].
+/// States are numbered in breadth-first order, the initial state is `0`.
+#[derive(Debug, Clone)]
+pub struct StateSpace<S, C = u32> {
+    states: Vec<S>,
+    /// `(target, cost)` of every transition, by source state.
+    edges: Vec<Vec<(usize, C)>>,
+    goals: Vec<bool>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> StateSpace<S, C> {
+    /// [`explore`]
+    /// Enumerates the reachable states, `None` if there are more than `state_limit`.
+    /// [Dead ends](SearchProblem::is_dead_end) are states of the space without
+    /// successors.
+    pub fn explore<P>(problem: &P, state_limit: usize) -> Option<Self>
+    where
+        P: SearchProblem<State = S, Cost = C>,
+    {
+        let initial = problem.initial_state();
+        let mut index = HashMap::from([(initial.clone(), 0)]);
+        let mut space = StateSpace {
+            states: vec![initial],
+            edges: vec![],
+            goals: vec![],
+        };
+        let mut current = 0;
+        while current < space.states.len() {
+            let state = space.states[current].clone();
+            space.goals.push(problem.is_goal(&state));
+            let mut edges = vec![];
+            // dead ends are reached, and counted as dead, but never expanded.
+            let successors = (!problem.is_dead_end(&state)).then(|| problem.successors(&state));
+            for successor in successors.into_iter().flatten() {
+                let target = match index.get(&successor.state) {
+                    Some(target) => *target,
+                    None => {
+                        if space.states.len() == state_limit {
+                            return None;
+                        }
+                        index.insert(successor.state.clone(), space.states.len());
+                        space.states.push(successor.state);
+                        space.states.len() - 1
+                    }
+                };
+                edges.push((target, successor.cost));
+            }
+            space.edges.push(edges);
+            current += 1;
+        }
+        Some(space)
+    }
+
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Cheapest cost from every state to a goal, `None` when no goal can be reached.
+    pub fn cost_to_goal(&self) -> Vec<Option<C>> {
+        let mut reverse = vec![vec![]; self.len()];
+        for (source, edges) in self.edges.iter().enumerate() {
+            for (target, cost) in edges {
+                reverse[*target].push((source, *cost));
+            }
+        }
+        let mut costs = vec![None; self.len()];
+        let mut heap = (0..self.len())
+            .filter(|state| self.goals[*state])
+            .map(|state| Reverse((C::zero(), state)))
+            .collect::<BinaryHeap<_>>();
+        while let Some(Reverse((cost, state))) = heap.pop() {
+            if costs[state].is_some() {
+                continue;
+            }
+            costs[state] = Some(cost);
+            for (source, step) in &reverse[state] {
+                if costs[*source].is_none() {
+                    heap.push(Reverse((cost.add(*step), *source)));
+                }
+            }
+        }
+        costs
+    }
+
+    /// States where `heuristic` overestimates the cost to the goal.
+    pub fn inadmissible_states<H>(&self, heuristic: H) -> Vec<&S>
+    where
+        H: Fn(&S) -> f64,
+    {
+        self.cost_to_goal()
+            .into_iter()
+            .zip(&self.states)
+            .filter(|(cost, state)| match cost {
+                Some(cost) => heuristic(state) > cost.to_f64(),
+                None => false,
+            })
+            .map(|(_, state)| state)
+            .collect()
+    }
+
+    /// Number of actions of the shortest path from `source` to every state.
+    fn distances_from(&self, source: usize) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.len()];
+        distances[source] = Some(0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(state) = queue.pop_front() {
+            let distance = distances[state].unwrap_or(0);
+            for (target, _) in &self.edges[state] {
+                if distances[*target].is_none() {
+                    distances[*target] = Some(distance + 1);
+                    queue.push_back(*target);
+                }
+            }
+        }
+        distances
+    }
+
+    /// [`analysis`]
+    /// The diameter needs a breadth-first search from every state, quadratic in the number
+    /// of states.
+    pub fn analysis(&self) -> StateSpaceAnalysis<C> {
+        let from_initial = self.distances_from(0);
+        let diameter = (0..self.len())
+            .flat_map(|source| self.distances_from(source).into_iter().flatten())
+            .max()
+            .unwrap_or(0);
+        StateSpaceAnalysis {
+            reachable_states: self.len(),
+            transitions: self.edges.iter().map(|edges| edges.len()).sum(),
+            goal_states: self.goals.iter().filter(|goal| **goal).count(),
+            dead_states: self.cost_to_goal().iter().filter(|c| c.is_none()).count(),
+            diameter,
+            optimal_solution_length: (0..self.len())
+                .filter(|state| self.goals[*state])
+                .filter_map(|state| from_initial[state])
+                .min(),
+            optimal_cost: self.cost_to_goal()[0],
+        }
+    }
+}
+
+/// [`StateSpaceAnalysis`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateSpaceAnalysis<C = u32> {
+    pub reachable_states: usize,
+    pub transitions: usize,
+    pub goal_states: usize,
+    /// Reachable states from which no goal can be reached.
+    pub dead_states: usize,
+    /// Longest shortest path (in actions) between two states, when one reaches the other.
+    pub diameter: usize,
+    /// Fewest actions from the initial state to a goal.
+    pub optimal_solution_length: Option<usize>,
+    /// Cheapest cost from the initial state to a goal.
+    pub optimal_cost: Option<C>,
+}
+
+/// [`analyze`]
+/// ## Reachable state space statistics.
+/// Enumerates the reachable states of `problem` and reports their statistics, `None`
+/// when there are more than `state_limit` states.
+pub fn analyze<P: SearchProblem>(
+    problem: &P,
+    state_limit: usize,
+) -> Option<StateSpaceAnalysis<P::Cost>> {
+    StateSpace::explore(problem, state_limit).map(|space| space.analysis())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::FixedCannibalsProblem;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::Successor;
+
+    #[test]
+    fn analysis_of_hanoi_matches_known_values() {
+        let problem = HanoiProblem::new(3, 3).unwrap();
+
+        let analysis = analyze(&problem, 1000).unwrap();
+
+        assert_eq!(analysis.reachable_states, 27);
+        // 39 edges in both directions.
+        assert_eq!(analysis.transitions, 78);
+        assert_eq!(analysis.goal_states, 1);
+        assert_eq!(analysis.dead_states, 0);
+        assert_eq!(analysis.diameter, 7);
+        assert_eq!(analysis.optimal_solution_length, Some(7));
+        assert_eq!(analysis.optimal_cost, Some(7));
+        assert_eq!(analyze(&problem, 26), None);
+    }
+
+    /// Counts up from 0 by 1 or 3 until 4, anything above 4 is a dead end.
+    struct Counter;
+
+    impl SearchProblem for Counter {
+        type State = u32;
+        type Action = u32;
+        type Cost = u32;
+        type Successors<'a> = std::vec::IntoIter<Successor<u32, u32>>;
+
+        fn initial_state(&self) -> u32 {
+            0
+        }
+
+        fn is_goal(&self, state: &u32) -> bool {
+            *state == 4
+        }
+
+        fn successors<'a>(&'a self, state: &'a u32) -> Self::Successors<'a> {
+            let steps = match state {
+                0..=3 => vec![1, 3],
+                _ => vec![],
+            };
+            steps
+                .into_iter()
+                .map(|step| Successor::new(step, state + step, step))
+                .collect::<Vec<_>>()
+                .into_iter()
+        }
+    }
+
+    #[test]
+    fn analysis_counts_dead_states_and_checks_heuristics() {
+        let space = StateSpace::explore(&Counter, 100).unwrap();
+
+        let analysis = space.analysis();
+
+        assert_eq!(analysis.reachable_states, 7);
+        assert_eq!(analysis.dead_states, 2);
+        assert_eq!(analysis.optimal_solution_length, Some(2));
+        assert_eq!(analysis.optimal_cost, Some(4));
+        assert!(space
+            .inadmissible_states(|s| 4.0 - f64::from(*s.min(&4)))
+            .is_empty());
+        assert_eq!(space.inadmissible_states(|_| 2.0), vec![&3, &4]);
+    }
+
+    #[test]
+    fn analysis_counts_dead_ends_as_dead_states() {
+        let problem = FixedCannibalsProblem::<3, 2>;
+
+        let space = StateSpace::explore(&problem, 1000).unwrap();
+        let analysis = space.analysis();
+
+        let game_overs = space
+            .states()
+            .iter()
+            .filter(|state| state.is_game_over())
+            .count();
+        assert!(game_overs > 0);
+        assert!(analysis.dead_states >= game_overs);
+        assert_eq!(analysis.optimal_solution_length, Some(11));
+    }
+}
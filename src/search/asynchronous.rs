@@ -0,0 +1,274 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+
+use thiserror::Error;
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::{Cancellation, SearchProblem};
+
+/// [`SolveProgress`]
+/// Sent by [`AsyncSolver`] every few expansions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolveProgress {
+    pub expanded: usize,
+}
+
+/// [`SolveAsyncError`]
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum SolveAsyncError {
+    #[error("the search was cancelled")]
+    Cancelled,
+    #[error("the solver panicked: {0}")]
+    Panicked(String),
+}
+
+/// [`AsyncSolver`]
+/// ## Runs a blocking solver on the tokio blocking pool.
+/// The solver receives the problem wrapped in a [`Monitored`], which counts expansions
+/// to report progress and stops expanding once the search is cancelled, so any solver
+/// of this module works unchanged. Dropping the returned future (e.g. losing a
+/// `tokio::select!` or a `tokio::time::timeout`) cancels the search too.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// let solution = runtime
+///     .block_on(solve_async(HanoiProblem::new(3, 3).unwrap(), bfs))
+///     .unwrap();
+/// assert_eq!(solution.unwrap().len(), 7);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AsyncSolver {
+    cancellation: Cancellation,
+    progress: Option<UnboundedSender<SolveProgress>>,
+    progress_every: usize,
+}
+
+impl AsyncSolver {
+    pub fn new() -> Self {
+        Self {
+            cancellation: Cancellation::default(),
+            progress: None,
+            progress_every: 1000,
+        }
+    }
+
+    /// Sends a [`SolveProgress`] to `sender` every `every` expansions.
+    pub fn progress(mut self, sender: UnboundedSender<SolveProgress>, every: usize) -> Self {
+        self.progress = Some(sender);
+        self.progress_every = every.max(1);
+        self
+    }
+
+    /// Handle that cancels the search, which then fails with
+    /// [`SolveAsyncError::Cancelled`].
+    pub fn cancellation(&self) -> Cancellation {
+        self.cancellation.clone()
+    }
+
+    pub async fn solve<P, T, F>(self, problem: P, solver: F) -> Result<T, SolveAsyncError>
+    where
+        P: SearchProblem + Send + 'static,
+        T: Send + 'static,
+        F: FnOnce(&Monitored<P>) -> T + Send + 'static,
+    {
+        let mut guard = CancelOnDrop(Some(self.cancellation.clone()));
+        let cancellation = self.cancellation.clone();
+        let task = tokio::task::spawn_blocking(move || {
+            solver(&Monitored {
+                problem,
+                cancellation: self.cancellation,
+                progress: self.progress,
+                progress_every: self.progress_every,
+                expanded: Cell::new(0),
+            })
+        });
+        let result = task
+            .await
+            .map_err(|error| SolveAsyncError::Panicked(error.to_string()));
+        guard.0 = None;
+        match result? {
+            _ if cancellation.is_cancelled() => Err(SolveAsyncError::Cancelled),
+            result => Ok(result),
+        }
+    }
+}
+
+impl Default for AsyncSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// [`solve_async`]
+/// [`AsyncSolver::solve`] without progress notifications.
+pub async fn solve_async<P, T, F>(problem: P, solver: F) -> Result<T, SolveAsyncError>
+where
+    P: SearchProblem + Send + 'static,
+    T: Send + 'static,
+    F: FnOnce(&Monitored<P>) -> T + Send + 'static,
+{
+    AsyncSolver::new().solve(problem, solver).await
+}
+
+/// Cancels the search when the future awaiting it is dropped before it finishes.
+struct CancelOnDrop(Option<Cancellation>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some(cancellation) = &self.0 {
+            cancellation.cancel();
+        }
+    }
+}
+
+/// [`Monitored`]
+/// The problem given to the solver of an [`AsyncSolver`]. Once cancelled, states have
+/// no successors so the search runs out of frontier.
+pub struct Monitored<P> {
+    problem: P,
+    cancellation: Cancellation,
+    progress: Option<UnboundedSender<SolveProgress>>,
+    progress_every: usize,
+    expanded: Cell<usize>,
+}
+
+impl<P> Monitored<P> {
+    pub fn inner(&self) -> &P {
+        &self.problem
+    }
+}
+
+impl<P: SearchProblem> SearchProblem for Monitored<P> {
+    type State = P::State;
+    type Action = P::Action;
+    type Cost = P::Cost;
+    type Successors<'a>
+        = std::iter::Take<P::Successors<'a>>
+    where
+        Self: 'a;
+
+    fn initial_state(&self) -> P::State {
+        self.problem.initial_state()
+    }
+
+    fn is_goal(&self, state: &P::State) -> bool {
+        self.problem.is_goal(state)
+    }
+
+    fn successors<'a>(&'a self, state: &'a P::State) -> Self::Successors<'a> {
+        let expanded = self.expanded.get() + 1;
+        self.expanded.set(expanded);
+        if let Some(progress) = &self.progress {
+            if expanded.is_multiple_of(self.progress_every) {
+                // nobody listening anymore is not a reason to stop.
+                let _ = progress.send(SolveProgress { expanded });
+            }
+        }
+        let limit = match self.cancellation.is_cancelled() {
+            true => 0,
+            false => usize::MAX,
+        };
+        self.problem.successors(state).take(limit)
+    }
+
+    fn canonicalize<'a>(&self, state: &'a P::State) -> Cow<'a, P::State> {
+        self.problem.canonicalize(state)
+    }
+
+    fn is_dead_end(&self, state: &P::State) -> bool {
+        self.problem.is_dead_end(state)
+    }
+
+    fn dominates(&self, a: &P::State, b: &P::State) -> bool {
+        self.problem.dominates(a, b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::{a_star, bfs, Successor};
+
+    /// Counts forever.
+    struct Endless;
+
+    impl SearchProblem for Endless {
+        type State = u64;
+        type Action = ();
+        type Cost = u32;
+        type Successors<'a> = std::iter::Once<Successor<u64, ()>>;
+
+        fn initial_state(&self) -> u64 {
+            0
+        }
+
+        fn is_goal(&self, _: &u64) -> bool {
+            false
+        }
+
+        fn successors<'a>(&'a self, state: &'a u64) -> Self::Successors<'a> {
+            std::iter::once(Successor::new((), state + 1, 1))
+        }
+    }
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn solve_async_reports_progress() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let solution = runtime()
+            .block_on(
+                AsyncSolver::new()
+                    .progress(sender, 10)
+                    .solve(HanoiProblem::new(4, 3).unwrap(), |problem| {
+                        a_star(problem, |state| problem.inner().heuristic(state))
+                    }),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(solution.len(), 15);
+        assert_eq!(receiver.try_recv(), Ok(SolveProgress { expanded: 10 }));
+        assert_eq!(receiver.try_recv(), Ok(SolveProgress { expanded: 20 }));
+    }
+
+    #[test]
+    fn solve_async_stops_when_cancelled() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let solver = AsyncSolver::new().progress(sender, 100);
+        let cancellation = solver.cancellation();
+        let result = runtime().block_on(async move {
+            let search = tokio::spawn(solver.solve(Endless, bfs));
+            receiver.recv().await;
+            cancellation.cancel();
+            search.await.unwrap()
+        });
+
+        assert_eq!(result, Err(SolveAsyncError::Cancelled));
+    }
+
+    #[test]
+    fn solve_async_is_cancelled_when_dropped() {
+        let solver = AsyncSolver::new();
+        let cancellation = solver.cancellation();
+        let result = runtime().block_on(async {
+            tokio::time::timeout(Duration::from_millis(20), solver.solve(Endless, bfs)).await
+        });
+
+        assert!(result.is_err());
+        assert!(cancellation.is_cancelled());
+    }
+}
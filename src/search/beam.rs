@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+use std::time::Instant;
+
+use super::best_first::{build_solution, Node};
+use super::{Cost, PruneReason, SearchEvent, SearchProblem, SearchSink, SearchStats, Solution};
+
+/// [`beam_search`]
+/// ## Beam search.
+/// A breadth-first search that keeps only the `width` successors of every level with
+/// the lowest `heuristic` (a width of 0 counts as 1), ties going to the first generated.
+/// Memory stays within `width` nodes per level, but the search is incomplete: it fails
+/// when every path to a goal leaves the beam, and the solution found is not guaranteed
+/// to be optimal. The wider the beam, the closer it gets to [`bfs`](super::bfs).
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::search::*;
+/// let problem = FixedCannibalsProblem::<3, 2>;
+/// let solution = beam_search(&problem, FixedWorldState::heuristic, 2).unwrap();
+/// assert_eq!(solution.len(), bfs(&problem).unwrap().len());
+/// ```
+pub fn beam_search<P, H>(
+    problem: &P,
+    heuristic: H,
+    width: usize,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    beam_search_traced(problem, heuristic, width, &mut ())
+}
+
+/// [`beam_search_traced`]
+/// [`beam_search`] reporting every [`SearchEvent`] to `sink`, `f` being the heuristic.
+/// Successors left out of the beam are pruned with [`PruneReason::Bound`].
+pub fn beam_search_traced<P, H>(
+    problem: &P,
+    heuristic: H,
+    width: usize,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    profile!("beam_search");
+    let width = width.max(1);
+    let start = Instant::now();
+    let initial_state = problem.initial_state();
+    let mut discovered = HashSet::from([problem.canonicalize(&initial_state).into_owned()]);
+    sink.event(SearchEvent::Generate {
+        state: &initial_state,
+        node: 0,
+        parent: None,
+        g: P::Cost::zero(),
+        f: heuristic(&initial_state),
+    });
+    let mut nodes = vec![Node {
+        state: initial_state,
+        parent: None,
+        action: None,
+        path_cost: P::Cost::zero(),
+        depth: 0,
+    }];
+    let mut level = vec![0];
+    let mut stats = SearchStats::started();
+
+    while !level.is_empty() {
+        // (heuristic, parent, key, successor) of every new state of the next level.
+        let mut candidates = vec![];
+        for (position, &current) in level.iter().enumerate() {
+            let path_cost = nodes[current].path_cost;
+            sink.event(SearchEvent::Expand {
+                state: &nodes[current].state,
+                node: current,
+                g: path_cost,
+                f: heuristic(&nodes[current].state),
+                frontier: level.len() - position - 1,
+            });
+            if problem.is_goal(&nodes[current].state) {
+                stats.elapsed = start.elapsed();
+                let solution = build_solution(&nodes, current, stats);
+                sink.event(SearchEvent::Solution {
+                    state: solution.goal(),
+                    cost: solution.cost,
+                    length: solution.len(),
+                });
+                return Some(solution);
+            }
+            let state = nodes[current].state.clone();
+            stats.expanded += 1;
+            profile!("expand");
+            for successor in problem.successors(&state) {
+                let successor_path_cost = path_cost.add(successor.cost);
+                let reason = problem
+                    .is_dead_end(&successor.state)
+                    .then_some(PruneReason::DeadEnd);
+                let key = problem.canonicalize(&successor.state).into_owned();
+                let reason = reason.or_else(|| {
+                    (!discovered.insert(key.clone())).then_some(PruneReason::Duplicate)
+                });
+                if let Some(reason) = reason {
+                    if reason == PruneReason::Duplicate {
+                        stats.duplicates += 1;
+                    }
+                    sink.event(SearchEvent::Prune {
+                        state: &successor.state,
+                        parent: current,
+                        g: successor_path_cost,
+                        reason,
+                    });
+                    continue;
+                }
+                candidates.push((heuristic(&successor.state), current, key, successor));
+            }
+        }
+
+        // stable, so ties keep the generation order.
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let dropped = candidates.split_off(width.min(candidates.len()));
+        for (_, parent, key, successor) in dropped {
+            // may still be reached through another path of a later level.
+            discovered.remove(&key);
+            sink.event(SearchEvent::Prune {
+                state: &successor.state,
+                parent,
+                g: nodes[parent].path_cost.add(successor.cost),
+                reason: PruneReason::Bound,
+            });
+        }
+        level = candidates
+            .into_iter()
+            .map(|(h, parent, _, successor)| {
+                let path_cost = nodes[parent].path_cost.add(successor.cost);
+                let depth = nodes[parent].depth + 1;
+                stats.record_generated(depth);
+                sink.event(SearchEvent::Generate {
+                    state: &successor.state,
+                    node: nodes.len(),
+                    parent: Some(parent),
+                    g: path_cost,
+                    f: h,
+                });
+                nodes.push(Node {
+                    state: successor.state,
+                    parent: Some(parent),
+                    action: Some(successor.action),
+                    path_cost,
+                    depth,
+                });
+                nodes.len() - 1
+            })
+            .collect();
+        stats.record_frontier(level.len());
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::{FixedCannibalsProblem, FixedWorldState};
+    use crate::search::bfs;
+
+    #[test]
+    fn beam_search_trades_completeness_for_memory() {
+        let problem = FixedCannibalsProblem::<3, 2>;
+        let mut widest_level = 0;
+        let mut beam_pruned = 0;
+
+        let solution = beam_search_traced(
+            &problem,
+            FixedWorldState::heuristic,
+            2,
+            &mut |event: SearchEvent<'_, FixedWorldState<3, 2>>| match event {
+                SearchEvent::Prune {
+                    reason: PruneReason::Bound,
+                    ..
+                } => beam_pruned += 1,
+                SearchEvent::Expand { frontier, .. } => widest_level = widest_level.max(frontier),
+                _ => {}
+            },
+        )
+        .unwrap();
+
+        assert!(problem.is_goal(solution.goal()));
+        assert!(widest_level < 2);
+        assert!(solution.stats.frontier_peak <= 2);
+        assert!(beam_pruned > 0);
+        let wide = beam_search(&problem, FixedWorldState::heuristic, usize::MAX).unwrap();
+        assert_eq!(wide.len(), bfs(&problem).unwrap().len());
+        let narrow = beam_search(
+            &FixedCannibalsProblem::<5, 3>,
+            FixedWorldState::heuristic,
+            1,
+        );
+        assert!(narrow.is_none());
+    }
+}
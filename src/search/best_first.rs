@@ -0,0 +1,613 @@
+use std::borrow::Cow;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::time::Instant;
+
+use super::{
+    BoxedSuccessors, Cost, PruneReason, SearchEvent, SearchProblem, SearchSink, SearchStats,
+    Solution, Successor,
+};
+
+/// [`greedy_best_first`]
+/// ## Greedy best-first search.
+/// Always expands the node that looks closest to the goal according to `heuristic`,
+/// ignoring the cost already paid to reach it. Fast, but the solution is not guaranteed
+/// to be optimal.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::search::*;
+/// let problem = CannibalsProblem::new(WorldState::try_from("0 0 3 3 right").unwrap());
+/// let solution = greedy_best_first(&problem, |state| f64::from(state.get_heuristic())).unwrap();
+/// assert!(solution.goal().is_solution());
+/// assert!(solution.stats.expanded <= bfs(&problem).unwrap().stats.expanded);
+/// ```
+pub fn greedy_best_first<P, H>(
+    problem: &P,
+    heuristic: H,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    best_first(problem, heuristic, CostFunction::OnlyHeuristic, &mut ())
+}
+
+/// [`greedy_best_first_traced`]
+/// [`greedy_best_first`] reporting every [`SearchEvent`] to `sink`.
+pub fn greedy_best_first_traced<P, H>(
+    problem: &P,
+    heuristic: H,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    best_first(problem, heuristic, CostFunction::OnlyHeuristic, sink)
+}
+
+/// [`a_star`]
+/// ## A* search.
+/// Expands the node with the lowest `f = g + h`, where `g` is the cost paid to reach the
+/// node and `h` is `heuristic`. Returns an optimal solution whenever the heuristic is
+/// admissible (never overestimates the remaining cost).
+pub fn a_star<P, H>(problem: &P, heuristic: H) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    best_first(
+        problem,
+        heuristic,
+        CostFunction::HeuristicPlusPathCost,
+        &mut (),
+    )
+}
+
+/// [`a_star_traced`]
+/// [`a_star`] reporting every [`SearchEvent`] to `sink`.
+pub fn a_star_traced<P, H>(
+    problem: &P,
+    heuristic: H,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    best_first(
+        problem,
+        heuristic,
+        CostFunction::HeuristicPlusPathCost,
+        sink,
+    )
+}
+
+/// [`weighted_a_star`]
+/// ## Weighted A* search.
+/// [`a_star`] expanding the node with the lowest `f = g + weight * h`. A weight above 1
+/// trusts the heuristic more, usually expanding fewer nodes for a solution that may
+/// cost up to `weight` times the optimal one (for an admissible heuristic). A weight of
+/// 1 is [`a_star`], 0 is [`uniform_cost_search`].
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = HanoiProblem::new(4, 3).unwrap();
+/// let optimal = a_star(&problem, |state| problem.heuristic(state)).unwrap();
+/// let inflated = weighted_a_star(&problem, |state| problem.heuristic(state), 3.0).unwrap();
+/// assert!(inflated.cost as f64 <= 3.0 * optimal.cost as f64);
+/// assert!(inflated.stats.expanded <= optimal.stats.expanded);
+/// ```
+pub fn weighted_a_star<P, H>(
+    problem: &P,
+    heuristic: H,
+    weight: f64,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    weighted_a_star_traced(problem, heuristic, weight, &mut ())
+}
+
+/// [`weighted_a_star_traced`]
+/// [`weighted_a_star`] reporting every [`SearchEvent`] to `sink`.
+pub fn weighted_a_star_traced<P, H>(
+    problem: &P,
+    heuristic: H,
+    weight: f64,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    best_first(
+        problem,
+        heuristic,
+        CostFunction::WeightedHeuristicPlusPathCost(weight),
+        sink,
+    )
+}
+
+/// [`uniform_cost_search`]
+/// ## Uniform cost search (Dijkstra).
+/// Always expands the node reached by the cheapest path so far, without any heuristic.
+/// The solution is optimal, the same as [`a_star`] with a zero heuristic.
+pub fn uniform_cost_search<P: SearchProblem>(
+    problem: &P,
+) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    uniform_cost_search_traced(problem, &mut ())
+}
+
+/// [`uniform_cost_search_traced`]
+/// [`uniform_cost_search`] reporting every [`SearchEvent`] to `sink`, `f` being the path
+/// cost.
+pub fn uniform_cost_search_traced<P: SearchProblem>(
+    problem: &P,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    best_first(problem, |_| 0.0, CostFunction::OnlyPathCost, sink)
+}
+
+/// [`uniform_cost_search_with`]
+/// [`uniform_cost_search`] paying `move_cost(state, action)` for every action instead of
+/// the cost given by the problem, the solution cost is the sum of those.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::search::*;
+/// let problem = FixedCannibalsProblem::<3, 2>;
+/// assert_eq!(uniform_cost_search(&problem).unwrap().cost, 11);
+/// // every person on the boat pays the crossing.
+/// let by_passenger = uniform_cost_search_with(&problem, |_, mov: &Move| mov.passengers().into());
+/// assert_eq!(by_passenger.unwrap().cost, 18);
+/// ```
+pub fn uniform_cost_search_with<P, F>(
+    problem: &P,
+    move_cost: F,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    F: Fn(&P::State, &P::Action) -> P::Cost,
+{
+    uniform_cost_search(&Repriced { problem, move_cost })
+}
+
+/// `problem` with the cost of every action given by `move_cost`.
+struct Repriced<'p, P, F> {
+    problem: &'p P,
+    move_cost: F,
+}
+
+impl<P, F> SearchProblem for Repriced<'_, P, F>
+where
+    P: SearchProblem,
+    F: Fn(&P::State, &P::Action) -> P::Cost,
+{
+    type State = P::State;
+    type Action = P::Action;
+    type Cost = P::Cost;
+    type Successors<'a>
+        = BoxedSuccessors<'a, P::State, P::Action, P::Cost>
+    where
+        Self: 'a;
+
+    fn initial_state(&self) -> P::State {
+        self.problem.initial_state()
+    }
+
+    fn is_goal(&self, state: &P::State) -> bool {
+        self.problem.is_goal(state)
+    }
+
+    fn successors<'a>(&'a self, state: &'a P::State) -> Self::Successors<'a> {
+        Box::new(self.problem.successors(state).map(move |successor| {
+            let cost = (self.move_cost)(state, &successor.action);
+            Successor { cost, ..successor }
+        }))
+    }
+
+    fn canonicalize<'a>(&self, state: &'a P::State) -> Cow<'a, P::State> {
+        self.problem.canonicalize(state)
+    }
+
+    fn is_dead_end(&self, state: &P::State) -> bool {
+        self.problem.is_dead_end(state)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CostFunction {
+    OnlyHeuristic,
+    HeuristicPlusPathCost,
+    WeightedHeuristicPlusPathCost(f64),
+    OnlyPathCost,
+}
+
+/// Search tree node, nodes are stored in an arena and point to their parent by index.
+pub(super) struct Node<S, A, C = u32> {
+    pub(super) state: S,
+    pub(super) parent: Option<usize>,
+    pub(super) action: Option<A>,
+    pub(super) path_cost: C,
+    /// Actions from the root.
+    pub(super) depth: usize,
+}
+
+/// Heap entry ordered by `f`, ties broken by node index (insertion order) so the search
+/// is deterministic.
+pub(super) struct HeapEntry {
+    pub(super) f: f64,
+    pub(super) node: usize,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for HeapEntry {}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.f.total_cmp(&other.f).then(self.node.cmp(&other.node))
+    }
+}
+
+fn best_first<P, H>(
+    problem: &P,
+    heuristic: H,
+    cost_function: CostFunction,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    profile!(match cost_function {
+        CostFunction::OnlyHeuristic => "greedy",
+        CostFunction::HeuristicPlusPathCost => "a_star",
+        CostFunction::WeightedHeuristicPlusPathCost(_) => "weighted_a_star",
+        CostFunction::OnlyPathCost => "uniform_cost",
+    });
+    let f = |state: &P::State, path_cost: P::Cost| {
+        profile!("heuristic");
+        match cost_function {
+            CostFunction::OnlyHeuristic => heuristic(state),
+            CostFunction::HeuristicPlusPathCost => path_cost.to_f64() + heuristic(state),
+            CostFunction::WeightedHeuristicPlusPathCost(weight) => {
+                path_cost.to_f64() + weight * heuristic(state)
+            }
+            CostFunction::OnlyPathCost => path_cost.to_f64(),
+        }
+    };
+
+    let mut nodes: Vec<Node<P::State, P::Action, P::Cost>> = vec![];
+    let mut best_path_cost: HashMap<P::State, P::Cost> = HashMap::new();
+    let mut frontier: BinaryHeap<Reverse<HeapEntry>> = BinaryHeap::new();
+    let mut stats = SearchStats::started();
+
+    let start = Instant::now();
+    let initial_state = problem.initial_state();
+    let initial_f = f(&initial_state, P::Cost::zero());
+    sink.event(SearchEvent::Generate {
+        state: &initial_state,
+        node: 0,
+        parent: None,
+        g: P::Cost::zero(),
+        f: initial_f,
+    });
+    best_path_cost.insert(
+        problem.canonicalize(&initial_state).into_owned(),
+        P::Cost::zero(),
+    );
+    frontier.push(Reverse(HeapEntry {
+        f: initial_f,
+        node: 0,
+    }));
+    nodes.push(Node {
+        state: initial_state,
+        parent: None,
+        action: None,
+        path_cost: P::Cost::zero(),
+        depth: 0,
+    });
+
+    loop {
+        let popped = {
+            profile!("heap");
+            frontier.pop()
+        };
+        let Some(Reverse(entry)) = popped else {
+            break;
+        };
+        let node = &nodes[entry.node];
+        // A cheaper path to this state was found after this entry was pushed.
+        let stale = {
+            profile!("dedup");
+            best_path_cost
+                .get(problem.canonicalize(&node.state).as_ref())
+                .is_some_and(|best| *best < node.path_cost)
+        };
+        if stale {
+            continue;
+        }
+        sink.event(SearchEvent::Expand {
+            state: &node.state,
+            node: entry.node,
+            g: node.path_cost,
+            f: entry.f,
+            frontier: frontier.len(),
+        });
+        if problem.is_goal(&node.state) {
+            stats.elapsed = start.elapsed();
+            let solution = build_solution(&nodes, entry.node, stats);
+            sink.event(SearchEvent::Solution {
+                state: solution.goal(),
+                cost: solution.cost,
+                length: solution.len(),
+            });
+            return Some(solution);
+        }
+
+        // the successors borrow the state while new nodes are pushed.
+        let (state, path_cost, depth) = (node.state.clone(), node.path_cost, node.depth + 1);
+        stats.expanded += 1;
+        profile!("expand");
+        for successor in problem.successors(&state) {
+            let successor_path_cost = path_cost.add(successor.cost);
+            if problem.is_dead_end(&successor.state) {
+                sink.event(SearchEvent::Prune {
+                    state: &successor.state,
+                    parent: entry.node,
+                    g: successor_path_cost,
+                    reason: PruneReason::DeadEnd,
+                });
+                continue;
+            }
+            let (key, should_push) = {
+                profile!("dedup");
+                let key = problem.canonicalize(&successor.state).into_owned();
+                let should_push = match best_path_cost.get(&key) {
+                    None => true,
+                    Some(best) => {
+                        cost_function != CostFunction::OnlyHeuristic && successor_path_cost < *best
+                    }
+                };
+                (key, should_push)
+            };
+            if !should_push {
+                stats.duplicates += 1;
+                sink.event(SearchEvent::Prune {
+                    state: &successor.state,
+                    parent: entry.node,
+                    g: successor_path_cost,
+                    reason: PruneReason::Duplicate,
+                });
+                continue;
+            }
+            let successor_f = f(&successor.state, successor_path_cost);
+            sink.event(SearchEvent::Generate {
+                state: &successor.state,
+                node: nodes.len(),
+                parent: Some(entry.node),
+                g: successor_path_cost,
+                f: successor_f,
+            });
+            best_path_cost.insert(key, successor_path_cost);
+            stats.record_generated(depth);
+            {
+                profile!("heap");
+                frontier.push(Reverse(HeapEntry {
+                    f: successor_f,
+                    node: nodes.len(),
+                }));
+            }
+            nodes.push(Node {
+                state: successor.state,
+                parent: Some(entry.node),
+                action: Some(successor.action),
+                path_cost: successor_path_cost,
+                depth,
+            });
+        }
+        stats.record_frontier(frontier.len());
+    }
+
+    None
+}
+
+pub(super) fn build_solution<S: Clone, A: Clone, C: Copy>(
+    nodes: &[Node<S, A, C>],
+    goal: usize,
+    stats: SearchStats,
+) -> Solution<S, A, C> {
+    let cost = nodes[goal].path_cost;
+    let mut path = vec![goal];
+    while let Some(parent) = nodes[*path.last().unwrap()].parent {
+        path.push(parent);
+    }
+    path.reverse();
+
+    let states = path.iter().map(|i| nodes[*i].state.clone()).collect();
+    let actions = path
+        .iter()
+        .filter_map(|i| nodes[*i].action.clone())
+        .collect();
+
+    Solution {
+        states,
+        actions,
+        cost,
+        stats,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// ```text
+    ///   S --1--> A --10--> G
+    ///   S --4--> B --2---> G
+    /// ```
+    /// `A` looks closer to the goal, but the cheapest path goes through `B`.
+    struct SmallGraph;
+
+    impl SearchProblem for SmallGraph {
+        type State = char;
+        type Action = char;
+        type Cost = u32;
+        type Successors<'a> = std::vec::IntoIter<Successor<char, char>>;
+
+        fn initial_state(&self) -> char {
+            'S'
+        }
+
+        fn is_goal(&self, state: &char) -> bool {
+            *state == 'G'
+        }
+
+        fn successors<'a>(&'a self, state: &'a char) -> Self::Successors<'a> {
+            let edges: &[(char, u32)] = match state {
+                'S' => &[('A', 1), ('B', 4)],
+                'A' => &[('G', 10)],
+                'B' => &[('G', 2)],
+                _ => &[],
+            };
+            edges
+                .iter()
+                .map(|(to, cost)| Successor::new(*to, *to, *cost))
+                .collect::<Vec<_>>()
+                .into_iter()
+        }
+    }
+
+    fn heuristic(state: &char) -> f64 {
+        match state {
+            'S' => 5.0,
+            'A' => 1.0,
+            'B' => 2.0,
+            _ => 0.0,
+        }
+    }
+
+    #[test]
+    fn greedy_best_first_follows_the_heuristic() {
+        let solution = greedy_best_first(&SmallGraph, heuristic).unwrap();
+
+        assert_eq!(solution.states, vec!['S', 'A', 'G']);
+        assert_eq!(solution.cost, 11);
+    }
+
+    #[test]
+    fn a_star_returns_optimal_solution() {
+        let solution = a_star(&SmallGraph, heuristic).unwrap();
+
+        assert_eq!(solution.states, vec!['S', 'B', 'G']);
+        assert_eq!(solution.actions, vec!['B', 'G']);
+        assert_eq!(solution.cost, 6);
+        assert_eq!(*solution.goal(), 'G');
+    }
+
+    #[test]
+    fn weighted_a_star_inflates_the_heuristic() {
+        let unweighted = weighted_a_star(&SmallGraph, heuristic, 1.0).unwrap();
+        let inflated = weighted_a_star(&SmallGraph, heuristic, 10.0).unwrap();
+
+        assert_eq!(unweighted, a_star(&SmallGraph, heuristic).unwrap());
+        assert_eq!(inflated.states, vec!['S', 'A', 'G']);
+        assert!(inflated.stats.expanded < unweighted.stats.expanded);
+    }
+
+    #[test]
+    fn uniform_cost_search_orders_by_path_cost() {
+        let solution = uniform_cost_search(&SmallGraph).unwrap();
+        let mut expanded = vec![];
+        uniform_cost_search_traced(&SmallGraph, &mut |event: SearchEvent<'_, char>| {
+            if let SearchEvent::Expand { state, .. } = event {
+                expanded.push(*state)
+            }
+        });
+
+        assert_eq!(solution.states, vec!['S', 'B', 'G']);
+        assert_eq!(expanded, vec!['S', 'A', 'B', 'G']);
+        let repriced =
+            uniform_cost_search_with(&SmallGraph, |_, to| if *to == 'B' { 10 } else { 1 });
+        assert_eq!(repriced.unwrap().states, vec!['S', 'A', 'G']);
+    }
+
+    #[test]
+    fn a_star_accumulates_real_valued_costs() {
+        use crate::search::OrderedFloat;
+
+        /// `SmallGraph` with fractional costs, the path through `A` is now cheapest.
+        struct Weighted;
+        impl SearchProblem for Weighted {
+            type State = char;
+            type Action = char;
+            type Cost = OrderedFloat;
+            type Successors<'a> = std::vec::IntoIter<Successor<char, char, OrderedFloat>>;
+            fn initial_state(&self) -> char {
+                'S'
+            }
+            fn is_goal(&self, state: &char) -> bool {
+                *state == 'G'
+            }
+            fn successors<'a>(&'a self, state: &'a char) -> Self::Successors<'a> {
+                let edges: &[(char, f64)] = match state {
+                    'S' => &[('A', 0.1), ('B', 0.2)],
+                    'A' => &[('G', 0.2)],
+                    'B' => &[('G', 0.15)],
+                    _ => &[],
+                };
+                edges
+                    .iter()
+                    .map(|(to, cost)| Successor::new(*to, *to, OrderedFloat(*cost)))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+            }
+        }
+
+        let solution = a_star(&Weighted, |_| 0.0).unwrap();
+
+        assert_eq!(solution.states, vec!['S', 'A', 'G']);
+        assert_eq!(solution.cost, OrderedFloat(0.1 + 0.2));
+    }
+
+    #[test]
+    fn a_star_returns_none_when_goal_is_unreachable() {
+        struct Dead;
+        impl SearchProblem for Dead {
+            type State = u8;
+            type Action = ();
+            type Cost = u32;
+            type Successors<'a> = std::vec::IntoIter<Successor<u8, ()>>;
+            fn initial_state(&self) -> u8 {
+                0
+            }
+            fn is_goal(&self, _: &u8) -> bool {
+                false
+            }
+            fn successors<'a>(&'a self, state: &'a u8) -> Self::Successors<'a> {
+                if *state < 3 {
+                    vec![Successor::new((), state + 1, 1)].into_iter()
+                } else {
+                    vec![].into_iter()
+                }
+            }
+        }
+
+        assert!(a_star(&Dead, |_| 0.0).is_none());
+    }
+}
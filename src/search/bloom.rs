@@ -0,0 +1,154 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use super::ClosedSet;
+
+/// [`BloomStats`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomStats {
+    pub bits: usize,
+    pub hashes: u32,
+    /// States accepted as new.
+    pub insertions: usize,
+    /// States rejected as already seen, true duplicates or false positives.
+    pub rejections: usize,
+    /// False positive rate the filter was sized for.
+    pub target_false_positive_rate: f64,
+    /// Current false positive rate, estimated from the fraction of bits set.
+    pub estimated_false_positive_rate: f64,
+}
+
+/// [`BloomClosedSet`]
+/// ## Approximate closed set for state spaces that don't fit in memory.
+/// Uses a fixed number of bits whatever the states, but a new state is sometimes taken
+/// for an already seen one (a false positive) and pruned, so a search using it may miss
+/// solutions or return longer ones. The filter is sized for `expected_states` at
+/// `false_positive_rate`, inserting more states raises the rate reported by
+/// [`stats`](Self::stats).
+/// # Example
+/// ```
+/// # use algoritmos_rust::search::*;
+/// let mut closed = BloomClosedSet::new(1000, 0.01);
+/// assert!(closed.insert(&"state"));
+/// assert!(closed.contains(&"state"));
+/// assert_eq!(closed.stats().hashes, 7);
+/// ```
+pub struct BloomClosedSet<S> {
+    bits: Vec<u64>,
+    bit_count: usize,
+    hashes: u32,
+    insertions: usize,
+    rejections: usize,
+    target_false_positive_rate: f64,
+    state: PhantomData<fn(&S)>,
+}
+
+impl<S: Hash> BloomClosedSet<S> {
+    pub fn new(expected_states: usize, false_positive_rate: f64) -> Self {
+        let rate = false_positive_rate.clamp(f64::MIN_POSITIVE, 0.5);
+        let expected_states = expected_states.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let bit_count = (-expected_states * rate.ln() / (ln2 * ln2))
+            .ceil()
+            .max(64.0) as usize;
+        let hashes = ((bit_count as f64 / expected_states) * ln2)
+            .round()
+            .max(1.0) as u32;
+        Self {
+            bits: vec![0; bit_count.div_ceil(64)],
+            bit_count,
+            hashes,
+            insertions: 0,
+            rejections: 0,
+            target_false_positive_rate: false_positive_rate,
+            state: PhantomData,
+        }
+    }
+
+    pub fn stats(&self) -> BloomStats {
+        let set_bits = self.bits.iter().map(|word| word.count_ones()).sum::<u32>();
+        let fill = f64::from(set_bits) / self.bit_count as f64;
+        BloomStats {
+            bits: self.bit_count,
+            hashes: self.hashes,
+            insertions: self.insertions,
+            rejections: self.rejections,
+            target_false_positive_rate: self.target_false_positive_rate,
+            estimated_false_positive_rate: fill.powi(self.hashes as i32),
+        }
+    }
+
+    /// Bit positions of `state`, by double hashing.
+    fn positions(&self, state: &S) -> impl Iterator<Item = usize> {
+        let hash = |salt: u64| {
+            let mut hasher = DefaultHasher::new();
+            salt.hash(&mut hasher);
+            state.hash(&mut hasher);
+            hasher.finish()
+        };
+        let (first, second) = (hash(0), hash(1) | 1);
+        let bit_count = self.bit_count as u64;
+        (0..u64::from(self.hashes))
+            .map(move |i| (first.wrapping_add(i.wrapping_mul(second)) % bit_count) as usize)
+    }
+
+    fn is_set(&self, position: usize) -> bool {
+        self.bits[position / 64] & (1 << (position % 64)) != 0
+    }
+}
+
+impl<S: Hash> ClosedSet<S> for BloomClosedSet<S> {
+    fn insert(&mut self, state: &S) -> bool {
+        let positions = self.positions(state).collect::<Vec<usize>>();
+        if positions.iter().all(|position| self.is_set(*position)) {
+            self.rejections += 1;
+            return false;
+        }
+        for position in positions {
+            self.bits[position / 64] |= 1 << (position % 64);
+        }
+        self.insertions += 1;
+        true
+    }
+
+    fn contains(&self, state: &S) -> bool {
+        self.positions(state).all(|position| self.is_set(position))
+    }
+
+    fn len(&self) -> usize {
+        self.insertions
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::{bfs, bfs_with_closed_set};
+
+    #[test]
+    fn bloom_closed_set_solves_with_few_false_positives() {
+        let problem = HanoiProblem::new(4, 3).unwrap();
+        let mut closed = BloomClosedSet::new(81, 0.001);
+
+        let solution = bfs_with_closed_set(&problem, &mut closed).unwrap();
+
+        assert_eq!(solution.len(), bfs(&problem).unwrap().len());
+        let stats = closed.stats();
+        assert!(stats.insertions <= 81);
+        assert!(stats.estimated_false_positive_rate < 0.01);
+    }
+
+    #[test]
+    fn bloom_closed_set_reports_overfilling() {
+        let mut closed = BloomClosedSet::new(10, 0.01);
+
+        let rejected = (0..1000).filter(|n| !closed.insert(n)).count();
+
+        let stats = closed.stats();
+        assert_eq!(stats.rejections, rejected);
+        assert_eq!(stats.insertions + stats.rejections, 1000);
+        assert!(stats.estimated_false_positive_rate > stats.target_false_positive_rate);
+    }
+}
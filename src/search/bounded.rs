@@ -0,0 +1,315 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::time::Instant;
+
+use super::best_first::{build_solution, Node};
+use super::{Cost, PruneReason, SearchEvent, SearchProblem, SearchSink, SearchStats, Solution};
+
+/// [`NoSolutionWithin`]
+/// Certificate that no solution respects the bound: every state that could lead to one
+/// was expanded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoSolutionWithin {
+    pub bound: u32,
+    pub states_explored: usize,
+    /// Successors discarded because they could only lead to solutions beyond the bound.
+    pub pruned: usize,
+    /// Lower bound on the cost (or depth) of any solution, `None` when nothing was pruned:
+    /// the problem has no solution at all.
+    pub next_bound: Option<u32>,
+}
+
+/// [`BoundedOutcome`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoundedOutcome<S, A, C = u32> {
+    Found(Solution<S, A, C>),
+    NoneWithin(NoSolutionWithin),
+}
+
+impl<S, A, C> BoundedOutcome<S, A, C> {
+    pub fn solution(self) -> Option<Solution<S, A, C>> {
+        match self {
+            BoundedOutcome::Found(solution) => Some(solution),
+            BoundedOutcome::NoneWithin(_) => None,
+        }
+    }
+}
+
+/// [`solve_within_cost`]
+/// ## Cost-bounded A*.
+/// Returns the cheapest solution if its cost is at most `bound`, otherwise a certificate
+/// that none exists. States whose `g + heuristic` exceeds the bound are pruned, so the
+/// certificate is only valid for an admissible `heuristic` (`|_| 0.0` always is).
+/// Costs must be `u32`, the next bound of the certificate is rounded up to a whole cost.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = HanoiProblem::new(3, 3).unwrap();
+/// let BoundedOutcome::NoneWithin(certificate) = solve_within_cost(&problem, 6, |_| 0.0) else {
+///     panic!()
+/// };
+/// assert_eq!(certificate.next_bound, Some(7));
+/// assert!(solve_within_cost(&problem, 7, |_| 0.0).solution().is_some());
+/// ```
+pub fn solve_within_cost<P, H>(
+    problem: &P,
+    bound: u32,
+    heuristic: H,
+) -> BoundedOutcome<P::State, P::Action>
+where
+    P: SearchProblem<Cost = u32>,
+    H: Fn(&P::State) -> f64,
+{
+    solve_within_cost_traced(problem, bound, heuristic, &mut ())
+}
+
+/// [`solve_within_cost_traced`]
+/// [`solve_within_cost`] reporting every [`SearchEvent`] to `sink`, successors beyond
+/// the bound are pruned with [`PruneReason::Bound`].
+pub fn solve_within_cost_traced<P, H>(
+    problem: &P,
+    bound: u32,
+    heuristic: H,
+    sink: &mut dyn SearchSink<P::State>,
+) -> BoundedOutcome<P::State, P::Action>
+where
+    P: SearchProblem<Cost = u32>,
+    H: Fn(&P::State) -> f64,
+{
+    let start = Instant::now();
+    let initial_state = problem.initial_state();
+    let mut certificate = NoSolutionWithin {
+        bound,
+        states_explored: 0,
+        pruned: 0,
+        next_bound: None,
+    };
+    let prune = |certificate: &mut NoSolutionWithin, f: f64| {
+        certificate.pruned += 1;
+        let f = f.ceil() as u32;
+        certificate.next_bound = Some(certificate.next_bound.map_or(f, |next| next.min(f)));
+    };
+    let initial_f = heuristic(&initial_state);
+    if initial_f > f64::from(bound) {
+        prune(&mut certificate, initial_f);
+        return BoundedOutcome::NoneWithin(certificate);
+    }
+
+    sink.event(SearchEvent::Generate {
+        state: &initial_state,
+        node: 0,
+        parent: None,
+        g: 0,
+        f: initial_f,
+    });
+    let mut best_path_cost =
+        HashMap::from([(problem.canonicalize(&initial_state).into_owned(), 0)]);
+    let mut nodes = vec![Node {
+        state: initial_state,
+        parent: None,
+        action: None,
+        path_cost: 0,
+        depth: 0,
+    }];
+    // (f, insertion) keeps the search deterministic, f is compared by bits since it's
+    // never negative.
+    let mut frontier = BinaryHeap::from([Reverse((initial_f.to_bits(), 0))]);
+    let mut stats = SearchStats::started();
+
+    while let Some(Reverse((f, current))) = frontier.pop() {
+        let node = &nodes[current];
+        if best_path_cost[problem.canonicalize(&node.state).as_ref()] < node.path_cost {
+            continue;
+        }
+        sink.event(SearchEvent::Expand {
+            state: &node.state,
+            node: current,
+            g: node.path_cost,
+            f: f64::from_bits(f),
+            frontier: frontier.len(),
+        });
+        if problem.is_goal(&node.state) {
+            stats.expanded = certificate.states_explored;
+            stats.elapsed = start.elapsed();
+            let solution = build_solution(&nodes, current, stats);
+            sink.event(SearchEvent::Solution {
+                state: solution.goal(),
+                cost: solution.cost,
+                length: solution.len(),
+            });
+            return BoundedOutcome::Found(solution);
+        }
+        certificate.states_explored += 1;
+
+        let (state, path_cost, depth) = (node.state.clone(), node.path_cost, node.depth + 1);
+        for successor in problem.successors(&state) {
+            let successor_path_cost = path_cost + successor.cost;
+            let mut pruned = |reason| {
+                sink.event(SearchEvent::Prune {
+                    state: &successor.state,
+                    parent: current,
+                    g: successor_path_cost,
+                    reason,
+                })
+            };
+            if problem.is_dead_end(&successor.state) {
+                pruned(PruneReason::DeadEnd);
+                continue;
+            }
+            let key = problem.canonicalize(&successor.state).into_owned();
+            if best_path_cost
+                .get(&key)
+                .is_some_and(|best| *best <= successor_path_cost)
+            {
+                stats.duplicates += 1;
+                pruned(PruneReason::Duplicate);
+                continue;
+            }
+            let f = f64::from(successor_path_cost) + heuristic(&successor.state);
+            if f > f64::from(bound) {
+                pruned(PruneReason::Bound);
+                prune(&mut certificate, f);
+                continue;
+            }
+            sink.event(SearchEvent::Generate {
+                state: &successor.state,
+                node: nodes.len(),
+                parent: Some(current),
+                g: successor_path_cost,
+                f,
+            });
+            best_path_cost.insert(key, successor_path_cost);
+            frontier.push(Reverse((f.to_bits(), nodes.len())));
+            stats.record_generated(depth);
+            nodes.push(Node {
+                state: successor.state,
+                parent: Some(current),
+                action: Some(successor.action),
+                path_cost: successor_path_cost,
+                depth,
+            });
+        }
+        stats.record_frontier(frontier.len());
+    }
+
+    BoundedOutcome::NoneWithin(certificate)
+}
+
+/// [`solve_within_depth`]
+/// ## Depth-bounded breadth-first search.
+/// Returns a solution with the fewest actions if it has at most `depth` actions,
+/// otherwise a certificate that none exists.
+pub fn solve_within_depth<P: SearchProblem>(
+    problem: &P,
+    depth: u32,
+) -> BoundedOutcome<P::State, P::Action, P::Cost> {
+    let start = Instant::now();
+    let initial_state = problem.initial_state();
+    let mut depths = HashMap::from([(initial_state.clone(), 0)]);
+    let mut nodes = vec![Node {
+        state: initial_state,
+        parent: None,
+        action: None,
+        path_cost: P::Cost::zero(),
+        depth: 0,
+    }];
+    let mut frontier = VecDeque::from([0]);
+    let mut stats = SearchStats::started();
+    let mut certificate = NoSolutionWithin {
+        bound: depth,
+        states_explored: 0,
+        pruned: 0,
+        next_bound: None,
+    };
+
+    while let Some(current) = frontier.pop_front() {
+        if problem.is_goal(&nodes[current].state) {
+            stats.expanded = certificate.states_explored;
+            stats.elapsed = start.elapsed();
+            return BoundedOutcome::Found(build_solution(&nodes, current, stats));
+        }
+        certificate.states_explored += 1;
+        let state = nodes[current].state.clone();
+        let current_depth = depths[&state];
+        for successor in problem.successors(&state) {
+            if problem.is_dead_end(&successor.state) {
+                continue;
+            }
+            if depths.contains_key(&successor.state) {
+                stats.duplicates += 1;
+                continue;
+            }
+            if current_depth == depth {
+                certificate.pruned += 1;
+                certificate.next_bound = Some(depth + 1);
+                continue;
+            }
+            depths.insert(successor.state.clone(), current_depth + 1);
+            frontier.push_back(nodes.len());
+            let path_cost = nodes[current].path_cost.add(successor.cost);
+            stats.record_generated(current_depth as usize + 1);
+            nodes.push(Node {
+                state: successor.state,
+                parent: Some(current),
+                action: Some(successor.action),
+                path_cost,
+                depth: current_depth as usize + 1,
+            });
+        }
+        stats.record_frontier(frontier.len());
+    }
+
+    BoundedOutcome::NoneWithin(certificate)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+
+    #[test]
+    fn bounded_cost_finds_optimum_only_within_bound() {
+        let problem = NPuzzleProblem::new(Board::try_from("4 1 3 7 2 6 0 5 8").unwrap());
+        let manhattan = |board: &Board| board.manhattan_distance() as f64;
+
+        let found = solve_within_cost(&problem, 10, manhattan)
+            .solution()
+            .unwrap();
+        let none = solve_within_cost(&problem, 5, manhattan);
+
+        assert_eq!(found.cost, 6);
+        let BoundedOutcome::NoneWithin(certificate) = none else {
+            panic!("no solution costs 5 or less")
+        };
+        assert_eq!(certificate.next_bound, Some(6));
+        assert!(certificate.pruned > 0);
+    }
+
+    #[test]
+    fn bounded_depth_certifies_unsolvable_problems() {
+        let solvable = NPuzzleProblem::new(Board::try_from("1 2 0 3").unwrap());
+        let unsolvable = NPuzzleProblem::new(Board::try_from("2 1 3 0").unwrap());
+
+        assert_eq!(
+            solve_within_depth(&solvable, 1).solution().unwrap().len(),
+            1
+        );
+        assert!(matches!(
+            solve_within_depth(&solvable, 0),
+            BoundedOutcome::NoneWithin(NoSolutionWithin {
+                next_bound: Some(1),
+                ..
+            })
+        ));
+        assert_eq!(
+            solve_within_depth(&unsolvable, 20),
+            BoundedOutcome::NoneWithin(NoSolutionWithin {
+                bound: 20,
+                states_explored: 12,
+                pruned: 0,
+                next_bound: None,
+            })
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use super::SearchProblem;
+
+/// [`Cancellation`]
+/// Stops a search running on another thread or task, shared by every clone.
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation(Arc<AtomicBool>);
+
+impl Cancellation {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// [`Cancellable`]
+/// `problem` until `cancellation` is cancelled, then states have no successors so any
+/// search runs out of frontier.
+pub struct Cancellable<'a, P> {
+    problem: &'a P,
+    cancellation: Cancellation,
+}
+
+impl<'a, P> Cancellable<'a, P> {
+    pub fn new(problem: &'a P, cancellation: Cancellation) -> Self {
+        Self {
+            problem,
+            cancellation,
+        }
+    }
+
+    pub fn inner(&self) -> &'a P {
+        self.problem
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+}
+
+impl<P: SearchProblem> SearchProblem for Cancellable<'_, P> {
+    type State = P::State;
+    type Action = P::Action;
+    type Cost = P::Cost;
+    type Successors<'b>
+        = std::iter::Take<P::Successors<'b>>
+    where
+        Self: 'b;
+
+    fn initial_state(&self) -> P::State {
+        self.problem.initial_state()
+    }
+
+    fn is_goal(&self, state: &P::State) -> bool {
+        self.problem.is_goal(state)
+    }
+
+    fn successors<'b>(&'b self, state: &'b P::State) -> Self::Successors<'b> {
+        let limit = match self.cancellation.is_cancelled() {
+            true => 0,
+            false => usize::MAX,
+        };
+        self.problem.successors(state).take(limit)
+    }
+
+    fn canonicalize<'b>(&self, state: &'b P::State) -> Cow<'b, P::State> {
+        self.problem.canonicalize(state)
+    }
+
+    fn is_dead_end(&self, state: &P::State) -> bool {
+        self.problem.is_dead_end(state)
+    }
+
+    fn dominates(&self, a: &P::State, b: &P::State) -> bool {
+        self.problem.dominates(a, b)
+    }
+}
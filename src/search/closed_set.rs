@@ -0,0 +1,137 @@
+use std::collections::{BTreeSet, HashSet};
+use std::hash::Hash;
+
+/// [`ClosedSet`]
+/// ## Storage for the states a search already reached.
+/// [`HashSet`] is the default, [`BTreeSet`] trades speed for ordered, more compact
+/// storage and [`BitSetClosedSet`] uses a single bit per state of enumerable domains.
+pub trait ClosedSet<S> {
+    /// Adds `state`, returns `false` when it was already in the set.
+    fn insert(&mut self, state: &S) -> bool;
+
+    fn contains(&self, state: &S) -> bool;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<S: Clone + Eq + Hash> ClosedSet<S> for HashSet<S> {
+    fn insert(&mut self, state: &S) -> bool {
+        !HashSet::contains(self, state) && HashSet::insert(self, state.clone())
+    }
+
+    fn contains(&self, state: &S) -> bool {
+        HashSet::contains(self, state)
+    }
+
+    fn len(&self) -> usize {
+        HashSet::len(self)
+    }
+}
+
+impl<S: Clone + Ord> ClosedSet<S> for BTreeSet<S> {
+    fn insert(&mut self, state: &S) -> bool {
+        !BTreeSet::contains(self, state) && BTreeSet::insert(self, state.clone())
+    }
+
+    fn contains(&self, state: &S) -> bool {
+        BTreeSet::contains(self, state)
+    }
+
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+}
+
+/// [`BitSetClosedSet`]
+/// ## One bit per state of an enumerable domain.
+/// `index` maps every state to a distinct number below `capacity`.
+/// # Example
+/// ```
+/// # use algoritmos_rust::search::*;
+/// let mut closed = BitSetClosedSet::new(100, |n: &u8| usize::from(*n));
+/// assert!(closed.insert(&42));
+/// assert!(!closed.insert(&42));
+/// assert_eq!(closed.len(), 1);
+/// ```
+pub struct BitSetClosedSet<F> {
+    bits: Vec<u64>,
+    index: F,
+    len: usize,
+}
+
+impl<F> BitSetClosedSet<F> {
+    pub fn new(capacity: usize, index: F) -> Self {
+        Self {
+            bits: vec![0; capacity.div_ceil(64)],
+            index,
+            len: 0,
+        }
+    }
+}
+
+impl<S, F: Fn(&S) -> usize> ClosedSet<S> for BitSetClosedSet<F> {
+    /// # Panics
+    /// When the index of `state` is not below the capacity.
+    fn insert(&mut self, state: &S) -> bool {
+        let index = (self.index)(state);
+        let (word, bit) = (index / 64, 1 << (index % 64));
+        let inserted = self.bits[word] & bit == 0;
+        self.bits[word] |= bit;
+        self.len += usize::from(inserted);
+        inserted
+    }
+
+    fn contains(&self, state: &S) -> bool {
+        let index = (self.index)(state);
+        self.bits
+            .get(index / 64)
+            .is_some_and(|word| word & (1 << (index % 64)) != 0)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::{FixedCannibalsProblem, FixedWorldState};
+    use crate::search::{bfs, bfs_with_closed_set};
+
+    #[test]
+    fn closed_set_backends_find_the_same_solution() {
+        let problem = FixedCannibalsProblem::<3, 2>;
+        let index = |state: &FixedWorldState<3, 2>| {
+            let left = state.left();
+            usize::from(left.cannibals.0) * 8
+                + usize::from(left.missionaries.0) * 2
+                + state.boat_side() as usize
+        };
+        let expected = bfs(&problem).unwrap();
+
+        let mut bitset = BitSetClosedSet::new(32, index);
+        let with_bitset = bfs_with_closed_set(&problem, &mut bitset).unwrap();
+        let mut hash_set = HashSet::new();
+        let with_hash_set = bfs_with_closed_set(&problem, &mut hash_set).unwrap();
+
+        assert_eq!(with_bitset, expected);
+        assert_eq!(with_hash_set, expected);
+        assert_eq!(ClosedSet::len(&bitset), hash_set.len());
+        assert!(ClosedSet::contains(&bitset, expected.goal()));
+    }
+
+    #[test]
+    fn closed_set_btree_set_keeps_states_ordered() {
+        let mut closed = BTreeSet::new();
+
+        assert!(ClosedSet::insert(&mut closed, &3));
+        assert!(ClosedSet::insert(&mut closed, &1));
+        assert!(!ClosedSet::insert(&mut closed, &3));
+        assert_eq!(closed.into_iter().collect::<Vec<i32>>(), vec![1, 3]);
+    }
+}
@@ -0,0 +1,152 @@
+use super::{build_state_graph, SearchProblem};
+
+/// [`Inconsistency`]
+/// A transition of a reversible problem that can't be undone as it was done.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inconsistency<S, C = u32> {
+    /// No action leads from `to` back to `from`.
+    MissingReverse { from: S, to: S, cost: C },
+    /// Going back from `to` to `from` costs `backward` instead of `forward`.
+    CostMismatch {
+        from: S,
+        to: S,
+        forward: C,
+        backward: C,
+    },
+}
+
+/// [`check_reversibility`]
+/// ## Verifies the model of a reversible problem.
+/// Every transition between reachable states must have a matching transition back at
+/// the same cost, which bidirectional searches take for granted when they expand
+/// backwards from the goal with the forward successors. Returns every transition that
+/// breaks the rule, in breadth-first order, or `None` when there are more than
+/// `state_limit` states.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// assert_eq!(check_reversibility(&HanoiProblem::new(3, 3).unwrap(), 1000), Some(vec![]));
+/// ```
+pub fn check_reversibility<P: SearchProblem>(
+    problem: &P,
+    state_limit: usize,
+) -> Option<Vec<Inconsistency<P::State, P::Cost>>> {
+    let graph = build_state_graph(problem, state_limit)?;
+    let mut inconsistencies = vec![];
+    for (source, node) in graph.nodes.iter().enumerate() {
+        for edge in &node.edges {
+            let mut backward = graph.nodes[edge.target]
+                .edges
+                .iter()
+                .filter(|back| back.target == source)
+                .map(|back| back.cost);
+            if backward.clone().any(|cost| cost == edge.cost) {
+                continue;
+            }
+            let (from, to) = (node.state.clone(), graph.nodes[edge.target].state.clone());
+            inconsistencies.push(match backward.next() {
+                Some(backward) => Inconsistency::CostMismatch {
+                    from,
+                    to,
+                    forward: edge.cost,
+                    backward,
+                },
+                None => Inconsistency::MissingReverse {
+                    from,
+                    to,
+                    cost: edge.cost,
+                },
+            });
+        }
+    }
+    Some(inconsistencies)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::search::Successor;
+
+    /// Walks around a ring of `size` cells, stepping clockwise costs 1 and stepping
+    /// counterclockwise costs `back_cost`. Without `counterclockwise` only clockwise steps
+    /// are possible.
+    struct Ring {
+        size: u32,
+        back_cost: u32,
+        counterclockwise: bool,
+    }
+
+    impl SearchProblem for Ring {
+        type State = u32;
+        type Action = i32;
+        type Cost = u32;
+        type Successors<'a> = std::vec::IntoIter<Successor<u32, i32>>;
+
+        fn initial_state(&self) -> u32 {
+            0
+        }
+
+        fn is_goal(&self, state: &u32) -> bool {
+            *state == self.size / 2
+        }
+
+        fn successors<'a>(&'a self, state: &'a u32) -> Self::Successors<'a> {
+            let mut successors = vec![Successor::new(1, (state + 1) % self.size, 1)];
+            if self.counterclockwise {
+                let previous = (state + self.size - 1) % self.size;
+                successors.push(Successor::new(-1, previous, self.back_cost));
+            }
+            successors.into_iter()
+        }
+    }
+
+    #[test]
+    fn reversibility_accepts_symmetric_models() {
+        let ring = Ring {
+            size: 5,
+            back_cost: 1,
+            counterclockwise: true,
+        };
+
+        assert_eq!(check_reversibility(&ring, 100), Some(vec![]));
+        assert_eq!(check_reversibility(&ring, 4), None);
+    }
+
+    #[test]
+    fn reversibility_reports_missing_and_mismatched_transitions() {
+        let one_way = Ring {
+            size: 3,
+            back_cost: 1,
+            counterclockwise: false,
+        };
+        let uneven = Ring {
+            size: 3,
+            back_cost: 2,
+            counterclockwise: true,
+        };
+
+        let missing = check_reversibility(&one_way, 100).unwrap();
+        let mismatched = check_reversibility(&uneven, 100).unwrap();
+
+        assert_eq!(missing.len(), 3);
+        assert_eq!(
+            missing[0],
+            Inconsistency::MissingReverse {
+                from: 0,
+                to: 1,
+                cost: 1
+            }
+        );
+        assert_eq!(mismatched.len(), 6);
+        assert_eq!(
+            mismatched[0],
+            Inconsistency::CostMismatch {
+                from: 0,
+                to: 1,
+                forward: 1,
+                backward: 2
+            }
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::num::Saturating;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [`Cost`]
+/// ## Type of the action costs of a [`SearchProblem`](super::SearchProblem).
+/// Path costs start at [`zero`](Cost::zero), grow with [`add`](Cost::add) and are
+/// compared with [`Ord`]. [`to_f64`](Cost::to_f64) is only used to combine a path cost
+/// with a heuristic estimate when ordering the frontier, the path costs themselves are
+/// accumulated without conversions.
+pub trait Cost: Copy + Ord + Debug {
+    fn zero() -> Self;
+
+    fn add(self, other: Self) -> Self;
+
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! integer_cost {
+    ($($integer:ty),*) => {$(
+        impl Cost for $integer {
+            fn zero() -> Self {
+                0
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+
+        /// Stops at the maximum value instead of overflowing.
+        impl Cost for Saturating<$integer> {
+            fn zero() -> Self {
+                Saturating(0)
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn to_f64(self) -> f64 {
+                self.0 as f64
+            }
+        }
+    )*};
+}
+
+integer_cost!(u8, u16, u32, u64, usize);
+
+/// [`OrderedFloat`]
+/// A `f64` cost totally ordered with [`f64::total_cmp`].
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct OrderedFloat(pub f64);
+
+impl PartialEq for OrderedFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedFloat {}
+
+impl PartialOrd for OrderedFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedFloat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Cost for OrderedFloat {
+    fn zero() -> Self {
+        OrderedFloat(0.0)
+    }
+
+    fn add(self, other: Self) -> Self {
+        OrderedFloat(self.0 + other.0)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cost_saturating_does_not_overflow() {
+        let cost = Saturating(u8::MAX - 1);
+
+        assert_eq!(Cost::add(cost, Saturating(5)), Saturating(u8::MAX));
+        assert_eq!(<Saturating<u8> as Cost>::zero(), Saturating(0));
+    }
+
+    #[test]
+    fn cost_ordered_float_is_totally_ordered() {
+        let mut costs = vec![OrderedFloat(2.5), OrderedFloat(-1.0), OrderedFloat(0.5)];
+        costs.sort();
+
+        assert_eq!(
+            costs,
+            vec![OrderedFloat(-1.0), OrderedFloat(0.5), OrderedFloat(2.5)]
+        );
+        assert_eq!(
+            OrderedFloat(0.25).add(OrderedFloat(0.5)),
+            OrderedFloat(0.75)
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::fmt::Display;
+
+use super::Solution;
+
+/// [`SolutionDiff`]
+/// Where two solutions of the same instance agree and diverge, comparing their states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolutionDiff {
+    /// Number of leading states shared by both solutions.
+    pub shared_prefix: usize,
+    /// Number of trailing states shared by both solutions, not overlapping the prefix.
+    pub shared_suffix: usize,
+    pub left_length: usize,
+    pub right_length: usize,
+    pub left_cost: u32,
+    pub right_cost: u32,
+}
+
+impl SolutionDiff {
+    pub fn is_identical(&self) -> bool {
+        self.left_length == self.right_length && self.shared_prefix == self.left_length + 1
+    }
+
+    /// Index of the first state that differs, `None` when the solutions are identical.
+    pub fn divergence(&self) -> Option<usize> {
+        (!self.is_identical()).then_some(self.shared_prefix)
+    }
+
+    /// `right_cost - left_cost`.
+    pub fn cost_difference(&self) -> i64 {
+        i64::from(self.right_cost) - i64::from(self.left_cost)
+    }
+}
+
+impl Display for SolutionDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "left: {} actions, cost {}",
+            self.left_length, self.left_cost
+        )?;
+        writeln!(
+            f,
+            "right: {} actions, cost {}",
+            self.right_length, self.right_cost
+        )?;
+        match self.divergence() {
+            None => write!(f, "the solutions are identical"),
+            Some(state) => write!(
+                f,
+                "shared prefix of {} states, diverge at state {}, shared suffix of {} states, cost difference {:+}",
+                self.shared_prefix,
+                state,
+                self.shared_suffix,
+                self.cost_difference()
+            ),
+        }
+    }
+}
+
+/// [`diff_solutions`]
+/// ## Compares two solutions of the same instance.
+/// # Example
+/// ```
+/// # use algoritmos_rust::search::*;
+/// let stats = SearchStats::default();
+/// let left = Solution { states: vec![0, 1, 2, 3], actions: vec![(); 3], cost: 3, stats };
+/// let right = Solution { states: vec![0, 5, 2, 3], actions: vec![(); 3], cost: 7, stats };
+/// let diff = diff_solutions(&left, &right);
+/// assert_eq!(diff.divergence(), Some(1));
+/// assert_eq!(diff.shared_suffix, 2);
+/// assert_eq!(diff.cost_difference(), 4);
+/// ```
+pub fn diff_solutions<S: PartialEq, A>(
+    left: &Solution<S, A>,
+    right: &Solution<S, A>,
+) -> SolutionDiff {
+    let shared_prefix = left
+        .states
+        .iter()
+        .zip(&right.states)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let shortest = left.states.len().min(right.states.len());
+    let shared_suffix = left
+        .states
+        .iter()
+        .rev()
+        .zip(right.states.iter().rev())
+        .take(shortest - shared_prefix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    SolutionDiff {
+        shared_prefix,
+        shared_suffix,
+        left_length: left.len(),
+        right_length: right.len(),
+        left_cost: left.cost,
+        right_cost: right.cost,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+    use crate::search::{a_star, greedy_best_first};
+
+    #[test]
+    fn diff_greedy_against_a_star() {
+        let problem = NPuzzleProblem::new(Board::try_from("8 6 7 2 5 4 3 0 1").unwrap());
+        let manhattan = |board: &Board| board.manhattan_distance() as f64;
+        let optimal = a_star(&problem, manhattan).unwrap();
+        let greedy = greedy_best_first(&problem, manhattan).unwrap();
+
+        let diff = diff_solutions(&optimal, &greedy);
+
+        assert!(diff.shared_prefix >= 1);
+        assert!(diff.shared_suffix >= 1);
+        assert!(diff.cost_difference() > 0);
+        assert!(diff.to_string().contains("diverge at state"));
+        assert!(diff_solutions(&optimal, &optimal).is_identical());
+        assert_eq!(diff_solutions(&optimal, &optimal).divergence(), None);
+    }
+}
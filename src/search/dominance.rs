@@ -0,0 +1,209 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::time::Instant;
+
+use super::best_first::{build_solution, Node};
+use super::{Cost, SearchProblem, SearchStats, Solution};
+
+/// [`DominanceStats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DominanceStats {
+    pub expanded: usize,
+    pub generated: usize,
+    /// Successors discarded because a state seen at no greater cost dominates them.
+    pub dominated: usize,
+    /// Successors discarded because their state was already reached as cheaply.
+    pub duplicates: usize,
+    pub dead_ends: usize,
+}
+
+/// [`DominanceOutcome`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DominanceOutcome<S, A, C = u32> {
+    pub solution: Option<Solution<S, A, C>>,
+    pub stats: DominanceStats,
+}
+
+/// [`a_star_with_dominance`]
+/// ## A* search with dominance pruning.
+/// [`a_star`](super::a_star) that also discards a successor when
+/// [`SearchProblem::dominates`] says that a state already seen at a path cost no greater
+/// than the successor's is at least as good. The solution stays optimal as long as the
+/// relation is sound. Every successor is compared with every state seen so far, so this
+/// pays off when dominance prunes whole subtrees (e.g. same position, fewer resources).
+pub fn a_star_with_dominance<P, H>(
+    problem: &P,
+    heuristic: H,
+) -> DominanceOutcome<P::State, P::Action, P::Cost>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    let start = Instant::now();
+    let mut stats = DominanceStats::default();
+    let initial_state = problem.initial_state();
+    let mut best_path_cost = HashMap::from([(
+        problem.canonicalize(&initial_state).into_owned(),
+        P::Cost::zero(),
+    )]);
+    // (f, insertion) keeps the search deterministic, f is compared by bits since it's
+    // never negative.
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&initial_state).to_bits(), 0))]);
+    let mut frontier_peak = 1;
+    let mut max_depth = 0;
+    let mut nodes = vec![Node {
+        state: initial_state,
+        parent: None,
+        action: None,
+        path_cost: P::Cost::zero(),
+        depth: 0,
+    }];
+
+    while let Some(Reverse((_, current))) = frontier.pop() {
+        let node = &nodes[current];
+        if best_path_cost[problem.canonicalize(&node.state).as_ref()] < node.path_cost {
+            continue;
+        }
+        if problem.is_goal(&node.state) {
+            return DominanceOutcome {
+                solution: Some(build_solution(
+                    &nodes,
+                    current,
+                    SearchStats {
+                        generated: stats.generated + 1,
+                        expanded: stats.expanded,
+                        duplicates: stats.duplicates,
+                        frontier_peak,
+                        max_depth,
+                        elapsed: start.elapsed(),
+                    },
+                )),
+                stats,
+            };
+        }
+        stats.expanded += 1;
+
+        let (state, path_cost, depth) = (node.state.clone(), node.path_cost, node.depth + 1);
+        for successor in problem.successors(&state) {
+            if problem.is_dead_end(&successor.state) {
+                stats.dead_ends += 1;
+                continue;
+            }
+            let successor_path_cost = path_cost.add(successor.cost);
+            let key = problem.canonicalize(&successor.state).into_owned();
+            if best_path_cost
+                .get(&key)
+                .is_some_and(|best| *best <= successor_path_cost)
+            {
+                stats.duplicates += 1;
+                continue;
+            }
+            if nodes.iter().any(|seen| {
+                seen.path_cost <= successor_path_cost
+                    && seen.state != successor.state
+                    && problem.dominates(&seen.state, &successor.state)
+            }) {
+                stats.dominated += 1;
+                continue;
+            }
+            stats.generated += 1;
+            max_depth = max_depth.max(depth);
+            let f = successor_path_cost.to_f64() + heuristic(&successor.state);
+            best_path_cost.insert(key, successor_path_cost);
+            frontier.push(Reverse((f.to_bits(), nodes.len())));
+            nodes.push(Node {
+                state: successor.state,
+                parent: Some(current),
+                action: Some(successor.action),
+                path_cost: successor_path_cost,
+                depth,
+            });
+        }
+        frontier_peak = frontier_peak.max(frontier.len());
+    }
+
+    DominanceOutcome {
+        solution: None,
+        stats,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::search::{a_star, Successor};
+
+    /// Drive a robot `length` cells along a corridor. Moving either way uses one unit of
+    /// charge, every third cell has a charger that fills the battery up to 3, and every
+    /// action takes one step. Being on the same cell with more charge is never worse.
+    struct Corridor {
+        length: u32,
+        dominance: bool,
+    }
+
+    impl SearchProblem for Corridor {
+        /// (cell, charge)
+        type State = (u32, u32);
+        type Action = &'static str;
+        type Cost = u32;
+        type Successors<'a> = std::vec::IntoIter<Successor<(u32, u32), &'static str>>;
+
+        fn initial_state(&self) -> (u32, u32) {
+            (0, 0)
+        }
+
+        fn is_goal(&self, state: &(u32, u32)) -> bool {
+            state.0 == self.length
+        }
+
+        fn successors<'a>(&'a self, &(cell, charge): &'a (u32, u32)) -> Self::Successors<'a> {
+            let mut successors = vec![];
+            if cell % 3 == 0 && charge < 3 {
+                successors.push(Successor::new("charge", (cell, 3), 1));
+            }
+            if charge > 0 && cell < self.length {
+                successors.push(Successor::new("forward", (cell + 1, charge - 1), 1));
+            }
+            if charge > 0 && cell > 0 {
+                successors.push(Successor::new("back", (cell - 1, charge - 1), 1));
+            }
+            successors.into_iter()
+        }
+
+        fn dominates(&self, a: &(u32, u32), b: &(u32, u32)) -> bool {
+            self.dominance && a.0 == b.0 && a.1 >= b.1
+        }
+    }
+
+    #[test]
+    fn dominance_prunes_states_without_losing_optimality() {
+        let plain = a_star_with_dominance(
+            &Corridor {
+                length: 6,
+                dominance: false,
+            },
+            |_| 0.0,
+        );
+        let pruned = a_star_with_dominance(
+            &Corridor {
+                length: 6,
+                dominance: true,
+            },
+            |_| 0.0,
+        );
+
+        let optimal = a_star(
+            &Corridor {
+                length: 6,
+                dominance: false,
+            },
+            |_| 0.0,
+        )
+        .unwrap();
+        assert_eq!(plain.solution.unwrap().cost, optimal.cost);
+        assert_eq!(pruned.solution.unwrap().cost, optimal.cost);
+        assert_eq!(plain.stats.dominated, 0);
+        assert!(pruned.stats.dominated > 0);
+        assert!(pruned.stats.expanded < plain.stats.expanded);
+    }
+}
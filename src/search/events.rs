@@ -0,0 +1,88 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// [`SearchEvent`]
+/// Something that happened during a search, nodes are numbered in generation order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchEvent<'a, S, C = u32> {
+    /// A node was added to the frontier.
+    Generate {
+        state: &'a S,
+        node: usize,
+        parent: Option<usize>,
+        g: C,
+        f: f64,
+    },
+    /// A node was taken out of the frontier, `frontier` is the size left.
+    Expand {
+        state: &'a S,
+        node: usize,
+        g: C,
+        f: f64,
+        frontier: usize,
+    },
+    /// A successor of node `parent` was discarded.
+    Prune {
+        state: &'a S,
+        parent: usize,
+        g: C,
+        reason: PruneReason,
+    },
+    Solution {
+        state: &'a S,
+        cost: C,
+        length: usize,
+    },
+}
+
+/// [`PruneReason`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PruneReason {
+    /// The state was already reached at least as cheaply.
+    Duplicate,
+    /// [`SearchProblem::is_dead_end`](super::SearchProblem::is_dead_end), e.g. a game over.
+    DeadEnd,
+    /// Every solution through the state costs more than the bound of the search, or it
+    /// was left out of the beam of [`beam_search`](super::beam_search).
+    Bound,
+}
+
+/// [`SearchSink`]
+/// Receives the events of a traced search (e.g. [`a_star_traced`](super::a_star_traced)).
+/// Closures taking a [`SearchEvent`] are sinks, `()` discards everything.
+pub trait SearchSink<S, C = u32> {
+    fn event(&mut self, event: SearchEvent<'_, S, C>);
+}
+
+impl<S, C> SearchSink<S, C> for () {
+    fn event(&mut self, _: SearchEvent<'_, S, C>) {}
+}
+
+impl<S, C, F: FnMut(SearchEvent<'_, S, C>)> SearchSink<S, C> for F {
+    fn event(&mut self, event: SearchEvent<'_, S, C>) {
+        self(event)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::a_star_traced;
+
+    #[test]
+    fn events_closures_are_sinks() {
+        let problem = HanoiProblem::new(3, 3).unwrap();
+        let mut expanded = 0;
+
+        a_star_traced(&problem, |_| 0.0, &mut |event: SearchEvent<'_, _>| {
+            if let SearchEvent::Expand { .. } = event {
+                expanded += 1
+            }
+        });
+
+        assert!(expanded >= 8);
+    }
+}
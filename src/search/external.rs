@@ -0,0 +1,328 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Lines, Write};
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error;
+
+use super::SearchProblem;
+
+/// [`ExternalBfs`]
+/// ## Breadth-first exploration with the frontier on disk.
+/// Every level of the search is a file of states sorted by their JSON encoding. While a
+/// level is expanded, successors are buffered in memory and spilled to sorted run files
+/// every `memory_limit` states, then the runs are merged into the next level, dropping
+/// duplicates and every state of the previous levels. Only `memory_limit` states and one
+/// state per open file are kept in memory, so domains far larger than RAM can be explored
+/// exhaustively. States must serialize deterministically (equal states, equal JSON).
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::FixedCannibalsProblem;
+/// # use algoritmos_rust::search::*;
+/// let directory = std::env::temp_dir().join("external_bfs_doc");
+/// let report = ExternalBfs::new(&directory)
+///     .memory_limit(8)
+///     .explore(&FixedCannibalsProblem::<3, 2>)
+///     .unwrap();
+/// assert_eq!(report.goal_depth, Some(11));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExternalBfs {
+    directory: PathBuf,
+    memory_limit: usize,
+    stop_at_goal: bool,
+}
+
+/// [`ExternalBfsReport`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalBfsReport<S> {
+    /// Number of distinct states at every depth, `level_sizes[0]` is the initial state.
+    pub level_sizes: Vec<usize>,
+    /// Depth of the shallowest goal, `None` when no goal was reached.
+    pub goal_depth: Option<usize>,
+    /// One of the shallowest goals.
+    pub goal: Option<S>,
+    /// Run files written while spilling successors.
+    pub runs_spilled: usize,
+}
+
+impl<S> ExternalBfsReport<S> {
+    pub fn states(&self) -> usize {
+        self.level_sizes.iter().sum()
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum ExternalBfsError {
+    #[error("Error while accessing the frontier files: {0}")]
+    Io(String),
+    #[error("Error while encoding or decoding a state: {0}")]
+    Serialization(String),
+}
+
+impl From<std::io::Error> for ExternalBfsError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for ExternalBfsError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Serialization(error.to_string())
+    }
+}
+
+impl ExternalBfs {
+    /// Frontier files are written to `directory`, created if needed, and removed once
+    /// the exploration ends.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            directory: directory.as_ref().to_path_buf(),
+            memory_limit: 100_000,
+            stop_at_goal: false,
+        }
+    }
+
+    /// Maximum number of successors buffered in memory before spilling a run.
+    pub fn memory_limit(mut self, memory_limit: usize) -> Self {
+        self.memory_limit = memory_limit.max(1);
+        self
+    }
+
+    /// Stops after the level of the shallowest goal instead of exploring everything.
+    pub fn stop_at_goal(mut self, stop_at_goal: bool) -> Self {
+        self.stop_at_goal = stop_at_goal;
+        self
+    }
+
+    pub fn explore<P>(&self, problem: &P) -> Result<ExternalBfsReport<P::State>, ExternalBfsError>
+    where
+        P: SearchProblem,
+        P::State: Serialize + DeserializeOwned,
+    {
+        fs::create_dir_all(&self.directory)?;
+        let result = self.explore_levels(problem);
+        let _ = fs::remove_dir_all(&self.directory);
+        result
+    }
+
+    fn explore_levels<P>(
+        &self,
+        problem: &P,
+    ) -> Result<ExternalBfsReport<P::State>, ExternalBfsError>
+    where
+        P: SearchProblem,
+        P::State: Serialize + DeserializeOwned,
+    {
+        let mut report = ExternalBfsReport {
+            level_sizes: vec![1],
+            goal_depth: None,
+            goal: None,
+            runs_spilled: 0,
+        };
+        let initial_state = problem.initial_state();
+        write_sorted(
+            &self.level_path(0),
+            [serde_json::to_string(&initial_state)?],
+        )?;
+        let mut depth = 0;
+        loop {
+            let mut runs = vec![];
+            let mut buffer = BTreeSet::new();
+            for line in BufReader::new(File::open(self.level_path(depth))?).lines() {
+                let state: P::State = serde_json::from_str(&line?)?;
+                if report.goal.is_none() && problem.is_goal(&state) {
+                    report.goal_depth = Some(depth);
+                    report.goal = Some(state.clone());
+                }
+                for successor in problem.successors(&state) {
+                    if problem.is_dead_end(&successor.state) {
+                        continue;
+                    }
+                    buffer.insert(serde_json::to_string(&successor.state)?);
+                    if buffer.len() >= self.memory_limit {
+                        let run = self.run_path(depth + 1, runs.len());
+                        write_sorted(&run, std::mem::take(&mut buffer))?;
+                        runs.push(run);
+                    }
+                }
+            }
+            if !buffer.is_empty() {
+                let run = self.run_path(depth + 1, runs.len());
+                write_sorted(&run, buffer)?;
+                runs.push(run);
+            }
+            report.runs_spilled += runs.len();
+            if self.stop_at_goal && report.goal.is_some() {
+                break;
+            }
+
+            let previous = (0..=depth)
+                .map(|level| self.level_path(level))
+                .collect::<Vec<PathBuf>>();
+            let size = merge_new_states(&runs, &previous, &self.level_path(depth + 1))?;
+            for run in runs {
+                fs::remove_file(run)?;
+            }
+            if size == 0 {
+                break;
+            }
+            report.level_sizes.push(size);
+            depth += 1;
+        }
+        Ok(report)
+    }
+
+    fn level_path(&self, depth: usize) -> PathBuf {
+        self.directory.join(format!("level_{}.jsonl", depth))
+    }
+
+    fn run_path(&self, depth: usize, run: usize) -> PathBuf {
+        self.directory
+            .join(format!("level_{}_run_{}.jsonl", depth, run))
+    }
+}
+
+fn write_sorted(
+    path: &Path,
+    lines: impl IntoIterator<Item = String>,
+) -> Result<(), ExternalBfsError> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for line in lines {
+        writeln!(writer, "{}", line)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Sorted lines of a file, one at a time.
+struct SortedFile {
+    lines: Lines<BufReader<File>>,
+    current: Option<String>,
+}
+
+impl SortedFile {
+    fn open(path: &Path) -> Result<Self, ExternalBfsError> {
+        let mut file = Self {
+            lines: BufReader::new(File::open(path)?).lines(),
+            current: None,
+        };
+        file.advance()?;
+        Ok(file)
+    }
+
+    fn advance(&mut self) -> Result<(), ExternalBfsError> {
+        self.current = self.lines.next().transpose()?;
+        Ok(())
+    }
+
+    /// Whether the file contains `line`, skipping every smaller line.
+    fn skip_to(&mut self, line: &str) -> Result<bool, ExternalBfsError> {
+        while self
+            .current
+            .as_deref()
+            .is_some_and(|current| current < line)
+        {
+            self.advance()?;
+        }
+        Ok(self.current.as_deref() == Some(line))
+    }
+}
+
+/// Merges the sorted `runs` into `output`, without duplicates nor lines of `previous`
+/// levels, returns the number of lines written.
+fn merge_new_states(
+    runs: &[PathBuf],
+    previous: &[PathBuf],
+    output: &Path,
+) -> Result<usize, ExternalBfsError> {
+    let mut runs = runs
+        .iter()
+        .map(|run| SortedFile::open(run))
+        .collect::<Result<Vec<SortedFile>, _>>()?;
+    let mut previous = previous
+        .iter()
+        .map(|level| SortedFile::open(level))
+        .collect::<Result<Vec<SortedFile>, _>>()?;
+    let mut heap = runs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, run)| run.current.clone().map(|line| Reverse((line, i))))
+        .collect::<BinaryHeap<_>>();
+
+    let mut writer = BufWriter::new(File::create(output)?);
+    let mut last: Option<String> = None;
+    let mut written = 0;
+    while let Some(Reverse((line, i))) = heap.pop() {
+        runs[i].advance()?;
+        if let Some(next) = runs[i].current.clone() {
+            heap.push(Reverse((next, i)));
+        }
+        if last.as_ref() == Some(&line) {
+            continue;
+        }
+        let mut seen = false;
+        for level in previous.iter_mut() {
+            seen |= level.skip_to(&line)?;
+        }
+        if !seen {
+            writeln!(writer, "{}", line)?;
+            written += 1;
+        }
+        last = Some(line);
+    }
+    writer.flush()?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::FixedCannibalsProblem;
+    use crate::search::{bfs, StateSpace};
+
+    fn directory(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("algoritmos_rust_{}_{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn external_bfs_matches_in_memory_exploration() {
+        let problem = FixedCannibalsProblem::<5, 3>;
+        let directory = directory("external_bfs");
+
+        let report = ExternalBfs::new(&directory)
+            .memory_limit(3)
+            .explore(&problem)
+            .unwrap();
+
+        let space = StateSpace::explore(&problem, 1000).unwrap();
+        // the external search drops dead ends, the state space keeps them.
+        let live = space
+            .states()
+            .iter()
+            .filter(|state| !problem.is_dead_end(state))
+            .count();
+        assert_eq!(report.states(), live);
+        assert_eq!(report.goal_depth, Some(bfs(&problem).unwrap().len()));
+        assert!(report.goal.unwrap().is_solution());
+        assert!(report.runs_spilled > report.level_sizes.len());
+        assert!(!directory.exists());
+    }
+
+    #[test]
+    fn external_bfs_stops_at_goal() {
+        let problem = FixedCannibalsProblem::<3, 2>;
+
+        let report = ExternalBfs::new(directory("external_bfs_stop"))
+            .stop_at_goal(true)
+            .explore(&problem)
+            .unwrap();
+
+        assert_eq!(report.level_sizes.len(), 12);
+        assert_eq!(report.goal_depth, Some(11));
+    }
+}
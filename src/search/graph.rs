@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use std::fmt::Debug;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+#[cfg(feature = "serde")]
+use serde_json::json;
+
+use super::SearchProblem;
+
+/// [`GraphEdge`]
+/// A transition to the node numbered `target`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphEdge<A, C = u32> {
+    pub action: A,
+    pub target: usize,
+    pub cost: C,
+}
+
+/// [`GraphNode`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphNode<S, A, C = u32> {
+    pub state: S,
+    pub goal: bool,
+    pub edges: Vec<GraphEdge<A, C>>,
+}
+
+/// [`StateGraph`]
+/// ## The reachable graph of a [`SearchProblem`] as an adjacency list.
+/// Nodes are numbered in breadth-first order, the initial state is `0`, and keep every
+/// transition with its action, so analytics and visualizers can work on the graph
+/// without expanding states again. Dead ends are left out, as searches do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateGraph<S, A, C = u32> {
+    pub nodes: Vec<GraphNode<S, A, C>>,
+}
+
+impl<S, A, C> StateGraph<S, A, C> {
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.nodes.iter().map(|node| node.edges.len()).sum()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S, A, C> StateGraph<S, A, C> {
+    /// [`to_json`]
+    /// Exports the graph as:
+    /// ```text
+    /// {"nodes":[{"id":0,"state":"..","goal":false,"edges":[{"action":"..","target":1,"cost":1}]}]}
+    /// ```
+    /// States and actions are formatted with [`Debug`], see [`to_json_with`](Self::to_json_with).
+    pub fn to_json(&self) -> serde_json::Value
+    where
+        S: Debug,
+        A: Debug,
+        C: Serialize,
+    {
+        self.to_json_with(
+            |state| format!("{:?}", state),
+            |action| format!("{:?}", action),
+        )
+    }
+
+    pub fn to_json_with<F, G>(&self, state_format: F, action_format: G) -> serde_json::Value
+    where
+        F: Fn(&S) -> String,
+        G: Fn(&A) -> String,
+        C: Serialize,
+    {
+        let nodes = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(id, node)| {
+                let edges = node
+                    .edges
+                    .iter()
+                    .map(|edge| {
+                        json!({
+                            "action": action_format(&edge.action),
+                            "target": edge.target,
+                            "cost": edge.cost,
+                        })
+                    })
+                    .collect::<Vec<serde_json::Value>>();
+                json!({
+                    "id": id,
+                    "state": state_format(&node.state),
+                    "goal": node.goal,
+                    "edges": edges,
+                })
+            })
+            .collect::<Vec<serde_json::Value>>();
+        json!({ "nodes": nodes })
+    }
+}
+
+/// [`build_state_graph`]
+/// ## Materializes every reachable state and transition.
+/// `None` when there are more than `state_limit` states.
+pub fn build_state_graph<P: SearchProblem>(
+    problem: &P,
+    state_limit: usize,
+) -> Option<StateGraph<P::State, P::Action, P::Cost>> {
+    let initial = problem.initial_state();
+    let mut index = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    let mut nodes = vec![];
+    while nodes.len() < states.len() {
+        let state = states[nodes.len()].clone();
+        let mut edges = vec![];
+        for successor in problem.successors(&state) {
+            if problem.is_dead_end(&successor.state) {
+                continue;
+            }
+            let target = match index.get(&successor.state) {
+                Some(target) => *target,
+                None => {
+                    if states.len() == state_limit {
+                        return None;
+                    }
+                    index.insert(successor.state.clone(), states.len());
+                    states.push(successor.state);
+                    states.len() - 1
+                }
+            };
+            edges.push(GraphEdge {
+                action: successor.action,
+                target,
+                cost: successor.cost,
+            });
+        }
+        nodes.push(GraphNode {
+            goal: problem.is_goal(&state),
+            state,
+            edges,
+        });
+    }
+    Some(StateGraph { nodes })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::analyze;
+
+    #[test]
+    fn state_graph_of_hanoi_matches_the_analysis() {
+        let problem = HanoiProblem::new(3, 3).unwrap();
+
+        let graph = build_state_graph(&problem, 1000).unwrap();
+        let analysis = analyze(&problem, 1000).unwrap();
+
+        assert_eq!(graph.len(), analysis.reachable_states);
+        assert_eq!(graph.edge_count(), analysis.transitions);
+        assert_eq!(graph.nodes.iter().filter(|node| node.goal).count(), 1);
+        assert_eq!(graph.nodes[0].state, problem.initial_state());
+        assert!(build_state_graph(&problem, 26).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn state_graph_exports_json() {
+        let graph = build_state_graph(&HanoiProblem::new(3, 3).unwrap(), 1000).unwrap();
+
+        let json = graph.to_json_with(|state| state.to_string(), |action| format!("{:?}", action));
+        let nodes = json["nodes"].as_array().unwrap();
+        assert_eq!(nodes.len(), 27);
+        assert_eq!(nodes[0]["id"], 0);
+        assert_eq!(
+            nodes[0]["edges"].as_array().unwrap().len(),
+            graph.nodes[0].edges.len()
+        );
+        let edge = &graph.nodes[0].edges[0];
+        assert_eq!(nodes[0]["edges"][0]["target"], edge.target);
+        assert_eq!(nodes[0]["edges"][0]["cost"], 1);
+    }
+}
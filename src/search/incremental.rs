@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use super::{a_star, Cost, SearchProblem, Solution};
+
+/// Exact cost to the goal of a state on a previously found optimal path, and the next
+/// step of that path.
+struct Known<S, A, C> {
+    cost_to_goal: C,
+    next: Option<(A, S)>,
+}
+
+type KnownStates<P> = HashMap<
+    <P as SearchProblem>::State,
+    Known<<P as SearchProblem>::State, <P as SearchProblem>::Action, <P as SearchProblem>::Cost>,
+>;
+
+/// [`IncrementalSearch`]
+/// ## A* that reuses previous searches when the start state changes.
+/// Every state of every solution found is remembered with its exact cost to the goal.
+/// When the start moves (e.g. the player made a move during interactive play):
+/// * if the new start is on a remembered path, its suffix is returned without searching;
+/// * otherwise A* runs from the new start, using the remembered exact costs as a better
+///   heuristic and stopping as soon as it pops a remembered state, whose suffix is
+///   reused.
+///
+/// Both shortcuts rely on the remembered paths being optimal, so `heuristic` must be
+/// admissible.
+pub struct IncrementalSearch<'a, P: SearchProblem, H> {
+    problem: &'a P,
+    heuristic: H,
+    known: KnownStates<P>,
+}
+
+impl<'a, P, H> IncrementalSearch<'a, P, H>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    pub fn new(problem: &'a P, heuristic: H) -> Self {
+        Self {
+            problem,
+            heuristic,
+            known: HashMap::new(),
+        }
+    }
+
+    /// Number of states whose exact cost to the goal is remembered.
+    pub fn known_states(&self) -> usize {
+        self.known.len()
+    }
+
+    pub fn solve(&mut self) -> Option<Solution<P::State, P::Action, P::Cost>> {
+        self.solve_from(self.problem.initial_state())
+    }
+
+    /// [`solve_from`]
+    /// Optimal solution from `start` instead of the initial state of the problem.
+    pub fn solve_from(
+        &mut self,
+        start: P::State,
+    ) -> Option<Solution<P::State, P::Action, P::Cost>> {
+        let restarted = Restarted {
+            problem: self.problem,
+            start,
+            known: &self.known,
+        };
+        let heuristic = |state: &P::State| match self.known.get(state) {
+            Some(known) => (self.heuristic)(state).max(known.cost_to_goal.to_f64()),
+            None => (self.heuristic)(state),
+        };
+        let mut solution = a_star(&restarted, heuristic)?;
+
+        let mut last = solution.goal().clone();
+        while let Some((action, state)) = self.known.get(&last).and_then(|k| k.next.clone()) {
+            solution.cost = solution.cost.add(step_cost(self.problem, &last, &state));
+            solution.actions.push(action);
+            solution.states.push(state.clone());
+            last = state;
+        }
+        self.remember(&solution);
+        Some(solution)
+    }
+
+    fn remember(&mut self, solution: &Solution<P::State, P::Action, P::Cost>) {
+        let mut cost_to_goal = P::Cost::zero();
+        self.known.insert(
+            solution.goal().clone(),
+            Known {
+                cost_to_goal,
+                next: None,
+            },
+        );
+        for i in (0..solution.len()).rev() {
+            let (state, next) = (&solution.states[i], &solution.states[i + 1]);
+            cost_to_goal = cost_to_goal.add(step_cost(self.problem, state, next));
+            self.known.insert(
+                state.clone(),
+                Known {
+                    cost_to_goal,
+                    next: Some((solution.actions[i].clone(), next.clone())),
+                },
+            );
+        }
+    }
+}
+
+/// Cheapest action cost from `from` to `to`.
+pub(super) fn step_cost<P: SearchProblem>(problem: &P, from: &P::State, to: &P::State) -> P::Cost {
+    problem
+        .successors(from)
+        .filter(|successor| successor.state == *to)
+        .map(|successor| successor.cost)
+        .min()
+        .unwrap_or(P::Cost::zero())
+}
+
+/// `problem` starting from `start`, remembered states count as goals.
+struct Restarted<'a, P: SearchProblem> {
+    problem: &'a P,
+    start: P::State,
+    known: &'a KnownStates<P>,
+}
+
+impl<P: SearchProblem> SearchProblem for Restarted<'_, P> {
+    type State = P::State;
+    type Action = P::Action;
+    type Cost = P::Cost;
+    type Successors<'b>
+        = P::Successors<'b>
+    where
+        Self: 'b;
+
+    fn initial_state(&self) -> P::State {
+        self.start.clone()
+    }
+
+    fn is_goal(&self, state: &P::State) -> bool {
+        self.known.contains_key(state) || self.problem.is_goal(state)
+    }
+
+    fn successors<'b>(&'b self, state: &'b P::State) -> P::Successors<'b> {
+        self.problem.successors(state)
+    }
+
+    fn is_dead_end(&self, state: &P::State) -> bool {
+        self.problem.is_dead_end(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem, Slide};
+
+    fn manhattan(board: &Board) -> f64 {
+        board.manhattan_distance() as f64
+    }
+
+    #[test]
+    fn incremental_reuses_suffix_when_start_follows_the_path() {
+        let problem = NPuzzleProblem::new(Board::try_from("8 6 7 2 5 4 3 0 1").unwrap());
+        let mut search = IncrementalSearch::new(&problem, manhattan);
+
+        let first = search.solve().unwrap();
+        let second = search.solve_from(first.states[1].clone()).unwrap();
+
+        assert_eq!(first.cost, 31);
+        assert_eq!(search.known_states(), 32);
+        assert_eq!(second.states, first.states[1..]);
+        assert_eq!(second.cost, 30);
+    }
+
+    #[test]
+    fn incremental_matches_a_star_when_start_leaves_the_path() {
+        let problem = NPuzzleProblem::new(Board::try_from("4 1 3 7 2 6 0 5 8").unwrap());
+        let mut search = IncrementalSearch::new(&problem, manhattan);
+        let first = search.solve().unwrap();
+
+        for slide in Slide::ALL {
+            let Some(start) = problem.start.slide(slide) else {
+                continue;
+            };
+
+            let incremental = search.solve_from(start.clone()).unwrap();
+
+            let fresh = a_star(&NPuzzleProblem::new(start), manhattan).unwrap();
+            assert_eq!(incremental.cost, fresh.cost);
+            assert!(incremental.goal().is_goal());
+            assert!(incremental.cost + 1 >= first.cost);
+        }
+    }
+}
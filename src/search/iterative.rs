@@ -0,0 +1,416 @@
+use std::collections::HashSet;
+use std::time::Instant;
+
+use super::{Cost, PruneReason, SearchEvent, SearchProblem, SearchSink, SearchStats, Solution};
+
+/// [`DepthLimitedOutcome`]
+/// Result of a [`depth_limited_search`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DepthLimitedOutcome<S, A, C = u32> {
+    Found(Solution<S, A, C>),
+    /// No solution within the limit, but some paths were cut at the limit, a deeper
+    /// search may find one.
+    Cutoff,
+    /// No path reaches the limit, there is no solution at any depth.
+    Failure,
+}
+
+impl<S, A, C> DepthLimitedOutcome<S, A, C> {
+    pub fn solution(self) -> Option<Solution<S, A, C>> {
+        match self {
+            DepthLimitedOutcome::Found(solution) => Some(solution),
+            _ => None,
+        }
+    }
+}
+
+/// [`depth_limited_search`]
+/// ## Depth-limited search.
+/// A depth-first search that doesn't follow paths longer than `limit` actions, states
+/// are not repeated along the current path. Tells a [`DepthLimitedOutcome::Cutoff`],
+/// where a larger limit could help, from a [`DepthLimitedOutcome::Failure`], where the
+/// state space was exhausted. [`iddfs`] runs it with growing limits.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::FixedCannibalsProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = FixedCannibalsProblem::<3, 2>;
+/// assert_eq!(depth_limited_search(&problem, 10), DepthLimitedOutcome::Cutoff);
+/// let solution = depth_limited_search(&problem, 11).solution().unwrap();
+/// assert_eq!(solution.len(), 11);
+/// let unsolvable = FixedCannibalsProblem::<4, 2>;
+/// assert_eq!(depth_limited_search(&unsolvable, 100), DepthLimitedOutcome::Failure);
+/// ```
+pub fn depth_limited_search<P: SearchProblem>(
+    problem: &P,
+    limit: usize,
+) -> DepthLimitedOutcome<P::State, P::Action, P::Cost> {
+    depth_limited_search_traced(problem, limit, &mut ())
+}
+
+/// [`depth_limited_search_traced`]
+/// [`depth_limited_search`] reporting every [`SearchEvent`] to `sink`, `f` being the
+/// depth. Successors beyond the limit are pruned with [`PruneReason::Bound`].
+pub fn depth_limited_search_traced<P: SearchProblem>(
+    problem: &P,
+    limit: usize,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> DepthLimitedOutcome<P::State, P::Action, P::Cost> {
+    profile!("depth_limited_search");
+    match Iteration::new(sink).run(problem, limit as f64, |depth, _, _| depth as f64) {
+        Outcome::Found(solution) => DepthLimitedOutcome::Found(solution),
+        Outcome::Exceeded(Some(_)) => DepthLimitedOutcome::Cutoff,
+        Outcome::Exceeded(None) => DepthLimitedOutcome::Failure,
+    }
+}
+
+/// [`iddfs`]
+/// ## Iterative deepening depth-first search.
+/// Runs [`depth_limited_search`]es limited to `increment`, `2 * increment`, ... actions
+/// (an increment of 0 counts as 1) until a solution is found or `max_depth` was searched.
+/// Only the current path is kept in memory, states are simply not repeated along it, so
+/// states reached by several paths are expanded again on each one and in every
+/// iteration. With an increment of 1 the solution has the fewest actions, with a larger
+/// one it may have up to `increment - 1` more.
+/// `stats` add up every iteration, the frontier being the current path.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::FixedCannibalsProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = FixedCannibalsProblem::<3, 2>;
+/// let solution = iddfs(&problem, 1, 20).unwrap();
+/// assert_eq!(solution.len(), bfs(&problem).unwrap().len());
+/// assert!(iddfs(&problem, 1, 10).is_none());
+/// ```
+pub fn iddfs<P: SearchProblem>(
+    problem: &P,
+    increment: usize,
+    max_depth: usize,
+) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    iddfs_traced(problem, increment, max_depth, &mut ())
+}
+
+/// [`iddfs_traced`]
+/// [`iddfs`] reporting the [`SearchEvent`]s of every iteration to `sink`, `f` being the
+/// depth. Node numbers keep growing across iterations, successors beyond the depth
+/// limit are pruned with [`PruneReason::Bound`].
+pub fn iddfs_traced<P: SearchProblem>(
+    problem: &P,
+    increment: usize,
+    max_depth: usize,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    profile!("iddfs");
+    let mut iteration = Iteration::new(sink);
+    let mut limit = increment.max(1).min(max_depth);
+    loop {
+        let outcome = iteration.run(problem, limit as f64, |depth, _, _| depth as f64);
+        match outcome {
+            Outcome::Found(solution) => return Some(solution),
+            Outcome::Exceeded(Some(_)) if limit < max_depth => {
+                limit = limit.saturating_add(increment.max(1)).min(max_depth)
+            }
+            Outcome::Exceeded(_) => return None,
+        }
+    }
+}
+
+/// [`IdaStats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IdaStats {
+    /// Depth-first searches run, one per f bound.
+    pub iterations: usize,
+    /// Expansions of nodes already expanded by the previous iteration, those whose f is
+    /// within its bound. Nodes reached by several paths of one iteration aren't counted.
+    pub reexpansions: usize,
+}
+
+/// [`IdaOutcome`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdaOutcome<S, A, C = u32> {
+    pub solution: Option<Solution<S, A, C>>,
+    pub stats: IdaStats,
+}
+
+/// [`ida_star`]
+/// ## Iterative deepening A*.
+/// Depth-first searches pruning the nodes whose `g + heuristic` exceeds a bound, which
+/// starts at the heuristic of the initial state and grows to the smallest f pruned by
+/// the previous iteration. Like [`iddfs`] only the current path is kept in memory, and
+/// like [`a_star`](super::a_star) the solution is optimal for an admissible `heuristic`.
+/// The solution `stats` add up every iteration, the frontier being the current path.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = HanoiProblem::new(3, 3).unwrap();
+/// let outcome = ida_star(&problem, |state| problem.heuristic(state));
+/// assert_eq!(outcome.solution.unwrap().cost, 7);
+/// assert!(outcome.stats.iterations > 1);
+/// ```
+pub fn ida_star<P, H>(problem: &P, heuristic: H) -> IdaOutcome<P::State, P::Action, P::Cost>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    ida_star_traced(problem, heuristic, &mut ())
+}
+
+/// [`ida_star_traced`]
+/// [`ida_star`] reporting the [`SearchEvent`]s of every iteration to `sink`. Node
+/// numbers keep growing across iterations, successors beyond the bound are pruned with
+/// [`PruneReason::Bound`].
+pub fn ida_star_traced<P, H>(
+    problem: &P,
+    heuristic: H,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> IdaOutcome<P::State, P::Action, P::Cost>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    profile!("ida_star");
+    let mut iteration = Iteration::new(sink);
+    let mut bound = heuristic(&problem.initial_state());
+    let solution = loop {
+        let outcome = iteration.run(problem, bound, |_, g: P::Cost, state| {
+            g.to_f64() + heuristic(state)
+        });
+        match outcome {
+            Outcome::Found(solution) => break Some(solution),
+            Outcome::Exceeded(Some(next_bound)) => bound = next_bound,
+            Outcome::Exceeded(None) => break None,
+        }
+    };
+    IdaOutcome {
+        solution,
+        stats: IdaStats {
+            iterations: iteration.iterations,
+            reexpansions: iteration.reexpansions,
+        },
+    }
+}
+
+/// Result of one depth-first search within a bound.
+enum Outcome<S, A, C> {
+    Found(Solution<S, A, C>),
+    /// Smallest `f` beyond the bound, `None` when nothing was pruned by the bound.
+    Exceeded(Option<f64>),
+}
+
+/// State shared by the depth-first searches of an iterative deepening algorithm.
+struct Iteration<'s, S, C> {
+    sink: &'s mut dyn SearchSink<S, C>,
+    start: Instant,
+    stats: SearchStats,
+    /// Number of the next generated node, across iterations.
+    nodes: usize,
+    iterations: usize,
+    /// Bound of the previous iteration, which expanded every node within it.
+    previous_bound: Option<f64>,
+    reexpansions: usize,
+}
+
+impl<'s, S: Clone + Eq + std::hash::Hash, C: Cost> Iteration<'s, S, C> {
+    fn new(sink: &'s mut dyn SearchSink<S, C>) -> Self {
+        Self {
+            sink,
+            start: Instant::now(),
+            stats: SearchStats::default(),
+            nodes: 0,
+            iterations: 0,
+            previous_bound: None,
+            reexpansions: 0,
+        }
+    }
+
+    /// Depth-first search pruning the states whose `f(depth, g, state)` exceeds `bound`.
+    fn run<P, F>(&mut self, problem: &P, bound: f64, f: F) -> Outcome<S, P::Action, C>
+    where
+        P: SearchProblem<State = S, Cost = C>,
+        F: Fn(usize, C, &S) -> f64,
+    {
+        self.iterations += 1;
+        let previous_bound = self.previous_bound.replace(bound);
+        let initial_state = problem.initial_state();
+        let initial_f = f(0, C::zero(), &initial_state);
+        if initial_f > bound {
+            return Outcome::Exceeded(Some(initial_f));
+        }
+        self.sink.event(SearchEvent::Generate {
+            state: &initial_state,
+            node: self.nodes,
+            parent: None,
+            g: C::zero(),
+            f: initial_f,
+        });
+        self.stats.record_generated(0);
+        let mut on_path = HashSet::from([problem.canonicalize(&initial_state).into_owned()]);
+        let mut states = vec![initial_state];
+        let mut actions = vec![];
+        // path cost, f and node number of every state of the path.
+        let mut path = vec![(C::zero(), initial_f, self.nodes)];
+        self.nodes += 1;
+        let mut children = vec![];
+        let mut next_bound: Option<f64> = None;
+
+        loop {
+            // the last state of the path was just reached.
+            let state = states.last().expect("the path is never empty here");
+            let (g, state_f, node) = *path.last().expect("one entry per state");
+            self.sink.event(SearchEvent::Expand {
+                state,
+                node,
+                g,
+                f: state_f,
+                frontier: states.len() - 1,
+            });
+            if problem.is_goal(state) {
+                self.stats.elapsed = self.start.elapsed();
+                let solution = Solution {
+                    states,
+                    actions,
+                    cost: g,
+                    stats: self.stats,
+                };
+                self.sink.event(SearchEvent::Solution {
+                    state: solution.goal(),
+                    cost: solution.cost,
+                    length: solution.len(),
+                });
+                return Outcome::Found(solution);
+            }
+            self.stats.expanded += 1;
+            if previous_bound.is_some_and(|previous| state_f <= previous) {
+                self.reexpansions += 1;
+            }
+            children.push(problem.successors(state).collect::<Vec<_>>().into_iter());
+            self.stats.record_frontier(states.len());
+
+            // first successor worth following, backtracking when a state has none left.
+            loop {
+                let Some(successor) = children.last_mut().map(Iterator::next) else {
+                    return Outcome::Exceeded(next_bound);
+                };
+                let Some(successor) = successor else {
+                    children.pop();
+                    let state = states.pop().expect("one state per children");
+                    on_path.remove(problem.canonicalize(&state).as_ref());
+                    actions.pop();
+                    path.pop();
+                    continue;
+                };
+                let (g, _, parent) = *path.last().expect("one entry per state");
+                let successor_g = g.add(successor.cost);
+                let pruned = |sink: &mut dyn SearchSink<S, C>, reason| {
+                    sink.event(SearchEvent::Prune {
+                        state: &successor.state,
+                        parent,
+                        g: successor_g,
+                        reason,
+                    })
+                };
+                if problem.is_dead_end(&successor.state) {
+                    pruned(self.sink, PruneReason::DeadEnd);
+                    continue;
+                }
+                if on_path.contains(problem.canonicalize(&successor.state).as_ref()) {
+                    self.stats.duplicates += 1;
+                    pruned(self.sink, PruneReason::Duplicate);
+                    continue;
+                }
+                let successor_f = f(states.len(), successor_g, &successor.state);
+                if successor_f > bound {
+                    pruned(self.sink, PruneReason::Bound);
+                    next_bound = Some(next_bound.map_or(successor_f, |next| next.min(successor_f)));
+                    continue;
+                }
+                self.sink.event(SearchEvent::Generate {
+                    state: &successor.state,
+                    node: self.nodes,
+                    parent: Some(parent),
+                    g: successor_g,
+                    f: successor_f,
+                });
+                self.stats.record_generated(states.len());
+                on_path.insert(problem.canonicalize(&successor.state).into_owned());
+                states.push(successor.state);
+                actions.push(successor.action);
+                path.push((successor_g, successor_f, self.nodes));
+                self.nodes += 1;
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::FixedCannibalsProblem;
+    use crate::hanoi::{HanoiProblem, HanoiState};
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+    use crate::search::{a_star, bfs, dfs};
+
+    #[test]
+    fn iddfs_finds_fewest_actions_with_a_path_sized_frontier() {
+        let problem = HanoiProblem::new(3, 3).unwrap();
+        let mut deepest_prune = 0;
+
+        let solution = iddfs_traced(&problem, 1, 30, &mut |event: SearchEvent<
+            '_,
+            HanoiState,
+        >| {
+            if let SearchEvent::Prune {
+                reason: PruneReason::Bound,
+                parent,
+                ..
+            } = event
+            {
+                deepest_prune = deepest_prune.max(parent);
+            }
+        })
+        .unwrap();
+
+        assert_eq!(solution.len(), bfs(&problem).unwrap().len());
+        assert!(solution.stats.frontier_peak <= solution.len() + 1);
+        assert!(deepest_prune > 0);
+        assert!(solution.stats.expanded > dfs(&problem).unwrap().stats.expanded);
+    }
+
+    #[test]
+    fn iddfs_increment_and_max_depth() {
+        let problem = FixedCannibalsProblem::<3, 2>;
+
+        let coarse = iddfs(&problem, 4, 12).unwrap();
+
+        assert!(problem.is_goal(coarse.goal()));
+        assert!(coarse.len() <= 12);
+        assert!(iddfs(&problem, 0, 11).is_some(), "increment 0 counts as 1");
+        assert!(iddfs(&problem, 5, 10).is_none());
+        assert!(
+            iddfs(&HanoiProblem::new(2, 3).unwrap(), 1, 100)
+                .unwrap()
+                .len()
+                == 3
+        );
+    }
+
+    #[test]
+    fn ida_star_is_optimal_and_counts_reexpansions() {
+        let problem = NPuzzleProblem::new(Board::try_from("8 6 7 2 5 4 3 0 1").unwrap());
+        let heuristic = |board: &Board| board.manhattan_distance() as f64;
+
+        let outcome = ida_star(&problem, heuristic);
+
+        let solution = outcome.solution.unwrap();
+        assert_eq!(solution.cost, a_star(&problem, heuristic).unwrap().cost);
+        assert!(outcome.stats.iterations > 1);
+        assert!(outcome.stats.reexpansions > 0);
+        assert!(outcome.stats.reexpansions < solution.stats.expanded);
+        let unsolvable = NPuzzleProblem::new(Board::try_from("2 1 3 0").unwrap());
+        let exhausted = ida_star(&unsolvable, |_| 0.0);
+        assert_eq!(exhausted.solution, None);
+        assert!(exhausted.stats.iterations > 1);
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Debug;
+use std::io::Write;
+
+use serde::Serialize;
+use serde_json::json;
+
+use super::{Cost, SearchEvent, SearchSink};
+
+type StateFormat<S> = Box<dyn Fn(&S) -> String>;
+
+/// [`JsonLinesSink`]
+/// ## Writes one JSON object per event.
+/// Every line has an `event` field (`generate`, `expand`, `prune` or `solution`), the
+/// `state` formatted as a string and the remaining fields of the [`SearchEvent`]:
+/// ```text
+/// {"event":"generate","f":4.0,"g":0,"node":0,"parent":null,"state":"0"}
+/// {"event":"expand","f":4.0,"frontier":0,"g":0,"node":0,"state":"0"}
+/// ```
+/// States are formatted with [`Debug`] unless [`with_state_format`](Self::with_state_format)
+/// is used. Write errors stop the output, [`error`](Self::error) returns the first one.
+pub struct JsonLinesSink<W: Write, S> {
+    writer: W,
+    format: StateFormat<S>,
+    error: Option<std::io::Error>,
+}
+
+impl<W: Write, S: Debug> JsonLinesSink<W, S> {
+    pub fn new(writer: W) -> Self {
+        Self::with_state_format(writer, |state| format!("{:?}", state))
+    }
+}
+
+impl<W: Write, S> JsonLinesSink<W, S> {
+    pub fn with_state_format(writer: W, format: impl Fn(&S) -> String + 'static) -> Self {
+        Self {
+            writer,
+            format: Box::new(format),
+            error: None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&std::io::Error> {
+        self.error.as_ref()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write, S, C: Cost + Serialize> SearchSink<S, C> for JsonLinesSink<W, S> {
+    fn event(&mut self, event: SearchEvent<'_, S, C>) {
+        if self.error.is_some() {
+            return;
+        }
+        let line = match event {
+            SearchEvent::Generate {
+                state,
+                node,
+                parent,
+                g,
+                f,
+            } => json!({
+                "event": "generate",
+                "state": (self.format)(state),
+                "node": node,
+                "parent": parent,
+                "g": g,
+                "f": f,
+            }),
+            SearchEvent::Expand {
+                state,
+                node,
+                g,
+                f,
+                frontier,
+            } => json!({
+                "event": "expand",
+                "state": (self.format)(state),
+                "node": node,
+                "g": g,
+                "f": f,
+                "frontier": frontier,
+            }),
+            SearchEvent::Prune {
+                state,
+                parent,
+                g,
+                reason,
+            } => json!({
+                "event": "prune",
+                "state": (self.format)(state),
+                "parent": parent,
+                "g": g,
+                "reason": reason,
+            }),
+            SearchEvent::Solution {
+                state,
+                cost,
+                length,
+            } => json!({
+                "event": "solution",
+                "state": (self.format)(state),
+                "cost": cost,
+                "length": length,
+            }),
+        };
+        if let Err(error) = writeln!(self.writer, "{}", line) {
+            self.error = Some(error);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::{a_star, a_star_traced};
+
+    #[test]
+    fn events_json_lines_sink_writes_one_object_per_event() {
+        let problem = HanoiProblem::new(2, 3).unwrap();
+        let mut sink = JsonLinesSink::with_state_format(vec![], |state: &_| format!("{}", state));
+
+        let solution = a_star_traced(&problem, |s| problem.heuristic(s), &mut sink).unwrap();
+
+        assert_eq!(Some(solution), a_star(&problem, |s| problem.heuristic(s)));
+        let output = String::from_utf8(sink.into_inner()).unwrap();
+        let events = output
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<serde_json::Value>>();
+        assert_eq!(events[0]["event"], "generate");
+        assert_eq!(events[0]["parent"], serde_json::Value::Null);
+        assert_eq!(events[1]["event"], "expand");
+        let last = events.last().unwrap();
+        assert_eq!(last["event"], "solution");
+        assert_eq!(last["length"], 3);
+        assert!(events.iter().any(|event| event["event"] == "prune"));
+    }
+}
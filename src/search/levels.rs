@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use super::SearchProblem;
+
+/// [`Levels`]
+/// ## Breadth-first levels of the state space.
+/// Yields the distinct states reachable in exactly 0, 1, 2, ... actions (and no fewer),
+/// one [`Vec`] per depth, until no new state is found. Equivalent states, as told by
+/// [`SearchProblem::canonicalize`], appear once and dead ends are skipped. Useful to
+/// build pattern databases or to plot how the state space grows with the depth.
+/// # Example
+/// ```
+/// # use algoritmos_rust::n_puzzle::{Board, NPuzzleProblem};
+/// # use algoritmos_rust::search::levels;
+/// let problem = NPuzzleProblem::new(Board::goal(3).unwrap());
+/// let sizes = levels(&problem).take(5).map(|level| level.len()).collect::<Vec<_>>();
+/// assert_eq!(sizes, [1, 2, 4, 8, 16]);
+/// ```
+pub struct Levels<'a, P: SearchProblem> {
+    problem: &'a P,
+    seen: HashSet<P::State>,
+    level: Vec<P::State>,
+}
+
+/// [`levels`]
+/// The [`Levels`] of `problem`, starting with its initial state.
+pub fn levels<P: SearchProblem>(problem: &P) -> Levels<'_, P> {
+    let initial = problem.initial_state();
+    Levels {
+        problem,
+        seen: HashSet::from([problem.canonicalize(&initial).into_owned()]),
+        level: vec![initial],
+    }
+}
+
+/// [`states_at_depth`]
+/// The distinct states `depth` actions away from the initial state, see [`Levels`].
+pub fn states_at_depth<P: SearchProblem>(problem: &P, depth: usize) -> Vec<P::State> {
+    levels(problem).nth(depth).unwrap_or_default()
+}
+
+impl<P: SearchProblem> Iterator for Levels<'_, P> {
+    type Item = Vec<P::State>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.level.is_empty() {
+            return None;
+        }
+        let mut next = vec![];
+        for state in &self.level {
+            for successor in self.problem.successors(state) {
+                if self.problem.is_dead_end(&successor.state) {
+                    continue;
+                }
+                if self
+                    .seen
+                    .insert(self.problem.canonicalize(&successor.state).into_owned())
+                {
+                    next.push(successor.state);
+                }
+            }
+        }
+        Some(std::mem::replace(&mut self.level, next))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+
+    #[test]
+    fn levels_partition_the_reachable_states() {
+        let problem = NPuzzleProblem::new(Board::goal(2).unwrap());
+
+        let levels = levels(&problem).collect::<Vec<_>>();
+
+        assert_eq!(levels[0], [problem.initial_state()]);
+        assert_eq!(
+            levels.iter().map(Vec::len).collect::<Vec<_>>(),
+            [1, 2, 2, 2, 2, 2, 1]
+        );
+        let all = levels.iter().flatten().collect::<HashSet<_>>();
+        assert_eq!(all.len(), 12);
+        assert_eq!(states_at_depth(&problem, 1), levels[1]);
+        assert!(states_at_depth(&problem, levels.len()).is_empty());
+    }
+}
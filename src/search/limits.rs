@@ -0,0 +1,301 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::time::{Duration, Instant};
+
+use super::{SearchProblem, Solution, Successor};
+
+/// [`SearchLimits`]
+/// ## Budgets a search stops at.
+/// Any solver can run under limits with [`solve`](Self::solve), which hands it the
+/// problem wrapped in a [`Limited`]. Once `max_nodes` nodes were generated or
+/// `max_time` has passed, states have no successors so the search runs out of frontier,
+/// successors deeper than `max_depth` actions are discarded. Limits left to `None` don't
+/// apply.
+/// # Example
+/// ```
+/// # use algoritmos_rust::n_puzzle::{Board, NPuzzleProblem};
+/// # use algoritmos_rust::search::*;
+/// let problem = NPuzzleProblem::new(Board::try_from("4 1 3 7 2 6 0 5 8").unwrap());
+/// let limits = SearchLimits {
+///     max_nodes: Some(50),
+///     ..SearchLimits::default()
+/// };
+/// let outcome = limits.solve(&problem, |problem| bfs(problem));
+/// assert_eq!(outcome, SearchOutcome::LimitReached(Limit::Nodes));
+/// let outcome = limits.solve(&problem, |problem| {
+///     a_star(problem, |board| board.manhattan_distance() as f64)
+/// });
+/// assert_eq!(outcome.solution().unwrap().cost, 6);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchLimits {
+    /// Most nodes generated, the initial one included.
+    pub max_nodes: Option<usize>,
+    pub max_time: Option<Duration>,
+    /// Most actions from the initial state.
+    pub max_depth: Option<usize>,
+}
+
+impl SearchLimits {
+    /// [`solve`]
+    /// Runs `solver` on `problem` under the limits. A search stopped by `max_nodes` or
+    /// `max_time` reached its limit whatever it returned, its solution may not be the one
+    /// it would have found without them. Solutions within `max_depth` are kept.
+    pub fn solve<P, F>(&self, problem: &P, solver: F) -> SearchOutcome<P::State, P::Action, P::Cost>
+    where
+        P: SearchProblem,
+        F: FnOnce(&Limited<'_, P>) -> Option<Solution<AtDepth<P::State>, P::Action, P::Cost>>,
+    {
+        let limited = Limited::new(problem, *self);
+        let solution = solver(&limited);
+        match (limited.stopped.get(), solution) {
+            (Some(limit), _) => SearchOutcome::LimitReached(limit),
+            (None, Some(solution)) => SearchOutcome::Solved(Solution {
+                states: solution.states.into_iter().map(|s| s.state).collect(),
+                actions: solution.actions,
+                cost: solution.cost,
+                stats: solution.stats,
+            }),
+            (None, None) if limited.depth_pruned.get() => SearchOutcome::LimitReached(Limit::Depth),
+            (None, None) => SearchOutcome::NoSolution,
+        }
+    }
+}
+
+/// [`Limit`]
+/// The [`SearchLimits`] budget a search ran out of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Limit {
+    Nodes,
+    Time,
+    /// No solution within `max_depth` actions, a deeper one may exist.
+    Depth,
+}
+
+/// [`SearchOutcome`]
+/// How a search run with [`SearchLimits::solve`] ended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchOutcome<S, A, C = u32> {
+    Solved(Solution<S, A, C>),
+    /// The whole reachable space was searched without meeting a goal.
+    NoSolution,
+    LimitReached(Limit),
+}
+
+impl<S, A, C> SearchOutcome<S, A, C> {
+    pub fn solution(self) -> Option<Solution<S, A, C>> {
+        match self {
+            SearchOutcome::Solved(solution) => Some(solution),
+            _ => None,
+        }
+    }
+}
+
+/// [`AtDepth`]
+/// A state of a [`Limited`] problem and the number of actions that reached it. Only the
+/// state takes part in equality and hashing, so searches detect duplicates like on the
+/// wrapped problem, and heuristics see the state through [`Deref`].
+#[derive(Debug, Clone)]
+pub struct AtDepth<S> {
+    pub state: S,
+    pub depth: usize,
+}
+
+impl<S: PartialEq> PartialEq for AtDepth<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<S: Eq> Eq for AtDepth<S> {}
+
+impl<S: Hash> Hash for AtDepth<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.state.hash(state)
+    }
+}
+
+impl<S> Deref for AtDepth<S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        &self.state
+    }
+}
+
+/// [`Limited`]
+/// `problem` under [`SearchLimits`], see [`SearchLimits::solve`].
+pub struct Limited<'a, P> {
+    problem: &'a P,
+    limits: SearchLimits,
+    start: Instant,
+    generated: Cell<usize>,
+    stopped: Cell<Option<Limit>>,
+    depth_pruned: Cell<bool>,
+}
+
+impl<'a, P> Limited<'a, P> {
+    pub fn new(problem: &'a P, limits: SearchLimits) -> Self {
+        Self {
+            problem,
+            limits,
+            start: Instant::now(),
+            generated: Cell::new(1),
+            stopped: Cell::new(None),
+            depth_pruned: Cell::new(false),
+        }
+    }
+
+    pub fn inner(&self) -> &'a P {
+        self.problem
+    }
+
+    /// The node or time limit the search ran out of, if any.
+    pub fn stopped(&self) -> Option<Limit> {
+        self.stopped.get()
+    }
+}
+
+impl<P: SearchProblem> SearchProblem for Limited<'_, P> {
+    type State = AtDepth<P::State>;
+    type Action = P::Action;
+    type Cost = P::Cost;
+    type Successors<'b>
+        = LimitedSuccessors<'b, P>
+    where
+        Self: 'b;
+
+    fn initial_state(&self) -> Self::State {
+        AtDepth {
+            state: self.problem.initial_state(),
+            depth: 0,
+        }
+    }
+
+    fn is_goal(&self, state: &Self::State) -> bool {
+        self.problem.is_goal(&state.state)
+    }
+
+    fn successors<'b>(&'b self, state: &'b Self::State) -> Self::Successors<'b> {
+        if self
+            .limits
+            .max_time
+            .is_some_and(|max_time| self.start.elapsed() >= max_time)
+        {
+            self.stopped.set(self.stopped.get().or(Some(Limit::Time)));
+        }
+        LimitedSuccessors {
+            limited: self,
+            successors: self.problem.successors(&state.state),
+            depth: state.depth + 1,
+        }
+    }
+
+    fn canonicalize<'b>(&self, state: &'b Self::State) -> Cow<'b, Self::State> {
+        match self.problem.canonicalize(&state.state) {
+            Cow::Borrowed(_) => Cow::Borrowed(state),
+            Cow::Owned(canonical) => Cow::Owned(AtDepth {
+                state: canonical,
+                depth: state.depth,
+            }),
+        }
+    }
+
+    fn is_dead_end(&self, state: &Self::State) -> bool {
+        self.problem.is_dead_end(&state.state)
+    }
+
+    fn dominates(&self, a: &Self::State, b: &Self::State) -> bool {
+        self.problem.dominates(&a.state, &b.state)
+    }
+}
+
+/// [`LimitedSuccessors`]
+/// The successors of a [`Limited`] state, `depth` actions from the initial one.
+pub struct LimitedSuccessors<'b, P: SearchProblem + 'b> {
+    limited: &'b Limited<'b, P>,
+    successors: P::Successors<'b>,
+    depth: usize,
+}
+
+impl<'b, P: SearchProblem> Iterator for LimitedSuccessors<'b, P> {
+    type Item = Successor<AtDepth<P::State>, P::Action, P::Cost>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let limited = self.limited;
+        if limited.stopped.get().is_some() {
+            return None;
+        }
+        if limited
+            .limits
+            .max_depth
+            .is_some_and(|max_depth| self.depth > max_depth)
+        {
+            limited.depth_pruned.set(true);
+            return None;
+        }
+        let successor = self.successors.next()?;
+        if limited
+            .limits
+            .max_nodes
+            .is_some_and(|max_nodes| limited.generated.get() >= max_nodes)
+        {
+            limited.stopped.set(Some(Limit::Nodes));
+            return None;
+        }
+        limited.generated.set(limited.generated.get() + 1);
+        Some(Successor::new(
+            successor.action,
+            AtDepth {
+                state: successor.state,
+                depth: self.depth,
+            },
+            successor.cost,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::FixedCannibalsProblem;
+    use crate::search::{bfs, dfs};
+
+    #[test]
+    fn limits_tell_apart_running_out_and_no_solution() {
+        let classic = FixedCannibalsProblem::<3, 2>;
+        let depth = |max_depth| SearchLimits {
+            max_depth: Some(max_depth),
+            ..SearchLimits::default()
+        };
+
+        assert_eq!(
+            depth(10).solve(&classic, |problem| bfs(problem)),
+            SearchOutcome::LimitReached(Limit::Depth)
+        );
+        let solved = depth(11).solve(&classic, |problem| dfs(problem)).solution();
+        assert_eq!(solved, dfs(&classic));
+        assert_eq!(
+            SearchLimits::default().solve(&FixedCannibalsProblem::<4, 2>, |problem| bfs(problem)),
+            SearchOutcome::NoSolution
+        );
+        let nodes = SearchLimits {
+            max_nodes: Some(5),
+            ..SearchLimits::default()
+        };
+        assert_eq!(
+            nodes.solve(&classic, |problem| bfs(problem)),
+            SearchOutcome::LimitReached(Limit::Nodes)
+        );
+        let time = SearchLimits {
+            max_time: Some(Duration::ZERO),
+            ..SearchLimits::default()
+        };
+        assert_eq!(
+            time.solve(&classic, |problem| bfs(problem)),
+            SearchOutcome::LimitReached(Limit::Time)
+        );
+    }
+}
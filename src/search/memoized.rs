@@ -0,0 +1,180 @@
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use super::{SearchProblem, Successor};
+
+type SuccessorList<P> = Vec<
+    Successor<
+        <P as SearchProblem>::State,
+        <P as SearchProblem>::Action,
+        <P as SearchProblem>::Cost,
+    >,
+>;
+
+/// [`MemoStats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    /// Expansions answered from the cache.
+    pub hits: usize,
+    /// Expansions that had to ask the wrapped problem.
+    pub misses: usize,
+}
+
+impl MemoStats {
+    /// Fraction of expansions answered from the cache, 0 before the first one.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+/// [`Memoized`]
+/// ## Caches the successors of a problem.
+/// Wraps any [`SearchProblem`] and remembers the successor list of every expanded
+/// state, so searches that expand the same states again and again (iterative deepening,
+/// recursive best-first, repeated runs on one instance) only pay for generating them
+/// once. Successors are collected eagerly on a miss. At most
+/// [`capacity`](Self::capacity) states are remembered, states expanded once the cache is
+/// full are generated every time.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = Memoized::new(HanoiProblem::new(3, 3).unwrap());
+/// for depth in 0..=7 {
+///     solve_within_depth(&problem, depth);
+/// }
+/// assert!(problem.stats().hit_rate() > 0.5);
+/// ```
+pub struct Memoized<P: SearchProblem> {
+    problem: P,
+    cache: RefCell<HashMap<P::State, SuccessorList<P>>>,
+    capacity: usize,
+    stats: Cell<MemoStats>,
+}
+
+impl<P: SearchProblem> Memoized<P> {
+    pub fn new(problem: P) -> Self {
+        Self {
+            problem,
+            cache: RefCell::new(HashMap::new()),
+            capacity: usize::MAX,
+            stats: Cell::new(MemoStats::default()),
+        }
+    }
+
+    /// Remembers the successors of at most `capacity` states.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.problem
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats.get()
+    }
+
+    /// Number of states whose successors are remembered.
+    pub fn cached_states(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    /// Forgets every successor list and resets the statistics.
+    pub fn clear(&self) {
+        self.cache.borrow_mut().clear();
+        self.stats.set(MemoStats::default());
+    }
+}
+
+impl<P: SearchProblem> SearchProblem for Memoized<P> {
+    type State = P::State;
+    type Action = P::Action;
+    type Cost = P::Cost;
+    type Successors<'a>
+        = std::vec::IntoIter<Successor<P::State, P::Action, P::Cost>>
+    where
+        Self: 'a;
+
+    fn initial_state(&self) -> P::State {
+        self.problem.initial_state()
+    }
+
+    fn is_goal(&self, state: &P::State) -> bool {
+        self.problem.is_goal(state)
+    }
+
+    fn successors<'a>(&'a self, state: &'a P::State) -> Self::Successors<'a> {
+        let mut stats = self.stats.get();
+        if let Some(successors) = self.cache.borrow().get(state) {
+            stats.hits += 1;
+            self.stats.set(stats);
+            return successors.clone().into_iter();
+        }
+        stats.misses += 1;
+        self.stats.set(stats);
+        let successors = self.problem.successors(state).collect::<Vec<_>>();
+        let mut cache = self.cache.borrow_mut();
+        if cache.len() < self.capacity {
+            cache.insert(state.clone(), successors.clone());
+        }
+        successors.into_iter()
+    }
+
+    fn canonicalize<'a>(&self, state: &'a P::State) -> Cow<'a, P::State> {
+        self.problem.canonicalize(state)
+    }
+
+    fn is_dead_end(&self, state: &P::State) -> bool {
+        self.problem.is_dead_end(state)
+    }
+
+    fn dominates(&self, a: &P::State, b: &P::State) -> bool {
+        self.problem.dominates(a, b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::{bfs, solve_within_depth};
+
+    #[test]
+    fn memoized_answers_repeated_expansions_from_the_cache() {
+        let problem = Memoized::new(HanoiProblem::new(3, 3).unwrap());
+
+        let first = bfs(&problem).unwrap();
+        let after_first = problem.stats();
+        let second = bfs(&problem).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first, bfs(problem.inner()).unwrap());
+        assert_eq!(after_first.hits, 0);
+        assert_eq!(problem.stats().misses, after_first.misses);
+        assert_eq!(problem.stats().hits, after_first.misses);
+        assert_eq!(problem.stats().hit_rate(), 0.5);
+
+        problem.clear();
+        assert_eq!(problem.stats(), MemoStats::default());
+        assert_eq!(problem.cached_states(), 0);
+    }
+
+    #[test]
+    fn memoized_stops_caching_when_full() {
+        let problem = Memoized::new(HanoiProblem::new(3, 3).unwrap()).capacity(5);
+
+        for depth in 0..=7 {
+            solve_within_depth(&problem, depth);
+        }
+
+        assert_eq!(problem.cached_states(), 5);
+        assert!(problem.stats().hits > 0);
+        assert!(problem.stats().hit_rate() < 0.5);
+    }
+}
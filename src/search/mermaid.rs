@@ -0,0 +1,76 @@
+use std::fmt::{Display, Write};
+
+use super::Solution;
+
+impl<S, A, C> Solution<S, A, C> {
+    /// [`to_mermaid`]
+    /// ## The path as a mermaid flowchart.
+    /// One node per state from top to bottom, every edge labelled with its action, ready
+    /// to paste in a ```` ```mermaid ```` block. States and actions are formatted with
+    /// [`Display`], see [`to_mermaid_with`](Self::to_mermaid_with).
+    /// # Example
+    /// ```
+    /// # use algoritmos_rust::cannibals::*;
+    /// # use algoritmos_rust::search::*;
+    /// let solution = bfs(&FixedCannibalsProblem::<3, 2>).unwrap();
+    /// let mermaid = solution.to_mermaid();
+    /// assert!(mermaid.starts_with("graph TD\n    s0[\"0 0 3 3 right\"]\n"));
+    /// assert_eq!(mermaid.matches("-->").count(), 11);
+    /// ```
+    pub fn to_mermaid(&self) -> String
+    where
+        S: Display,
+        A: Display,
+    {
+        self.to_mermaid_with(|state| state.to_string(), |action| action.to_string())
+    }
+
+    pub fn to_mermaid_with<F, G>(&self, state_format: F, action_format: G) -> String
+    where
+        F: Fn(&S) -> String,
+        G: Fn(&A) -> String,
+    {
+        let mut mermaid = String::from("graph TD\n");
+        for (index, state) in self.states.iter().enumerate() {
+            let _ = writeln!(mermaid, "    s{index}[\"{}\"]", label(&state_format(state)));
+        }
+        for (index, action) in self.actions.iter().enumerate() {
+            let _ = writeln!(
+                mermaid,
+                "    s{index} -->|\"{}\"| s{}",
+                label(&action_format(action)),
+                index + 1
+            );
+        }
+        mermaid
+    }
+}
+
+/// `text` inside a quoted mermaid label, lines broken with `<br/>`.
+fn label(text: &str) -> String {
+    text.trim_end()
+        .replace('"', "#quot;")
+        .replace('\n', "<br/>")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::hanoi::HanoiProblem;
+    use crate::search::bfs;
+
+    #[test]
+    fn mermaid_labels_every_state_and_move() {
+        let solution = bfs(&HanoiProblem::new(2, 3).unwrap()).unwrap();
+
+        let mermaid = solution.to_mermaid();
+
+        let lines = mermaid.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 1 + solution.states.len() + solution.len());
+        assert_eq!(lines[1], "    s0[\"0: 2 1<br/>1: <br/>2:\"]");
+        assert!(lines[solution.states.len() + 1].starts_with("    s0 -->|\""));
+        assert!(lines
+            .last()
+            .unwrap()
+            .ends_with(&format!("| s{}", solution.len())));
+    }
+}
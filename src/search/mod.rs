@@ -0,0 +1,231 @@
+/// Opens a [`profiling::span`] until the end of the block when the `profiling` feature
+/// is enabled, does nothing otherwise.
+macro_rules! profile {
+    ($name:expr) => {
+        #[cfg(feature = "profiling")]
+        let _span = $crate::search::profiling::span($name);
+    };
+}
+
+pub mod analysis;
+#[cfg(feature = "async")]
+pub mod asynchronous;
+pub mod beam;
+pub mod best_first;
+pub mod bloom;
+pub mod bounded;
+pub mod cancellation;
+pub mod closed_set;
+pub mod consistency;
+pub mod cost;
+pub mod diff;
+pub mod dominance;
+pub mod events;
+#[cfg(feature = "serde")]
+pub mod external;
+pub mod graph;
+pub mod incremental;
+pub mod iterative;
+#[cfg(feature = "serde")]
+pub mod json_lines;
+pub mod levels;
+pub mod limits;
+pub mod memoized;
+pub mod mermaid;
+pub mod observer;
+pub mod optimality;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod portfolio;
+#[cfg(feature = "profiling")]
+pub mod profiling;
+pub mod proof;
+pub mod pruning;
+pub mod random_walk;
+pub mod rbfs;
+pub mod recording;
+pub mod registry;
+pub mod sampling;
+pub mod shortcut;
+pub mod stats;
+pub mod trace;
+pub mod uninformed;
+
+pub use analysis::*;
+#[cfg(feature = "async")]
+pub use asynchronous::*;
+pub use beam::*;
+pub use best_first::*;
+pub use bloom::*;
+pub use bounded::*;
+pub use cancellation::*;
+pub use closed_set::*;
+pub use consistency::*;
+pub use cost::*;
+pub use diff::*;
+pub use dominance::*;
+pub use events::*;
+#[cfg(feature = "serde")]
+pub use external::*;
+pub use graph::*;
+pub use incremental::*;
+pub use iterative::*;
+#[cfg(feature = "serde")]
+pub use json_lines::*;
+pub use levels::*;
+pub use limits::*;
+pub use memoized::*;
+pub use observer::*;
+pub use optimality::*;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
+pub use portfolio::*;
+#[cfg(feature = "profiling")]
+pub use profiling::*;
+pub use proof::*;
+pub use pruning::*;
+pub use random_walk::*;
+pub use rbfs::*;
+pub use recording::*;
+pub use registry::*;
+pub use sampling::*;
+pub use shortcut::*;
+pub use stats::*;
+pub use trace::*;
+pub use uninformed::*;
+
+use std::borrow::Cow;
+use std::hash::Hash;
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// [`SearchProblem`]
+/// ## A problem that can be solved by the path finding algorithms of this module.
+/// A problem knows its initial state, how to recognize a goal and how to expand a state
+/// into its successors (each one reached by an action with a [`Cost`]).
+/// Successors are returned as an iterator so that algorithms that stop early (e.g. a
+/// depth-first search meeting a goal among the first children) only pay for the ones
+/// they look at. Problems that build every child anyway can return a
+/// [`std::vec::IntoIter`], the others a named iterator or [`BoxedSuccessors`].
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+    type Action: Clone;
+    type Cost: Cost;
+    type Successors<'a>: Iterator<Item = Successor<Self::State, Self::Action, Self::Cost>>
+    where
+        Self: 'a;
+
+    fn initial_state(&self) -> Self::State;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    fn successors<'a>(&'a self, state: &'a Self::State) -> Self::Successors<'a>;
+
+    /// Representative of the states equivalent to `state` under a symmetry of the
+    /// problem, used as the key of duplicate detection so that only one state of every
+    /// class is expanded. Equivalent states must agree on being goals and have
+    /// equivalent successors at the same costs. By default no two states are equivalent.
+    fn canonicalize<'a>(&self, state: &'a Self::State) -> Cow<'a, Self::State> {
+        Cow::Borrowed(state)
+    }
+
+    /// Whether no goal can be reached from `state` (e.g. a game over). Searches discard
+    /// such successors as soon as they are generated and report them as pruned.
+    fn is_dead_end(&self, _state: &Self::State) -> bool {
+        false
+    }
+
+    /// Whether `a` is at least as good as `b`: every path from `b` to a goal can be
+    /// matched from `a` at no greater cost, so `b` can be dropped once `a` has been
+    /// reached as cheaply. Used by [`a_star_with_dominance`], by default no state
+    /// dominates another.
+    fn dominates(&self, _a: &Self::State, _b: &Self::State) -> bool {
+        false
+    }
+}
+
+/// [`BoxedSuccessors`]
+/// Lazy successors whose iterator type can't be named (e.g. it captures closures).
+pub type BoxedSuccessors<'a, S, A, C = u32> = Box<dyn Iterator<Item = Successor<S, A, C>> + 'a>;
+
+/// [`Successor`]
+/// A state reached from another one by applying `action`, paying `cost`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Successor<S, A, C = u32> {
+    pub action: A,
+    pub state: S,
+    pub cost: C,
+}
+
+impl<S, A, C> Successor<S, A, C> {
+    pub fn new(action: A, state: S, cost: C) -> Self {
+        Self {
+            action,
+            state,
+            cost,
+        }
+    }
+}
+
+/// [`Solution`]
+/// A path from the initial state to a goal state.
+/// `states` contains the initial state, every intermediate state and the goal state,
+/// `actions[i]` is the action that leads from `states[i]` to `states[i + 1]`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Solution<S, A, C = u32> {
+    pub states: Vec<S>,
+    pub actions: Vec<A>,
+    pub cost: C,
+    /// Work done by the search that found the solution, zero when it doesn't count it.
+    pub stats: SearchStats,
+}
+
+impl<S, A, C> Solution<S, A, C> {
+    pub fn goal(&self) -> &S {
+        self.states
+            .last()
+            .expect("a solution always contains at least the initial state")
+    }
+
+    /// Number of actions in the path.
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// [`to_json`]
+    /// The solution as a JSON object with its `states`, `actions`, `cost` and `stats`, see
+    /// [`from_json`](Self::from_json) to read it back.
+    /// # Example
+    /// ```
+    /// # use algoritmos_rust::cannibals::*;
+    /// # use algoritmos_rust::search::*;
+    /// let solution = bfs(&FixedCannibalsProblem::<3, 2>).unwrap();
+    /// let json = solution.to_json().unwrap();
+    /// assert_eq!(Solution::from_json(&json).unwrap(), solution);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error>
+    where
+        S: Serialize,
+        A: Serialize,
+        C: Serialize,
+    {
+        serde_json::to_string(self)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error>
+    where
+        S: DeserializeOwned,
+        A: DeserializeOwned,
+        C: DeserializeOwned,
+    {
+        serde_json::from_str(json)
+    }
+}
@@ -0,0 +1,126 @@
+use super::{PruneReason, SearchEvent, SearchSink};
+
+/// [`SearchObserver`]
+/// ## Callbacks on the states a search goes through.
+/// A simpler view of the [`SearchEvent`]s of a traced search for visualizations and
+/// debugging tools, every callback does nothing by default. Wrapped in an
+/// [`ObserverSink`], an observer can be given to any `_traced` solver.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::search::*;
+/// #[derive(Default)]
+/// struct Trail(Vec<FixedWorldState<3, 2>>);
+///
+/// impl SearchObserver<FixedWorldState<3, 2>> for Trail {
+///     fn on_expand(&mut self, state: &FixedWorldState<3, 2>) {
+///         self.0.push(*state);
+///     }
+/// }
+///
+/// let mut trail = Trail::default();
+/// let solution = bfs_traced(&FixedCannibalsProblem::<3, 2>, &mut ObserverSink(&mut trail));
+/// assert_eq!(trail.0.first(), solution.as_ref().map(|s| &s.states[0]));
+/// assert_eq!(trail.0.last(), solution.as_ref().map(Solution::goal));
+/// ```
+pub trait SearchObserver<S> {
+    /// `state` was taken out of the frontier, goals included.
+    fn on_expand(&mut self, _state: &S) {}
+
+    /// `state` was added to the frontier.
+    fn on_push(&mut self, _state: &S) {}
+
+    /// A successor was discarded without being added to the frontier.
+    fn on_prune(&mut self, _state: &S, _reason: PruneReason) {}
+
+    /// The search returns a solution ending in `state`.
+    fn on_goal(&mut self, _state: &S) {}
+}
+
+impl<S, O: SearchObserver<S> + ?Sized> SearchObserver<S> for &mut O {
+    fn on_expand(&mut self, state: &S) {
+        (**self).on_expand(state)
+    }
+
+    fn on_push(&mut self, state: &S) {
+        (**self).on_push(state)
+    }
+
+    fn on_prune(&mut self, state: &S, reason: PruneReason) {
+        (**self).on_prune(state, reason)
+    }
+
+    fn on_goal(&mut self, state: &S) {
+        (**self).on_goal(state)
+    }
+}
+
+/// [`ObserverSink`]
+/// The [`SearchSink`] calling the callbacks of an observer.
+#[derive(Debug, Clone, Default)]
+pub struct ObserverSink<O>(pub O);
+
+impl<S, C, O: SearchObserver<S>> SearchSink<S, C> for ObserverSink<O> {
+    fn event(&mut self, event: SearchEvent<'_, S, C>) {
+        match event {
+            SearchEvent::Generate { state, .. } => self.0.on_push(state),
+            SearchEvent::Expand { state, .. } => self.0.on_expand(state),
+            SearchEvent::Prune { state, reason, .. } => self.0.on_prune(state, reason),
+            SearchEvent::Solution { state, .. } => self.0.on_goal(state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::{FixedCannibalsProblem, FixedWorldState};
+    use crate::search::a_star_traced;
+
+    #[derive(Default)]
+    struct Counts {
+        expanded: usize,
+        pushed: usize,
+        dead_ends: usize,
+        goals: Vec<FixedWorldState<3, 2>>,
+    }
+
+    impl SearchObserver<FixedWorldState<3, 2>> for Counts {
+        fn on_expand(&mut self, _: &FixedWorldState<3, 2>) {
+            self.expanded += 1;
+        }
+
+        fn on_push(&mut self, _: &FixedWorldState<3, 2>) {
+            self.pushed += 1;
+        }
+
+        fn on_prune(&mut self, state: &FixedWorldState<3, 2>, reason: PruneReason) {
+            if reason == PruneReason::DeadEnd {
+                assert!(state.is_game_over());
+                self.dead_ends += 1;
+            }
+        }
+
+        fn on_goal(&mut self, state: &FixedWorldState<3, 2>) {
+            self.goals.push(*state);
+        }
+    }
+
+    #[test]
+    fn observer_sees_every_state_of_the_search() {
+        let mut counts = Counts::default();
+
+        let solution = a_star_traced(
+            &FixedCannibalsProblem::<3, 2>,
+            FixedWorldState::heuristic,
+            &mut ObserverSink(&mut counts),
+        )
+        .unwrap();
+
+        // the goal is taken out of the frontier but not expanded.
+        assert_eq!(counts.expanded, solution.stats.expanded + 1);
+        assert_eq!(counts.pushed, solution.stats.generated);
+        assert!(counts.dead_ends > 0);
+        assert_eq!(counts.goals, vec![*solution.goal()]);
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use super::{bfs, SearchProblem, Solution};
+
+/// [`OptimalityReport`]
+/// Path length (number of actions) returned by a solver compared with the optimum found
+/// by [`bfs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptimalityReport {
+    pub instance: String,
+    /// `None` when the instance has no solution.
+    pub optimal_length: Option<usize>,
+    /// `None` when the solver found no solution.
+    pub solver_length: Option<usize>,
+}
+
+impl OptimalityReport {
+    /// Extra actions taken by the solver, `None` unless both found a solution.
+    pub fn gap(&self) -> Option<usize> {
+        match (self.solver_length, self.optimal_length) {
+            (Some(solver), Some(optimal)) => Some(solver.saturating_sub(optimal)),
+            _ => None,
+        }
+    }
+
+    /// Whether the solver found an optimal solution, or correctly found none.
+    pub fn is_optimal(&self) -> bool {
+        self.solver_length == self.optimal_length
+    }
+}
+
+impl Display for OptimalityReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let length = |length: Option<usize>| match length {
+            Some(length) => length.to_string(),
+            None => "-".to_string(),
+        };
+        write!(
+            f,
+            "{}: optimal {}, solver {}",
+            self.instance,
+            length(self.optimal_length),
+            length(self.solver_length)
+        )?;
+        match self.gap() {
+            Some(gap) => write!(f, ", gap {}", gap),
+            None if !self.is_optimal() => write!(f, ", solver disagrees on solvability"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// [`check_optimality`]
+/// ## Compares a solver with breadth-first search.
+/// Runs `solver` and [`bfs`] on `problem` and reports both path lengths. Only meaningful
+/// when every action costs the same, otherwise the fewest actions is not the cheapest
+/// path.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = HanoiProblem::new(3, 3).unwrap();
+/// let report = check_optimality("hanoi 3", &problem, |p| a_star(p, |s| p.heuristic(s)));
+/// assert!(report.is_optimal());
+/// assert_eq!(report.gap(), Some(0));
+/// ```
+pub fn check_optimality<P, F>(instance: &str, problem: &P, solver: F) -> OptimalityReport
+where
+    P: SearchProblem,
+    F: FnOnce(&P) -> Option<Solution<P::State, P::Action, P::Cost>>,
+{
+    OptimalityReport {
+        instance: instance.to_string(),
+        optimal_length: bfs(problem).map(|solution| solution.len()),
+        solver_length: solver(problem).map(|solution| solution.len()),
+    }
+}
+
+/// [`optimality_reports`]
+/// [`check_optimality`] on every named instance.
+pub fn optimality_reports<'a, P, F>(
+    instances: impl IntoIterator<Item = (&'a str, P)>,
+    mut solver: F,
+) -> Vec<OptimalityReport>
+where
+    P: SearchProblem,
+    F: FnMut(&P) -> Option<Solution<P::State, P::Action, P::Cost>>,
+{
+    instances
+        .into_iter()
+        .map(|(instance, problem)| check_optimality(instance, &problem, &mut solver))
+        .collect()
+}
+
+/// [`assert_optimal`]
+/// Panics with the report when `solver` is not optimal on `problem`, meant for tests.
+pub fn assert_optimal<P, F>(problem: &P, solver: F)
+where
+    P: SearchProblem,
+    F: FnOnce(&P) -> Option<Solution<P::State, P::Action, P::Cost>>,
+{
+    let report = check_optimality("instance", problem, solver);
+    assert!(report.is_optimal(), "solver is not optimal: {}", report);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+    use crate::search::{a_star, greedy_best_first};
+
+    fn boards() -> Vec<(&'static str, NPuzzleProblem)> {
+        [
+            "1 2 3 4 5 6 0 7 8",
+            "4 1 3 7 2 6 0 5 8",
+            "8 6 7 2 5 4 3 0 1",
+        ]
+        .into_iter()
+        .map(|tiles| (tiles, NPuzzleProblem::new(Board::try_from(tiles).unwrap())))
+        .collect()
+    }
+
+    #[test]
+    fn optimality_a_star_matches_bfs_and_greedy_has_a_gap() {
+        let a_star_reports =
+            optimality_reports(boards(), |p| a_star(p, |b| b.manhattan_distance() as f64));
+        let greedy_reports = optimality_reports(boards(), |p| {
+            greedy_best_first(p, |b| b.manhattan_distance() as f64)
+        });
+
+        assert!(a_star_reports.iter().all(|report| report.is_optimal()));
+        assert_eq!(a_star_reports[0].optimal_length, Some(2));
+        assert_eq!(a_star_reports[2].optimal_length, Some(31));
+        assert!(greedy_reports.iter().any(|report| report.gap() > Some(0)));
+        assert!(greedy_reports[2].to_string().contains("gap"));
+    }
+
+    #[test]
+    #[should_panic(expected = "solver is not optimal")]
+    fn optimality_assert_optimal_panics_on_missing_solution() {
+        let (_, problem) = boards().remove(0);
+        assert_optimal(&problem, |_| None);
+    }
+}
@@ -0,0 +1,331 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::RandomState;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+use std::sync::Mutex;
+use std::time::Instant;
+
+use super::best_first::{build_solution, HeapEntry, Node};
+use super::{Cost, SearchProblem, SearchStats, Solution};
+
+/// Shards of the visited set of [`parallel_bfs`] per thread, more shards than threads
+/// keep them from waiting on each other's locks.
+const SHARDS_PER_THREAD: usize = 4;
+
+type ProblemNode<P> =
+    Node<<P as SearchProblem>::State, <P as SearchProblem>::Action, <P as SearchProblem>::Cost>;
+
+/// [`parallel_bfs`]
+/// ## Multi-threaded breadth-first search.
+/// Expands the frontier level by level, every level split among `threads` threads (at
+/// least 1) that share a sharded visited set. Returns a solution with the fewest actions
+/// like [`bfs`](super::bfs), but which of the shortest paths it is depends on the
+/// scheduling when several parents reach the same state. No [`SearchEvent`]s are
+/// reported, the sinks are not shared across threads.
+///
+/// [`SearchEvent`]: super::SearchEvent
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = HanoiProblem::new(5, 3).unwrap();
+/// let solution = parallel_bfs(&problem, 4).unwrap();
+/// assert_eq!(solution.len(), bfs(&problem).unwrap().len());
+/// ```
+pub fn parallel_bfs<P>(
+    problem: &P,
+    threads: usize,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem + Sync,
+    P::State: Send + Sync,
+    P::Action: Send + Sync,
+    P::Cost: Send + Sync,
+{
+    profile!("parallel_bfs");
+    let start = Instant::now();
+    let threads = threads.max(1);
+    let discovered = ShardedSet::new(threads * SHARDS_PER_THREAD);
+    let initial_state = problem.initial_state();
+    discovered.insert(problem.canonicalize(&initial_state).into_owned());
+    let mut nodes = vec![Node {
+        state: initial_state,
+        parent: None,
+        action: None,
+        path_cost: P::Cost::zero(),
+        depth: 0,
+    }];
+    let mut level = vec![0];
+    let mut stats = SearchStats::started();
+
+    while !level.is_empty() {
+        if let Some(goal) = level
+            .iter()
+            .find(|node| problem.is_goal(&nodes[**node].state))
+        {
+            stats.elapsed = start.elapsed();
+            return Some(build_solution(&nodes, *goal, stats));
+        }
+        stats.expanded += level.len();
+        let chunk_size = level.len().div_ceil(threads);
+        let children = std::thread::scope(|scope| {
+            let workers = level
+                .chunks(chunk_size)
+                .map(|parents| {
+                    let (nodes, discovered) = (&nodes, &discovered);
+                    scope.spawn(move || expand_level(problem, nodes, parents, discovered))
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("expanding threads don't panic"))
+                .collect::<Vec<_>>()
+        });
+        let mut next_level = vec![];
+        for (level_children, duplicates) in children {
+            stats.duplicates += duplicates;
+            for child in level_children {
+                stats.record_generated(child.depth);
+                next_level.push(nodes.len());
+                nodes.push(child);
+            }
+        }
+        level = next_level;
+        stats.record_frontier(level.len());
+    }
+
+    None
+}
+
+/// The children of `parents` that no thread discovered before, and how many children
+/// were already discovered.
+fn expand_level<P: SearchProblem>(
+    problem: &P,
+    nodes: &[ProblemNode<P>],
+    parents: &[usize],
+    discovered: &ShardedSet<P::State>,
+) -> (Vec<ProblemNode<P>>, usize) {
+    let (mut children, mut duplicates) = (vec![], 0);
+    for parent in parents {
+        let node = &nodes[*parent];
+        for successor in problem.successors(&node.state) {
+            if problem.is_dead_end(&successor.state) {
+                continue;
+            }
+            if !discovered.insert(problem.canonicalize(&successor.state).into_owned()) {
+                duplicates += 1;
+                continue;
+            }
+            children.push(Node {
+                path_cost: node.path_cost.add(successor.cost),
+                state: successor.state,
+                parent: Some(*parent),
+                action: Some(successor.action),
+                depth: node.depth + 1,
+            });
+        }
+    }
+    (children, duplicates)
+}
+
+/// [`parallel_a_star`]
+/// ## Multi-threaded A* search.
+/// [`a_star`](super::a_star) popping up to `threads` nodes (at least 1) at a time and
+/// generating their successors and heuristics in parallel, the frontier being updated
+/// by one thread. A goal is only returned once it has the lowest `f` of the frontier,
+/// so the solution is optimal for an admissible heuristic. Pays off when successors or
+/// heuristics are expensive, a few extra nodes may be expanded. No
+/// [`SearchEvent`](super::SearchEvent)s are reported.
+/// # Example
+/// ```
+/// # use algoritmos_rust::n_puzzle::{Board, NPuzzleProblem};
+/// # use algoritmos_rust::search::*;
+/// let problem = NPuzzleProblem::new(Board::try_from("8 6 7 2 5 4 3 0 1").unwrap());
+/// let solution = parallel_a_star(&problem, |board| board.manhattan_distance() as f64, 4);
+/// assert_eq!(solution.unwrap().cost, 31);
+/// ```
+pub fn parallel_a_star<P, H>(
+    problem: &P,
+    heuristic: H,
+    threads: usize,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem + Sync,
+    P::State: Send + Sync,
+    P::Action: Send + Sync,
+    P::Cost: Send + Sync,
+    H: Fn(&P::State) -> f64 + Sync,
+{
+    profile!("parallel_a_star");
+    let start = Instant::now();
+    let threads = threads.max(1);
+    let mut nodes: Vec<ProblemNode<P>> = vec![];
+    let mut best_path_cost: HashMap<P::State, P::Cost> = HashMap::new();
+    let mut frontier: BinaryHeap<Reverse<HeapEntry>> = BinaryHeap::new();
+    let mut stats = SearchStats::started();
+
+    let initial_state = problem.initial_state();
+    best_path_cost.insert(
+        problem.canonicalize(&initial_state).into_owned(),
+        P::Cost::zero(),
+    );
+    frontier.push(Reverse(HeapEntry {
+        f: heuristic(&initial_state),
+        node: 0,
+    }));
+    nodes.push(Node {
+        state: initial_state,
+        parent: None,
+        action: None,
+        path_cost: P::Cost::zero(),
+        depth: 0,
+    });
+
+    loop {
+        let mut batch = vec![];
+        while batch.len() < threads {
+            let Some(Reverse(entry)) = frontier.pop() else {
+                break;
+            };
+            let node = &nodes[entry.node];
+            // A cheaper path to this state was found after this entry was pushed.
+            if best_path_cost
+                .get(problem.canonicalize(&node.state).as_ref())
+                .is_some_and(|best| *best < node.path_cost)
+            {
+                continue;
+            }
+            if problem.is_goal(&node.state) {
+                if batch.is_empty() {
+                    stats.elapsed = start.elapsed();
+                    return Some(build_solution(&nodes, entry.node, stats));
+                }
+                // the nodes of the batch may still lead to a cheaper goal.
+                frontier.push(Reverse(entry));
+                break;
+            }
+            batch.push(entry.node);
+        }
+        if batch.is_empty() {
+            return None;
+        }
+
+        stats.expanded += batch.len();
+        let children = std::thread::scope(|scope| {
+            let workers = batch
+                .iter()
+                .map(|parent| {
+                    let (nodes, heuristic) = (&nodes, &heuristic);
+                    scope.spawn(move || expand_node(problem, nodes, *parent, heuristic))
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("expanding threads don't panic"))
+                .collect::<Vec<_>>()
+        });
+        for (child, f) in children {
+            let key = problem.canonicalize(&child.state).into_owned();
+            if best_path_cost
+                .get(&key)
+                .is_some_and(|best| *best <= child.path_cost)
+            {
+                stats.duplicates += 1;
+                continue;
+            }
+            best_path_cost.insert(key, child.path_cost);
+            stats.record_generated(child.depth);
+            frontier.push(Reverse(HeapEntry {
+                f,
+                node: nodes.len(),
+            }));
+            nodes.push(child);
+        }
+        stats.record_frontier(frontier.len());
+    }
+}
+
+/// The children of `parent` that are not dead ends, with their `f`.
+fn expand_node<P, H>(
+    problem: &P,
+    nodes: &[ProblemNode<P>],
+    parent: usize,
+    heuristic: &H,
+) -> Vec<(ProblemNode<P>, f64)>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    let node = &nodes[parent];
+    problem
+        .successors(&node.state)
+        .filter(|successor| !problem.is_dead_end(&successor.state))
+        .map(|successor| {
+            let path_cost = node.path_cost.add(successor.cost);
+            let f = path_cost.to_f64() + heuristic(&successor.state);
+            let child = Node {
+                state: successor.state,
+                parent: Some(parent),
+                action: Some(successor.action),
+                path_cost,
+                depth: node.depth + 1,
+            };
+            (child, f)
+        })
+        .collect()
+}
+
+/// Visited set split in shards picked by hash, every one behind its own lock.
+struct ShardedSet<S> {
+    shards: Vec<Mutex<HashSet<S>>>,
+    hasher: RandomState,
+}
+
+impl<S: Hash + Eq> ShardedSet<S> {
+    fn new(shards: usize) -> Self {
+        Self {
+            shards: (0..shards.max(1)).map(|_| Mutex::default()).collect(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// Whether `state` was not in the set yet.
+    fn insert(&self, state: S) -> bool {
+        let shard = self.hasher.hash_one(&state) as usize % self.shards.len();
+        self.shards[shard]
+            .lock()
+            .expect("no thread panics holding a shard")
+            .insert(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::FixedCannibalsProblem;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::{a_star, bfs};
+
+    #[test]
+    fn parallel_searches_match_their_sequential_versions() {
+        let hanoi = HanoiProblem::new(5, 3).unwrap();
+        let heuristic = |state: &_| hanoi.heuristic(state);
+
+        for threads in [0, 1, 3, 8] {
+            let breadth_first = parallel_bfs(&hanoi, threads).unwrap();
+            let best_first = parallel_a_star(&hanoi, heuristic, threads).unwrap();
+
+            assert_eq!(breadth_first.len(), bfs(&hanoi).unwrap().len());
+            assert_eq!(best_first.cost, a_star(&hanoi, heuristic).unwrap().cost);
+            assert!(hanoi.is_goal(best_first.goal()));
+        }
+        assert_eq!(
+            parallel_bfs(&FixedCannibalsProblem::<3, 2>, 4)
+                .unwrap()
+                .len(),
+            11
+        );
+        assert!(parallel_bfs(&FixedCannibalsProblem::<4, 2>, 4).is_none());
+        assert!(parallel_a_star(&FixedCannibalsProblem::<4, 2>, |_| 0.0, 4).is_none());
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::mpsc;
+
+use super::{Cancellable, Cancellation, SearchProblem, Solution};
+
+type PortfolioSolver<P> = Box<
+    dyn Fn(
+            &Cancellable<'_, P>,
+        ) -> Option<
+            Solution<
+                <P as SearchProblem>::State,
+                <P as SearchProblem>::Action,
+                <P as SearchProblem>::Cost,
+            >,
+        > + Send
+        + Sync,
+>;
+
+/// [`PortfolioWinner`]
+/// The solution of the first solver of a [`Portfolio`] to find one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortfolioWinner<S, A, C = u32> {
+    pub solver: String,
+    pub solution: Solution<S, A, C>,
+}
+
+/// [`Portfolio`]
+/// ## Races several solvers on the same instance.
+/// Every solver runs on its own thread and receives the problem wrapped in a
+/// [`Cancellable`]. The first one to return a solution wins and the others are
+/// cancelled, so they run out of frontier at their next expansion. Solvers that give
+/// up without a solution don't stop the race. The whole race can be stopped from
+/// another thread with [`cancellation`](Self::cancellation).
+/// # Example
+/// ```
+/// # use algoritmos_rust::n_puzzle::{Board, NPuzzleProblem};
+/// # use algoritmos_rust::search::*;
+/// let problem = NPuzzleProblem::new(Board::try_from("8 6 7 2 5 4 3 0 1").unwrap());
+/// let winner = Portfolio::<NPuzzleProblem>::new()
+///     .solver("bfs", |problem| bfs(problem))
+///     .solver("a_star", |problem| {
+///         a_star(problem, |board| board.manhattan_distance() as f64)
+///     })
+///     .solve(&problem)
+///     .unwrap();
+/// assert_eq!(winner.solution.cost, 31);
+/// ```
+pub struct Portfolio<P: SearchProblem> {
+    solvers: Vec<(String, PortfolioSolver<P>)>,
+    cancellation: Cancellation,
+}
+
+impl<P: SearchProblem> Portfolio<P> {
+    pub fn new() -> Self {
+        Self {
+            solvers: vec![],
+            cancellation: Cancellation::default(),
+        }
+    }
+
+    /// Adds `solver` to the race under `name`.
+    pub fn solver<F>(mut self, name: &str, solver: F) -> Self
+    where
+        F: Fn(&Cancellable<'_, P>) -> Option<Solution<P::State, P::Action, P::Cost>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.solvers.push((name.to_string(), Box::new(solver)));
+        self
+    }
+
+    /// Handle that stops every solver, the race then has no winner.
+    pub fn cancellation(&self) -> Cancellation {
+        self.cancellation.clone()
+    }
+
+    /// [`solve`]
+    /// Returns once a solver finds a solution or every solver gave up, `None` when no
+    /// solver finds one.
+    pub fn solve(self, problem: &P) -> Option<PortfolioWinner<P::State, P::Action, P::Cost>>
+    where
+        P: Sync,
+        P::State: Send,
+        P::Action: Send,
+        P::Cost: Send,
+    {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::scope(|scope| {
+            for (index, (_, solver)) in self.solvers.iter().enumerate() {
+                let sender = sender.clone();
+                let problem = Cancellable::new(problem, self.cancellation.clone());
+                scope.spawn(move || {
+                    // the race may be over already.
+                    let _ = sender.send((index, solver(&problem)));
+                });
+            }
+            drop(sender);
+            let winner = receiver
+                .iter()
+                .find_map(|(index, solution)| match solution {
+                    Some(solution) if !self.cancellation.is_cancelled() => Some(PortfolioWinner {
+                        solver: self.solvers[index].0.clone(),
+                        solution,
+                    }),
+                    _ => None,
+                });
+            self.cancellation.cancel();
+            winner
+        })
+    }
+}
+
+impl<P: SearchProblem> Default for Portfolio<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::{a_star, bfs};
+
+    #[test]
+    fn portfolio_returns_the_first_solution_and_cancels_the_rest() {
+        let problem = HanoiProblem::new(4, 3).unwrap();
+
+        let winner = Portfolio::<HanoiProblem>::new()
+            .solver("stubborn", |problem| {
+                while !problem.is_cancelled() {
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+                None
+            })
+            .solver("quitter", |_| None)
+            .solver("a_star", |problem| {
+                a_star(problem, |state| problem.inner().heuristic(state))
+            })
+            .solve(&problem)
+            .unwrap();
+
+        assert_eq!(winner.solver, "a_star");
+        assert_eq!(winner.solution.len(), 15);
+    }
+
+    #[test]
+    fn portfolio_has_no_winner_when_cancelled_or_unsolved() {
+        let problem = HanoiProblem::new(4, 3).unwrap();
+        let cancelled = Portfolio::<HanoiProblem>::new().solver("bfs", |problem| bfs(problem));
+        cancelled.cancellation().cancel();
+
+        assert_eq!(cancelled.solve(&problem), None);
+        assert_eq!(
+            Portfolio::<HanoiProblem>::new()
+                .solver("quitter", |_| None)
+                .solve(&problem),
+            None
+        );
+        assert_eq!(Portfolio::new().solve(&problem), None);
+    }
+}
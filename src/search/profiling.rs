@@ -0,0 +1,151 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// [`Profile`]
+/// ## Time spent in every phase of the searches run on a thread.
+/// Keys are stacks of span names separated by `;` (e.g. `a_star;expand;heuristic`),
+/// values the time spent in the innermost span, not counting the spans nested in it.
+/// [`Display`] renders the folded stacks format read by `inferno-flamegraph` and
+/// `flamegraph.pl`, one `stack nanoseconds` line per stack.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Profile {
+    pub stacks: BTreeMap<String, Duration>,
+}
+
+impl Profile {
+    /// Time spent in spans named `name` anywhere in the stacks, excluding nested spans.
+    pub fn time_in(&self, name: &str) -> Duration {
+        self.stacks
+            .iter()
+            .filter(|(stack, _)| stack.rsplit(';').next() == Some(name))
+            .map(|(_, time)| *time)
+            .sum()
+    }
+
+    pub fn total(&self) -> Duration {
+        self.stacks.values().sum()
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (stack, time) in &self.stacks {
+            writeln!(f, "{} {}", stack, time.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    /// Open spans, with when they started and the time spent in their children.
+    open: Vec<(&'static str, Instant, Duration)>,
+    profile: Profile,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Recorder> = RefCell::new(Recorder::default());
+}
+
+/// [`Span`]
+/// Measures the time until dropped, see [`span`].
+pub struct Span(());
+
+/// [`span`]
+/// Opens a span named `name` nested in the spans open on this thread.
+pub fn span(name: &'static str) -> Span {
+    RECORDER.with(|recorder| {
+        recorder
+            .borrow_mut()
+            .open
+            .push((name, Instant::now(), Duration::ZERO))
+    });
+    Span(())
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        RECORDER.with(|recorder| {
+            let recorder = &mut *recorder.borrow_mut();
+            let Some((name, start, children)) = recorder.open.pop() else {
+                return;
+            };
+            let elapsed = start.elapsed();
+            let mut stack = recorder
+                .open
+                .iter()
+                .map(|(name, _, _)| *name)
+                .collect::<Vec<&str>>();
+            stack.push(name);
+            *recorder.profile.stacks.entry(stack.join(";")).or_default() +=
+                elapsed.saturating_sub(children);
+            if let Some(parent) = recorder.open.last_mut() {
+                parent.2 += elapsed;
+            }
+        })
+    }
+}
+
+/// [`take_profile`]
+/// The profile of the spans closed on this thread since the last call.
+pub fn take_profile() -> Profile {
+    RECORDER.with(|recorder| std::mem::take(&mut recorder.borrow_mut().profile))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+    use crate::search::{a_star, bfs};
+
+    #[test]
+    fn profiling_folds_nested_spans() {
+        take_profile();
+        {
+            let _search = span("search");
+            for _ in 0..3 {
+                let _expand = span("expand");
+                let _heuristic = span("heuristic");
+            }
+        }
+        let profile = take_profile();
+
+        assert_eq!(
+            profile.stacks.keys().collect::<Vec<_>>(),
+            ["search", "search;expand", "search;expand;heuristic"]
+        );
+        assert_eq!(
+            profile.total(),
+            profile.time_in("search") + profile.time_in("expand") + profile.time_in("heuristic")
+        );
+        assert!(profile.to_string().lines().all(|line| line
+            .rsplit_once(' ')
+            .unwrap()
+            .1
+            .parse::<u128>()
+            .is_ok()));
+        assert_eq!(take_profile(), Profile::default());
+    }
+
+    #[test]
+    fn profiling_reports_the_phases_of_searches() {
+        let problem = NPuzzleProblem::new(Board::try_from("4 1 3 7 2 6 0 5 8").unwrap());
+        take_profile();
+
+        a_star(&problem, |board| board.manhattan_distance() as f64);
+        bfs(&problem);
+        let profile = take_profile();
+
+        for stack in [
+            "a_star;expand",
+            "a_star;expand;heuristic",
+            "a_star;expand;dedup",
+            "a_star;heap",
+            "bfs;expand;dedup",
+        ] {
+            assert!(profile.stacks.contains_key(stack), "{} is missing", stack);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+use std::time::Instant;
+
+use super::best_first::{build_solution, Node};
+use super::{Cost, SearchProblem, SearchStats, Solution};
+
+/// [`UnsolvabilityProof`]
+/// Statistics of a breadth-first search that closed the whole reachable space without
+/// meeting a goal state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsolvabilityProof {
+    /// Every reachable state, all of them expanded.
+    pub states_explored: usize,
+    pub transitions: usize,
+    /// Successors already discovered through another path.
+    pub duplicates: usize,
+    pub max_frontier: usize,
+    /// Actions needed to reach the farthest state.
+    pub depth: usize,
+}
+
+impl Display for UnsolvabilityProof {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "explored {} states ({} transitions, {} duplicates, frontier peak {}, depth {}), no goal reachable",
+            self.states_explored, self.transitions, self.duplicates, self.max_frontier, self.depth
+        )
+    }
+}
+
+/// [`Verdict`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict<S, A, C = u32> {
+    /// A solution with the fewest actions.
+    Solved(Solution<S, A, C>),
+    Unsolvable(UnsolvabilityProof),
+    /// More than the state limit were discovered before deciding.
+    Undecided {
+        states_explored: usize,
+    },
+}
+
+/// [`solve_or_prove`]
+/// ## Breadth-first search that proves unsolvability.
+/// Either finds a solution or closes every reachable state, returning how the frontier
+/// got exhausted. Gives up after discovering `state_limit` states.
+/// # Example
+/// ```
+/// # use algoritmos_rust::crossing::*;
+/// # use algoritmos_rust::search::*;
+/// let problem = CrossingProblem::new(JealousHusbands::new(4, 2).unwrap()).unwrap();
+/// let Verdict::Unsolvable(proof) = solve_or_prove(&problem, 10_000) else { panic!() };
+/// assert!(proof.to_string().ends_with("no goal reachable"));
+/// ```
+pub fn solve_or_prove<P: SearchProblem>(
+    problem: &P,
+    state_limit: usize,
+) -> Verdict<P::State, P::Action, P::Cost> {
+    let start = Instant::now();
+    let initial_state = problem.initial_state();
+    let mut discovered = HashSet::from([problem.canonicalize(&initial_state).into_owned()]);
+    let mut nodes = vec![Node {
+        state: initial_state,
+        parent: None,
+        action: None,
+        path_cost: P::Cost::zero(),
+        depth: 0,
+    }];
+    let mut frontier = VecDeque::from([0]);
+    let mut proof = UnsolvabilityProof {
+        states_explored: 0,
+        transitions: 0,
+        duplicates: 0,
+        max_frontier: 1,
+        depth: 0,
+    };
+
+    while let Some(current) = frontier.pop_front() {
+        if problem.is_goal(&nodes[current].state) {
+            let stats = SearchStats {
+                generated: nodes.len(),
+                expanded: proof.states_explored,
+                duplicates: proof.duplicates,
+                frontier_peak: proof.max_frontier,
+                // breadth-first, the last node is the deepest.
+                max_depth: nodes.last().map_or(0, |node| node.depth),
+                elapsed: start.elapsed(),
+            };
+            return Verdict::Solved(build_solution(&nodes, current, stats));
+        }
+        proof.states_explored += 1;
+        proof.depth = proof.depth.max(nodes[current].depth);
+        let state = nodes[current].state.clone();
+        for successor in problem.successors(&state) {
+            if problem.is_dead_end(&successor.state) {
+                continue;
+            }
+            proof.transitions += 1;
+            if !discovered.insert(problem.canonicalize(&successor.state).into_owned()) {
+                proof.duplicates += 1;
+                continue;
+            }
+            if discovered.len() > state_limit {
+                return Verdict::Undecided {
+                    states_explored: proof.states_explored,
+                };
+            }
+            frontier.push_back(nodes.len());
+            let path_cost = nodes[current].path_cost.add(successor.cost);
+            nodes.push(Node {
+                state: successor.state,
+                parent: Some(current),
+                action: Some(successor.action),
+                path_cost,
+                depth: nodes[current].depth + 1,
+            });
+        }
+        proof.max_frontier = proof.max_frontier.max(frontier.len());
+    }
+
+    Verdict::Unsolvable(proof)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+
+    #[test]
+    fn proof_closes_unsolvable_n_puzzle() {
+        // swapping two tiles makes the 2x2 puzzle unsolvable, half of the 24 boards.
+        let problem = NPuzzleProblem::new(Board::try_from("2 1 3 0").unwrap());
+
+        let verdict = solve_or_prove(&problem, 100);
+
+        let Verdict::Unsolvable(proof) = verdict else {
+            panic!("expected a proof, got {:?}", verdict)
+        };
+        assert_eq!(proof.states_explored, 12);
+        assert_eq!(proof.transitions, 24);
+        assert_eq!(proof.depth, 6);
+    }
+
+    #[test]
+    fn proof_solves_or_gives_up() {
+        let problem = NPuzzleProblem::new(Board::try_from("1 2 0 3").unwrap());
+
+        assert!(matches!(solve_or_prove(&problem, 100), Verdict::Solved(s) if s.len() == 1));
+        let unsolvable = NPuzzleProblem::new(Board::try_from("2 1 3 0").unwrap());
+        assert!(matches!(
+            solve_or_prove(&unsolvable, 5),
+            Verdict::Undecided { .. }
+        ));
+    }
+}
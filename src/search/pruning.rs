@@ -0,0 +1,170 @@
+use std::fmt::Display;
+
+use super::{PruneReason, SearchEvent, SearchSink};
+
+/// [`DepthStats`]
+/// What happened to the nodes of one depth (number of actions from the initial state).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DepthStats {
+    pub generated: usize,
+    pub expanded: usize,
+    /// Successors already reached at least as cheaply.
+    pub duplicates: usize,
+    /// Successors that were dead ends (e.g. a game over).
+    pub dead_ends: usize,
+    /// Successors beyond the bound of the search.
+    pub bounded: usize,
+}
+
+impl DepthStats {
+    fn add(&mut self, other: &DepthStats) {
+        self.generated += other.generated;
+        self.expanded += other.expanded;
+        self.duplicates += other.duplicates;
+        self.dead_ends += other.dead_ends;
+        self.bounded += other.bounded;
+    }
+}
+
+/// [`PruningStats`]
+/// ## A [`SearchSink`] counting generated, expanded and pruned nodes per depth.
+/// Shows where an algorithm spends its effort, e.g. how deep duplicates start to
+/// dominate. `depths[d]` holds the nodes `d` actions away from the initial state.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = HanoiProblem::new(3, 3).unwrap();
+/// let mut stats = PruningStats::new();
+/// a_star_traced(&problem, |state| problem.heuristic(state), &mut stats);
+/// assert_eq!(stats.depths[0].generated, 1);
+/// assert!(stats.total().duplicates > 0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PruningStats {
+    pub depths: Vec<DepthStats>,
+    /// Depth of every generated node, by node number.
+    node_depths: Vec<usize>,
+}
+
+impl PruningStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sum over every depth.
+    pub fn total(&self) -> DepthStats {
+        let mut total = DepthStats::default();
+        for depth in &self.depths {
+            total.add(depth);
+        }
+        total
+    }
+
+    fn at(&mut self, depth: usize) -> &mut DepthStats {
+        if self.depths.len() <= depth {
+            self.depths.resize(depth + 1, DepthStats::default());
+        }
+        &mut self.depths[depth]
+    }
+}
+
+impl<S, C> SearchSink<S, C> for PruningStats {
+    fn event(&mut self, event: SearchEvent<'_, S, C>) {
+        match event {
+            SearchEvent::Generate { node, parent, .. } => {
+                let depth = parent.map_or(0, |parent| self.node_depths[parent] + 1);
+                if self.node_depths.len() <= node {
+                    self.node_depths.resize(node + 1, 0);
+                }
+                self.node_depths[node] = depth;
+                self.at(depth).generated += 1;
+            }
+            SearchEvent::Expand { node, .. } => {
+                let depth = self.node_depths[node];
+                self.at(depth).expanded += 1;
+            }
+            SearchEvent::Prune { parent, reason, .. } => {
+                let depth = self.node_depths[parent] + 1;
+                let stats = self.at(depth);
+                match reason {
+                    PruneReason::Duplicate => stats.duplicates += 1,
+                    PruneReason::DeadEnd => stats.dead_ends += 1,
+                    PruneReason::Bound => stats.bounded += 1,
+                }
+            }
+            SearchEvent::Solution { .. } => {}
+        }
+    }
+}
+
+/// One row per depth and a total row.
+impl Display for PruningStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>6} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "depth", "generated", "expanded", "duplicate", "dead end", "bound"
+        )?;
+        let total = self.total();
+        let rows = self
+            .depths
+            .iter()
+            .enumerate()
+            .map(|(depth, stats)| (depth.to_string(), stats));
+        for (depth, stats) in rows.chain(std::iter::once(("total".to_string(), &total))) {
+            writeln!(
+                f,
+                "{:>6} {:>10} {:>10} {:>10} {:>10} {:>10}",
+                depth,
+                stats.generated,
+                stats.expanded,
+                stats.duplicates,
+                stats.dead_ends,
+                stats.bounded
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::FixedCannibalsProblem;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::{a_star_traced, solve_within_cost_traced};
+
+    #[test]
+    fn pruning_stats_count_game_overs_and_duplicates_per_depth() {
+        let mut stats = PruningStats::new();
+
+        let solution = a_star_traced(&FixedCannibalsProblem::<3, 2>, |_| 0.0, &mut stats).unwrap();
+
+        assert_eq!(solution.len(), 11);
+        // two of the five first crossings leave missionaries outnumbered.
+        assert_eq!(stats.depths[1].dead_ends, 2);
+        assert_eq!(stats.depths[1].generated, 3);
+        let total = stats.total();
+        assert!(total.duplicates > 0);
+        assert_eq!(total.bounded, 0);
+        assert!(stats
+            .to_string()
+            .lines()
+            .last()
+            .unwrap()
+            .starts_with(" total"));
+    }
+
+    #[test]
+    fn pruning_stats_count_bound_prunes() {
+        let problem = HanoiProblem::new(3, 3).unwrap();
+        let mut stats = PruningStats::new();
+
+        solve_within_cost_traced(&problem, 6, |_| 0.0, &mut stats);
+
+        // every successor of the 6th action is beyond the bound.
+        assert!(stats.depths[7].bounded > 0);
+        assert_eq!(stats.depths[7].generated, 0);
+    }
+}
@@ -0,0 +1,70 @@
+use std::time::Instant;
+
+use super::{Cost, SearchProblem, SearchStats, Solution};
+use crate::rng::Rng;
+
+/// [`random_walk`]
+/// ## Random walk baseline.
+/// Starting from the initial state, repeatedly applies a successor chosen uniformly at
+/// random until a goal is reached or `step_limit` actions were taken. The returned path
+/// may revisit states, it is only meant as a baseline for the informed algorithms.
+/// Returns `None` when the limit is reached or the walk gets stuck in a state without
+/// successors.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::rng::Rng;
+/// # use algoritmos_rust::search::random_walk;
+/// let solution = random_walk(&HanoiProblem::new(2, 3).unwrap(), 10_000, &mut Rng::new(1)).unwrap();
+/// assert!(solution.len() >= 3);
+/// ```
+pub fn random_walk<P: SearchProblem>(
+    problem: &P,
+    step_limit: usize,
+    rng: &mut Rng,
+) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    let start = Instant::now();
+    let mut solution = Solution {
+        states: vec![problem.initial_state()],
+        actions: vec![],
+        cost: P::Cost::zero(),
+        stats: SearchStats::started(),
+    };
+    while !problem.is_goal(solution.goal()) {
+        if solution.len() == step_limit {
+            return None;
+        }
+        solution.stats.expanded += 1;
+        let successors = problem
+            .successors(solution.goal())
+            .filter(|successor| !problem.is_dead_end(&successor.state))
+            .collect::<Vec<_>>();
+        let successor = rng.choose(&successors)?.clone();
+        solution.states.push(successor.state);
+        solution.actions.push(successor.action);
+        solution.cost = solution.cost.add(successor.cost);
+        solution.stats.record_generated(solution.len());
+    }
+    solution.stats.elapsed = start.elapsed();
+    Some(solution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hanoi::HanoiProblem;
+
+    #[test]
+    fn random_walk_is_reproducible_and_respects_limit() {
+        let problem = HanoiProblem::new(3, 3).unwrap();
+
+        let a = random_walk(&problem, 100_000, &mut Rng::new(3)).unwrap();
+        let b = random_walk(&problem, 100_000, &mut Rng::new(3)).unwrap();
+
+        assert_eq!(a, b);
+        assert!(problem.is_goal(a.goal()));
+        assert_eq!(a.states.len(), a.len() + 1);
+        assert!(a.cost >= 7);
+        assert_eq!(random_walk(&problem, 6, &mut Rng::new(3)), None);
+    }
+}
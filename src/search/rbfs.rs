@@ -0,0 +1,229 @@
+use std::collections::HashSet;
+use std::time::Instant;
+
+use super::{
+    Cost, PruneReason, SearchEvent, SearchProblem, SearchSink, SearchStats, Solution, Successor,
+};
+
+/// [`rbfs`]
+/// ## Recursive best-first search.
+/// Follows the child with the lowest `f = g + heuristic` as long as it stays within the
+/// `f` of the best alternative left higher up, then backtracks, replacing the `f` of
+/// the abandoned child with the lowest `f` found below it so that it is only expanded
+/// again once it looks best. Only the current path and the children of its states are
+/// kept in memory, states are not repeated along the path. Like
+/// [`a_star`](super::a_star) the solution is optimal for an admissible `heuristic`,
+/// at the price of regenerating the abandoned subtrees.
+/// The solution `stats` count every expansion, the frontier being the current path.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = HanoiProblem::new(3, 3).unwrap();
+/// let solution = rbfs(&problem, |state| problem.heuristic(state)).unwrap();
+/// assert_eq!(solution.cost, 7);
+/// ```
+pub fn rbfs<P, H>(problem: &P, heuristic: H) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    rbfs_traced(problem, heuristic, &mut ())
+}
+
+/// [`rbfs_traced`]
+/// [`rbfs`] reporting every [`SearchEvent`] to `sink`, `f` being the backed-up value
+/// when a node is expanded again. Node numbers keep growing when abandoned subtrees are
+/// regenerated.
+pub fn rbfs_traced<P, H>(
+    problem: &P,
+    heuristic: H,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    profile!("rbfs");
+    let start = Instant::now();
+    let initial_state = problem.initial_state();
+    let initial_f = heuristic(&initial_state);
+    sink.event(SearchEvent::Generate {
+        state: &initial_state,
+        node: 0,
+        parent: None,
+        g: P::Cost::zero(),
+        f: initial_f,
+    });
+    let mut search = Rbfs {
+        problem,
+        heuristic,
+        sink,
+        stats: SearchStats::started(),
+        nodes: 1,
+        on_path: HashSet::from([problem.canonicalize(&initial_state).into_owned()]),
+        states: vec![initial_state],
+        actions: vec![],
+    };
+    let cost = search
+        .expand(0, P::Cost::zero(), initial_f, f64::INFINITY)
+        .ok()?;
+    search.stats.elapsed = start.elapsed();
+    let solution = Solution {
+        states: search.states,
+        actions: search.actions,
+        cost,
+        stats: search.stats,
+    };
+    search.sink.event(SearchEvent::Solution {
+        state: solution.goal(),
+        cost: solution.cost,
+        length: solution.len(),
+    });
+    Some(solution)
+}
+
+/// A child of a state of the path, `f` is backed up when its subtree is abandoned.
+struct Child<S, A, C> {
+    successor: Successor<S, A, C>,
+    g: C,
+    f: f64,
+    node: usize,
+}
+
+struct Rbfs<'p, P: SearchProblem, H> {
+    problem: &'p P,
+    heuristic: H,
+    sink: &'p mut dyn SearchSink<P::State, P::Cost>,
+    stats: SearchStats,
+    /// Number of the next generated node.
+    nodes: usize,
+    on_path: HashSet<P::State>,
+    /// Current path, from the initial state to the state being expanded.
+    states: Vec<P::State>,
+    actions: Vec<P::Action>,
+}
+
+impl<P, H> Rbfs<'_, P, H>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    /// Searches below the last state of the path, numbered `node`, without going beyond
+    /// `f_limit`. Returns the cost of the solution, which is then the path, or the
+    /// backed-up `f` of the state.
+    fn expand(&mut self, node: usize, g: P::Cost, f: f64, f_limit: f64) -> Result<P::Cost, f64> {
+        let problem = self.problem;
+        let state = self.states.last().expect("the path is never empty");
+        self.sink.event(SearchEvent::Expand {
+            state,
+            node,
+            g,
+            f,
+            frontier: self.states.len() - 1,
+        });
+        if problem.is_goal(state) {
+            return Ok(g);
+        }
+        self.stats.expanded += 1;
+        self.stats.record_frontier(self.states.len());
+
+        let mut children = vec![];
+        for successor in problem.successors(state) {
+            let successor_g = g.add(successor.cost);
+            let reason = if problem.is_dead_end(&successor.state) {
+                Some(PruneReason::DeadEnd)
+            } else if self
+                .on_path
+                .contains(problem.canonicalize(&successor.state).as_ref())
+            {
+                Some(PruneReason::Duplicate)
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                if reason == PruneReason::Duplicate {
+                    self.stats.duplicates += 1;
+                }
+                self.sink.event(SearchEvent::Prune {
+                    state: &successor.state,
+                    parent: node,
+                    g: successor_g,
+                    reason,
+                });
+                continue;
+            }
+            // a child can't look better than its parent already proved to be.
+            let successor_f = (successor_g.to_f64() + (self.heuristic)(&successor.state)).max(f);
+            self.sink.event(SearchEvent::Generate {
+                state: &successor.state,
+                node: self.nodes,
+                parent: Some(node),
+                g: successor_g,
+                f: successor_f,
+            });
+            self.stats.record_generated(self.states.len());
+            children.push(Child {
+                successor,
+                g: successor_g,
+                f: successor_f,
+                node: self.nodes,
+            });
+            self.nodes += 1;
+        }
+
+        loop {
+            let Some((best, _)) = children
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.f.total_cmp(&b.f))
+            else {
+                return Err(f64::INFINITY);
+            };
+            let child = &children[best];
+            if child.f > f_limit || child.f == f64::INFINITY {
+                return Err(child.f);
+            }
+            let alternative = children
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != best)
+                .map(|(_, other)| other.f)
+                .fold(f64::INFINITY, f64::min);
+
+            self.on_path
+                .insert(problem.canonicalize(&child.successor.state).into_owned());
+            self.states.push(child.successor.state.clone());
+            self.actions.push(child.successor.action.clone());
+            match self.expand(child.node, child.g, child.f, f_limit.min(alternative)) {
+                Ok(cost) => return Ok(cost),
+                Err(backed_up) => children[best].f = backed_up,
+            }
+            let state = self.states.pop().expect("the child was pushed");
+            self.on_path.remove(problem.canonicalize(&state).as_ref());
+            self.actions.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+    use crate::search::{a_star, ida_star};
+
+    #[test]
+    fn rbfs_is_optimal_and_expands_less_than_ida_star() {
+        let problem = NPuzzleProblem::new(Board::try_from("8 6 7 2 5 4 3 0 1").unwrap());
+        let heuristic = |board: &Board| board.manhattan_distance() as f64;
+
+        let solution = rbfs(&problem, heuristic).unwrap();
+
+        assert_eq!(solution.cost, a_star(&problem, heuristic).unwrap().cost);
+        assert!(solution.stats.frontier_peak <= solution.len() + 1);
+        let ida = ida_star(&problem, heuristic).solution.unwrap();
+        assert!(solution.stats.expanded < ida.stats.expanded);
+        let unsolvable = NPuzzleProblem::new(Board::try_from("2 1 3 0").unwrap());
+        assert_eq!(rbfs(&unsolvable, |_| 0.0), None);
+    }
+}
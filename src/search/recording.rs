@@ -0,0 +1,82 @@
+use super::{SearchEvent, SearchSink};
+
+/// [`ExpansionRecord`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpansionRecord<C = u32> {
+    pub g: C,
+    pub f: f64,
+    /// Frontier size right after the node was taken out.
+    pub frontier: usize,
+}
+
+/// [`SearchRecording`]
+/// ## A [`SearchSink`] that keeps the history of a search.
+/// Records every expansion in order, the number of generated and pruned nodes, and the
+/// solution found, leaving the states out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchRecording<C = u32> {
+    pub expansions: Vec<ExpansionRecord<C>>,
+    pub generated: usize,
+    pub pruned: usize,
+    /// `(cost, length)` of the solution.
+    pub solution: Option<(C, usize)>,
+}
+
+impl<C> Default for SearchRecording<C> {
+    fn default() -> Self {
+        Self {
+            expansions: vec![],
+            generated: 0,
+            pruned: 0,
+            solution: None,
+        }
+    }
+}
+
+impl<C> SearchRecording<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_frontier(&self) -> usize {
+        self.expansions
+            .iter()
+            .map(|expansion| expansion.frontier)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl<S, C> SearchSink<S, C> for SearchRecording<C> {
+    fn event(&mut self, event: SearchEvent<'_, S, C>) {
+        match event {
+            SearchEvent::Generate { .. } => self.generated += 1,
+            SearchEvent::Expand { g, f, frontier, .. } => {
+                self.expansions.push(ExpansionRecord { g, f, frontier })
+            }
+            SearchEvent::Prune { .. } => self.pruned += 1,
+            SearchEvent::Solution { cost, length, .. } => self.solution = Some((cost, length)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+    use crate::search::a_star_traced;
+
+    #[test]
+    fn recording_keeps_expansions_in_order() {
+        let problem = NPuzzleProblem::new(Board::try_from("4 1 3 7 2 6 0 5 8").unwrap());
+        let mut recording = SearchRecording::new();
+
+        a_star_traced(&problem, |b| b.manhattan_distance() as f64, &mut recording);
+
+        assert_eq!(recording.solution, Some((6, 6)));
+        assert!(recording.generated >= recording.expansions.len());
+        // with a consistent heuristic A* expands nodes by non-decreasing f.
+        assert!(recording.expansions.windows(2).all(|w| w[0].f <= w[1].f));
+        assert!(recording.max_frontier() > 0);
+    }
+}
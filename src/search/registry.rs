@@ -0,0 +1,321 @@
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+use super::{
+    a_star, a_star_with_dominance, beam_search, bfs, greedy_best_first, ida_star, random_walk,
+    rbfs, uniform_cost_search, weighted_a_star, SearchProblem, Solution,
+};
+use crate::rng::Rng;
+
+/// Beam width of the registered `"beam"`, the default of the `beam_search` binary.
+pub const REGISTRY_BEAM_WIDTH: usize = 2;
+/// Weight of the registered `"weighted_a_star"`, the default of its binary.
+pub const REGISTRY_WEIGHT: f64 = 2.0;
+/// Most steps of the registered `"random_walk"`, which always starts from seed 1.
+pub const REGISTRY_WALK_LIMIT: usize = 10_000;
+
+/// [`BoxedHeuristic`]
+/// A heuristic chosen at runtime.
+pub type BoxedHeuristic<S> = Box<dyn Fn(&S) -> f64>;
+
+type HeuristicConstructor<P> = Box<dyn Fn(&P) -> BoxedHeuristic<<P as SearchProblem>::State>>;
+
+/// [`RegistryError`]
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum RegistryError {
+    #[error("Unknown heuristic {0}, expected one of: {1}")]
+    UnknownHeuristic(String, String),
+    #[error("Unknown algorithm {0}, expected one of: {1}")]
+    UnknownAlgorithm(String, String),
+}
+
+/// [`HeuristicRegistry`]
+/// ## Heuristics of a problem type, selectable by name.
+/// Every name maps to a constructor that builds the heuristic for a given instance, so
+/// that a command line option like `--heuristic manhattan` picks one without
+/// recompiling. Domains provide a registry with their own heuristics (e.g.
+/// [`NPuzzleProblem::heuristics`](crate::n_puzzle::NPuzzleProblem::heuristics)) to which
+/// more can be registered. `"zero"`, always admissible, is registered for every problem.
+/// # Example
+/// ```
+/// # use algoritmos_rust::n_puzzle::*;
+/// # use algoritmos_rust::search::*;
+/// let mut heuristics = NPuzzleProblem::heuristics();
+/// heuristics.register("double_manhattan", |_| {
+///     Box::new(|board: &Board| 2.0 * board.manhattan_distance() as f64)
+/// });
+///
+/// let problem = NPuzzleProblem::new(Board::try_from("1 2 3 4 5 6 0 7 8").unwrap());
+/// let heuristic = heuristics.build("double_manhattan", &problem).unwrap();
+/// assert_eq!(heuristic(&problem.start), 4.0);
+/// assert!(heuristics.build("euclidean", &problem).is_err());
+/// ```
+pub struct HeuristicRegistry<P: SearchProblem> {
+    constructors: BTreeMap<String, HeuristicConstructor<P>>,
+}
+
+impl<P: SearchProblem> HeuristicRegistry<P> {
+    pub fn new() -> Self {
+        let mut registry = Self {
+            constructors: BTreeMap::new(),
+        };
+        registry.register("zero", |_| Box::new(|_| 0.0));
+        registry
+    }
+
+    /// Registers `constructor` under `name`, replacing the heuristic with that name.
+    pub fn register<F>(&mut self, name: &str, constructor: F) -> &mut Self
+    where
+        F: Fn(&P) -> BoxedHeuristic<P::State> + 'static,
+    {
+        self.constructors
+            .insert(name.to_string(), Box::new(constructor));
+        self
+    }
+
+    /// Registered names, sorted.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.constructors.keys().map(String::as_str)
+    }
+
+    /// The heuristic named `name` for `problem`.
+    pub fn build(
+        &self,
+        name: &str,
+        problem: &P,
+    ) -> Result<BoxedHeuristic<P::State>, RegistryError> {
+        match self.constructors.get(name) {
+            Some(constructor) => Ok(constructor(problem)),
+            None => Err(RegistryError::UnknownHeuristic(
+                name.to_string(),
+                self.names().collect::<Vec<&str>>().join(", "),
+            )),
+        }
+    }
+}
+
+impl<P: SearchProblem> Default for HeuristicRegistry<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// [`Solver`]
+/// A search algorithm chosen at runtime. Uninformed algorithms ignore `heuristic`.
+/// Implemented by every `Fn(&P, &dyn Fn(&P::State) -> f64) -> Option<Solution<..>>`.
+pub trait Solver<P: SearchProblem> {
+    fn solve(
+        &self,
+        problem: &P,
+        heuristic: &dyn Fn(&P::State) -> f64,
+    ) -> Option<Solution<P::State, P::Action, P::Cost>>;
+}
+
+impl<P, F> Solver<P> for F
+where
+    P: SearchProblem,
+    F: Fn(&P, &dyn Fn(&P::State) -> f64) -> Option<Solution<P::State, P::Action, P::Cost>>,
+{
+    fn solve(
+        &self,
+        problem: &P,
+        heuristic: &dyn Fn(&P::State) -> f64,
+    ) -> Option<Solution<P::State, P::Action, P::Cost>> {
+        self(problem, heuristic)
+    }
+}
+
+/// [`AlgorithmRegistry`]
+/// ## Solvers selectable by name.
+/// Starts with the algorithms of this module (`"a_star"`, `"a_star_dominance"`, `"beam"`,
+/// `"bfs"`, `"greedy"`, `"ida_star"`, `"random_walk"`, `"rbfs"`, `"uniform_cost"` and
+/// `"weighted_a_star"`, the parameterized ones with the defaults above), more can be
+/// registered as [`Solver`] trait objects so that front ends can list and run them
+/// without knowing them at compile time.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::search::*;
+/// let mut algorithms = AlgorithmRegistry::new();
+/// algorithms.register("proof", |problem: &HanoiProblem, _: &dyn Fn(&_) -> f64| {
+///     match solve_or_prove(problem, 10_000) {
+///         Verdict::Solved(solution) => Some(solution),
+///         _ => None,
+///     }
+/// });
+///
+/// let problem = HanoiProblem::new(3, 3).unwrap();
+/// for name in algorithms.names() {
+///     let solution = algorithms.get(name).unwrap().solve(&problem, &|_| 0.0);
+///     assert!(solution.unwrap().len() >= 7);
+/// }
+/// let optimal = algorithms.get("ida_star").unwrap().solve(&problem, &|_| 0.0);
+/// assert_eq!(optimal.unwrap().len(), 7);
+/// ```
+pub struct AlgorithmRegistry<P: SearchProblem> {
+    solvers: BTreeMap<String, Box<dyn Solver<P>>>,
+}
+
+impl<P: SearchProblem> AlgorithmRegistry<P> {
+    pub fn new() -> Self {
+        let mut registry = Self {
+            solvers: BTreeMap::new(),
+        };
+        registry
+            .register(
+                "a_star",
+                |problem: &P, heuristic: &dyn Fn(&P::State) -> f64| a_star(problem, heuristic),
+            )
+            .register(
+                "a_star_dominance",
+                |problem: &P, heuristic: &dyn Fn(&P::State) -> f64| {
+                    a_star_with_dominance(problem, heuristic).solution
+                },
+            )
+            .register("bfs", |problem: &P, _: &dyn Fn(&P::State) -> f64| {
+                bfs(problem)
+            })
+            .register(
+                "greedy",
+                |problem: &P, heuristic: &dyn Fn(&P::State) -> f64| {
+                    greedy_best_first(problem, heuristic)
+                },
+            )
+            .register(
+                "uniform_cost",
+                |problem: &P, _: &dyn Fn(&P::State) -> f64| uniform_cost_search(problem),
+            )
+            .register(
+                "ida_star",
+                |problem: &P, heuristic: &dyn Fn(&P::State) -> f64| {
+                    ida_star(problem, heuristic).solution
+                },
+            )
+            .register(
+                "rbfs",
+                |problem: &P, heuristic: &dyn Fn(&P::State) -> f64| rbfs(problem, heuristic),
+            )
+            .register(
+                "beam",
+                |problem: &P, heuristic: &dyn Fn(&P::State) -> f64| {
+                    beam_search(problem, heuristic, REGISTRY_BEAM_WIDTH)
+                },
+            )
+            .register(
+                "weighted_a_star",
+                |problem: &P, heuristic: &dyn Fn(&P::State) -> f64| {
+                    weighted_a_star(problem, heuristic, REGISTRY_WEIGHT)
+                },
+            )
+            .register(
+                "random_walk",
+                |problem: &P, _: &dyn Fn(&P::State) -> f64| {
+                    random_walk(problem, REGISTRY_WALK_LIMIT, &mut Rng::new(1))
+                },
+            );
+        registry
+    }
+
+    /// Registers `solver` under `name`, replacing the algorithm with that name.
+    pub fn register<S: Solver<P> + 'static>(&mut self, name: &str, solver: S) -> &mut Self {
+        self.solvers.insert(name.to_string(), Box::new(solver));
+        self
+    }
+
+    /// Registered names, sorted.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.solvers.keys().map(String::as_str)
+    }
+
+    pub fn get(&self, name: &str) -> Result<&dyn Solver<P>, RegistryError> {
+        match self.solvers.get(name) {
+            Some(solver) => Ok(solver.as_ref()),
+            None => Err(RegistryError::UnknownAlgorithm(
+                name.to_string(),
+                self.names().collect::<Vec<&str>>().join(", "),
+            )),
+        }
+    }
+}
+
+impl<P: SearchProblem> Default for AlgorithmRegistry<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hanoi::HanoiProblem;
+    use crate::search::a_star;
+
+    #[test]
+    fn heuristic_registry_builds_heuristics_by_name() {
+        let heuristics = HanoiProblem::heuristics();
+        let problem = HanoiProblem::new(3, 3).unwrap();
+
+        assert_eq!(
+            heuristics.names().collect::<Vec<_>>(),
+            ["discs_off", "zero"]
+        );
+        for name in ["discs_off", "zero"] {
+            let heuristic = heuristics.build(name, &problem).unwrap();
+            assert_eq!(a_star(&problem, heuristic).unwrap().len(), 7);
+        }
+        assert_eq!(
+            heuristics.build("pegs", &problem).err(),
+            Some(RegistryError::UnknownHeuristic(
+                "pegs".into(),
+                "discs_off, zero".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn algorithm_registry_runs_user_solvers() {
+        /// Gives up on every problem.
+        struct Quitter;
+        impl Solver<HanoiProblem> for Quitter {
+            fn solve(
+                &self,
+                _: &HanoiProblem,
+                _: &dyn Fn(&crate::hanoi::HanoiState) -> f64,
+            ) -> Option<Solution<crate::hanoi::HanoiState, crate::hanoi::HanoiMove>> {
+                None
+            }
+        }
+
+        let mut algorithms = AlgorithmRegistry::new();
+        algorithms.register("quit", Quitter);
+        let problem = HanoiProblem::new(3, 3).unwrap();
+
+        assert_eq!(
+            algorithms.names().collect::<Vec<_>>(),
+            [
+                "a_star",
+                "a_star_dominance",
+                "beam",
+                "bfs",
+                "greedy",
+                "ida_star",
+                "quit",
+                "random_walk",
+                "rbfs",
+                "uniform_cost",
+                "weighted_a_star"
+            ]
+        );
+        assert!(algorithms
+            .get("quit")
+            .unwrap()
+            .solve(&problem, &|_| 0.0)
+            .is_none());
+        assert!(matches!(
+            algorithms.get("dfs"),
+            Err(RegistryError::UnknownAlgorithm(..))
+        ));
+    }
+}
@@ -0,0 +1,104 @@
+use super::{SearchProblem, Successor};
+use crate::rng::Rng;
+
+/// [`sample_successor`]
+/// ## Biased random successor.
+/// Picks one of the successors of `state` with probability proportional to `weight`,
+/// for stochastic simulations and rollouts. Dead ends and successors with a weight
+/// that isn't positive are never picked, `None` when nothing can be picked.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::HanoiProblem;
+/// # use algoritmos_rust::rng::Rng;
+/// # use algoritmos_rust::search::*;
+/// let problem = HanoiProblem::new(3, 3).unwrap();
+/// let start = problem.initial_state();
+/// // only moves to the last peg.
+/// let successor = sample_successor(&problem, &start, &mut Rng::new(1), |successor| {
+///     if successor.action.to == 2 { 1.0 } else { 0.0 }
+/// });
+/// assert_eq!(successor.unwrap().action.to, 2);
+/// ```
+pub fn sample_successor<P, W>(
+    problem: &P,
+    state: &P::State,
+    rng: &mut Rng,
+    mut weight: W,
+) -> Option<Successor<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    W: FnMut(&Successor<P::State, P::Action, P::Cost>) -> f64,
+{
+    let mut successors = problem
+        .successors(state)
+        .filter(|successor| !problem.is_dead_end(&successor.state))
+        .collect::<Vec<_>>();
+    let weights = successors.iter().map(&mut weight).collect::<Vec<f64>>();
+    let index = rng.choose_weighted(&weights)?;
+    Some(successors.swap_remove(index))
+}
+
+/// [`softmax_successor`]
+/// ## Boltzmann selection over a heuristic.
+/// [`sample_successor`] weighting every successor by `exp(-h / temperature)`, where `h`
+/// is the heuristic value of its state: the lower the temperature, the more likely the
+/// successors closer to the goal are. A temperature of zero (or below) always picks a
+/// successor with the lowest heuristic value, an infinite one picks uniformly.
+pub fn softmax_successor<P, H>(
+    problem: &P,
+    state: &P::State,
+    rng: &mut Rng,
+    temperature: f64,
+    heuristic: H,
+) -> Option<Successor<P::State, P::Action, P::Cost>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> f64,
+{
+    let values = problem
+        .successors(state)
+        .filter(|successor| !problem.is_dead_end(&successor.state))
+        .map(|successor| heuristic(&successor.state))
+        .collect::<Vec<f64>>();
+    // shifted by the best value so exp doesn't underflow.
+    let best = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let mut values = values.into_iter();
+    sample_successor(problem, state, rng, |_| {
+        let value = values.next().unwrap_or(f64::INFINITY);
+        if temperature > 0.0 {
+            (-(value - best) / temperature).exp()
+        } else if value == best {
+            1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+
+    #[test]
+    fn sampling_temperature_controls_greediness() {
+        let problem = NPuzzleProblem::new(Board::try_from("1 2 3 4 5 6 7 0 8").unwrap());
+        let start = problem.initial_state();
+        let heuristic = |board: &Board| board.manhattan_distance() as f64;
+        let mut rng = Rng::new(9);
+        let goal_picks = |temperature: f64, rng: &mut Rng| {
+            (0..1000)
+                .filter(|_| {
+                    let successor =
+                        softmax_successor(&problem, &start, rng, temperature, heuristic).unwrap();
+                    successor.state.manhattan_distance() == 0
+                })
+                .count()
+        };
+
+        assert_eq!(goal_picks(0.0, &mut rng), 1000);
+        assert!(goal_picks(0.5, &mut rng) > goal_picks(f64::INFINITY, &mut rng));
+        let uniform = goal_picks(f64::INFINITY, &mut rng);
+        assert!((250..420).contains(&uniform), "{}", uniform);
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use super::incremental::step_cost;
+use super::{Cost, SearchProblem, Solution};
+
+type ProblemSolution<P> =
+    Solution<<P as SearchProblem>::State, <P as SearchProblem>::Action, <P as SearchProblem>::Cost>;
+
+/// [`Shortening`]
+/// What [`remove_cycles`] cut from a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shortening<C = u32> {
+    pub removed_actions: usize,
+    /// Cost of the solution before removing the cycles.
+    pub original_cost: C,
+}
+
+/// [`remove_cycles`]
+/// ## Removes the detours of a solution.
+/// Whenever the path comes back to a state it already went through (a back-and-forth
+/// pair of moves or any longer cycle), the actions in between are dropped, so the
+/// result never repeats a state. Useful after the searches that don't return optimal
+/// paths, like depth-first or greedy searches. The cost of the kept actions is found
+/// again with [`SearchProblem::successors`], taking the cheapest action between every
+/// pair of consecutive states.
+/// # Example
+/// ```
+/// # use algoritmos_rust::hanoi::{HanoiMove, HanoiProblem};
+/// # use algoritmos_rust::search::*;
+/// let problem = HanoiProblem::new(2, 3).unwrap();
+/// let optimal = bfs(&problem).unwrap();
+/// let mut detour = optimal.clone();
+/// // goes back to the initial state and starts again.
+/// detour.states.splice(1..1, [optimal.states[1].clone(), optimal.states[0].clone()]);
+/// let HanoiMove { from, to } = optimal.actions[0];
+/// detour.actions.splice(0..0, [HanoiMove { from, to }, HanoiMove { from: to, to: from }]);
+/// detour.cost += 2;
+///
+/// let (shortened, shortening) = remove_cycles(&problem, detour);
+/// assert_eq!(shortened, optimal);
+/// assert_eq!(shortening.removed_actions, 2);
+/// assert_eq!(shortening.original_cost, optimal.cost + 2);
+/// ```
+pub fn remove_cycles<P: SearchProblem>(
+    problem: &P,
+    solution: ProblemSolution<P>,
+) -> (ProblemSolution<P>, Shortening<P::Cost>) {
+    let original_len = solution.len();
+    let original_cost = solution.cost;
+    let stats = solution.stats;
+    let mut states: Vec<P::State> = vec![];
+    let mut actions: Vec<P::Action> = vec![];
+    let mut position: HashMap<P::State, usize> = HashMap::new();
+    let mut incoming = solution.actions.into_iter();
+
+    for state in solution.states {
+        let action = if states.is_empty() {
+            None
+        } else {
+            incoming.next()
+        };
+        match position.get(&state) {
+            Some(&index) => {
+                for removed in states.drain(index + 1..) {
+                    position.remove(&removed);
+                }
+                actions.truncate(index);
+            }
+            None => {
+                position.insert(state.clone(), states.len());
+                states.push(state);
+                actions.extend(action);
+            }
+        }
+    }
+
+    let cost = states.windows(2).fold(P::Cost::zero(), |cost, pair| {
+        cost.add(step_cost(problem, &pair[0], &pair[1]))
+    });
+    let shortening = Shortening {
+        removed_actions: original_len - actions.len(),
+        original_cost,
+    };
+    (
+        Solution {
+            states,
+            actions,
+            cost,
+            stats,
+        },
+        shortening,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::search::{SearchStats, Successor};
+
+    /// Walks the integers one step at a time, stepping down costs 2.
+    struct Line;
+
+    impl SearchProblem for Line {
+        type State = i32;
+        type Action = i32;
+        type Cost = u32;
+        type Successors<'a> = std::vec::IntoIter<Successor<i32, i32>>;
+
+        fn initial_state(&self) -> i32 {
+            0
+        }
+
+        fn is_goal(&self, state: &i32) -> bool {
+            *state == 2
+        }
+
+        fn successors<'a>(&'a self, state: &'a i32) -> Self::Successors<'a> {
+            vec![
+                Successor::new(1, state + 1, 1),
+                Successor::new(-1, state - 1, 2),
+            ]
+            .into_iter()
+        }
+    }
+
+    fn walk(states: &[i32]) -> Solution<i32, i32> {
+        let actions = states
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect::<Vec<i32>>();
+        let cost = actions.iter().map(|a| if *a > 0 { 1 } else { 2 }).sum();
+        Solution {
+            states: states.to_vec(),
+            actions,
+            cost,
+            stats: SearchStats::default(),
+        }
+    }
+
+    #[test]
+    fn shortcut_removes_back_and_forth_moves_and_cycles() {
+        let (shortened, shortening) = remove_cycles(&Line, walk(&[0, 1, 0, -1, 0, 1, 2, 3, 2]));
+
+        assert_eq!(shortened, walk(&[0, 1, 2]));
+        assert_eq!(
+            shortening,
+            Shortening {
+                removed_actions: 6,
+                original_cost: 11
+            }
+        );
+    }
+
+    #[test]
+    fn shortcut_keeps_paths_without_repeated_states() {
+        let (shortened, shortening) = remove_cycles(&Line, walk(&[0, -1, -2]));
+
+        assert_eq!(shortened, walk(&[0, -1, -2]));
+        assert_eq!(shortening.removed_actions, 0);
+        assert_eq!(shortening.original_cost, 4);
+    }
+}
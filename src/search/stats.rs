@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [`SearchStats`]
+/// ## How much work a search did to find a [`Solution`](super::Solution).
+/// Every solver of this module fills them in, the ones that don't keep a frontier
+/// report the nodes on their path instead. Stats are equal when the searches did the
+/// same work, whatever time they took, so solutions of different runs compare equal.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SearchStats {
+    /// Nodes created, the initial one included.
+    pub generated: usize,
+    /// States whose successors were generated, the goal is not expanded.
+    pub expanded: usize,
+    /// Successors dropped because their state had already been reached.
+    pub duplicates: usize,
+    /// Most nodes waiting in the frontier at once.
+    pub frontier_peak: usize,
+    /// Actions from the initial state to the deepest node generated.
+    pub max_depth: usize,
+    /// Wall time of the search.
+    pub elapsed: Duration,
+}
+
+impl PartialEq for SearchStats {
+    fn eq(&self, other: &Self) -> bool {
+        self.generated == other.generated
+            && self.expanded == other.expanded
+            && self.duplicates == other.duplicates
+            && self.frontier_peak == other.frontier_peak
+            && self.max_depth == other.max_depth
+    }
+}
+
+impl Eq for SearchStats {}
+
+impl SearchStats {
+    /// Stats of a search that only generated its initial node.
+    pub(super) fn started() -> Self {
+        Self {
+            generated: 1,
+            frontier_peak: 1,
+            ..Self::default()
+        }
+    }
+
+    pub(super) fn record_generated(&mut self, depth: usize) {
+        self.generated += 1;
+        self.max_depth = self.max_depth.max(depth);
+    }
+
+    pub(super) fn record_frontier(&mut self, frontier: usize) {
+        self.frontier_peak = self.frontier_peak.max(frontier);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::n_puzzle::{Board, NPuzzleProblem};
+    use crate::search::{a_star, bfs, dfs};
+
+    #[test]
+    fn stats_are_returned_with_solutions() {
+        let problem = NPuzzleProblem::new(Board::try_from("4 1 3 7 2 6 0 5 8").unwrap());
+
+        let informed = a_star(&problem, |board| board.manhattan_distance() as f64).unwrap();
+        let uninformed = bfs(&problem).unwrap();
+
+        assert_eq!(informed.cost, uninformed.cost);
+        assert!(informed.stats.expanded < uninformed.stats.expanded);
+        assert!(informed.stats.frontier_peak < uninformed.stats.frontier_peak);
+        let depth_first = dfs(&problem).unwrap();
+        assert!(depth_first.stats.expanded >= depth_first.len());
+        assert!(depth_first.stats.frontier_peak > 0);
+        for solution in [&informed, &uninformed, &depth_first] {
+            let stats = solution.stats;
+            assert!(stats.generated > stats.expanded);
+            assert!(stats.max_depth >= solution.len());
+        }
+        assert!(uninformed.stats.duplicates > 0);
+        assert_eq!(uninformed.stats.max_depth, uninformed.len() + 1);
+    }
+}
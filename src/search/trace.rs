@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+
+use super::{PruneReason, SearchEvent, SearchSink};
+
+/// [`TreeNodeKind`]
+/// What became of a node of a [`SearchTree`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TreeNodeKind {
+    /// Added to the frontier and never taken out.
+    Generated,
+    Expanded,
+    /// The goal of the solution.
+    Goal,
+    /// Discarded when generated, never added to the frontier.
+    Pruned(PruneReason),
+}
+
+impl TreeNodeKind {
+    /// Fill color of the node in DOT.
+    fn color(&self) -> &'static str {
+        match self {
+            TreeNodeKind::Generated => "white",
+            TreeNodeKind::Expanded => "lightblue",
+            TreeNodeKind::Goal => "palegreen",
+            TreeNodeKind::Pruned(PruneReason::DeadEnd) => "salmon",
+            TreeNodeKind::Pruned(_) => "lightgray",
+        }
+    }
+}
+
+/// [`TreeNode`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeNode<S> {
+    pub state: S,
+    /// Index of the parent in [`SearchTree::nodes`], `None` for the root.
+    pub parent: Option<usize>,
+    pub kind: TreeNodeKind,
+}
+
+/// [`SearchTree`]
+/// ## A [`SearchSink`] that keeps the tree a search explored.
+/// Every generated and pruned successor becomes a node under its parent, so a state
+/// reached several times appears several times, and iterative searches hang their
+/// regenerated nodes under the latest copy of their parent.
+/// [`to_dot`](Self::to_dot) draws the tree with Graphviz, the path to the goal in bold.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::*;
+/// # use algoritmos_rust::search::*;
+/// let mut tree = SearchTree::new();
+/// bfs_traced(&FixedCannibalsProblem::<3, 2>, &mut tree).unwrap();
+/// let dot = tree.to_dot();
+/// assert!(dot.starts_with("digraph search {"));
+/// assert!(dot.contains(r#"n0 [label="0 0 3 3 right", fillcolor=lightblue];"#));
+/// assert_eq!(tree.goal().map(|goal| goal.to_string()), Some("3 3 0 0 left".into()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchTree<S> {
+    pub nodes: Vec<TreeNode<S>>,
+    /// Index in `nodes` of the latest node numbered by the search with each number.
+    indices: HashMap<usize, usize>,
+    last_expanded: Option<usize>,
+}
+
+impl<S> Default for SearchTree<S> {
+    fn default() -> Self {
+        Self {
+            nodes: vec![],
+            indices: HashMap::new(),
+            last_expanded: None,
+        }
+    }
+}
+
+impl<S> SearchTree<S> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn goal(&self) -> Option<&S> {
+        self.nodes
+            .iter()
+            .find(|node| node.kind == TreeNodeKind::Goal)
+            .map(|node| &node.state)
+    }
+
+    /// [`to_dot`]
+    /// The tree as a Graphviz digraph labelled with the states: expanded nodes blue, the
+    /// goal green, dead ends red and other pruned nodes gray.
+    pub fn to_dot(&self) -> String
+    where
+        S: Display,
+    {
+        let mut on_path = vec![false; self.nodes.len()];
+        let mut next = self
+            .nodes
+            .iter()
+            .position(|node| node.kind == TreeNodeKind::Goal);
+        while let Some(index) = next {
+            on_path[index] = true;
+            next = self.nodes[index].parent;
+        }
+
+        let mut dot = String::from("digraph search {\n    node [shape=box, style=filled];\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let label = node.state.to_string().replace('"', "\\\"");
+            let _ = writeln!(
+                dot,
+                "    n{index} [label=\"{label}\", fillcolor={}];",
+                node.kind.color()
+            );
+        }
+        for (index, node) in self.nodes.iter().enumerate() {
+            if let Some(parent) = node.parent {
+                let style = if on_path[index] { " [penwidth=3]" } else { "" };
+                let _ = writeln!(dot, "    n{parent} -> n{index}{style};");
+            }
+        }
+        dot.push('}');
+        dot.push('\n');
+        dot
+    }
+
+    fn push(&mut self, state: S, parent: Option<usize>, kind: TreeNodeKind) -> usize {
+        let parent = parent.and_then(|parent| self.indices.get(&parent).copied());
+        self.nodes.push(TreeNode {
+            state,
+            parent,
+            kind,
+        });
+        self.nodes.len() - 1
+    }
+}
+
+impl<S: Clone + PartialEq, C> SearchSink<S, C> for SearchTree<S> {
+    fn event(&mut self, event: SearchEvent<'_, S, C>) {
+        match event {
+            SearchEvent::Generate {
+                state,
+                node,
+                parent,
+                ..
+            } => {
+                let index = self.push(state.clone(), parent, TreeNodeKind::Generated);
+                self.indices.insert(node, index);
+            }
+            SearchEvent::Expand { node, .. } => {
+                if let Some(index) = self.indices.get(&node) {
+                    self.nodes[*index].kind = TreeNodeKind::Expanded;
+                    self.last_expanded = Some(*index);
+                }
+            }
+            SearchEvent::Prune {
+                state,
+                parent,
+                reason,
+                ..
+            } => {
+                self.push(state.clone(), Some(parent), TreeNodeKind::Pruned(reason));
+            }
+            SearchEvent::Solution { state, .. } => {
+                // searches testing goals when they generate them don't expand the goal.
+                let goal = self
+                    .last_expanded
+                    .filter(|index| &self.nodes[*index].state == state)
+                    .or_else(|| {
+                        self.nodes.iter().rposition(|node| {
+                            node.kind == TreeNodeKind::Generated && &node.state == state
+                        })
+                    });
+                if let Some(goal) = goal {
+                    self.nodes[goal].kind = TreeNodeKind::Goal;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cannibals::{FixedCannibalsProblem, FixedWorldState};
+    use crate::search::{a_star_traced, iddfs_traced};
+
+    #[test]
+    fn trace_tree_marks_goal_dead_ends_and_the_path() {
+        let problem = FixedCannibalsProblem::<3, 2>;
+        let mut tree = SearchTree::new();
+
+        let solution = a_star_traced(&problem, FixedWorldState::heuristic, &mut tree).unwrap();
+        let dot = tree.to_dot();
+
+        assert_eq!(tree.goal(), Some(solution.goal()));
+        assert!(tree
+            .nodes
+            .iter()
+            .any(|node| node.kind == TreeNodeKind::Pruned(PruneReason::DeadEnd)));
+        assert_eq!(dot.matches("fillcolor=palegreen").count(), 1);
+        assert_eq!(dot.matches("[penwidth=3]").count(), solution.len());
+        assert_eq!(dot.matches("->").count(), tree.nodes.len() - 1);
+        let mut iterative = SearchTree::new();
+        iddfs_traced(&problem, 1, 20, &mut iterative).unwrap();
+        assert_eq!(iterative.goal(), Some(solution.goal()));
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::{HashSet, VecDeque};
+use std::time::Instant;
+
+use super::best_first::{build_solution, Node};
+use super::{
+    ClosedSet, Cost, PruneReason, SearchEvent, SearchProblem, SearchSink, SearchStats, Solution,
+};
+
+/// [`bfs`]
+/// ## Breadth-first search.
+/// Expands states in the order they were discovered, returning a solution with the
+/// fewest actions (optimal whenever every action costs the same).
+pub fn bfs<P: SearchProblem>(problem: &P) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    bfs_with_closed_set(problem, &mut HashSet::new())
+}
+
+/// [`bfs_traced`]
+/// [`bfs`] reporting every [`SearchEvent`] to `sink`, `f` being the path cost.
+pub fn bfs_traced<P: SearchProblem>(
+    problem: &P,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    uninformed(problem, Order::BreadthFirst, &mut HashSet::new(), sink)
+}
+
+/// [`bfs_with_closed_set`]
+/// [`bfs`] keeping the discovered states in `discovered`, which must start empty.
+pub fn bfs_with_closed_set<P: SearchProblem>(
+    problem: &P,
+    discovered: &mut dyn ClosedSet<P::State>,
+) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    uninformed(problem, Order::BreadthFirst, discovered, &mut ())
+}
+
+/// [`dfs`]
+/// ## Depth-first search.
+/// Always expands the most recently discovered state, following one path as deep as
+/// it goes before backtracking. Every state is expanded at most once, so it stops on
+/// finite state spaces, but the solution found may be much longer than the shortest
+/// one (see [`remove_cycles`](super::remove_cycles)).
+/// Goals are recognized as soon as they are generated, the successors after them are
+/// never pulled from the iterator.
+/// # Example
+/// ```
+/// # use algoritmos_rust::cannibals::FixedCannibalsProblem;
+/// # use algoritmos_rust::search::*;
+/// let problem = FixedCannibalsProblem::<3, 2>;
+/// let solution = dfs(&problem).unwrap();
+/// assert!(problem.is_goal(solution.goal()));
+/// assert!(solution.len() >= bfs(&problem).unwrap().len());
+/// ```
+pub fn dfs<P: SearchProblem>(problem: &P) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    dfs_traced(problem, &mut ())
+}
+
+/// [`dfs_traced`]
+/// [`dfs`] reporting every [`SearchEvent`] to `sink`, `f` being the path cost.
+pub fn dfs_traced<P: SearchProblem>(
+    problem: &P,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    uninformed(problem, Order::DepthFirst, &mut HashSet::new(), sink)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Order {
+    /// The frontier is a queue.
+    BreadthFirst,
+    /// The frontier is a stack.
+    DepthFirst,
+}
+
+fn uninformed<P: SearchProblem>(
+    problem: &P,
+    order: Order,
+    discovered: &mut dyn ClosedSet<P::State>,
+    sink: &mut dyn SearchSink<P::State, P::Cost>,
+) -> Option<Solution<P::State, P::Action, P::Cost>> {
+    profile!(match order {
+        Order::BreadthFirst => "bfs",
+        Order::DepthFirst => "dfs",
+    });
+    let start = Instant::now();
+    let initial_state = problem.initial_state();
+    discovered.insert(&problem.canonicalize(&initial_state));
+    sink.event(SearchEvent::Generate {
+        state: &initial_state,
+        node: 0,
+        parent: None,
+        g: P::Cost::zero(),
+        f: 0.0,
+    });
+    let mut nodes = vec![Node {
+        state: initial_state,
+        parent: None,
+        action: None,
+        path_cost: P::Cost::zero(),
+        depth: 0,
+    }];
+    let mut frontier = VecDeque::from([0]);
+    let mut stats = SearchStats::started();
+
+    loop {
+        let popped = match order {
+            Order::BreadthFirst => frontier.pop_front(),
+            Order::DepthFirst => frontier.pop_back(),
+        };
+        let Some(current) = popped else {
+            break;
+        };
+        let path_cost = nodes[current].path_cost;
+        sink.event(SearchEvent::Expand {
+            state: &nodes[current].state,
+            node: current,
+            g: path_cost,
+            f: path_cost.to_f64(),
+            frontier: frontier.len(),
+        });
+        if problem.is_goal(&nodes[current].state) {
+            stats.elapsed = start.elapsed();
+            return Some(finish(&nodes, current, stats, sink));
+        }
+        let (state, depth) = (nodes[current].state.clone(), nodes[current].depth + 1);
+        stats.expanded += 1;
+        profile!("expand");
+        for successor in problem.successors(&state) {
+            let successor_path_cost = path_cost.add(successor.cost);
+            if problem.is_dead_end(&successor.state) {
+                sink.event(SearchEvent::Prune {
+                    state: &successor.state,
+                    parent: current,
+                    g: successor_path_cost,
+                    reason: PruneReason::DeadEnd,
+                });
+                continue;
+            }
+            let discovered = {
+                profile!("dedup");
+                discovered.insert(&problem.canonicalize(&successor.state))
+            };
+            if !discovered {
+                stats.duplicates += 1;
+                sink.event(SearchEvent::Prune {
+                    state: &successor.state,
+                    parent: current,
+                    g: successor_path_cost,
+                    reason: PruneReason::Duplicate,
+                });
+                continue;
+            }
+            sink.event(SearchEvent::Generate {
+                state: &successor.state,
+                node: nodes.len(),
+                parent: Some(current),
+                g: successor_path_cost,
+                f: successor_path_cost.to_f64(),
+            });
+            frontier.push_back(nodes.len());
+            stats.record_generated(depth);
+            nodes.push(Node {
+                state: successor.state,
+                parent: Some(current),
+                action: Some(successor.action),
+                path_cost: successor_path_cost,
+                depth,
+            });
+            if order == Order::DepthFirst && problem.is_goal(&nodes[nodes.len() - 1].state) {
+                stats.record_frontier(frontier.len());
+                stats.elapsed = start.elapsed();
+                return Some(finish(&nodes, nodes.len() - 1, stats, sink));
+            }
+        }
+        stats.record_frontier(frontier.len());
+    }
+
+    None
+}
+
+/// The solution ending at node `goal`, reported to `sink`.
+fn finish<S: Clone, A: Clone, C: Cost>(
+    nodes: &[Node<S, A, C>],
+    goal: usize,
+    stats: SearchStats,
+    sink: &mut dyn SearchSink<S, C>,
+) -> Solution<S, A, C> {
+    let solution = build_solution(nodes, goal, stats);
+    sink.event(SearchEvent::Solution {
+        state: solution.goal(),
+        cost: solution.cost,
+        length: solution.len(),
+    });
+    solution
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::cannibals::FixedCannibalsProblem;
+    use crate::hanoi::{HanoiProblem, HanoiState};
+    use crate::search::{BoxedSuccessors, Successor};
+
+    #[test]
+    fn bfs_returns_fewest_actions() {
+        let solution = bfs(&HanoiProblem::new(3, 3).unwrap()).unwrap();
+
+        assert_eq!(solution.len(), 7);
+        assert_eq!(solution.cost, 7);
+    }
+
+    #[test]
+    fn dfs_solves_without_revisiting_states() {
+        let problem = HanoiProblem::new(4, 3).unwrap();
+        let mut expanded = HashSet::new();
+        let mut dead_ends = 0;
+
+        let solution = dfs_traced(
+            &problem,
+            &mut |event: SearchEvent<'_, HanoiState>| match event {
+                SearchEvent::Expand { state, .. } => assert!(expanded.insert(state.clone())),
+                SearchEvent::Prune {
+                    reason: PruneReason::DeadEnd,
+                    ..
+                } => dead_ends += 1,
+                _ => {}
+            },
+        )
+        .unwrap();
+
+        assert!(problem.is_goal(solution.goal()));
+        assert!(solution.len() >= 15);
+        assert_eq!(dead_ends, 0);
+        let cannibals = dfs(&FixedCannibalsProblem::<3, 2>).unwrap();
+        assert!(cannibals.goal().is_solution());
+    }
+
+    /// A root with `width` children, the first one being the goal, that counts the
+    /// successors pulled from its iterators.
+    struct Wide {
+        width: u32,
+        pulled: Cell<u32>,
+    }
+
+    impl SearchProblem for Wide {
+        type State = u32;
+        type Action = u32;
+        type Cost = u32;
+        type Successors<'a> = BoxedSuccessors<'a, u32, u32>;
+
+        fn initial_state(&self) -> u32 {
+            0
+        }
+
+        fn is_goal(&self, state: &u32) -> bool {
+            *state == 1
+        }
+
+        fn successors<'a>(&'a self, state: &'a u32) -> Self::Successors<'a> {
+            let width = if *state == 0 { self.width } else { 0 };
+            Box::new((1..=width).map(|child| {
+                self.pulled.set(self.pulled.get() + 1);
+                Successor::new(child, child, 1)
+            }))
+        }
+    }
+
+    #[test]
+    fn dfs_stops_pulling_successors_at_the_goal() {
+        let problem = Wide {
+            width: 1000,
+            pulled: Cell::new(0),
+        };
+
+        let solution = dfs(&problem).unwrap();
+
+        assert_eq!(solution.actions, vec![1]);
+        assert_eq!(problem.pulled.get(), 1);
+        bfs(&problem).unwrap();
+        assert_eq!(problem.pulled.get(), 1 + 1000);
+    }
+}
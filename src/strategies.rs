@@ -0,0 +1,74 @@
+//! [`proptest`] strategies for the states and boards of the crate, enabled by the
+//! `proptest` feature.
+use proptest::prelude::*;
+
+use crate::cannibals::{BoatSide, Cannibals, Missionaries, SideState, WorldState};
+use crate::n_puzzle::Board;
+
+/// [`side_state`]
+/// Any bank with up to 3 cannibals and 3 missionaries.
+pub fn side_state() -> impl Strategy<Value = SideState> {
+    (0..=3u8, 0..=3u8).prop_map(|(cannibals, missionaries)| {
+        SideState::new(Cannibals(cannibals), Missionaries(missionaries))
+    })
+}
+
+pub fn boat_side() -> impl Strategy<Value = BoatSide> {
+    prop_oneof![Just(BoatSide::LeftSide), Just(BoatSide::RightSide)]
+}
+
+/// [`world_state`]
+/// Any valid [`WorldState`] (3 cannibals and 3 missionaries in total), game over or not.
+pub fn world_state() -> impl Strategy<Value = WorldState> {
+    (side_state(), boat_side()).prop_map(|(left, boat_side)| {
+        let right = SideState::new(
+            Cannibals(3 - left.cannibals.0),
+            Missionaries(3 - left.missionaries.0),
+        );
+        WorldState::new(left, right, boat_side, vec![], 0)
+            .expect("both sides add up to 3 cannibals and 3 missionaries")
+    })
+}
+
+/// [`safe_world_state`]
+/// A [`world_state`] where nobody can be eaten.
+pub fn safe_world_state() -> impl Strategy<Value = WorldState> {
+    world_state().prop_filter("missionaries outnumbered", |state| !state.is_game_over())
+}
+
+/// [`n_puzzle_board`]
+/// Any `size x size` board, solvable or not.
+pub fn n_puzzle_board(size: usize) -> impl Strategy<Value = Board> {
+    Just((0..(size * size) as u8).collect::<Vec<u8>>())
+        .prop_shuffle()
+        .prop_map(move |tiles| Board::new(size, tiles).expect("tiles are a permutation"))
+}
+
+/// [`solvable_n_puzzle_board`]
+/// A [`n_puzzle_board`] that can reach the goal board.
+pub fn solvable_n_puzzle_board(size: usize) -> impl Strategy<Value = Board> {
+    n_puzzle_board(size).prop_filter("unsolvable board", |board| board.is_solvable())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn strategies_world_state_children_are_valid(state in safe_world_state()) {
+            for child in state.get_child_states() {
+                let child = child.unwrap();
+                prop_assert_ne!(child.boat_side, state.boat_side);
+            }
+        }
+
+        #[test]
+        fn strategies_n_puzzle_slides_preserve_solvability(board in n_puzzle_board(3)) {
+            for (slide, child) in board.get_child_boards() {
+                prop_assert_eq!(child.is_solvable(), board.is_solvable());
+                prop_assert_eq!(child.slide(slide.opposite()), Some(board.clone()));
+            }
+        }
+    }
+}
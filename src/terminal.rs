@@ -0,0 +1,249 @@
+//! Colored terminal output for the binaries.
+use thiserror::Error;
+
+/// [`Color`]
+/// ANSI style applied to a piece of text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Plain,
+    Bold,
+    Dim,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn code(&self) -> Option<&'static str> {
+        match self {
+            Color::Plain => None,
+            Color::Bold => Some("1"),
+            Color::Dim => Some("2"),
+            Color::Red => Some("31"),
+            Color::Green => Some("32"),
+            Color::Yellow => Some("33"),
+            Color::Blue => Some("34"),
+            Color::Magenta => Some("35"),
+            Color::Cyan => Some("36"),
+        }
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ThemeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "plain" | "none" => Ok(Color::Plain),
+            "bold" => Ok(Color::Bold),
+            "dim" => Ok(Color::Dim),
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "yellow" => Ok(Color::Yellow),
+            "blue" => Ok(Color::Blue),
+            "magenta" => Ok(Color::Magenta),
+            "cyan" => Ok(Color::Cyan),
+            other => Err(ThemeError::UnknownColor(other.to_string())),
+        }
+    }
+}
+
+/// [`Theme`]
+/// ## Colors of the binaries output.
+/// The solution path is green, pruned branches dim, statistics cyan, headings bold and
+/// errors red, unless colors are disabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub enabled: bool,
+    pub heading: Color,
+    pub path: Color,
+    pub pruned: Color,
+    pub stats: Color,
+    pub error: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            heading: Color::Bold,
+            path: Color::Green,
+            pruned: Color::Dim,
+            stats: Color::Cyan,
+            error: Color::Red,
+        }
+    }
+}
+
+impl Theme {
+    /// Theme without colors.
+    pub fn plain() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+
+    /// [`new`]
+    /// ## Theme of the binaries.
+    /// Colors are disabled by `no_color` (the parsed `--no-color` option) or the
+    /// `NO_COLOR` variable, otherwise the `ALGORITMOS_THEME` variable (see
+    /// [`TryFrom<&str>`](#impl-TryFrom<%26str>-for-Theme)) overrides the default colors.
+    /// An invalid theme falls back to the default one.
+    pub fn new(no_color: bool) -> Self {
+        let no_color =
+            no_color || std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        if no_color {
+            return Self::plain();
+        }
+        std::env::var("ALGORITMOS_THEME")
+            .ok()
+            .and_then(|theme| Theme::try_from(theme.as_str()).ok())
+            .unwrap_or_default()
+    }
+
+    /// [`from_env`]
+    /// [`new`](Self::new) for the binaries that don't parse their options, colors are
+    /// disabled when any argument is `--no-color`.
+    pub fn from_env() -> Self {
+        Self::new(std::env::args().any(|arg| arg == "--no-color"))
+    }
+
+    /// `text` wrapped in the ANSI escape codes of `color`.
+    pub fn paint(&self, color: Color, text: &str) -> String {
+        match (self.enabled, color.code()) {
+            (true, Some(code)) => format!("\x1b[{}m{}\x1b[0m", code, text),
+            _ => text.to_string(),
+        }
+    }
+
+    /// [`stats_table`]
+    /// Two columns table, names padded to the longest one and values in the stats color.
+    pub fn stats_table(&self, rows: &[(&str, String)]) -> String {
+        let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        rows.iter()
+            .map(|(name, value)| format!("{:<width$}  {}", name, self.paint(self.stats, value)))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// [`table`]
+    /// Columns padded to their widest cell, the header in the heading color.
+    pub fn table(&self, header: &[&str], rows: &[Vec<String>]) -> String {
+        let widths = (0..header.len())
+            .map(|column| {
+                rows.iter()
+                    .filter_map(|row| row.get(column).map(String::len))
+                    .chain([header[column].len()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<usize>>();
+        let line = |cells: Vec<&str>| {
+            let padded = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell))
+                .collect::<Vec<String>>();
+            padded.join("  ").trim_end().to_string()
+        };
+        let mut lines = vec![self.paint(self.heading, &line(header.to_vec()))];
+        lines.extend(
+            rows.iter()
+                .map(|row| line(row.iter().map(String::as_str).collect())),
+        );
+        lines.join("\n")
+    }
+}
+
+/// [TryFrom<&str>]
+/// Overrides the default colors with `element=color` pairs separated by commas or new
+/// lines, e.g. `"path=yellow, stats=magenta"`.
+/// Elements are `heading`, `path`, `pruned`, `stats` and `error`, colors are `plain`,
+/// `bold`, `dim`, `red`, `green`, `yellow`, `blue`, `magenta` and `cyan`.
+impl TryFrom<&str> for Theme {
+    type Error = ThemeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut theme = Theme::default();
+        for entry in value
+            .split([',', '\n'])
+            .map(str::trim)
+            .filter(|e| !e.is_empty())
+        {
+            let (element, color) = entry
+                .split_once('=')
+                .ok_or_else(|| ThemeError::ParseFromStringError(entry.to_string()))?;
+            let color = Color::try_from(color)?;
+            match element.trim() {
+                "heading" => theme.heading = color,
+                "path" => theme.path = color,
+                "pruned" => theme.pruned = color,
+                "stats" => theme.stats = color,
+                "error" => theme.error = color,
+                other => return Err(ThemeError::UnknownElement(other.to_string())),
+            }
+        }
+        Ok(theme)
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum ThemeError {
+    #[error("Unknown color {0}")]
+    UnknownColor(String),
+    #[error("Unknown theme element {0}")]
+    UnknownElement(String),
+    #[error("Error when trying to parse from Theme string")]
+    ParseFromStringError(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn terminal_theme_paints_only_when_enabled() {
+        let theme = Theme::default();
+
+        assert_eq!(theme.paint(theme.path, "ok"), "\x1b[32mok\x1b[0m");
+        assert_eq!(theme.paint(Color::Plain, "ok"), "ok");
+        assert_eq!(Theme::plain().paint(Color::Red, "ok"), "ok");
+        assert_eq!(
+            Theme::plain().stats_table(&[("steps", "11".into()), ("visited states", "13".into())]),
+            "steps           11\nvisited states  13"
+        );
+        assert_eq!(
+            Theme::plain().table(
+                &["algorithm", "steps"],
+                &[
+                    vec!["bfs".into(), "11".into()],
+                    vec!["greedy".into(), "9".into()]
+                ]
+            ),
+            "algorithm  steps\nbfs        11\ngreedy     9"
+        );
+    }
+
+    #[test]
+    fn terminal_theme_try_from_overrides_defaults() {
+        let theme = Theme::try_from("path=yellow,\nstats = magenta").unwrap();
+
+        assert_eq!(theme.path, Color::Yellow);
+        assert_eq!(theme.stats, Color::Magenta);
+        assert_eq!(theme.pruned, Color::Dim);
+        assert_eq!(
+            Theme::try_from("path=pink"),
+            Err(ThemeError::UnknownColor("pink".into()))
+        );
+        assert_eq!(
+            Theme::try_from("boat=red"),
+            Err(ThemeError::UnknownElement("boat".into()))
+        );
+        assert!(Theme::try_from("path").is_err());
+    }
+}
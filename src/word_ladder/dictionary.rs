@@ -0,0 +1,126 @@
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::path::Path;
+
+use super::WordLadderError;
+
+/// [`Dictionary`]
+/// ## Word list used by the word ladder puzzle.
+/// Words are normalized to lowercase, blank lines and lines containing anything other than
+/// ascii letters are ignored.
+/// An index from "wildcard patterns" (e.g. `"c_t"`) to words is kept so the one-letter
+/// neighbors of a word can be found without scanning the whole list.
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    words: HashSet<String>,
+    patterns: HashMap<String, Vec<String>>,
+}
+
+impl Dictionary {
+    pub fn from_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut dictionary = Self::default();
+        words
+            .into_iter()
+            .for_each(|word| dictionary.insert(word.as_ref()));
+        dictionary
+    }
+
+    /// [`from_reader`]
+    /// Loads a word list with one word per line.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, WordLadderError> {
+        let mut dictionary = Self::default();
+        for line in reader.lines() {
+            let line = line.map_err(|e| WordLadderError::Io(e.to_string()))?;
+            dictionary.insert(&line);
+        }
+        Ok(dictionary)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, WordLadderError> {
+        let file = std::fs::File::open(path).map_err(|e| WordLadderError::Io(e.to_string()))?;
+        Self::from_reader(std::io::BufReader::new(file))
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    /// [`neighbors`]
+    /// Every word of the dictionary that differs from `word` by exactly one letter.
+    pub fn neighbors(&self, word: &str) -> Vec<&str> {
+        let mut neighbors = wildcard_patterns(word)
+            .iter()
+            .filter_map(|pattern| self.patterns.get(pattern))
+            .flatten()
+            .filter(|candidate| candidate.as_str() != word)
+            .map(|candidate| candidate.as_str())
+            .collect::<Vec<&str>>();
+        neighbors.sort();
+        neighbors
+    }
+
+    fn insert(&mut self, word: &str) {
+        let word = word.trim().to_lowercase();
+        if word.is_empty() || !word.chars().all(|c| c.is_ascii_lowercase()) {
+            return;
+        }
+        if !self.words.insert(word.clone()) {
+            return;
+        }
+        for pattern in wildcard_patterns(&word) {
+            self.patterns.entry(pattern).or_default().push(word.clone());
+        }
+    }
+}
+
+/// `word` with each of its letters replaced by `_`, whatever their width in bytes.
+fn wildcard_patterns(word: &str) -> Vec<String> {
+    word.char_indices()
+        .map(|(i, c)| format!("{}_{}", &word[..i], &word[i + c.len_utf8()..]))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dictionary_from_reader_normalizes_and_skips_invalid_lines() {
+        let input = "Cat\n\ncot\ndog's\n  dot  \ncat\n";
+
+        let dictionary = Dictionary::from_reader(input.as_bytes()).unwrap();
+
+        assert_eq!(dictionary.len(), 3);
+        assert!(dictionary.contains("CAT"));
+        assert!(dictionary.contains("dot"));
+        assert!(!dictionary.contains("dog's"));
+    }
+
+    #[test]
+    fn dictionary_neighbors_returns_one_letter_changes() {
+        let dictionary = Dictionary::from_words(["cat", "cot", "cut", "dog", "cats", "bat"]);
+
+        assert_eq!(dictionary.neighbors("cat"), vec!["bat", "cot", "cut"]);
+        assert!(dictionary.neighbors("dog").is_empty());
+        assert_eq!(dictionary.neighbors("çat"), vec!["bat", "cat"]);
+    }
+
+    #[test]
+    fn dictionary_from_file_returns_error_when_file_does_not_exist() {
+        let result = Dictionary::from_file("/this/file/does/not/exist.txt");
+
+        assert!(matches!(result, Err(WordLadderError::Io(_))));
+    }
+}
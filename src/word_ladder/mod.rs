@@ -0,0 +1,18 @@
+pub mod dictionary;
+pub mod problem;
+
+pub use dictionary::*;
+pub use problem::*;
+
+use thiserror::Error;
+
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq)]
+pub enum WordLadderError {
+    #[error("Start and goal words have different lengths")]
+    DifferentLengths(usize, usize),
+    #[error("Word is not in the dictionary")]
+    UnknownWord(String),
+    #[error("Error while reading the word list")]
+    Io(String),
+}
@@ -0,0 +1,131 @@
+use super::{Dictionary, WordLadderError};
+use crate::search::{SearchProblem, Successor};
+
+/// [`WordLadderProblem`]
+/// ## Word ladder puzzle.
+/// Turn `start` into `goal` changing one letter at a time, every intermediate word must be
+/// in the dictionary. Each change costs 1.
+/// # Example
+/// ```
+/// # use algoritmos_rust::word_ladder::*;
+/// # use algoritmos_rust::search::a_star;
+/// let dictionary = Dictionary::from_words(["cold", "cord", "card", "ward", "warm", "word", "worm"]);
+/// let problem = WordLadderProblem::new(&dictionary, "cold", "warm").unwrap();
+/// let solution = a_star(&problem, |word| problem.heuristic(word)).unwrap();
+/// assert_eq!(solution.states, vec!["cold", "cord", "card", "ward", "warm"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WordLadderProblem<'a> {
+    dictionary: &'a Dictionary,
+    start: String,
+    goal: String,
+}
+
+impl<'a> WordLadderProblem<'a> {
+    pub fn new(
+        dictionary: &'a Dictionary,
+        start: &str,
+        goal: &str,
+    ) -> Result<Self, WordLadderError> {
+        let (start, goal) = (start.trim().to_lowercase(), goal.trim().to_lowercase());
+        if start.len() != goal.len() {
+            return Err(WordLadderError::DifferentLengths(start.len(), goal.len()));
+        }
+        for word in [&start, &goal] {
+            if !dictionary.contains(word) {
+                return Err(WordLadderError::UnknownWord(word.to_string()));
+            }
+        }
+        Ok(Self {
+            dictionary,
+            start,
+            goal,
+        })
+    }
+
+    /// [`heuristic`]
+    /// Hamming distance to the goal: how many letters are still different.
+    /// Each move changes a single letter, so it never overestimates.
+    pub fn heuristic(&self, word: &str) -> f64 {
+        hamming_distance(word, &self.goal) as f64
+    }
+}
+
+pub fn hamming_distance(a: &str, b: &str) -> usize {
+    a.chars().zip(b.chars()).filter(|(x, y)| x != y).count()
+}
+
+impl SearchProblem for WordLadderProblem<'_> {
+    type State = String;
+    type Action = String;
+    type Cost = u32;
+    type Successors<'a>
+        = std::vec::IntoIter<Successor<String, String>>
+    where
+        Self: 'a;
+
+    fn initial_state(&self) -> String {
+        self.start.clone()
+    }
+
+    fn is_goal(&self, state: &String) -> bool {
+        *state == self.goal
+    }
+
+    fn successors<'a>(&'a self, state: &'a String) -> Self::Successors<'a> {
+        self.dictionary
+            .neighbors(state)
+            .into_iter()
+            .map(|word| Successor::new(word.to_string(), word.to_string(), 1))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::search::a_star;
+
+    fn dictionary() -> Dictionary {
+        Dictionary::from_words(["hit", "hot", "dot", "dog", "cog", "lot", "log"])
+    }
+
+    #[test]
+    fn word_ladder_new_returns_error_when_words_are_invalid() {
+        let dictionary = dictionary();
+
+        assert_eq!(
+            WordLadderProblem::new(&dictionary, "hit", "dogs").unwrap_err(),
+            WordLadderError::DifferentLengths(3, 4)
+        );
+        assert_eq!(
+            WordLadderProblem::new(&dictionary, "hit", "zzz").unwrap_err(),
+            WordLadderError::UnknownWord("zzz".into())
+        );
+    }
+
+    #[test]
+    fn word_ladder_a_star_finds_shortest_ladder() {
+        let dictionary = dictionary();
+        let problem = WordLadderProblem::new(&dictionary, "hit", "cog").unwrap();
+
+        let solution = a_star(&problem, |word| problem.heuristic(word)).unwrap();
+
+        assert_eq!(solution.cost, 4);
+        assert_eq!(solution.states.first().unwrap(), "hit");
+        assert_eq!(solution.goal(), "cog");
+        solution
+            .states
+            .windows(2)
+            .for_each(|w| assert_eq!(hamming_distance(&w[0], &w[1]), 1));
+    }
+
+    #[test]
+    fn word_ladder_returns_none_when_no_ladder_exists() {
+        let dictionary = Dictionary::from_words(["abc", "xyz"]);
+        let problem = WordLadderProblem::new(&dictionary, "abc", "xyz").unwrap();
+
+        assert!(a_star(&problem, |word| problem.heuristic(word)).is_none());
+    }
+}